@@ -0,0 +1,397 @@
+//! Archive bundle sources in the cache
+//!
+//! Bundles published as `.tar.gz` or `.zip` archives are downloaded (or read,
+//! for `file://` URLs), hashed, and extracted once per URL and content hash:
+//!
+//! ```text
+//! AUGENT_CACHE_DIR/archives/
+//! └── <url_key>/            # Path-safe archive URL
+//!     └── <content_hash>/   # BLAKE3 of the archive bytes
+//!         └── <name>/       # Extracted content, named after the archive file
+//! ```
+//!
+//! When an archive holds a single top-level directory, as release tarballs
+//! usually do, that directory's content is used as the archive root. The
+//! extracted `<name>` directory is then resolved like a local directory
+//! bundle. In offline mode, remote archives come from the newest extracted
+//! entry of their URL, and an extraction whose content matches the lockfile
+//! is reused without downloading the archive again.
+//!
+//! Downloads time out after [`FETCH_TIMEOUT`] and archives larger than
+//! [`MAX_ARCHIVE_SIZE`] are rejected.
+
+use std::collections::HashMap;
+use std::io::{Cursor, Read};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::error::{AugentError, Result};
+
+use super::lookup::{FetchOptions, offline_error};
+use super::paths::{bundle_name_to_cache_key, cache_dir};
+
+/// Archive cache directory name (inside the cache directory)
+pub const ARCHIVES_DIR: &str = "archives";
+
+/// How long to wait for an archive download
+pub const FETCH_TIMEOUT: Duration = Duration::from_secs(300);
+
+/// Largest archive that is downloaded or read, in bytes
+pub const MAX_ARCHIVE_SIZE: u64 = 512 * 1024 * 1024;
+
+/// Locked content hashes of archive bundles, by archive URL and subdirectory
+pub type LockedArchives = HashMap<(String, Option<String>), String>;
+
+/// Compression and container format of an archive
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArchiveFormat {
+    /// Gzip-compressed tar (`.tar.gz`, `.tgz`)
+    TarGz,
+    /// Zip (`.zip`)
+    Zip,
+}
+
+impl ArchiveFormat {
+    /// Format indicated by the file extension of `url`, ignoring query and fragment
+    pub fn from_url(url: &str) -> Option<Self> {
+        Self::split(url_file_name(url)).map(|(format, _)| format)
+    }
+
+    /// Format and stem of an archive file name
+    fn split(file_name: &str) -> Option<(Self, &str)> {
+        let path = Path::new(file_name);
+        let ext = path.extension()?;
+        let stem = path.file_stem()?.to_str()?;
+        if ext.eq_ignore_ascii_case("zip") {
+            return Some((Self::Zip, stem));
+        }
+        if ext.eq_ignore_ascii_case("tgz") {
+            return Some((Self::TarGz, stem));
+        }
+        let inner = Path::new(stem);
+        let is_tar = inner
+            .extension()
+            .is_some_and(|inner_ext| inner_ext.eq_ignore_ascii_case("tar"));
+        if !ext.eq_ignore_ascii_case("gz") || !is_tar {
+            return None;
+        }
+        Some((Self::TarGz, inner.file_stem()?.to_str()?))
+    }
+}
+
+/// Last segment of the path of `url`, ignoring query and fragment
+fn url_file_name(url: &str) -> &str {
+    let path = url.split(['?', '#']).next().unwrap_or(url);
+    path.rsplit('/').next().unwrap_or(path)
+}
+
+/// Get the archives cache directory path
+pub fn archives_cache_dir() -> Result<PathBuf> {
+    Ok(cache_dir()?.join(ARCHIVES_DIR))
+}
+
+/// Whether `path` lies inside the archives cache
+///
+/// Extracted archives live outside the workspace, so local path checks that
+/// keep directory bundles within the workspace do not apply to them.
+pub fn is_extracted_archive(path: &Path) -> bool {
+    archives_cache_dir().is_ok_and(|dir| {
+        let dir = std::fs::canonicalize(&dir).unwrap_or(dir);
+        path.starts_with(dunce::simplified(&dir))
+    })
+}
+
+/// Directory holding a locked archive bundle: its newest extraction in the cache
+///
+/// Falls back to the archive's cache entry when it has not been extracted yet.
+pub fn cached_archive_path(url: &str, path: Option<&str>) -> PathBuf {
+    let url_dir = archives_cache_dir()
+        .unwrap_or_default()
+        .join(bundle_name_to_cache_key(url));
+    match newest_entry(&url_dir, &archive_name(url)) {
+        Some(root) => path.map_or_else(|| root.clone(), |p| root.join(p)),
+        None => url_dir,
+    }
+}
+
+/// Bundle name of an archive: its file name without the archive extension
+pub fn archive_name(url: &str) -> String {
+    let file_name = url_file_name(url);
+    let stem = ArchiveFormat::split(file_name).map_or(file_name, |(_, stem)| stem);
+    let name = bundle_name_to_cache_key(stem);
+    if name.is_empty() || name == "unknown" {
+        "bundle".to_string()
+    } else {
+        name
+    }
+}
+
+/// Fetch and extract the archive at `url`, returning its extracted root
+///
+/// Archives without a recognizable extension are read as `.tar.gz`.
+pub fn cache_archive(url: &str, options: FetchOptions) -> Result<PathBuf> {
+    let url_dir = archives_cache_dir()?.join(bundle_name_to_cache_key(url));
+    let name = archive_name(url);
+    let is_remote = !url.starts_with("file://");
+
+    if is_remote && options.offline {
+        return newest_entry(&url_dir, &name).ok_or_else(|| offline_error(url, None));
+    }
+
+    let bytes = fetch(url)?;
+    let hash = blake3::hash(&bytes).to_hex().to_string();
+    let target = url_dir.join(&hash).join(&name);
+    if target.is_dir() {
+        return Ok(target);
+    }
+
+    let failed = |reason: String| AugentError::CacheOperationFailed {
+        message: format!("Failed to extract archive {url}: {reason}"),
+    };
+    std::fs::create_dir_all(&url_dir).map_err(|e| failed(e.to_string()))?;
+    let temp = tempfile::TempDir::new_in(&url_dir).map_err(|e| failed(e.to_string()))?;
+    let format = ArchiveFormat::from_url(url).unwrap_or(ArchiveFormat::TarGz);
+    extract(&bytes, format, temp.path()).map_err(failed)?;
+
+    let root = single_top_level_dir(temp.path()).unwrap_or_else(|| temp.path().to_path_buf());
+    std::fs::create_dir_all(url_dir.join(&hash)).map_err(|e| failed(e.to_string()))?;
+    // A concurrent install may have extracted the same content first
+    if std::fs::rename(&root, &target).is_err() && !target.is_dir() {
+        return Err(failed(format!("could not move into {}", target.display())));
+    }
+    Ok(target)
+}
+
+/// Extracted root of `url` whose bundle at `path` has the locked content `hash`
+///
+/// Lets an install that matches the lockfile skip downloading the archive.
+pub fn locked_archive_root(url: &str, path: Option<&str>, hash: &str) -> Option<PathBuf> {
+    let url_dir = archives_cache_dir()
+        .ok()?
+        .join(bundle_name_to_cache_key(url));
+    let name = archive_name(url);
+    std::fs::read_dir(url_dir)
+        .ok()?
+        .filter_map(std::result::Result::ok)
+        .map(|entry| entry.path().join(&name))
+        .find(|root| {
+            let dir = path.map_or_else(|| root.clone(), |p| root.join(p));
+            dir.is_dir() && crate::hash::hash_directory(&dir).is_ok_and(|h| h == hash)
+        })
+}
+
+/// Read the archive at `url`, failing on timeout or above [`MAX_ARCHIVE_SIZE`]
+fn fetch(url: &str) -> Result<Vec<u8>> {
+    let read_failed = |reason: String| AugentError::FileReadFailed {
+        path: url.to_string(),
+        reason,
+    };
+    let reader: Box<dyn Read> = if let Some(path) = url.strip_prefix("file://") {
+        Box::new(std::fs::File::open(path).map_err(|e| read_failed(e.to_string()))?)
+    } else {
+        let agent = ureq::AgentBuilder::new().timeout(FETCH_TIMEOUT).build();
+        let response = agent
+            .get(url)
+            .call()
+            .map_err(|e| read_failed(e.to_string()))?;
+        response.into_reader()
+    };
+    let mut bytes = Vec::new();
+    reader
+        .take(MAX_ARCHIVE_SIZE + 1)
+        .read_to_end(&mut bytes)
+        .map_err(|e| read_failed(e.to_string()))?;
+    if bytes.len() as u64 > MAX_ARCHIVE_SIZE {
+        return Err(read_failed(format!(
+            "archive is larger than {MAX_ARCHIVE_SIZE} bytes"
+        )));
+    }
+    Ok(bytes)
+}
+
+/// Unpack `bytes` into `dir`; entries escaping `dir` are skipped or rejected
+pub(super) fn extract(
+    bytes: &[u8],
+    format: ArchiveFormat,
+    dir: &Path,
+) -> std::result::Result<(), String> {
+    match format {
+        ArchiveFormat::TarGz => tar::Archive::new(flate2::read::GzDecoder::new(bytes))
+            .unpack(dir)
+            .map_err(|e| e.to_string()),
+        ArchiveFormat::Zip => zip::ZipArchive::new(Cursor::new(bytes))
+            .and_then(|mut archive| archive.extract(dir))
+            .map_err(|e| e.to_string()),
+    }
+}
+
+pub(super) fn single_top_level_dir(dir: &Path) -> Option<PathBuf> {
+    let mut entries = std::fs::read_dir(dir)
+        .ok()?
+        .filter_map(std::result::Result::ok);
+    let first = entries.next()?;
+    if entries.next().is_some() || !first.file_type().ok()?.is_dir() {
+        return None;
+    }
+    Some(first.path())
+}
+
+/// Most recently extracted `<name>` directory among the entries of one URL
+fn newest_entry(url_dir: &Path, name: &str) -> Option<PathBuf> {
+    std::fs::read_dir(url_dir)
+        .ok()?
+        .filter_map(std::result::Result::ok)
+        .map(|entry| entry.path().join(name))
+        .filter(|path| path.is_dir())
+        .max_by_key(|path| std::fs::metadata(path).and_then(|m| m.modified()).ok())
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    const COMMAND: &str = "# Review\n";
+
+    fn write_tar_gz(path: &Path) {
+        let file = std::fs::File::create(path).expect("Failed to create archive");
+        let encoder = flate2::write::GzEncoder::new(file, flate2::Compression::default());
+        let mut builder = tar::Builder::new(encoder);
+        let mut header = tar::Header::new_gnu();
+        header.set_size(COMMAND.len() as u64);
+        header.set_mode(0o644);
+        header.set_cksum();
+        builder
+            .append_data(
+                &mut header,
+                "tools-1.0/commands/review.md",
+                COMMAND.as_bytes(),
+            )
+            .expect("Failed to add file");
+        builder
+            .into_inner()
+            .expect("Failed to finish tar")
+            .finish()
+            .expect("Failed to finish gzip");
+    }
+
+    fn write_zip(path: &Path) {
+        let file = std::fs::File::create(path).expect("Failed to create archive");
+        let mut writer = zip::ZipWriter::new(file);
+        let options = zip::write::SimpleFileOptions::default();
+        writer
+            .start_file("plugins/tools/commands/review.md", options)
+            .expect("Failed to start file");
+        writer
+            .write_all(COMMAND.as_bytes())
+            .expect("Failed to write file");
+        writer
+            .start_file("README.md", options)
+            .expect("Failed to start file");
+        writer.write_all(b"Readme\n").expect("Failed to write file");
+        writer.finish().expect("Failed to finish zip");
+    }
+
+    fn set_cache_dir(value: Option<&std::ffi::OsStr>) {
+        // SAFETY: std::env::set_var is safe in test context.
+        unsafe {
+            match value {
+                Some(dir) => std::env::set_var("AUGENT_CACHE_DIR", dir),
+                None => std::env::remove_var("AUGENT_CACHE_DIR"),
+            }
+        }
+        crate::cache::index::invalidate_index_cache();
+    }
+
+    #[test]
+    fn test_archive_format_and_name_from_url() {
+        let url = "https://example.com/releases/Tools-1.0.tar.gz?download=1";
+        assert_eq!(ArchiveFormat::from_url(url), Some(ArchiveFormat::TarGz));
+        assert_eq!(archive_name(url), "Tools-1.0");
+        assert_eq!(
+            ArchiveFormat::from_url("file:///tmp/tools.zip#plugins/tools"),
+            Some(ArchiveFormat::Zip)
+        );
+        assert_eq!(
+            ArchiveFormat::from_url("https://example.com/TOOLS.TGZ"),
+            Some(ArchiveFormat::TarGz)
+        );
+        assert_eq!(ArchiveFormat::from_url("https://example.com/tools"), None);
+        assert_eq!(
+            ArchiveFormat::from_url("https://example.com/tools.gz"),
+            None
+        );
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_resolve_tar_gz_archive() {
+        let temp = tempfile::TempDir::new_in(crate::temp::temp_dir_base())
+            .expect("Failed to create temp dir");
+        let archive = temp.path().join("tools.tar.gz");
+        write_tar_gz(&archive);
+        let source = format!("file://{}", archive.display());
+
+        let original = std::env::var_os("AUGENT_CACHE_DIR");
+        set_cache_dir(Some(temp.path().join("cache").as_os_str()));
+        let first = cache_archive(&source, FetchOptions::default());
+        let second = cache_archive(&source, FetchOptions::default());
+        let resolved = crate::resolver::Resolver::new(temp.path()).resolve(&source, false);
+        let locked_path = cached_archive_path(&source, None);
+        let locked_root = first.as_ref().ok().and_then(|root| {
+            let hash = crate::hash::hash_directory(root).ok()?;
+            locked_archive_root(&source, None, &hash)
+        });
+        let stale_root = locked_archive_root(&source, None, "blake3:stale");
+        set_cache_dir(original.as_deref());
+
+        // The single top-level directory is stripped
+        let root = first.expect("Failed to cache archive");
+        assert_eq!(
+            std::fs::read_to_string(root.join("commands/review.md")).expect("Missing command"),
+            COMMAND
+        );
+        assert_eq!(second.expect("Failed to reuse archive"), root);
+
+        let bundles = resolved.expect("Failed to resolve archive");
+        assert_eq!(bundles.len(), 1);
+        assert_eq!(bundles[0].name, "tools");
+        assert!(bundles[0].git_source.is_none());
+        assert!(bundles[0].source_path.join("commands/review.md").is_file());
+        assert_eq!(
+            bundles[0].artifact_source,
+            Some(crate::domain::ArtifactSource::Archive {
+                url: source.clone(),
+                path: None,
+            })
+        );
+        assert_eq!(locked_path, root);
+        assert_eq!(locked_root, Some(root));
+        assert_eq!(stale_root, None);
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_resolve_zip_archive_subpath() {
+        let temp = tempfile::TempDir::new_in(crate::temp::temp_dir_base())
+            .expect("Failed to create temp dir");
+        let archive = temp.path().join("plugins.zip");
+        write_zip(&archive);
+        let source = format!("file://{}#plugins/tools", archive.display());
+
+        let original = std::env::var_os("AUGENT_CACHE_DIR");
+        set_cache_dir(Some(temp.path().join("cache").as_os_str()));
+        let resolved = crate::resolver::Resolver::new(temp.path()).resolve(&source, false);
+        set_cache_dir(original.as_deref());
+
+        let bundles = resolved.expect("Failed to resolve archive");
+        assert_eq!(bundles.len(), 1);
+        assert_eq!(bundles[0].name, "tools");
+        assert_eq!(
+            std::fs::read_to_string(bundles[0].source_path.join("commands/review.md"))
+                .expect("Missing command"),
+            COMMAND
+        );
+    }
+}
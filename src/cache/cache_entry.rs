@@ -7,15 +7,19 @@ use std::path::PathBuf;
 
 use crate::config::marketplace::operations;
 use crate::error::{AugentError, Result};
-use crate::git;
 use crate::source::GitSource;
 
 use super::{
-    clone::clone_and_checkout, lookup::marketplace_plugin_name, populate::ensure_bundle_cached,
+    clone::clone_and_checkout,
+    lookup::{FetchOptions, marketplace_plugin_name},
+    populate::ensure_bundle_cached,
 };
 
 /// Try to get bundle from cache, checking both resolved SHA and resolving refs if needed.
-fn try_get_from_cache(source: &GitSource) -> Result<Option<(PathBuf, String, Option<String>)>> {
+fn try_get_from_cache(
+    source: &GitSource,
+    options: FetchOptions,
+) -> Result<Option<(PathBuf, String, Option<String>)>> {
     if let Some(sha) = &source.resolved_sha {
         if let Some((path, _, ref_name)) = super::lookup::get_cached(source)? {
             return Ok(Some((path, sha.clone(), ref_name)));
@@ -23,7 +27,7 @@ fn try_get_from_cache(source: &GitSource) -> Result<Option<(PathBuf, String, Opt
         return Ok(None);
     }
 
-    if let Ok(sha) = git::ls_remote(&source.url, source.git_ref.as_deref()) {
+    if let Ok(sha) = super::lookup::resolve_sha(&source.url, source.git_ref.as_deref(), options) {
         let source_with_sha = GitSource {
             url: source.url.clone(),
             path: source.path.clone(),
@@ -45,9 +49,8 @@ fn prepare_marketplace_bundle(
     temp_dir: &tempfile::TempDir,
 ) -> Result<(String, PathBuf, Option<tempfile::TempDir>)> {
     let bundle_name = super::bundle_name::derive_marketplace_bundle_name(&source.url, plugin_name);
-    let base = crate::temp::temp_dir_base();
     let synthetic_temp =
-        tempfile::TempDir::new_in(&base).map_err(|e| AugentError::CacheOperationFailed {
+        crate::temp::temp_dir().map_err(|e| AugentError::CacheOperationFailed {
             message: format!("Failed to create temp directory: {e}"),
         })?;
     operations::create_synthetic_bundle_to(
@@ -94,7 +97,9 @@ fn try_get_existing_cache_entry(
         None => path_opt_str.map_or_else(|| resources.clone(), |p| resources.join(p)),
     };
 
-    Ok(content.is_dir().then_some(content))
+    let corrupted =
+        super::verify::verify_cache_entry(&entry_path) == super::verify::EntryIntegrity::Corrupted;
+    Ok((content.is_dir() && !corrupted).then_some(content))
 }
 
 /// Cache a bundle by cloning from a git source (or use existing cache).
@@ -102,14 +107,17 @@ fn try_get_existing_cache_entry(
 /// Returns (`resources_path`, sha, `resolved_ref`).
 /// When `resolved_sha` is None, resolves ref via ls-remote first so we can check cache without cloning.
 #[allow(dead_code)]
-pub fn cache_bundle(source: &GitSource) -> Result<(PathBuf, String, Option<String>)> {
+pub fn cache_bundle(
+    source: &GitSource,
+    options: FetchOptions,
+) -> Result<(PathBuf, String, Option<String>)> {
     use super::populate::BundleCacheMetadata;
 
-    if let Some(result) = try_get_from_cache(source)? {
+    if let Some(result) = try_get_from_cache(source, options)? {
         return Ok(result);
     }
 
-    let (temp_dir, sha, resolved_ref) = clone_and_checkout(source)?;
+    let (temp_dir, sha, resolved_ref) = clone_and_checkout(source, options)?;
     let path_opt_str = source.path.as_deref();
 
     let (bundle_name, content_path, _synthetic_guard) =
@@ -149,4 +157,56 @@ mod tests {
         let path = bundle_name::content_path_in_repo(repo_path, &source);
         assert_eq!(path, PathBuf::from("/cache/repo"));
     }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_offline_cache_bundle_fails_without_network_for_uncached_source() {
+        let temp = tempfile::TempDir::new_in(crate::temp::temp_dir_base())
+            .expect("Failed to create temp directory");
+        let original = std::env::var("AUGENT_CACHE_DIR").ok();
+        // SAFETY: std::env::set_var is safe in test context.
+        unsafe {
+            std::env::set_var("AUGENT_CACHE_DIR", temp.path());
+        }
+        super::super::index::invalidate_index_cache();
+
+        // An unresolvable host: reaching the network would fail with a git error instead
+        let unpinned = GitSource {
+            url: "https://augent.invalid/author/repo.git".to_string(),
+            path: None,
+            git_ref: Some("main".to_string()),
+            resolved_sha: None,
+        };
+        let pinned = GitSource {
+            resolved_sha: Some("0123456789abcdef0123456789abcdef01234567".to_string()),
+            ..unpinned.clone()
+        };
+
+        let options = FetchOptions {
+            offline: true,
+            ..FetchOptions::default()
+        };
+        let results = [
+            cache_bundle(&unpinned, options),
+            cache_bundle(&pinned, options),
+        ];
+
+        for result in results {
+            match result {
+                Err(AugentError::BundleNotFound { name }) => {
+                    assert!(name.contains("https://augent.invalid/author/repo.git@main"));
+                    assert!(name.contains("offline"));
+                }
+                other => panic!("Expected offline BundleNotFound, got {other:?}"),
+            }
+        }
+
+        unsafe {
+            match original {
+                Some(o) => std::env::set_var("AUGENT_CACHE_DIR", o),
+                None => std::env::remove_var("AUGENT_CACHE_DIR"),
+            }
+        }
+        super::super::index::invalidate_index_cache();
+    }
 }
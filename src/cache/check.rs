@@ -0,0 +1,340 @@
+//! Consistency of the cache index with the cache on disk (`augent doctor --check-cache`)
+//!
+//! The index (`bundles/.augent_cache_index.json`) and the entry directories
+//! are written separately, so an interrupted install or a manual cleanup can
+//! leave them out of step. The check reports:
+//!
+//! - index entries whose entry directory no longer exists
+//! - entry directories that no index entry points to
+//! - entries without a `.augent_bundle_name` file
+//! - entries without a `.augent_ref` file although the index records a ref
+//!
+//! Repairing removes stale index entries, re-indexes entries whose repository
+//! URL can be read from the `origin` remote of their clone, and rewrites the
+//! missing marker files from the index.
+
+use std::collections::HashSet;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::error::{AugentError, Result};
+
+use super::clone::write_ref_to_cache;
+use super::index::{IndexEntry, read_index, write_index};
+use super::paths::{
+    BUNDLE_NAME_FILE, REF_FILE, bundles_cache_dir, entry_repository_path, repo_cache_entry_path,
+    repo_name_from_url,
+};
+
+/// A mismatch between the cache index and the cache directory
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CacheIssue {
+    /// Index entry whose entry directory does not exist
+    StaleIndexEntry {
+        bundle_name: String,
+        sha: String,
+        path: PathBuf,
+    },
+    /// Entry directory with no index entry
+    UnindexedEntry { path: PathBuf },
+    /// Entry directory without a bundle name file
+    MissingBundleName { path: PathBuf },
+    /// Entry directory without a ref file for a ref recorded in the index
+    MissingRef { path: PathBuf, resolved_ref: String },
+}
+
+impl fmt::Display for CacheIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::StaleIndexEntry {
+                bundle_name,
+                sha,
+                path,
+            } => write!(
+                f,
+                "index entry {bundle_name} ({}) points to missing {}",
+                short_sha(sha),
+                path.display()
+            ),
+            Self::UnindexedEntry { path } => write!(f, "{} is not in the index", path.display()),
+            Self::MissingBundleName { path } => {
+                write!(f, "{} has no {BUNDLE_NAME_FILE}", path.display())
+            }
+            Self::MissingRef { path, resolved_ref } => write!(
+                f,
+                "{} has no {REF_FILE} (index records {resolved_ref})",
+                path.display()
+            ),
+        }
+    }
+}
+
+/// Compare the cache index with the entry directories on disk
+pub fn check_cache() -> Result<Vec<CacheIssue>> {
+    let bundles_dir = bundles_cache_dir()?;
+    let index = read_index()?;
+
+    let mut issues = Vec::new();
+    let mut indexed = HashSet::new();
+    let mut missing_ref = HashSet::new();
+    for entry in &index {
+        let path = repo_cache_entry_path(&entry.url, &entry.sha)?;
+        if !path.is_dir() {
+            issues.push(CacheIssue::StaleIndexEntry {
+                bundle_name: entry.bundle_name.clone(),
+                sha: entry.sha.clone(),
+                path,
+            });
+            continue;
+        }
+        let ref_missing = entry.resolved_ref.is_some() && !path.join(REF_FILE).is_file();
+        if ref_missing && missing_ref.insert(path.clone()) {
+            issues.push(CacheIssue::MissingRef {
+                path: path.clone(),
+                resolved_ref: entry.resolved_ref.clone().unwrap_or_default(),
+            });
+        }
+        indexed.insert(path);
+    }
+
+    if !bundles_dir.is_dir() {
+        return Ok(issues);
+    }
+    for path in entry_dirs(&bundles_dir)? {
+        if !indexed.contains(&path) {
+            issues.push(CacheIssue::UnindexedEntry { path: path.clone() });
+        }
+        if !path.join(BUNDLE_NAME_FILE).is_file() {
+            issues.push(CacheIssue::MissingBundleName { path });
+        }
+    }
+    Ok(issues)
+}
+
+/// Repair the given issues; returns the issues that could not be repaired
+///
+/// An unindexed entry is only re-indexed when the `origin` remote of its
+/// clone maps back to the same entry directory; legacy per-bundle entries
+/// are left for `augent cache migrate`.
+pub fn fix_cache_issues(issues: &[CacheIssue]) -> Result<Vec<CacheIssue>> {
+    let mut index = read_index()?;
+    let before = index.len();
+    let mut unfixed = Vec::new();
+
+    let has_stale = issues
+        .iter()
+        .any(|issue| matches!(issue, CacheIssue::StaleIndexEntry { .. }));
+    if has_stale {
+        index.retain(|entry| {
+            repo_cache_entry_path(&entry.url, &entry.sha).is_ok_and(|p| p.is_dir())
+        });
+    }
+    let pruned = index.len();
+
+    for issue in issues {
+        let CacheIssue::UnindexedEntry { path } = issue else {
+            continue;
+        };
+        match reindex_entry(path) {
+            Some(entry) => index.push(entry),
+            None => unfixed.push(issue.clone()),
+        }
+    }
+    if index.len() != before || index.len() != pruned {
+        write_index(&index)?;
+    }
+
+    for issue in issues {
+        match issue {
+            CacheIssue::MissingBundleName { path } => match index_entry_for(&index, path)? {
+                Some(entry) => write_bundle_name(path, &entry.bundle_name)?,
+                None => unfixed.push(issue.clone()),
+            },
+            CacheIssue::MissingRef { path, resolved_ref } => {
+                write_ref_to_cache(path, resolved_ref)?;
+            }
+            CacheIssue::StaleIndexEntry { .. } | CacheIssue::UnindexedEntry { .. } => {}
+        }
+    }
+    Ok(unfixed)
+}
+
+/// Index entry for an entry directory found on disk, from its clone's `origin` remote
+fn reindex_entry(path: &Path) -> Option<IndexEntry> {
+    let sha = path.file_name()?.to_str()?.to_string();
+    let repo = git2::Repository::open(entry_repository_path(path)).ok()?;
+    let remote = repo.find_remote("origin").ok()?;
+    let url = remote.url()?.to_string();
+    if repo_cache_entry_path(&url, &sha).ok()? != path {
+        return None;
+    }
+    let bundle_name = fs::read_to_string(path.join(BUNDLE_NAME_FILE))
+        .map_or_else(|_| repo_name_from_url(&url), |name| name.trim().to_string());
+    Some(IndexEntry {
+        url,
+        sha,
+        path: None,
+        bundle_name,
+        resolved_ref: None,
+    })
+}
+
+/// Repo-level index entry (no sub-bundle path) for an entry directory, else any entry for it
+fn index_entry_for<'a>(index: &'a [IndexEntry], path: &Path) -> Result<Option<&'a IndexEntry>> {
+    let mut found = None;
+    for entry in index {
+        if repo_cache_entry_path(&entry.url, &entry.sha)? != path {
+            continue;
+        }
+        if entry.path.is_none() {
+            return Ok(Some(entry));
+        }
+        found = found.or(Some(entry));
+    }
+    Ok(found)
+}
+
+fn write_bundle_name(path: &Path, bundle_name: &str) -> Result<()> {
+    let name_file = path.join(BUNDLE_NAME_FILE);
+    fs::write(&name_file, bundle_name).map_err(|e| AugentError::CacheOperationFailed {
+        message: format!(
+            "Failed to write bundle name file {}: {}",
+            name_file.display(),
+            e
+        ),
+    })
+}
+
+/// Entry directories (`<key>/<sha>`) under the bundles directory
+fn entry_dirs(bundles_dir: &Path) -> Result<Vec<PathBuf>> {
+    let mut dirs = Vec::new();
+    for key_dir in subdirectories(bundles_dir)? {
+        dirs.extend(subdirectories(&key_dir)?);
+    }
+    Ok(dirs)
+}
+
+fn subdirectories(dir: &Path) -> Result<Vec<PathBuf>> {
+    let entries = fs::read_dir(dir).map_err(|e| AugentError::CacheOperationFailed {
+        message: format!("Failed to read cache directory {}: {e}", dir.display()),
+    })?;
+    let mut dirs: Vec<PathBuf> = entries
+        .filter_map(std::result::Result::ok)
+        .map(|e| e.path())
+        .filter(|p| p.is_dir())
+        .collect();
+    dirs.sort();
+    Ok(dirs)
+}
+
+fn short_sha(sha: &str) -> &str {
+    sha.get(..7).unwrap_or(sha)
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::*;
+    use crate::cache::index::invalidate_index_cache;
+    use serial_test::serial;
+
+    const SHA: &str = "0123456789abcdef0123456789abcdef01234567";
+
+    fn index_entry(url: &str, resolved_ref: Option<&str>) -> IndexEntry {
+        IndexEntry {
+            url: url.to_string(),
+            sha: SHA.to_string(),
+            path: None,
+            bundle_name: repo_name_from_url(url),
+            resolved_ref: resolved_ref.map(str::to_string),
+        }
+    }
+
+    #[test]
+    #[serial]
+    fn test_check_cache_detects_and_fixes_index_drift() {
+        let temp = tempfile::TempDir::new_in(crate::temp::temp_dir_base())
+            .expect("Failed to create temp directory");
+        let original = std::env::var("AUGENT_CACHE_DIR").ok();
+        // SAFETY: std::env::set_var is safe in test context.
+        unsafe {
+            std::env::set_var("AUGENT_CACHE_DIR", temp.path());
+        }
+        invalidate_index_cache();
+
+        let indexed_url = "https://github.com/author/indexed.git";
+        let unindexed_url = "https://github.com/author/unindexed.git";
+        let removed_url = "https://github.com/author/removed.git";
+
+        // Indexed entry with a recorded ref but no ref file
+        let indexed = repo_cache_entry_path(indexed_url, SHA).expect("Failed to get entry path");
+        fs::create_dir_all(&indexed).expect("Failed to create entry");
+        write_bundle_name(&indexed, &repo_name_from_url(indexed_url))
+            .expect("Failed to write bundle name");
+
+        // Entry on disk that the index lost, without a bundle name file
+        let unindexed =
+            repo_cache_entry_path(unindexed_url, SHA).expect("Failed to get entry path");
+        let repository = entry_repository_path(&unindexed);
+        fs::create_dir_all(&repository).expect("Failed to create repository dir");
+        git2::Repository::init(&repository)
+            .and_then(|repo| repo.remote("origin", unindexed_url).map(|_| ()))
+            .expect("Failed to init repository");
+
+        write_index(&[
+            index_entry(indexed_url, Some("main")),
+            index_entry(removed_url, None),
+        ])
+        .expect("Failed to write index");
+
+        let issues = check_cache().expect("Failed to check cache");
+        assert_eq!(issues.len(), 4, "{issues:?}");
+        assert!(issues.contains(&CacheIssue::MissingRef {
+            path: indexed.clone(),
+            resolved_ref: "main".to_string(),
+        }));
+        assert!(issues.contains(&CacheIssue::UnindexedEntry {
+            path: unindexed.clone()
+        }));
+        assert!(issues.contains(&CacheIssue::MissingBundleName {
+            path: unindexed.clone()
+        }));
+        assert!(issues.iter().any(
+            |issue| matches!(issue, CacheIssue::StaleIndexEntry { bundle_name, .. }
+                    if *bundle_name == repo_name_from_url(removed_url))
+        ));
+
+        let unfixed = fix_cache_issues(&issues).expect("Failed to fix cache");
+        assert!(unfixed.is_empty(), "{unfixed:?}");
+        assert!(check_cache().expect("Failed to check cache").is_empty());
+
+        let urls: Vec<String> = read_index()
+            .expect("Failed to read index")
+            .into_iter()
+            .map(|e| e.url)
+            .collect();
+        assert_eq!(
+            urls,
+            vec![indexed_url.to_string(), unindexed_url.to_string()]
+        );
+        assert_eq!(
+            fs::read_to_string(unindexed.join(BUNDLE_NAME_FILE)).expect("Failed to read name"),
+            repo_name_from_url(unindexed_url)
+        );
+        assert_eq!(
+            fs::read_to_string(indexed.join(REF_FILE)).expect("Failed to read ref"),
+            "main"
+        );
+
+        // SAFETY: std::env::set_var is safe in test context.
+        unsafe {
+            if let Some(value) = original {
+                std::env::set_var("AUGENT_CACHE_DIR", value);
+            } else {
+                std::env::remove_var("AUGENT_CACHE_DIR");
+            }
+        }
+        invalidate_index_cache();
+    }
+}
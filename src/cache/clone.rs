@@ -7,24 +7,64 @@ use std::fs;
 use std::path::Path;
 
 use crate::error::{AugentError, Result};
-use crate::git;
+use crate::git::{self, CloneDepth};
 use crate::source::GitSource;
 
-/// File name for storing the resolved ref (repository has detached HEAD after checkout)
-const REF_FILE: &str = ".augent_ref";
+use super::paths::REF_FILE;
+
+/// Depth of the first clone attempt: full with `--full-clone`, else from `AUGENT_CLONE_DEPTH`
+fn clone_depth(options: super::FetchOptions) -> CloneDepth {
+    if options.full_clone {
+        CloneDepth::Full
+    } else {
+        CloneDepth::from_env()
+    }
+}
 
 /// Clone and checkout to a temp directory; returns (`temp_dir`, sha, `resolved_ref`).
 /// Caller must keep `temp_dir` alive until done using the path.
+/// Fails without cloning in offline mode.
+///
+/// A ref that cannot be resolved in a partial clone (for example a tag on
+/// an older commit) is retried once with a full-history clone.
 pub fn clone_and_checkout(
     source: &GitSource,
+    options: super::FetchOptions,
 ) -> Result<(tempfile::TempDir, String, Option<String>)> {
-    let base = crate::temp::temp_dir_base();
-    let temp_dir =
-        tempfile::TempDir::new_in(&base).map_err(|e| AugentError::CacheOperationFailed {
-            message: format!("Failed to create temp directory: {e}"),
-        })?;
+    if options.offline {
+        return Err(super::lookup::offline_error(
+            &source.url,
+            source.git_ref.as_deref(),
+        ));
+    }
+    clone_with_fallback(source, clone_depth(options), clone_at_depth)
+}
 
-    let repo = git::clone(&source.url, temp_dir.path(), true)?;
+/// Run `clone` at `depth`, retrying once at full depth when the ref is not in the partial clone
+fn clone_with_fallback(
+    source: &GitSource,
+    depth: CloneDepth,
+    clone: impl Fn(&GitSource, CloneDepth) -> Result<(tempfile::TempDir, String, Option<String>)>,
+) -> Result<(tempfile::TempDir, String, Option<String>)> {
+    match clone(source, depth) {
+        Err(AugentError::GitRefResolveFailed { .. } | AugentError::GitCheckoutFailed { .. })
+            if depth != CloneDepth::Full =>
+        {
+            clone(source, CloneDepth::Full)
+        }
+        result => result,
+    }
+}
+
+fn clone_at_depth(
+    source: &GitSource,
+    depth: CloneDepth,
+) -> Result<(tempfile::TempDir, String, Option<String>)> {
+    let temp_dir = crate::temp::temp_dir().map_err(|e| AugentError::CacheOperationFailed {
+        message: format!("Failed to create temp directory: {e}"),
+    })?;
+
+    let repo = git::clone(&source.url, temp_dir.path(), depth)?;
 
     let resolved_ref = if source.git_ref.is_none() {
         git::get_head_ref_name(&repo)?
@@ -48,7 +88,6 @@ fn read_ref_from_cache(repo_path: &std::path::Path) -> Option<String> {
 }
 
 /// Write ref to cache.
-#[allow(dead_code)]
 pub fn write_ref_to_cache(repo_path: &Path, ref_name: &str) -> Result<()> {
     let ref_path = repo_path.join(REF_FILE);
     fs::write(&ref_path, ref_name).map_err(|e| AugentError::CacheOperationFailed {
@@ -69,6 +108,15 @@ mod tests {
         assert!(read_ref_from_cache(temp.path()).is_none());
     }
 
+    #[test]
+    fn test_full_clone_option_clones_full_history() {
+        let options = crate::cache::FetchOptions {
+            full_clone: true,
+            ..crate::cache::FetchOptions::default()
+        };
+        assert_eq!(clone_depth(options), CloneDepth::Full);
+    }
+
     #[test]
     fn test_write_read_ref() {
         let temp = tempfile::TempDir::new().unwrap_or_else(|e| {
@@ -81,4 +129,108 @@ mod tests {
         assert_eq!(read_ref_from_cache(temp.path()), Some("main".to_string()));
         assert!(ref_path.exists());
     }
+
+    fn commit_file(repo: &git2::Repository, content: &str) -> git2::Oid {
+        let workdir = repo.workdir().expect("Repository has no workdir");
+        fs::write(workdir.join("README.md"), content).expect("Failed to write file");
+        let mut index = repo.index().expect("Failed to open index");
+        index
+            .add_path(Path::new("README.md"))
+            .expect("Failed to stage file");
+        index.write().expect("Failed to write index");
+        let tree = repo
+            .find_tree(index.write_tree().expect("Failed to write tree"))
+            .expect("Failed to find tree");
+        let signature =
+            git2::Signature::now("Augent", "augent@example.com").expect("Failed to sign");
+        let parent = repo.head().ok().and_then(|h| h.peel_to_commit().ok());
+        let parents: Vec<&git2::Commit> = parent.iter().collect();
+        repo.commit(
+            Some("HEAD"),
+            &signature,
+            &signature,
+            content,
+            &tree,
+            &parents,
+        )
+        .expect("Failed to commit")
+    }
+
+    /// Repository with a commit tagged `v1.0` and a newer untagged commit; returns the tagged commit
+    fn repo_with_older_tag(path: &Path) -> git2::Oid {
+        let origin = git2::Repository::init(path).expect("Failed to init repository");
+        let tagged = commit_file(&origin, "v1\n");
+        origin
+            .tag_lightweight(
+                "v1.0",
+                &origin
+                    .find_object(tagged, None)
+                    .expect("Failed to find commit"),
+                false,
+            )
+            .expect("Failed to tag");
+        let head = commit_file(&origin, "v2\n");
+        assert_ne!(tagged, head);
+        tagged
+    }
+
+    fn tag_source(path: &Path) -> GitSource {
+        GitSource {
+            url: format!("file://{}", path.display()),
+            path: None,
+            git_ref: Some("v1.0".to_string()),
+            resolved_sha: None,
+        }
+    }
+
+    #[test]
+    fn test_clone_and_checkout_tag_on_older_commit() {
+        let temp = tempfile::TempDir::new_in(crate::temp::temp_dir_base())
+            .expect("Failed to create temp directory");
+        let tagged = repo_with_older_tag(temp.path());
+
+        let (checkout, sha, resolved_ref) = clone_and_checkout(
+            &tag_source(temp.path()),
+            crate::cache::FetchOptions::default(),
+        )
+        .expect("Failed to clone tagged commit");
+
+        assert_eq!(sha, tagged.to_string());
+        assert_eq!(resolved_ref.as_deref(), Some("v1.0"));
+        assert_eq!(
+            fs::read_to_string(checkout.path().join("README.md")).expect("Failed to read file"),
+            "v1\n"
+        );
+    }
+
+    #[test]
+    fn test_shallow_clone_without_ref_retries_full_clone() {
+        let temp = tempfile::TempDir::new_in(crate::temp::temp_dir_base())
+            .expect("Failed to create temp directory");
+        let tagged = repo_with_older_tag(temp.path());
+
+        // Local clones are never shallow, so the partial clone's missing ref is simulated
+        let attempts = std::cell::RefCell::new(Vec::new());
+        let result = clone_with_fallback(
+            &tag_source(temp.path()),
+            CloneDepth::SHALLOW,
+            |source, depth| {
+                attempts.borrow_mut().push(depth);
+                match depth {
+                    CloneDepth::Full => clone_at_depth(source, depth),
+                    CloneDepth::Commits(_) => Err(AugentError::GitRefResolveFailed {
+                        git_ref: "v1.0".to_string(),
+                        reason: "not in shallow history".to_string(),
+                    }),
+                }
+            },
+        );
+
+        let (_checkout, sha, _) = result.expect("Full clone retry should succeed");
+        assert_eq!(sha, tagged.to_string());
+        assert_eq!(
+            attempts.into_inner(),
+            vec![CloneDepth::SHALLOW, CloneDepth::Full]
+        );
+    }
 }
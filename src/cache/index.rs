@@ -1,11 +1,18 @@
 //! Cache index management
 //!
 //! This module handles cache index that tracks cached bundles.
+//!
+//! Entries are added under a process-wide mutex and a file lock on the
+//! index, so threads and processes caching different bundles at the same
+//! time do not drop each other's entries. The index is replaced atomically,
+//! so readers never see a partly written file.
 
 use std::fs;
+use std::io::Write;
 use std::path::{Path, PathBuf};
 use std::sync::Mutex;
 
+use fs2::FileExt;
 use serde::{Deserialize, Serialize};
 
 use crate::error::{AugentError, Result};
@@ -31,6 +38,9 @@ pub const INDEX_FILE: &str = ".augent_cache_index.json";
 /// Subdirectory for marketplace synthetic bundle content under repo-level resources
 pub const SYNTHETIC_DIR: &str = ".claude-plugin";
 
+/// Serializes index updates between threads of this process
+static INDEX_UPDATE: Mutex<()> = Mutex::new(());
+
 /// In-memory cache of index to avoid repeated disk reads during a run
 type IndexCacheState = Option<Vec<IndexEntry>>;
 static INDEX_CACHE: std::sync::OnceLock<Mutex<IndexCacheState>> = std::sync::OnceLock::new();
@@ -90,9 +100,14 @@ pub fn write_index(entries: &[IndexEntry]) -> Result<()> {
             message: format!("Failed to serialize index: {e}"),
         })?;
 
-    fs::write(&index_path, content).map_err(|e| AugentError::CacheOperationFailed {
+    let write_error = |e: std::io::Error| AugentError::CacheOperationFailed {
         message: format!("Failed to write index file {}: {}", index_path.display(), e),
-    })?;
+    };
+    let dir = index_path.parent().unwrap_or_else(|| Path::new("."));
+    let mut temp = tempfile::NamedTempFile::new_in(dir).map_err(write_error)?;
+    temp.write_all(content.as_bytes()).map_err(write_error)?;
+    temp.persist(&index_path)
+        .map_err(|e| write_error(e.error))?;
 
     invalidate_index_cache();
     Ok(())
@@ -100,11 +115,38 @@ pub fn write_index(entries: &[IndexEntry]) -> Result<()> {
 
 /// Add a new entry to the index
 pub fn add_index_entry(entry: IndexEntry) -> Result<()> {
+    let _guard = INDEX_UPDATE
+        .lock()
+        .map_err(|e| AugentError::CacheOperationFailed {
+            message: format!("Failed to acquire index update lock: {e}"),
+        })?;
+    let _lock = lock_index()?;
+
+    // Another process may have added entries since the index was last read
+    invalidate_index_cache();
     let mut entries = read_index()?;
     entries.push(entry);
     write_index(&entries)
 }
 
+/// Block until this process holds the index lock; released when the file is dropped
+fn lock_index() -> Result<fs::File> {
+    let bundles_dir = super::bundles_cache_dir()?;
+    let lock_path = bundles_dir.join(super::paths::INDEX_LOCK_FILE);
+    let lock_error = |e: std::io::Error| AugentError::CacheOperationFailed {
+        message: format!("Failed to lock cache index {}: {}", lock_path.display(), e),
+    };
+    fs::create_dir_all(&bundles_dir).map_err(lock_error)?;
+    let file = fs::OpenOptions::new()
+        .create(true)
+        .truncate(false)
+        .write(true)
+        .open(&lock_path)
+        .map_err(lock_error)?;
+    file.lock_exclusive().map_err(lock_error)?;
+    Ok(file)
+}
+
 /// Lookup entries in the index by url and sha
 pub fn index_lookup(url: &str, sha: &str) -> Vec<IndexEntry> {
     match read_index() {
@@ -116,6 +158,13 @@ pub fn index_lookup(url: &str, sha: &str) -> Vec<IndexEntry> {
     }
 }
 
+/// Lookup all entries in the index for a url
+pub fn index_lookup_url(url: &str) -> Vec<IndexEntry> {
+    read_index()
+        .map(|entries| entries.into_iter().filter(|e| e.url == url).collect())
+        .unwrap_or_default()
+}
+
 /// Check if path is a marketplace plugin
 fn marketplace_plugin_name(path: Option<&str>) -> Option<&str> {
     path.and_then(|p| p.strip_prefix("$claudeplugin/"))
@@ -10,6 +10,27 @@ use super::paths::SYNTHETIC_DIR;
 use std::path::Path;
 use std::path::PathBuf;
 
+/// How git sources are looked up and fetched
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FetchOptions {
+    /// Resolve refs to the newest cached SHA without a network check (`--prefer-cache`)
+    pub prefer_cache: bool,
+    /// Refuse any network access; git sources resolve only from the cache (`--offline`)
+    pub offline: bool,
+    /// Clone full history instead of `AUGENT_CLONE_DEPTH` (`--full-clone`)
+    pub full_clone: bool,
+}
+
+/// Error for a git source that offline mode would have to fetch
+pub fn offline_error(url: &str, git_ref: Option<&str>) -> AugentError {
+    AugentError::BundleNotFound {
+        name: format!(
+            "{url}@{} (not in cache, and offline mode forbids fetching it)",
+            git_ref.unwrap_or("HEAD")
+        ),
+    }
+}
+
 /// Extract plugin name from `\$claudeplugin`/path (e.g. "\$claudeplugin/ai-ml-toolkit" -> "ai-ml-toolkit").
 pub fn marketplace_plugin_name(path: Option<&str>) -> Option<&str> {
     path.and_then(|p| p.strip_prefix(r"\$claudeplugin/"))
@@ -47,7 +68,8 @@ fn resolve_content_path(_entry_path: &Path, resources: &Path, path_opt: Option<&
 
 /// Get a cached bundle if it exists (lookup by url, sha, path in index).
 ///
-/// Returns (`content_path`, sha, `resolved_ref`) or None if not cached.
+/// Returns (`content_path`, sha, `resolved_ref`) or None if not cached or the
+/// entry fails its checksum.
 /// Repo-level: `content_path` = resources/ or resources/<path>. `\$claudeplugin`: per-bundle entry.
 pub fn get_cached(source: &GitSource) -> Result<Option<(PathBuf, String, Option<String>)>> {
     let sha = source
@@ -66,13 +88,66 @@ pub fn get_cached(source: &GitSource) -> Result<Option<(PathBuf, String, Option<
     let resources = super::paths::entry_resources_path(&entry_path);
     let content_path = resolve_content_path(&entry_path, resources.as_path(), path_opt);
 
-    if content_path.is_dir() {
+    // A corrupted entry is a miss, so the repository is cloned again
+    let corrupted =
+        super::verify::verify_cache_entry(&entry_path) == super::verify::EntryIntegrity::Corrupted;
+    if content_path.is_dir() && !corrupted {
+        super::prune::mark_used(&entry_path);
         return Ok(Some((content_path, sha.to_string(), resolved_ref)));
     }
 
     Ok(None)
 }
 
+/// Newest cached SHA of `url` at `git_ref` (any ref when `None`)
+///
+/// Only entries whose content is still on disk count; the newest is the
+/// entry directory modified last.
+pub fn newest_cached_sha(url: &str, git_ref: Option<&str>) -> Option<String> {
+    super::index::index_lookup_url(url)
+        .into_iter()
+        .filter(|e| git_ref.is_none_or(|r| e.resolved_ref.as_deref() == Some(r)))
+        .filter_map(|e| {
+            let entry_path = super::paths::repo_cache_entry_path(url, &e.sha).ok()?;
+            let resources = super::paths::entry_resources_path(&entry_path);
+            let modified = std::fs::metadata(&resources).ok()?.modified().ok()?;
+            Some((modified, e.sha))
+        })
+        .max_by_key(|(modified, _)| *modified)
+        .map(|(_, sha)| sha)
+}
+
+/// Refs of `url` whose cached content is still on disk
+pub fn cached_refs(url: &str) -> Vec<String> {
+    super::index::index_lookup_url(url)
+        .into_iter()
+        .filter(|e| {
+            super::paths::repo_cache_entry_path(url, &e.sha)
+                .is_ok_and(|entry_path| super::paths::entry_resources_path(&entry_path).is_dir())
+        })
+        .filter_map(|e| e.resolved_ref)
+        .collect()
+}
+
+/// Resolve `git_ref` of `url` to a SHA
+///
+/// Asks the remote with `git ls-remote` (local repositories are listed
+/// directly), unless `prefer_cache` is set and a SHA for the ref is already
+/// cached, in which case the newest one is used without any network access.
+/// In offline mode the remote is never asked and a ref with no cached SHA is
+/// an error.
+pub fn resolve_sha(url: &str, git_ref: Option<&str>, options: FetchOptions) -> Result<String> {
+    if options.offline {
+        return newest_cached_sha(url, git_ref).ok_or_else(|| offline_error(url, git_ref));
+    }
+    if options.prefer_cache {
+        if let Some(sha) = newest_cached_sha(url, git_ref) {
+            return Ok(sha);
+        }
+    }
+    crate::git::remote_sha(url, git_ref)
+}
+
 #[cfg(test)]
 #[allow(clippy::expect_used)]
 mod tests {
@@ -93,4 +168,144 @@ mod tests {
         let result = index_lookup("https://github.com/test/repo", "abc123", None);
         assert!(result.is_none());
     }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_resolve_sha_prefers_newest_cached_sha() {
+        let temp = tempfile::TempDir::new_in(crate::temp::temp_dir_base())
+            .expect("Failed to create temp directory");
+        let original = std::env::var("AUGENT_CACHE_DIR").ok();
+        // SAFETY: std::env::set_var is safe in test context.
+        unsafe {
+            std::env::set_var("AUGENT_CACHE_DIR", temp.path());
+        }
+        super::super::index::invalidate_index_cache();
+
+        // An unresolvable host: any ls-remote would fail
+        let url = "https://augent.invalid/author/repo.git";
+        let old_sha = "1111111111111111111111111111111111111111";
+        let new_sha = "2222222222222222222222222222222222222222";
+        std::fs::create_dir_all(temp.path().join("bundles")).expect("Failed to create bundles");
+        for sha in [old_sha, new_sha] {
+            let entry = super::super::paths::repo_cache_entry_path(url, sha)
+                .expect("Failed to get entry path");
+            std::fs::create_dir_all(super::super::paths::entry_resources_path(&entry))
+                .expect("Failed to create resources");
+            std::thread::sleep(std::time::Duration::from_millis(20));
+            super::super::index::add_index_entry(super::super::index::IndexEntry {
+                url: url.to_string(),
+                sha: sha.to_string(),
+                path: None,
+                bundle_name: "@author/repo".to_string(),
+                resolved_ref: Some("main".to_string()),
+            })
+            .expect("Failed to add index entry");
+        }
+
+        let options = FetchOptions {
+            prefer_cache: true,
+            ..FetchOptions::default()
+        };
+        let resolved = resolve_sha(url, Some("main"), options);
+        let other_ref = newest_cached_sha(url, Some("develop"));
+
+        assert_eq!(resolved.expect("Should resolve from cache"), new_sha);
+        assert!(other_ref.is_none());
+
+        unsafe {
+            match original {
+                Some(o) => std::env::set_var("AUGENT_CACHE_DIR", o),
+                None => std::env::remove_var("AUGENT_CACHE_DIR"),
+            }
+        }
+        super::super::index::invalidate_index_cache();
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_offline_resolve_sha_uses_cache_or_fails() {
+        let temp = tempfile::TempDir::new_in(crate::temp::temp_dir_base())
+            .expect("Failed to create temp directory");
+        let original = std::env::var("AUGENT_CACHE_DIR").ok();
+        // SAFETY: std::env::set_var is safe in test context.
+        unsafe {
+            std::env::set_var("AUGENT_CACHE_DIR", temp.path());
+        }
+        super::super::index::invalidate_index_cache();
+
+        let url = "https://augent.invalid/author/repo.git";
+        let sha = "1111111111111111111111111111111111111111";
+        let entry =
+            super::super::paths::repo_cache_entry_path(url, sha).expect("Failed to get entry path");
+        std::fs::create_dir_all(super::super::paths::entry_resources_path(&entry))
+            .expect("Failed to create resources");
+        super::super::index::add_index_entry(super::super::index::IndexEntry {
+            url: url.to_string(),
+            sha: sha.to_string(),
+            path: None,
+            bundle_name: "@author/repo".to_string(),
+            resolved_ref: Some("main".to_string()),
+        })
+        .expect("Failed to add index entry");
+
+        let options = FetchOptions {
+            offline: true,
+            ..FetchOptions::default()
+        };
+        let cached = resolve_sha(url, Some("main"), options);
+        let uncached = resolve_sha(url, Some("develop"), options);
+
+        assert_eq!(cached.expect("Should resolve from cache"), sha);
+        assert!(matches!(uncached, Err(AugentError::BundleNotFound { .. })));
+
+        unsafe {
+            match original {
+                Some(o) => std::env::set_var("AUGENT_CACHE_DIR", o),
+                None => std::env::remove_var("AUGENT_CACHE_DIR"),
+            }
+        }
+        super::super::index::invalidate_index_cache();
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_get_cached_treats_corrupted_entry_as_miss() {
+        let temp = tempfile::TempDir::new_in(crate::temp::temp_dir_base())
+            .expect("Failed to create temp directory");
+        let original = std::env::var("AUGENT_CACHE_DIR").ok();
+        // SAFETY: std::env::set_var is safe in test context.
+        unsafe {
+            std::env::set_var("AUGENT_CACHE_DIR", temp.path());
+        }
+
+        let source = GitSource {
+            url: "https://augent.invalid/author/repo.git".to_string(),
+            path: None,
+            git_ref: None,
+            resolved_sha: Some("3333333333333333333333333333333333333333".to_string()),
+        };
+        let entry = super::super::paths::repo_cache_entry_path(
+            &source.url,
+            "3333333333333333333333333333333333333333",
+        )
+        .expect("Failed to get entry path");
+        let resources = super::super::paths::entry_resources_path(&entry);
+        std::fs::create_dir_all(&resources).expect("Failed to create resources");
+        std::fs::write(resources.join("AGENTS.md"), "# Agents\n").expect("Failed to write");
+        super::super::verify::write_checksum(&entry).expect("Failed to write checksum");
+
+        let cached = get_cached(&source).expect("Lookup failed").is_some();
+        std::fs::write(resources.join("AGENTS.md"), "# Ag").expect("Failed to corrupt");
+        let corrupted = get_cached(&source).expect("Lookup failed").is_some();
+
+        unsafe {
+            match original {
+                Some(o) => std::env::set_var("AUGENT_CACHE_DIR", o),
+                None => std::env::remove_var("AUGENT_CACHE_DIR"),
+            }
+        }
+
+        assert!(cached);
+        assert!(!corrupted);
+    }
 }
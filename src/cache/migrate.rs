@@ -0,0 +1,240 @@
+//! Migration of legacy per-bundle cache entries (`augent cache migrate`)
+//!
+//! Older versions keyed cache entries by bundle name
+//! (`bundles/<bundle_key>/<sha>`, see `bundle_cache_entry_path`), while lookups
+//! now use repo-level keys derived from the repository URL
+//! (`bundles/<repo_key>/<sha>`). Legacy entries are never matched and only
+//! waste space.
+//!
+//! A legacy entry is recognised by its repository URL (from the cache index,
+//! or the `origin` remote of its clone) mapping to a different key than the
+//! directory it lives in. Migration moves it to the repo-level entry, or
+//! merges its resources into an existing one, indexes the bundle and removes
+//! the legacy directory.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::common::fs::{CopyOptions, copy_dir_recursive};
+use crate::error::{AugentError, Result};
+
+use super::index::{IndexEntry, read_index, write_index};
+use super::paths::{BUNDLE_NAME_FILE, bundles_cache_dir, entry_repository_path};
+use super::{bundle_name_to_cache_key, entry_resources_path, repo_cache_entry_path};
+
+/// A legacy cache entry that was re-keyed
+#[derive(Debug, Clone)]
+pub struct MigratedEntry {
+    /// Bundle name recorded in the legacy entry
+    pub bundle_name: String,
+    /// Legacy entry directory (removed)
+    pub from: PathBuf,
+    /// Repo-level entry directory
+    pub to: PathBuf,
+}
+
+/// Re-key every legacy per-bundle cache entry to its repo-level entry
+pub fn migrate_legacy_entries() -> Result<Vec<MigratedEntry>> {
+    let bundles_dir = bundles_cache_dir()?;
+    if !bundles_dir.is_dir() {
+        return Ok(Vec::new());
+    }
+
+    let mut index = read_index()?;
+    let mut migrated = Vec::new();
+    for key_dir in subdirectories(&bundles_dir)? {
+        for entry_path in subdirectories(&key_dir)? {
+            migrated.extend(migrate_entry(&entry_path, &mut index)?);
+        }
+        remove_if_empty(&key_dir);
+    }
+
+    if !migrated.is_empty() {
+        write_index(&index)?;
+    }
+    Ok(migrated)
+}
+
+fn migrate_entry(entry_path: &Path, index: &mut Vec<IndexEntry>) -> Result<Option<MigratedEntry>> {
+    let Some(sha) = entry_path.file_name().and_then(|s| s.to_str()) else {
+        return Ok(None);
+    };
+    let bundle_name = fs::read_to_string(entry_path.join(BUNDLE_NAME_FILE))
+        .map(|s| s.trim().to_string())
+        .unwrap_or_default();
+    let Some(url) = legacy_url(entry_path, sha, index) else {
+        return Ok(None);
+    };
+    let target = repo_cache_entry_path(&url, sha)?;
+    if target == entry_path {
+        return Ok(None);
+    }
+
+    move_or_merge(entry_path, &target)?;
+    if !index.iter().any(|e| e.url == url && e.sha == sha) {
+        let path = path_in_repo(&bundle_name, &url, &target);
+        index.push(IndexEntry {
+            url,
+            sha: sha.to_string(),
+            path,
+            bundle_name: bundle_name.clone(),
+            resolved_ref: None,
+        });
+    }
+
+    Ok(Some(MigratedEntry {
+        bundle_name,
+        from: entry_path.to_path_buf(),
+        to: target,
+    }))
+}
+
+/// Repository URL of an entry: from an index entry for the bundle at this
+/// SHA, else from the `origin` remote of the cached clone
+fn legacy_url(entry_path: &Path, sha: &str, index: &[IndexEntry]) -> Option<String> {
+    let key = entry_path
+        .parent()
+        .and_then(Path::file_name)
+        .and_then(|s| s.to_str())?;
+    index
+        .iter()
+        .find(|e| e.sha == sha && bundle_name_to_cache_key(&e.bundle_name) == key)
+        .map(|e| e.url.clone())
+        .or_else(|| {
+            let repo = git2::Repository::open(entry_repository_path(entry_path)).ok()?;
+            let remote = repo.find_remote("origin").ok()?;
+            remote.url().map(str::to_string)
+        })
+}
+
+/// Sub-bundle path within the repo, from a bundle name like `@author/repo/<path>`
+fn path_in_repo(bundle_name: &str, url: &str, target: &Path) -> Option<String> {
+    let repo_name = super::repo_name_from_url(url);
+    let path = bundle_name.strip_prefix(&repo_name)?.strip_prefix('/')?;
+    entry_resources_path(target)
+        .join(path)
+        .is_dir()
+        .then(|| path.to_string())
+}
+
+fn move_or_merge(entry_path: &Path, target: &Path) -> Result<()> {
+    if target.exists() {
+        let resources = entry_resources_path(entry_path);
+        if resources.is_dir() {
+            copy_dir_recursive(
+                &resources,
+                entry_resources_path(target),
+                &CopyOptions::default(),
+            )?;
+        }
+        return fs::remove_dir_all(entry_path).map_err(|e| migrate_error(entry_path, &e));
+    }
+
+    if let Some(parent) = target.parent() {
+        fs::create_dir_all(parent).map_err(|e| migrate_error(parent, &e))?;
+    }
+    fs::rename(entry_path, target).map_err(|e| migrate_error(entry_path, &e))
+}
+
+fn subdirectories(dir: &Path) -> Result<Vec<PathBuf>> {
+    let entries = fs::read_dir(dir).map_err(|e| migrate_error(dir, &e))?;
+    let mut dirs: Vec<PathBuf> = entries
+        .filter_map(std::result::Result::ok)
+        .map(|e| e.path())
+        .filter(|p| p.is_dir())
+        .collect();
+    dirs.sort();
+    Ok(dirs)
+}
+
+fn remove_if_empty(dir: &Path) {
+    let is_empty = fs::read_dir(dir).is_ok_and(|mut entries| entries.next().is_none());
+    if is_empty {
+        let _ = fs::remove_dir(dir);
+    }
+}
+
+fn migrate_error(path: &Path, e: &std::io::Error) -> AugentError {
+    AugentError::CacheOperationFailed {
+        message: format!("Failed to migrate cache entry {}: {e}", path.display()),
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::*;
+    use crate::cache::index::invalidate_index_cache;
+    use crate::cache::paths::bundle_cache_entry_path;
+    use serial_test::serial;
+
+    const URL: &str = "https://github.com/author/repo.git";
+    const SHA: &str = "0123456789abcdef0123456789abcdef01234567";
+
+    fn seed_legacy_entry() -> PathBuf {
+        let legacy = bundle_cache_entry_path("@author/repo/tools", SHA)
+            .expect("Failed to get legacy entry path");
+        let repository = entry_repository_path(&legacy);
+        fs::create_dir_all(&repository).expect("Failed to create repository dir");
+        let repo = git2::Repository::init(&repository).expect("Failed to init repository");
+        repo.remote("origin", URL).expect("Failed to add origin");
+
+        let resources = entry_resources_path(&legacy);
+        fs::create_dir_all(resources.join("tools/commands")).expect("Failed to create resources");
+        fs::write(resources.join("tools/commands/run.md"), "# Run\n")
+            .expect("Failed to write resource");
+        fs::write(legacy.join(BUNDLE_NAME_FILE), "@author/repo/tools")
+            .expect("Failed to write bundle name");
+        legacy
+    }
+
+    #[test]
+    #[serial]
+    fn test_migrate_rekeys_legacy_entry_to_repo_level() {
+        let temp = tempfile::TempDir::new_in(crate::temp::temp_dir_base())
+            .expect("Failed to create temp directory");
+        let original = std::env::var("AUGENT_CACHE_DIR").ok();
+        // SAFETY: std::env::set_var is safe in test context.
+        unsafe {
+            std::env::set_var("AUGENT_CACHE_DIR", temp.path());
+        }
+        invalidate_index_cache();
+
+        let legacy = seed_legacy_entry();
+        let migrated = migrate_legacy_entries().expect("Migration should succeed");
+
+        let target = repo_cache_entry_path(URL, SHA).expect("Failed to get repo entry path");
+        assert_eq!(migrated.len(), 1);
+        assert_eq!(migrated[0].bundle_name, "@author/repo/tools");
+        assert_eq!(migrated[0].to, target);
+        assert!(!legacy.exists());
+        assert!(
+            entry_resources_path(&target)
+                .join("tools/commands/run.md")
+                .is_file()
+        );
+        assert!(entry_repository_path(&target).is_dir());
+
+        let index = read_index().expect("Failed to read index");
+        let entry = index
+            .iter()
+            .find(|e| e.url == URL && e.sha == SHA)
+            .expect("Migrated bundle should be indexed");
+        assert_eq!(entry.path.as_deref(), Some("tools"));
+
+        // Already migrated entries are left alone
+        assert!(
+            migrate_legacy_entries()
+                .expect("Migration should succeed")
+                .is_empty()
+        );
+
+        unsafe {
+            match original {
+                Some(o) => std::env::set_var("AUGENT_CACHE_DIR", o),
+                None => std::env::remove_var("AUGENT_CACHE_DIR"),
+            }
+        }
+        invalidate_index_cache();
+    }
+}
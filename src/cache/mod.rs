@@ -13,7 +13,9 @@
 //! └── <repo_key>/            # Path-safe repo name: @author/repo -> author-repo
 //!     └── <sha>/              # Exact commit SHA (one per repo+sha, not per bundle)
 //!         ├── repository/        # Shallow clone, full git repository
-//!         └── resources/         # Repo content without .git/ (for file access)
+//!         ├── resources/         # Repo content without .git/ (for file access)
+//!         ├── .augent_checksum   # Hash of resources/, verified before reuse
+//!         └── .augent_lock       # Held while the entry is populated
 //! ```
 //!
 //! ### Cache Key Composition
@@ -49,7 +51,8 @@
 //! 2. If not, performs shallow clone and checkout to the SHA
 //! 3. Stores the full repository in `repository/` directory
 //! 4. Exports repository content (without .git/) to `resources/` directory
-//! 5. Returns the cache path for later use
+//! 5. Writes a checksum of `resources/` to `.augent_checksum`
+//! 6. Returns the cache path for later use
 //!
 //! ### Cache Lookup
 //!
@@ -114,23 +117,35 @@
 //!
 //! The cache module is organized into specialized submodules:
 //!
+//! - **archive**: Downloaded and extracted archive (`.tar.gz`, `.zip`) sources
 //! - **`bundle_name`**: Bundle name derivation from repo URLs
 //! - **`cache_entry`**: Single cache entry operations
+//! - **check**: Consistency of the cache index with the cache on disk (`doctor --check-cache`)
 //! - **clone**: Git cloning and checkout operations
 //! - **index**: Cache index management for workspace tracking
 //! - **lookup**: Cache lookup and validation
+//! - **migrate**: Re-keying of legacy per-bundle entries to repo-level entries
+//! - **oci**: OCI artifacts pulled from a registry, cached by manifest digest
 //! - **paths**: Path utilities and cache structure constants
 //! - **populate**: High-level "ensure cached" operations
+//! - **prune**: Removal of entries older than a given age, and LRU eviction to a size budget
 //! - **stats**: Cache statistics and management commands
+//! - **verify**: Checksums of cached resources and `cache verify`
 
+pub mod archive;
 pub mod bundle_name;
 pub mod cache_entry;
+pub mod check;
 pub mod clone;
 pub mod index;
 pub mod lookup;
+pub mod migrate;
+pub mod oci;
 pub mod paths;
 pub mod populate;
+pub mod prune;
 pub mod stats;
+pub mod verify;
 
 #[cfg(test)]
 #[allow(clippy::expect_used)]
@@ -141,8 +156,12 @@ pub use bundle_name::{content_path_in_repo, derive_marketplace_bundle_name};
 pub use cache_entry::cache_bundle;
 pub use clone::clone_and_checkout;
 pub use index::list_cached_entries_for_url_sha;
+pub use lookup::FetchOptions;
+pub use migrate::migrate_legacy_entries;
 pub use populate::ensure_bundle_cached;
+pub use prune::{enforce_cache_budget, prune_cache_older_than};
 pub use stats::{cache_stats, clear_cache, list_cached_bundles, remove_cached_bundle};
+pub use verify::verify_all_entries;
 
 // Re-export path utilities and constants
 pub use paths::{
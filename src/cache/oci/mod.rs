@@ -0,0 +1,394 @@
+//! OCI artifact bundle sources in the cache
+//!
+//! `oci://registry/repository:tag` (or `@sha256:...`) sources are pulled from
+//! an OCI distribution registry. The manifest digest identifies the content
+//! the way a commit SHA does for git bundles, so each digest is pulled and
+//! extracted once:
+//!
+//! ```text
+//! AUGENT_CACHE_DIR/oci/
+//! └── <digest_key>/   # Path-safe manifest digest
+//!     └── <name>/     # Extracted layers, named after the repository
+//! ```
+//!
+//! Tar layers (`...tar`, `...tar+gzip`) are unpacked; other layers are written
+//! as files named by their `org.opencontainers.image.title` annotation, which
+//! is how ORAS pushes plain files. As with archives, a single top-level
+//! directory is stripped. The extracted `<name>` directory is then resolved
+//! like a local directory bundle.
+//!
+//! Talking to a registry needs the `oci` cargo feature. A digest-pinned
+//! source that is already cached resolves without it, and in offline mode.
+
+use std::path::{Path, PathBuf};
+
+use crate::error::{AugentError, Result};
+
+use super::archive::{ArchiveFormat, extract, single_top_level_dir};
+use super::lookup::FetchOptions;
+use super::paths::{bundle_name_to_cache_key, cache_dir};
+
+#[cfg(feature = "oci")]
+mod registry;
+
+/// OCI cache directory name (inside the cache directory)
+pub const OCI_DIR: &str = "oci";
+
+/// A parsed `registry/repository[:tag][@digest]` reference
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OciReference {
+    /// Registry host, with port if any (e.g. `ghcr.io`, `localhost:5000`)
+    pub registry: String,
+    /// Repository path within the registry
+    pub repository: String,
+    /// Tag, when the reference names one
+    pub tag: Option<String>,
+    /// Manifest digest (`sha256:...`), when the reference is pinned
+    pub digest: Option<String>,
+}
+
+impl OciReference {
+    /// Parse a reference without the `oci://` scheme
+    pub fn parse(reference: &str) -> Result<Self> {
+        let invalid = |reason: &str| AugentError::SourceParseFailed {
+            input: format!("oci://{reference}"),
+            reason: reason.to_string(),
+        };
+        let (name, digest) = match reference.split_once('@') {
+            Some((name, digest)) if digest.contains(':') => (name, Some(digest.to_string())),
+            Some(_) => return Err(invalid("digest must look like 'sha256:<hex>'")),
+            None => (reference, None),
+        };
+        let (registry, path) = name
+            .split_once('/')
+            .ok_or_else(|| invalid("expected 'registry/repository[:tag]'"))?;
+        // A colon after the last slash starts the tag; one before it is a port
+        let (repository, tag) = match path.rsplit_once(':') {
+            Some((repository, tag)) if !tag.contains('/') => (repository, Some(tag.to_string())),
+            _ => (path, None),
+        };
+        if registry.is_empty() || repository.is_empty() || tag.as_deref() == Some("") {
+            return Err(invalid("expected 'registry/repository[:tag]'"));
+        }
+        Ok(Self {
+            registry: registry.to_string(),
+            repository: repository.to_string(),
+            tag,
+            digest,
+        })
+    }
+
+    /// Bundle name: the last segment of the repository path
+    pub fn name(&self) -> &str {
+        self.repository
+            .rsplit('/')
+            .next()
+            .unwrap_or(&self.repository)
+    }
+}
+
+impl std::fmt::Display for OciReference {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}", self.registry, self.repository)?;
+        if let Some(tag) = &self.tag {
+            write!(f, ":{tag}")?;
+        }
+        if let Some(digest) = &self.digest {
+            write!(f, "@{digest}")?;
+        }
+        Ok(())
+    }
+}
+
+/// One layer of a pulled artifact
+#[derive(Debug, Clone)]
+pub struct OciLayer {
+    /// Layer media type
+    pub media_type: String,
+    /// Value of the `org.opencontainers.image.title` annotation, if any
+    pub title: Option<String>,
+    /// Layer content
+    pub data: Vec<u8>,
+}
+
+/// Registry access needed to cache an OCI artifact
+pub trait OciClient {
+    /// Digest of the manifest `reference` currently points to
+    fn manifest_digest(&self, reference: &OciReference) -> Result<String>;
+
+    /// Layers of the manifest with `digest` in the repository of `reference`
+    fn pull_layers(&self, reference: &OciReference, digest: &str) -> Result<Vec<OciLayer>>;
+}
+
+/// Get the OCI cache directory path
+pub fn oci_cache_dir() -> Result<PathBuf> {
+    Ok(cache_dir()?.join(OCI_DIR))
+}
+
+/// Whether `path` lies inside the OCI cache
+pub fn is_extracted_artifact(path: &Path) -> bool {
+    oci_cache_dir().is_ok_and(|dir| {
+        let dir = std::fs::canonicalize(&dir).unwrap_or(dir);
+        path.starts_with(dunce::simplified(&dir))
+    })
+}
+
+/// Registry client of this build
+///
+/// Fails when augent was built without the `oci` feature.
+pub fn default_client() -> Result<Box<dyn OciClient>> {
+    #[cfg(feature = "oci")]
+    {
+        Ok(Box::new(registry::RegistryClient::from_env()))
+    }
+    #[cfg(not(feature = "oci"))]
+    {
+        Err(AugentError::CacheOperationFailed {
+            message: "OCI sources need augent built with the 'oci' feature".to_string(),
+        })
+    }
+}
+
+/// Directory holding a locked OCI bundle: its extraction in the cache
+pub fn cached_oci_path(reference: &str, digest: &str, path: Option<&str>) -> PathBuf {
+    let name = OciReference::parse(reference).map_or_else(
+        |_| reference.to_string(),
+        |reference| reference.name().to_string(),
+    );
+    let root = entry_path(digest, &name).unwrap_or_default();
+    path.map_or_else(|| root.clone(), |p| root.join(p))
+}
+
+/// Pull and extract the artifact at `reference`, returning its extracted
+/// root and manifest digest
+pub fn cache_oci(reference: &OciReference, options: FetchOptions) -> Result<(PathBuf, String)> {
+    // A pinned digest that is already extracted needs no registry access
+    if let Some(digest) = &reference.digest {
+        let target = entry_path(digest, reference.name())?;
+        if target.is_dir() {
+            return Ok((target, digest.clone()));
+        }
+    }
+    if options.offline {
+        return Err(AugentError::BundleNotFound {
+            name: format!("oci://{reference} (not in cache, and offline mode forbids fetching it)"),
+        });
+    }
+    cache_oci_with(reference, default_client()?.as_ref())
+}
+
+/// [`cache_oci`] against the registry behind `client`
+pub fn cache_oci_with(
+    reference: &OciReference,
+    client: &dyn OciClient,
+) -> Result<(PathBuf, String)> {
+    let digest = match &reference.digest {
+        Some(digest) => digest.clone(),
+        None => client.manifest_digest(reference)?,
+    };
+    let target = entry_path(&digest, reference.name())?;
+    if target.is_dir() {
+        return Ok((target, digest));
+    }
+
+    let failed = |reason: String| AugentError::CacheOperationFailed {
+        message: format!("Failed to extract OCI artifact {reference}: {reason}"),
+    };
+    let layers = client.pull_layers(reference, &digest)?;
+    let digest_dir = target.parent().map(Path::to_path_buf).unwrap_or_default();
+    std::fs::create_dir_all(&digest_dir).map_err(|e| failed(e.to_string()))?;
+    let temp = tempfile::TempDir::new_in(&digest_dir).map_err(|e| failed(e.to_string()))?;
+    for layer in &layers {
+        extract_layer(layer, temp.path()).map_err(failed)?;
+    }
+
+    let root = single_top_level_dir(temp.path()).unwrap_or_else(|| temp.path().to_path_buf());
+    // A concurrent install may have extracted the same digest first
+    if std::fs::rename(&root, &target).is_err() && !target.is_dir() {
+        return Err(failed(format!("could not move into {}", target.display())));
+    }
+    Ok((target, digest))
+}
+
+fn entry_path(digest: &str, name: &str) -> Result<PathBuf> {
+    Ok(oci_cache_dir()?
+        .join(bundle_name_to_cache_key(digest))
+        .join(bundle_name_to_cache_key(name)))
+}
+
+fn extract_layer(layer: &OciLayer, dir: &Path) -> std::result::Result<(), String> {
+    let media_type = layer.media_type.as_str();
+    // The media type suffix names the layer format, as in `...layer.v1.tar+gzip`
+    let media_path = Path::new(media_type);
+    let suffix = media_path.extension().unwrap_or_default();
+    let is_tar_stem = Path::new(media_path.file_stem().unwrap_or_default())
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("tar"));
+    if suffix.eq_ignore_ascii_case("tar+gzip")
+        || (suffix.eq_ignore_ascii_case("gzip") && is_tar_stem)
+    {
+        return extract(&layer.data, ArchiveFormat::TarGz, dir);
+    }
+    if suffix.eq_ignore_ascii_case("tar") {
+        return tar::Archive::new(layer.data.as_slice())
+            .unpack(dir)
+            .map_err(|e| e.to_string());
+    }
+    let Some(title) = &layer.title else {
+        return Err(format!("layer of type {media_type} has no file name"));
+    };
+    let relative = Path::new(title);
+    if relative.is_absolute()
+        || relative
+            .components()
+            .any(|c| matches!(c, std::path::Component::ParentDir))
+    {
+        return Err(format!("layer file name '{title}' leaves the bundle"));
+    }
+    let path = dir.join(relative);
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+    std::fs::write(path, &layer.data).map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    const DIGEST: &str = "sha256:0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef";
+    const COMMAND: &str = "# Review\n";
+
+    /// Registry stub serving one tar+gzip layer and counting pulls
+    struct StubClient {
+        pulls: Cell<usize>,
+    }
+
+    impl OciClient for StubClient {
+        fn manifest_digest(&self, _reference: &OciReference) -> Result<String> {
+            Ok(DIGEST.to_string())
+        }
+
+        fn pull_layers(&self, _reference: &OciReference, _digest: &str) -> Result<Vec<OciLayer>> {
+            self.pulls.set(self.pulls.get() + 1);
+            let mut builder = tar::Builder::new(flate2::write::GzEncoder::new(
+                Vec::new(),
+                flate2::Compression::default(),
+            ));
+            let mut header = tar::Header::new_gnu();
+            header.set_size(COMMAND.len() as u64);
+            header.set_mode(0o644);
+            header.set_cksum();
+            builder
+                .append_data(&mut header, "tools/commands/review.md", COMMAND.as_bytes())
+                .expect("Failed to add file");
+            let data = builder
+                .into_inner()
+                .expect("Failed to finish tar")
+                .finish()
+                .expect("Failed to finish gzip");
+            Ok(vec![
+                OciLayer {
+                    media_type: "application/vnd.oci.image.layer.v1.tar+gzip".to_string(),
+                    title: Some("tools.tar.gz".to_string()),
+                    data,
+                },
+                OciLayer {
+                    media_type: "text/markdown".to_string(),
+                    title: Some("tools/README.md".to_string()),
+                    data: b"Readme\n".to_vec(),
+                },
+            ])
+        }
+    }
+
+    #[test]
+    fn test_parse_reference() {
+        let reference =
+            OciReference::parse("localhost:5000/acme/tools:1.0").expect("Failed to parse");
+        assert_eq!(reference.registry, "localhost:5000");
+        assert_eq!(reference.repository, "acme/tools");
+        assert_eq!(reference.tag.as_deref(), Some("1.0"));
+        assert_eq!(reference.digest, None);
+        assert_eq!(reference.name(), "tools");
+    }
+
+    #[test]
+    fn test_parse_pinned_reference() {
+        let pinned = format!("ghcr.io/acme/tools@{DIGEST}");
+        let reference = OciReference::parse(&pinned).expect("Failed to parse");
+        assert_eq!(reference.tag, None);
+        assert_eq!(reference.digest.as_deref(), Some(DIGEST));
+        assert_eq!(reference.to_string(), pinned);
+
+        assert!(OciReference::parse("tools").is_err());
+        assert!(OciReference::parse("ghcr.io/acme/tools@latest").is_err());
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_oci_source_resolves_to_cached_bundle() {
+        let temp = tempfile::TempDir::new_in(crate::temp::temp_dir_base())
+            .expect("Failed to create temp dir");
+        let client = StubClient {
+            pulls: Cell::new(0),
+        };
+        let reference = OciReference::parse("ghcr.io/acme/tools:1.0").expect("Failed to parse");
+        let pinned = OciReference {
+            digest: Some(DIGEST.to_string()),
+            ..reference.clone()
+        };
+
+        let original = std::env::var_os("AUGENT_CACHE_DIR");
+        // SAFETY: std::env::set_var is safe in test context.
+        unsafe {
+            std::env::set_var("AUGENT_CACHE_DIR", temp.path().join("cache"));
+        }
+        crate::cache::index::invalidate_index_cache();
+        let first = cache_oci_with(&reference, &client);
+        // Once extracted, a digest-pinned reference needs no registry
+        let second = cache_oci(&pinned, FetchOptions::default());
+        let locked_path = cached_oci_path(&reference.to_string(), DIGEST, None);
+        let resolved = first.as_ref().ok().map(|(root, _)| {
+            crate::resolver::local::resolve_local(&crate::resolver::local::ResolveLocalContext {
+                path: root,
+                workspace_root: temp.path(),
+                dependency: None,
+                resolution_stack: &[],
+                skip_deps: false,
+                resolved: &std::collections::HashMap::new(),
+            })
+        });
+        // SAFETY: std::env::set_var is safe in test context.
+        unsafe {
+            match original {
+                Some(dir) => std::env::set_var("AUGENT_CACHE_DIR", dir),
+                None => std::env::remove_var("AUGENT_CACHE_DIR"),
+            }
+        }
+        crate::cache::index::invalidate_index_cache();
+
+        let (root, digest) = first.expect("Failed to cache artifact");
+        assert_eq!(digest, DIGEST);
+        assert_eq!(locked_path, root);
+        assert!(root.ends_with(Path::new(&bundle_name_to_cache_key(DIGEST)).join("tools")));
+        assert_eq!(
+            std::fs::read_to_string(root.join("commands/review.md")).expect("Missing command"),
+            COMMAND
+        );
+        assert!(root.join("README.md").is_file());
+        assert_eq!(
+            second.expect("Failed to reuse artifact"),
+            (root, DIGEST.to_string())
+        );
+        assert_eq!(client.pulls.get(), 1);
+
+        let bundle = resolved
+            .expect("Artifact was not cached")
+            .expect("Failed to resolve artifact");
+        assert_eq!(bundle.name, "tools");
+        assert!(bundle.resolved_sha.is_none());
+    }
+}
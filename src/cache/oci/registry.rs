@@ -0,0 +1,121 @@
+//! OCI distribution registry client (`oci` feature)
+//!
+//! Wraps the async `oci-client` crate in a current-thread runtime, since the
+//! rest of augent is synchronous. Credentials come from `AUGENT_OCI_USERNAME`
+//! and `AUGENT_OCI_PASSWORD` (anonymous access otherwise), and registries
+//! listed in `AUGENT_OCI_INSECURE_REGISTRIES` (comma-separated) use plain HTTP.
+
+use oci_client::client::{ClientConfig, ClientProtocol};
+use oci_client::manifest::OciManifest;
+use oci_client::secrets::RegistryAuth;
+use oci_client::{Client, Reference};
+
+use crate::error::{AugentError, Result};
+
+use super::{OciClient, OciLayer, OciReference};
+
+/// Layer annotation holding the file name of a non-tar layer
+const TITLE_ANNOTATION: &str = "org.opencontainers.image.title";
+
+/// Registry client backed by `oci-client`
+pub struct RegistryClient {
+    client: Client,
+    auth: RegistryAuth,
+}
+
+impl RegistryClient {
+    /// Client configured from the `AUGENT_OCI_*` environment variables
+    pub fn from_env() -> Self {
+        let insecure: Vec<String> = std::env::var("AUGENT_OCI_INSECURE_REGISTRIES")
+            .unwrap_or_default()
+            .split(',')
+            .map(str::trim)
+            .filter(|registry| !registry.is_empty())
+            .map(str::to_string)
+            .collect();
+        let auth = match (
+            std::env::var("AUGENT_OCI_USERNAME"),
+            std::env::var("AUGENT_OCI_PASSWORD"),
+        ) {
+            (Ok(username), Ok(password)) => RegistryAuth::Basic(username, password),
+            _ => RegistryAuth::Anonymous,
+        };
+        Self {
+            client: Client::new(ClientConfig {
+                protocol: ClientProtocol::HttpsExcept(insecure),
+                ..ClientConfig::default()
+            }),
+            auth,
+        }
+    }
+
+    fn block_on<F: std::future::Future>(future: F) -> Result<F::Output> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .map_err(|e| AugentError::CacheOperationFailed {
+                message: format!("Failed to start OCI client runtime: {e}"),
+            })?;
+        Ok(runtime.block_on(future))
+    }
+}
+
+fn pull_failed(reference: &OciReference, reason: impl std::fmt::Display) -> AugentError {
+    AugentError::CacheOperationFailed {
+        message: format!("Failed to pull oci://{reference}: {reason}"),
+    }
+}
+
+impl OciClient for RegistryClient {
+    fn manifest_digest(&self, reference: &OciReference) -> Result<String> {
+        let image = Reference::with_tag(
+            reference.registry.clone(),
+            reference.repository.clone(),
+            reference
+                .tag
+                .clone()
+                .unwrap_or_else(|| "latest".to_string()),
+        );
+        Self::block_on(self.client.fetch_manifest_digest(&image, &self.auth))?
+            .map_err(|e| pull_failed(reference, e))
+    }
+
+    fn pull_layers(&self, reference: &OciReference, digest: &str) -> Result<Vec<OciLayer>> {
+        let image = Reference::with_digest(
+            reference.registry.clone(),
+            reference.repository.clone(),
+            digest.to_string(),
+        );
+        Self::block_on(async {
+            let (manifest, _) = self
+                .client
+                .pull_manifest(&image, &self.auth)
+                .await
+                .map_err(|e| pull_failed(reference, e))?;
+            let OciManifest::Image(manifest) = manifest else {
+                return Err(pull_failed(
+                    reference,
+                    "expected an artifact manifest, got an image index",
+                ));
+            };
+            let mut layers = Vec::with_capacity(manifest.layers.len());
+            for descriptor in &manifest.layers {
+                let mut data = Vec::new();
+                self.client
+                    .pull_blob(&image, descriptor, &mut data)
+                    .await
+                    .map_err(|e| pull_failed(reference, e))?;
+                layers.push(OciLayer {
+                    media_type: descriptor.media_type.clone(),
+                    title: descriptor
+                        .annotations
+                        .as_ref()
+                        .and_then(|annotations| annotations.get(TITLE_ANNOTATION))
+                        .cloned(),
+                    data,
+                });
+            }
+            Ok(layers)
+        })?
+    }
+}
@@ -23,6 +23,18 @@ pub const RESOURCES_DIR: &str = "resources";
 /// File name for storing the bundle display name in each cache entry
 pub const BUNDLE_NAME_FILE: &str = ".augent_bundle_name";
 
+/// File name for storing the checksum of `resources/` in each cache entry
+pub const CHECKSUM_FILE: &str = ".augent_checksum";
+
+/// Lock file held while a cache entry is being populated
+pub const LOCK_FILE: &str = ".augent_lock";
+
+/// Lock file held while the cache index is read, modified and written back
+pub const INDEX_LOCK_FILE: &str = ".augent_cache_index.lock";
+
+/// File name for storing the resolved ref in each cache entry (the clone has a detached HEAD)
+pub const REF_FILE: &str = ".augent_ref";
+
 /// Subdirectory for marketplace synthetic bundles
 pub const SYNTHETIC_DIR: &str = ".claude-plugin";
 
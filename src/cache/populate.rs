@@ -2,10 +2,16 @@
 //!
 //! This module handles copying and storing bundles to cache,
 //! including directory structure setup and file copying operations.
+//!
+//! Population of an entry is serialized across processes by an advisory lock
+//! on `.augent_lock` in the entry directory, so concurrent installs of the
+//! same repository and SHA never interleave their copies.
 
 use std::fs;
 use std::path::{Path, PathBuf};
 
+use fs2::FileExt;
+
 use crate::common::fs::{CopyOptions, copy_dir_recursive};
 use crate::error::{AugentError, Result};
 
@@ -61,6 +67,30 @@ fn create_cache_entry_dir(entry_path: &Path) -> Result<()> {
     })
 }
 
+/// Block until this process holds the entry's lock; released when the file is dropped
+fn lock_cache_entry(entry_path: &Path) -> Result<fs::File> {
+    use crate::cache::paths::LOCK_FILE;
+
+    let lock_path = entry_path.join(LOCK_FILE);
+    let lock_error = |e: std::io::Error| AugentError::CacheOperationFailed {
+        message: format!("Failed to lock cache entry {}: {}", lock_path.display(), e),
+    };
+    let file = fs::OpenOptions::new()
+        .create(true)
+        .truncate(false)
+        .write(true)
+        .open(&lock_path)
+        .map_err(lock_error)?;
+    file.lock_exclusive().map_err(lock_error)?;
+    Ok(file)
+}
+
+/// Whether another process already populated and indexed this bundle
+fn is_already_cached(entry_path: &Path, metadata: &BundleCacheMetadata) -> bool {
+    super::lookup::index_lookup(metadata.url, metadata.sha, metadata.path_opt).is_some()
+        && super::verify::verify_cache_entry(entry_path) == super::verify::EntryIntegrity::Valid
+}
+
 fn copy_repository_to_cache(temp_dir: &Path, repo_dst: &Path) -> Result<()> {
     copy_dir_recursive(temp_dir, repo_dst, &CopyOptions::default()).map_err(|e| {
         AugentError::IoError {
@@ -106,7 +136,12 @@ fn write_bundle_name_file(entry_path: &Path, bundle_name: &str) -> Result<()> {
 /// Ensure a bundle is cached by copying from temp directory to cache.
 ///
 /// Creates the cache entry structure, copies repository and content,
-/// writes to the bundle name file, and adds to index.
+/// writes to the bundle name file and checksum, and adds to index.
+/// A corrupted entry has its resources discarded before the copy.
+///
+/// The entry lock is held from before the copy until the index entry is
+/// written. A caller that waited on the lock finds the bundle cached and
+/// indexed by the holder and skips copying.
 pub fn ensure_bundle_cached(
     metadata: &BundleCacheMetadata,
     temp_dir: &Path,
@@ -116,14 +151,23 @@ pub fn ensure_bundle_cached(
 
     let entry_path = repo_cache_entry_path(metadata.url, metadata.sha)?;
     create_cache_entry_dir(&entry_path)?;
+    let _lock = lock_cache_entry(&entry_path)?;
+
+    // Another process may have written the index while this one waited
+    super::index::invalidate_index_cache();
+    let resources = entry_resources_path(&entry_path);
+    if is_already_cached(&entry_path, metadata) {
+        return Ok(resources);
+    }
+    super::verify::discard_if_corrupted(&entry_path)?;
 
     let repo_dst = entry_repository_path(&entry_path);
     copy_repository_to_cache(temp_dir, &repo_dst)?;
 
-    let resources = entry_resources_path(&entry_path);
     copy_content_to_resources(temp_dir, &resources, metadata)?;
 
     write_bundle_name_file(&entry_path, metadata.bundle_name)?;
+    super::verify::write_checksum(&entry_path)?;
 
     create_and_add_index_entry(metadata)?;
 
@@ -178,4 +222,76 @@ mod tests {
         assert!(dst.join("test.txt").exists());
         assert!(!dst.join(".git").exists());
     }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_concurrent_ensure_bundle_cached_copies_once() {
+        let temp = tempfile::TempDir::new_in(crate::temp::temp_dir_base())
+            .expect("Failed to create temp directory");
+        let original = std::env::var("AUGENT_CACHE_DIR").ok();
+        // SAFETY: std::env::set_var is safe in test context.
+        unsafe {
+            std::env::set_var("AUGENT_CACHE_DIR", temp.path().join("cache"));
+        }
+        super::super::index::invalidate_index_cache();
+
+        // Each caller copies from its own checkout holding a distinct file, so
+        // the entry ends up with both files if both callers copied
+        let repos: Vec<PathBuf> = ["first", "second"]
+            .iter()
+            .map(|name| {
+                let repo = temp.path().join(name);
+                fs::create_dir_all(repo.join("commands")).expect("Failed to create repo");
+                fs::write(repo.join(format!("commands/{name}.md")), "Review.\n")
+                    .expect("Failed to write file");
+                repo
+            })
+            .collect();
+
+        let url = "https://github.com/author/repo.git";
+        let sha = "0123456789abcdef0123456789abcdef01234567";
+        let results: Vec<Result<PathBuf>> = std::thread::scope(|scope| {
+            let handles: Vec<_> = repos
+                .iter()
+                .map(|repo| {
+                    scope.spawn(move || {
+                        let metadata = BundleCacheMetadata {
+                            bundle_name: "@author/repo",
+                            sha,
+                            url,
+                            path_opt: None,
+                            resolved_ref: Some("main"),
+                        };
+                        ensure_bundle_cached(&metadata, repo, repo)
+                    })
+                })
+                .collect();
+            handles
+                .into_iter()
+                .map(|h| h.join().expect("Caching thread panicked"))
+                .collect()
+        });
+
+        let paths: Vec<PathBuf> = results
+            .into_iter()
+            .map(|r| r.expect("Failed to cache bundle"))
+            .collect();
+        assert_eq!(paths[0], paths[1]);
+        let copies = ["first", "second"]
+            .iter()
+            .filter(|name| paths[0].join(format!("commands/{name}.md")).is_file())
+            .count();
+        assert_eq!(copies, 1);
+        assert_eq!(super::super::index::index_lookup(url, sha).len(), 1);
+
+        // SAFETY: std::env::set_var is safe in test context.
+        unsafe {
+            if let Some(value) = original {
+                std::env::set_var("AUGENT_CACHE_DIR", value);
+            } else {
+                std::env::remove_var("AUGENT_CACHE_DIR");
+            }
+        }
+        super::super::index::invalidate_index_cache();
+    }
 }
@@ -0,0 +1,368 @@
+//! Pruning of stale cache entries (`augent cache clear --older-than`,
+//! `--max-size` and `AUGENT_CACHE_MAX_SIZE`)
+//!
+//! Entries are whole `bundles/<repo_key>/<sha>` directories; pruning never
+//! removes part of one. An entry's last use is the modification time of its
+//! directory, which is refreshed whenever a lookup reuses the entry. Index
+//! entries pointing at removed directories are dropped so the index stays
+//! consistent with the disk.
+
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use crate::error::{AugentError, Result};
+
+use super::index::{read_index, write_index};
+use super::paths::{bundles_cache_dir, repo_cache_entry_path};
+use super::stats::{
+    CacheStats, cache_stats, dir_size, format_size_human_readable, read_bundle_name,
+};
+
+/// Environment variable holding the cache size budget, e.g. `2G`
+pub const MAX_SIZE_ENV: &str = "AUGENT_CACHE_MAX_SIZE";
+
+/// A cache entry removed by pruning
+#[derive(Debug, Clone)]
+pub struct PrunedEntry {
+    /// Bundle name recorded in the entry
+    pub bundle_name: String,
+    /// Commit SHA of the entry
+    pub sha: String,
+    /// Bytes the entry used on disk
+    pub size: u64,
+}
+
+/// What pruning removed
+#[derive(Debug, Default)]
+pub struct PruneSummary {
+    pub removed: Vec<PrunedEntry>,
+    /// Total bytes freed
+    pub reclaimed: u64,
+}
+
+impl PruneSummary {
+    /// Format reclaimed bytes as human-readable string
+    pub fn formatted_reclaimed(&self) -> String {
+        format_size_human_readable(self.reclaimed)
+    }
+}
+
+/// Parse an age such as `30d`, `12h`, `45m`, `90s` or `2w`
+pub fn parse_age(s: &str) -> std::result::Result<Duration, String> {
+    let invalid = || format!("expected a number followed by s, m, h, d or w, got '{s}'");
+    let s = s.trim();
+    let unit = s.chars().last().ok_or_else(invalid)?;
+    let number: u64 = s[..s.len() - unit.len_utf8()]
+        .parse()
+        .map_err(|_| invalid())?;
+    let seconds = match unit {
+        's' => 1,
+        'm' => 60,
+        'h' => 60 * 60,
+        'd' => 24 * 60 * 60,
+        'w' => 7 * 24 * 60 * 60,
+        _ => return Err(invalid()),
+    };
+    Ok(Duration::from_secs(number.saturating_mul(seconds)))
+}
+
+/// Parse a size such as `500M`, `2G`, `512KB` or a plain number of bytes
+///
+/// Units are binary (`1K` = 1024 bytes) and case-insensitive.
+pub fn parse_size(s: &str) -> std::result::Result<u64, String> {
+    let invalid =
+        || format!("expected a number of bytes, optionally followed by K, M or G, got '{s}'");
+    let upper = s.trim().to_ascii_uppercase();
+    let digits_end = upper
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(upper.len());
+    let (number, unit) = upper.split_at(digits_end);
+    let number: u64 = number.parse().map_err(|_| invalid())?;
+    let multiplier: u64 = match unit.trim().trim_end_matches('B') {
+        "" => 1,
+        "K" => 1024,
+        "M" => 1024 * 1024,
+        "G" => 1024 * 1024 * 1024,
+        _ => return Err(invalid()),
+    };
+    Ok(number.saturating_mul(multiplier))
+}
+
+/// Record that an entry was just used, so it is evicted last
+///
+/// Best effort: an entry whose time cannot be updated only ages sooner.
+pub fn mark_used(entry_path: &Path) {
+    let _ = fs::File::open(entry_path).and_then(|f| f.set_modified(SystemTime::now()));
+}
+
+/// Remove every cache entry last used more than `max_age` ago
+pub fn prune_cache_older_than(max_age: Duration) -> Result<PruneSummary> {
+    let cutoff = SystemTime::now()
+        .checked_sub(max_age)
+        .unwrap_or(SystemTime::UNIX_EPOCH);
+    prune_entries(|entry_path| entry_modified(entry_path).is_some_and(|m| m < cutoff))
+}
+
+/// Every `<repo_key>/<sha>` entry directory in the bundles cache
+fn cache_entry_dirs() -> Result<Vec<PathBuf>> {
+    let bundles_dir = bundles_cache_dir()?;
+    if !bundles_dir.is_dir() {
+        return Ok(Vec::new());
+    }
+    let mut entries = Vec::new();
+    for key_dir in subdirectories(&bundles_dir)? {
+        entries.extend(subdirectories(&key_dir)?);
+    }
+    Ok(entries)
+}
+
+/// Evict least recently used entries until the cache fits in `max_bytes`
+///
+/// Returns the statistics of the cache after eviction, with the number of
+/// evicted entries.
+pub fn enforce_cache_budget(max_bytes: u64) -> Result<CacheStats> {
+    let mut entries = Vec::new();
+    for entry_path in cache_entry_dirs()? {
+        let used = entry_modified(&entry_path).unwrap_or(SystemTime::UNIX_EPOCH);
+        let size = dir_size(&entry_path).unwrap_or(0);
+        entries.push((used, size, entry_path));
+    }
+    entries.sort();
+
+    let mut total: u64 = entries.iter().map(|(_, size, _)| size).sum();
+    let mut victims = HashSet::new();
+    for (_, size, entry_path) in entries {
+        if total <= max_bytes {
+            break;
+        }
+        total -= size;
+        victims.insert(entry_path);
+    }
+
+    let summary = prune_entries(|entry_path| victims.contains(entry_path))?;
+    Ok(CacheStats {
+        evicted: summary.removed.len(),
+        ..cache_stats()?
+    })
+}
+
+/// Enforce the budget in `AUGENT_CACHE_MAX_SIZE`, if set
+pub fn enforce_configured_budget() -> Result<Option<CacheStats>> {
+    let Ok(value) = std::env::var(MAX_SIZE_ENV) else {
+        return Ok(None);
+    };
+    let max_bytes = parse_size(&value).map_err(|e| AugentError::CacheOperationFailed {
+        message: format!("Invalid {MAX_SIZE_ENV}: {e}"),
+    })?;
+    enforce_cache_budget(max_bytes).map(Some)
+}
+
+/// Remove cache entries matching `expired`, then drop their index entries
+fn prune_entries(expired: impl Fn(&Path) -> bool) -> Result<PruneSummary> {
+    let bundles_dir = bundles_cache_dir()?;
+    if !bundles_dir.is_dir() {
+        return Ok(PruneSummary::default());
+    }
+
+    let mut summary = PruneSummary::default();
+    let mut removed_paths = HashSet::new();
+    for key_dir in subdirectories(&bundles_dir)? {
+        for entry_path in subdirectories(&key_dir)?.into_iter().filter(|p| expired(p)) {
+            summary.removed.push(remove_entry(&entry_path)?);
+            removed_paths.insert(entry_path);
+        }
+        remove_if_empty(&key_dir);
+    }
+    summary.reclaimed = summary.removed.iter().map(|e| e.size).sum();
+
+    if !removed_paths.is_empty() {
+        let mut index = read_index()?;
+        index.retain(|e| {
+            repo_cache_entry_path(&e.url, &e.sha)
+                .ok()
+                .is_none_or(|p| !removed_paths.contains(&p))
+        });
+        write_index(&index)?;
+    }
+    Ok(summary)
+}
+
+fn remove_entry(entry_path: &Path) -> Result<PrunedEntry> {
+    let entry = PrunedEntry {
+        bundle_name: read_bundle_name(entry_path).unwrap_or_default(),
+        sha: entry_path
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_default(),
+        size: dir_size(entry_path).unwrap_or(0),
+    };
+    fs::remove_dir_all(entry_path).map_err(|e| AugentError::CacheOperationFailed {
+        message: format!(
+            "Failed to remove cache entry {}: {}",
+            entry_path.display(),
+            e
+        ),
+    })?;
+    Ok(entry)
+}
+
+fn entry_modified(entry_path: &Path) -> Option<SystemTime> {
+    fs::metadata(entry_path).ok()?.modified().ok()
+}
+
+fn subdirectories(dir: &Path) -> Result<Vec<PathBuf>> {
+    let entries = fs::read_dir(dir).map_err(|e| AugentError::CacheOperationFailed {
+        message: format!("Failed to read cache directory {}: {e}", dir.display()),
+    })?;
+    let mut dirs: Vec<PathBuf> = entries
+        .filter_map(std::result::Result::ok)
+        .map(|e| e.path())
+        .filter(|p| p.is_dir())
+        .collect();
+    dirs.sort();
+    Ok(dirs)
+}
+
+fn remove_if_empty(dir: &Path) {
+    let is_empty = fs::read_dir(dir).is_ok_and(|mut entries| entries.next().is_none());
+    if is_empty {
+        let _ = fs::remove_dir(dir);
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_age() {
+        assert_eq!(parse_age("30d"), Ok(Duration::from_secs(30 * 24 * 60 * 60)));
+        assert_eq!(parse_age("12h"), Ok(Duration::from_secs(12 * 60 * 60)));
+        assert_eq!(parse_age("2w"), Ok(Duration::from_secs(14 * 24 * 60 * 60)));
+        assert!(parse_age("30").is_err());
+        assert!(parse_age("d").is_err());
+        assert!(parse_age("").is_err());
+        assert!(parse_age("3y").is_err());
+    }
+
+    #[test]
+    fn test_parse_size() {
+        assert_eq!(parse_size("512"), Ok(512));
+        assert_eq!(parse_size("2k"), Ok(2048));
+        assert_eq!(parse_size("500MB"), Ok(500 * 1024 * 1024));
+        assert_eq!(parse_size("1G"), Ok(1024 * 1024 * 1024));
+        assert!(parse_size("G").is_err());
+        assert!(parse_size("5T").is_err());
+    }
+
+    #[cfg(unix)]
+    fn set_mtime(path: &Path, time: SystemTime) {
+        fs::File::open(path)
+            .and_then(|f| f.set_modified(time))
+            .expect("Failed to set mtime");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    #[serial_test::serial]
+    fn test_prune_cache_older_than_removes_only_expired_entries() {
+        use super::super::index::{IndexEntry, add_index_entry, invalidate_index_cache};
+
+        let temp = tempfile::TempDir::new_in(crate::temp::temp_dir_base())
+            .expect("Failed to create temp directory");
+        let original = std::env::var("AUGENT_CACHE_DIR").ok();
+        // SAFETY: std::env::set_var is safe in test context.
+        unsafe {
+            std::env::set_var("AUGENT_CACHE_DIR", temp.path());
+        }
+        invalidate_index_cache();
+
+        let url = "https://augent.invalid/author/repo.git";
+        let old_sha = "1111111111111111111111111111111111111111";
+        let new_sha = "2222222222222222222222222222222222222222";
+        let day = Duration::from_secs(24 * 60 * 60);
+        let mut entries = Vec::new();
+        for (sha, age) in [(old_sha, 40 * day), (new_sha, day)] {
+            let entry = repo_cache_entry_path(url, sha).expect("Failed to get entry path");
+            let resources = super::super::paths::entry_resources_path(&entry);
+            fs::create_dir_all(&resources).expect("Failed to create resources");
+            fs::write(resources.join("AGENTS.md"), "# Agents\n").expect("Failed to write");
+            add_index_entry(IndexEntry {
+                url: url.to_string(),
+                sha: sha.to_string(),
+                path: None,
+                bundle_name: "@author/repo".to_string(),
+                resolved_ref: None,
+            })
+            .expect("Failed to add index entry");
+            set_mtime(&entry, SystemTime::now() - age);
+            entries.push(entry);
+        }
+
+        let summary = prune_cache_older_than(30 * day).expect("Failed to prune");
+        let index = read_index().expect("Failed to read index");
+
+        unsafe {
+            match original {
+                Some(o) => std::env::set_var("AUGENT_CACHE_DIR", o),
+                None => std::env::remove_var("AUGENT_CACHE_DIR"),
+            }
+        }
+        invalidate_index_cache();
+
+        assert_eq!(summary.removed.len(), 1);
+        assert_eq!(summary.removed[0].sha, old_sha);
+        assert!(summary.reclaimed > 0);
+        assert!(!entries[0].exists());
+        assert!(entries[1].exists());
+        let shas: Vec<&str> = index.iter().map(|e| e.sha.as_str()).collect();
+        assert_eq!(shas, [new_sha]);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    #[serial_test::serial]
+    fn test_enforce_cache_budget_evicts_least_recently_used_first() {
+        use super::super::index::invalidate_index_cache;
+
+        let temp = tempfile::TempDir::new_in(crate::temp::temp_dir_base())
+            .expect("Failed to create temp directory");
+        let original = std::env::var("AUGENT_CACHE_DIR").ok();
+        // SAFETY: std::env::set_var is safe in test context.
+        unsafe {
+            std::env::set_var("AUGENT_CACHE_DIR", temp.path());
+        }
+        invalidate_index_cache();
+
+        let url = "https://augent.invalid/author/repo.git";
+        let day = Duration::from_secs(24 * 60 * 60);
+        let mut entries = Vec::new();
+        for (sha, age) in [("a", 3 * day), ("b", 2 * day), ("c", day)] {
+            let entry = repo_cache_entry_path(url, sha).expect("Failed to get entry path");
+            let resources = super::super::paths::entry_resources_path(&entry);
+            fs::create_dir_all(&resources).expect("Failed to create resources");
+            fs::write(resources.join("AGENTS.md"), vec![b'x'; 1000]).expect("Failed to write");
+            set_mtime(&entry, SystemTime::now() - age);
+            entries.push(entry);
+        }
+
+        let stats = enforce_cache_budget(2500).expect("Failed to enforce budget");
+
+        unsafe {
+            match original {
+                Some(o) => std::env::set_var("AUGENT_CACHE_DIR", o),
+                None => std::env::remove_var("AUGENT_CACHE_DIR"),
+            }
+        }
+        invalidate_index_cache();
+
+        assert_eq!(stats.evicted, 1);
+        assert_eq!(stats.versions, 2);
+        assert!(stats.total_size <= 2500);
+        assert!(!entries[0].exists());
+        assert!(entries[1].exists() && entries[2].exists());
+    }
+}
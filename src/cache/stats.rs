@@ -32,7 +32,7 @@ impl CachedBundle {
     }
 }
 
-fn format_size_human_readable(size_bytes: u64) -> String {
+pub fn format_size_human_readable(size_bytes: u64) -> String {
     #[allow(clippy::cast_precision_loss)]
     let size = size_bytes as f64;
     if size < 1024.0 {
@@ -55,6 +55,8 @@ pub struct CacheStats {
     pub versions: usize,
     /// Total size in bytes
     pub total_size: u64,
+    /// Number of cached versions evicted to stay under the size budget
+    pub evicted: usize,
 }
 
 impl CacheStats {
@@ -145,7 +147,7 @@ fn collect_sha_bundles(entry_path: &Path) -> Result<Vec<ShaBundleStats>> {
 }
 
 /// Read bundle name from `BUNDLE_NAME_FILE`
-fn read_bundle_name(entry_path: &Path) -> Result<String> {
+pub(super) fn read_bundle_name(entry_path: &Path) -> Result<String> {
     fs::read_to_string(entry_path.join(BUNDLE_NAME_FILE))
         .map(|s| s.trim().to_string())
         .map_err(|e| AugentError::CacheOperationFailed {
@@ -258,7 +260,7 @@ pub fn clear_cache() -> Result<()> {
 }
 
 /// Calculate directory size recursively
-fn dir_size(path: &Path) -> Result<u64> {
+pub fn dir_size(path: &Path) -> Result<u64> {
     let mut size = 0u64;
     for entry in WalkDir::new(path)
         .follow_links(false)
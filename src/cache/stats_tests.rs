@@ -18,6 +18,7 @@ fn test_cache_stats_formatted_size() {
         repositories: 1,
         versions: 1,
         total_size: 1024,
+        evicted: 0,
     };
     assert_eq!(stats.formatted_size(), "1.0 KB");
 }
@@ -0,0 +1,173 @@
+//! Integrity checks for cache entries (`augent cache verify`)
+//!
+//! When a bundle is cached, a hash over its `resources/` tree is written to
+//! `.augent_checksum` in the entry directory. Lookups verify it before reuse,
+//! so an entry that was partially written or corrupted on disk is treated as
+//! a cache miss and the repository is cloned again.
+//!
+//! Entries cached before checksums were introduced have no checksum file;
+//! they are reported as unverified and still used.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::error::{AugentError, Result};
+
+use super::paths::{BUNDLE_NAME_FILE, CHECKSUM_FILE, bundles_cache_dir, entry_resources_path};
+
+/// Result of checking a cache entry against its recorded checksum
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EntryIntegrity {
+    /// Resources match the recorded checksum
+    Valid,
+    /// No checksum was recorded for the entry
+    Unverified,
+    /// Resources are missing or differ from the recorded checksum
+    Corrupted,
+}
+
+/// A cache entry checked by `cache verify`
+#[derive(Debug, Clone)]
+pub struct VerifiedEntry {
+    /// Bundle name recorded in the entry (the directory name when missing)
+    pub bundle_name: String,
+    /// Commit SHA of the entry
+    pub sha: String,
+    /// Entry directory (`bundles/<repo_key>/<sha>`)
+    pub path: PathBuf,
+    pub integrity: EntryIntegrity,
+}
+
+/// Record the checksum of the entry's `resources/` tree
+pub fn write_checksum(entry_path: &Path) -> Result<()> {
+    let hash = crate::hash::hash_directory(&entry_resources_path(entry_path))?;
+    let checksum_file = entry_path.join(CHECKSUM_FILE);
+    fs::write(&checksum_file, hash).map_err(|e| AugentError::CacheOperationFailed {
+        message: format!(
+            "Failed to write checksum file {}: {}",
+            checksum_file.display(),
+            e
+        ),
+    })
+}
+
+/// Check the entry's `resources/` tree against its recorded checksum
+pub fn verify_cache_entry(entry_path: &Path) -> EntryIntegrity {
+    let Ok(expected) = fs::read_to_string(entry_path.join(CHECKSUM_FILE)) else {
+        return EntryIntegrity::Unverified;
+    };
+    match crate::hash::hash_directory(&entry_resources_path(entry_path)) {
+        Ok(actual) if crate::hash::verify_hash(expected.trim(), &actual) => EntryIntegrity::Valid,
+        _ => EntryIntegrity::Corrupted,
+    }
+}
+
+/// Remove the resources of a corrupted entry so it is populated from scratch
+pub fn discard_if_corrupted(entry_path: &Path) -> Result<()> {
+    if verify_cache_entry(entry_path) != EntryIntegrity::Corrupted {
+        return Ok(());
+    }
+    let resources = entry_resources_path(entry_path);
+    if resources.exists() {
+        fs::remove_dir_all(&resources).map_err(|e| AugentError::CacheOperationFailed {
+            message: format!(
+                "Failed to remove corrupted cache entry {}: {}",
+                resources.display(),
+                e
+            ),
+        })?;
+    }
+    Ok(())
+}
+
+/// Check every cache entry, sorted by entry path
+pub fn verify_all_entries() -> Result<Vec<VerifiedEntry>> {
+    let bundles_dir = bundles_cache_dir()?;
+    if !bundles_dir.is_dir() {
+        return Ok(Vec::new());
+    }
+
+    let mut verified = Vec::new();
+    for key_dir in subdirectories(&bundles_dir)? {
+        for entry_path in subdirectories(&key_dir)? {
+            let sha = dir_name(&entry_path);
+            let bundle_name = fs::read_to_string(entry_path.join(BUNDLE_NAME_FILE))
+                .map_or_else(|_| dir_name(&key_dir), |name| name.trim().to_string());
+            verified.push(VerifiedEntry {
+                bundle_name,
+                sha,
+                integrity: verify_cache_entry(&entry_path),
+                path: entry_path,
+            });
+        }
+    }
+    Ok(verified)
+}
+
+fn subdirectories(dir: &Path) -> Result<Vec<PathBuf>> {
+    let entries = fs::read_dir(dir).map_err(|e| AugentError::CacheOperationFailed {
+        message: format!("Failed to read cache directory {}: {e}", dir.display()),
+    })?;
+    let mut dirs: Vec<PathBuf> = entries
+        .filter_map(std::result::Result::ok)
+        .map(|e| e.path())
+        .filter(|p| p.is_dir())
+        .collect();
+    dirs.sort();
+    Ok(dirs)
+}
+
+fn dir_name(path: &Path) -> String {
+    path.file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::*;
+
+    fn cached_entry(root: &Path) -> PathBuf {
+        let entry = root.join("author-repo").join("abc123");
+        let resources = entry_resources_path(&entry);
+        fs::create_dir_all(resources.join("commands")).expect("Failed to create resources");
+        fs::write(resources.join("commands/review.md"), "Review.\n")
+            .expect("Failed to write resource");
+        entry
+    }
+
+    #[test]
+    fn test_verify_cache_entry_detects_changed_resources() {
+        let temp = tempfile::TempDir::new_in(crate::temp::temp_dir_base())
+            .expect("Failed to create temp dir");
+        let entry = cached_entry(temp.path());
+        assert_eq!(verify_cache_entry(&entry), EntryIntegrity::Unverified);
+
+        write_checksum(&entry).expect("Failed to write checksum");
+        assert_eq!(verify_cache_entry(&entry), EntryIntegrity::Valid);
+
+        fs::write(
+            entry_resources_path(&entry).join("commands/review.md"),
+            "Rev",
+        )
+        .expect("Failed to truncate resource");
+        assert_eq!(verify_cache_entry(&entry), EntryIntegrity::Corrupted);
+    }
+
+    #[test]
+    fn test_discard_if_corrupted_removes_only_corrupted_resources() {
+        let temp = tempfile::TempDir::new_in(crate::temp::temp_dir_base())
+            .expect("Failed to create temp dir");
+        let entry = cached_entry(temp.path());
+        write_checksum(&entry).expect("Failed to write checksum");
+
+        discard_if_corrupted(&entry).expect("Failed to check entry");
+        assert!(entry_resources_path(&entry).is_dir());
+
+        fs::remove_file(entry_resources_path(&entry).join("commands/review.md"))
+            .expect("Failed to remove resource");
+        discard_if_corrupted(&entry).expect("Failed to discard entry");
+        assert!(!entry_resources_path(&entry).exists());
+    }
+}
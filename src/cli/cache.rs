@@ -6,7 +6,11 @@ use clap::{Parser, Subcommand};
                   Show cache statistics:\n    augent cache\n\n\
                   List cached bundles:\n    augent cache list\n\n\
                   Clear all cached bundles:\n    augent cache clear\n\n\
-                  Remove specific bundle:\n    augent cache clear --only @author/repo")]
+                  Remove specific bundle:\n    augent cache clear --only @author/repo\n\n\
+                  Remove entries cached more than 30 days ago:\n    augent cache clear --older-than 30d\n\n\
+                  Shrink the cache to 1 GB:\n    augent cache clear --max-size 1G\n\n\
+                  Check cached bundles for corruption:\n    augent cache verify\n\n\
+                  Upgrade legacy cache entries:\n    augent cache migrate")]
 pub struct CacheArgs {
     #[command(subcommand)]
     pub command: Option<CacheSubcommand>,
@@ -19,7 +23,14 @@ pub enum CacheSubcommand {
     List,
 
     /// Clear cached bundles
+    #[command(alias = "clean")]
     Clear(ClearCacheArgs),
+
+    /// Re-key legacy per-bundle cache entries to repo-level entries
+    Migrate,
+
+    /// Check cached bundles against their checksums and report corrupted ones
+    Verify,
 }
 
 /// Arguments for cache clear command
@@ -28,4 +39,22 @@ pub struct ClearCacheArgs {
     /// Remove only specific bundle by name (e.g., @author/repo)
     #[arg(long)]
     pub only: Option<String>,
+
+    /// Remove only entries cached longer ago than AGE (e.g. 30d, 12h)
+    #[arg(
+        long,
+        value_name = "AGE",
+        value_parser = crate::cache::prune::parse_age,
+        conflicts_with = "only"
+    )]
+    pub older_than: Option<std::time::Duration>,
+
+    /// Evict least recently used entries until the cache fits in SIZE (e.g. 500M, 2G)
+    #[arg(
+        long,
+        value_name = "SIZE",
+        value_parser = crate::cache::prune::parse_size,
+        conflicts_with = "only"
+    )]
+    pub max_size: Option<u64>,
 }
@@ -0,0 +1,58 @@
+use clap::{ArgGroup, Parser};
+
+/// Arguments for the clean command
+#[derive(Parser, Debug)]
+#[command(
+    group(ArgGroup::new("target").required(true).args(["platforms", "all_platforms"])),
+    after_help = "EXAMPLES:\n  \
+                  Remove augent-managed Cursor files:\n    augent clean --platform cursor\n\n\
+                  Remove augent-managed files for every platform:\n    augent clean --all-platforms\n\n\
+                  Preview what would be removed:\n    augent clean --platform cursor --dry-run"
+)]
+pub struct CleanArgs {
+    /// Platform whose augent-managed files to remove (can be repeated)
+    #[arg(long = "platform", value_name = "PLATFORM")]
+    pub platforms: Vec<String>,
+
+    /// Remove augent-managed files for all platforms
+    #[arg(long)]
+    pub all_platforms: bool,
+
+    /// Show what would be removed without removing anything
+    #[arg(long)]
+    pub dry_run: bool,
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cli_parsing_clean_platform() {
+        let cli = super::super::Cli::try_parse_from([
+            "augent",
+            "clean",
+            "--platform",
+            "cursor",
+            "--platform",
+            "claude",
+        ])
+        .unwrap_or_else(|e| {
+            panic!("Failed to parse CLI arguments: {e}");
+        });
+        match cli.command {
+            super::super::Commands::Clean(args) => {
+                assert_eq!(args.platforms, vec!["cursor", "claude"]);
+                assert!(!args.all_platforms);
+            }
+            _ => panic!("Expected Clean command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_parsing_clean_requires_target() {
+        let result = super::super::Cli::try_parse_from(["augent", "clean"]);
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,19 @@
+use clap::Parser;
+
+/// Arguments for the diff command
+#[derive(Parser, Debug)]
+#[command(after_help = "EXAMPLES:\n  \
+                  Show what installing a bundle would change:\n    augent diff github:author/bundle\n\n  \
+                  Only list the files that would change:\n    augent diff ./bundles/tools --name-only")]
+pub struct DiffArgs {
+    /// Bundle source (path, URL, or GitHub short-form)
+    pub source: String,
+
+    /// Compare only the files of specific platforms (e.g., --to cursor opencode)
+    #[arg(long = "to", short = 't', value_name = "PLATFORM", num_args = 1..)]
+    pub platforms: Vec<String>,
+
+    /// Only list the files that would be added or modified
+    #[arg(long)]
+    pub name_only: bool,
+}
@@ -0,0 +1,17 @@
+use clap::Parser;
+
+/// Arguments for the doctor command
+#[derive(Parser, Debug)]
+#[command(after_help = "EXAMPLES:\n  \
+                  Check the workspace for problems:\n    augent doctor\n\n  \
+                  Also check the cache index against the cache on disk:\n    augent doctor --check-cache\n\n  \
+                  Repair cache index problems:\n    augent doctor --check-cache --fix")]
+pub struct DoctorArgs {
+    /// Also check that the cache index matches the cache entries on disk
+    #[arg(long)]
+    pub check_cache: bool,
+
+    /// Repair problems found by --check-cache
+    #[arg(long, requires = "check_cache")]
+    pub fix: bool,
+}
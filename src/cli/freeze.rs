@@ -0,0 +1,12 @@
+use clap::Parser;
+use std::path::PathBuf;
+
+/// Arguments for the freeze command
+#[derive(Parser, Debug)]
+#[command(after_help = "EXAMPLES:\n  \
+                  Snapshot all locked bundles into vendor/frozen:\n    augent freeze vendor/frozen\n\n  \
+                  Reproduce the snapshot without network access:\n    augent install ./vendor/frozen")]
+pub struct FreezeArgs {
+    /// Output directory for the frozen bundle (inside the workspace)
+    pub out_dir: PathBuf,
+}
@@ -1,7 +1,7 @@
 use clap::Parser;
 
 /// Arguments for the install command
-#[derive(Parser, Debug)]
+#[derive(Parser, Debug, Clone)]
 #[allow(clippy::struct_excessive_bools)]
 #[command(after_help = "EXAMPLES:\n  \
                    Install from GitHub:\n    augent install @author/bundle\n    \
@@ -14,10 +14,27 @@ pub struct InstallArgs {
     /// Supports: @author/repo, github:author/repo, author/repo, ./local-path, https://...
     pub source: Option<String>,
 
+    /// Install every source listed in FILE, one per line (# starts a comment)
+    #[arg(long, value_name = "FILE", conflicts_with = "source")]
+    pub sources_file: Option<std::path::PathBuf>,
+
+    /// Install the sources and platforms declared in a TOML (or .json) manifest FILE
+    #[arg(long, value_name = "FILE", conflicts_with_all = ["source", "sources_file"])]
+    pub manifest: Option<std::path::PathBuf>,
+
     /// Install only for specific platforms (e.g., --to cursor opencode)
     #[arg(long = "to", short = 't', value_name = "PLATFORM", num_args = 1..)]
     pub platforms: Vec<String>,
 
+    /// Also install for these platforms, creating their directories if missing
+    #[arg(
+        long = "platforms-create",
+        value_name = "PLATFORM",
+        num_args = 1..,
+        value_delimiter = ','
+    )]
+    pub platforms_create: Vec<String>,
+
     /// Fail if lockfile would change
     #[arg(long)]
     pub frozen: bool,
@@ -34,9 +51,169 @@ pub struct InstallArgs {
     #[arg(long)]
     pub dry_run: bool,
 
+    /// Resolve bundles and update augent.lock without writing platform files
+    #[arg(long)]
+    pub lock_only: bool,
+
     /// Skip confirmation prompt when uninstalling deselected bundles
     #[arg(long, short = 'y')]
     pub yes: bool,
+
+    /// Verify that generated TOML files (e.g. Gemini commands) parse after writing
+    #[arg(long)]
+    pub validate_toml: bool,
+
+    /// Fail before writing any files if two bundles would replace the same target
+    #[arg(long)]
+    pub strict: bool,
+
+    /// Symlink installed files back to the bundle source when they need no transformation
+    #[arg(long)]
+    pub link: bool,
+
+    /// Install Gemini commands as markdown instead of converting them to TOML
+    #[arg(long)]
+    pub no_universal_gemini_toml: bool,
+
+    /// Copy resource files verbatim instead of merging universal frontmatter
+    #[arg(long)]
+    pub no_frontmatter_merge: bool,
+
+    /// Hide progress and per-file output, print only the final summary
+    #[arg(long)]
+    pub summary_only: bool,
+
+    /// Prepend a comment naming the source bundle, URL and SHA to generated files
+    #[arg(long)]
+    pub stamp: bool,
+
+    /// Merge composite targets (e.g. AGENTS.md) in place, replacing each bundle's previous section
+    #[arg(long)]
+    pub dedupe_composite: bool,
+
+    /// Substitute {{var}} placeholders in markdown bodies (implied by --var)
+    #[arg(long)]
+    pub template: bool,
+
+    /// Template variable as key=value (repeatable; the workspace name and repo URL are built in)
+    #[arg(long = "var", value_name = "KEY=VALUE", value_parser = crate::installer::template::parse_var)]
+    pub vars: Vec<(String, String)>,
+
+    /// Number of parallel workers for fetching and installing (default: CPU count; 1 = sequential)
+    #[arg(
+        long,
+        visible_alias = "jobs",
+        value_name = "N",
+        env = "AUGENT_CONCURRENCY"
+    )]
+    pub concurrency: Option<usize>,
+
+    /// Override a platform's output extension for one resource type (e.g. cursor:rules=txt)
+    #[arg(
+        long,
+        value_name = "PLATFORM:TYPE=EXT",
+        value_parser = crate::platform::output_ext::OutputExtOverride::parse
+    )]
+    pub platform_output_ext: Vec<crate::platform::output_ext::OutputExtOverride>,
+
+    /// Merge one resource type with a different strategy on every platform (e.g. rules=composite; repeatable)
+    #[arg(
+        long,
+        value_name = "TYPE=STRATEGY",
+        value_parser = crate::platform::strategy_override::StrategyOverride::parse
+    )]
+    pub strategy_override: Vec<crate::platform::strategy_override::StrategyOverride>,
+
+    /// Install the raw bundle layout for a platform with no transforms (repeatable)
+    #[arg(long, value_name = "PLATFORM")]
+    pub platform_passthrough: Vec<String>,
+
+    /// Install only JSON settings and MCP config files,
+    /// leaving commands, rules, agents and skills untouched
+    #[arg(long)]
+    pub settings_only: bool,
+
+    /// Skip executable scripts in skill directories, keeping SKILL.md and docs
+    #[arg(long)]
+    pub ignore_scripts: bool,
+
+    /// Order to process platforms in (e.g. claude,cursor); unlisted platforms follow
+    #[arg(long, value_name = "PLATFORMS", value_delimiter = ',')]
+    pub platform_order: Vec<String>,
+
+    /// Never access the network: git bundles must already be cached, and one that
+    /// is not fails the install. With --dry-run, report the cache status of every
+    /// locked git bundle and stop before planning if any needs the network
+    #[arg(long)]
+    pub offline: bool,
+
+    /// With --dry-run, print the install plan as JSON instead of the human-readable summary
+    #[arg(long, requires = "dry_run")]
+    pub json: bool,
+
+    /// Re-read every written file and fail if it does not match what was written
+    #[arg(long)]
+    pub verify: bool,
+
+    /// Resolve refs to the newest cached SHA without checking the remote for updates
+    #[arg(long)]
+    pub prefer_cache: bool,
+
+    /// Reinstall only dir bundle resources modified after FILE's mtime, then touch FILE
+    #[arg(long, value_name = "FILE")]
+    pub since_file: Option<std::path::PathBuf>,
+
+    /// Copy existing files into DIR before install overwrites or merges into them
+    #[arg(long, value_name = "DIR")]
+    pub backup: Option<std::path::PathBuf>,
+
+    /// Write only targets that do not exist yet; existing files are skipped and reported
+    #[arg(long)]
+    pub copy_only_new: bool,
+
+    /// Allow writing into platform directories that are symlinks resolving outside the workspace
+    #[arg(long)]
+    pub allow_external_targets: bool,
+
+    /// Install without warning about uncommitted changes in platform directories
+    #[arg(long)]
+    pub allow_dirty: bool,
+
+    /// Fail instead of creating directories that do not exist yet
+    #[arg(long, conflicts_with = "confirm_new_dirs")]
+    pub no_new_dirs: bool,
+
+    /// Ask before creating each directory that does not exist yet
+    #[arg(long)]
+    pub confirm_new_dirs: bool,
+
+    /// Write a JSON manifest of the files written for each platform to FILE
+    #[arg(long, value_name = "FILE")]
+    pub platform_summary_json: Option<std::path::PathBuf>,
+
+    /// Record each installed file's bundle and SHA in a .augent-provenance.json per platform directory
+    #[arg(long)]
+    pub record_provenance: bool,
+
+    /// Clone git bundles with full history instead of shallow (overrides `AUGENT_CLONE_DEPTH`)
+    #[arg(long)]
+    pub full_clone: bool,
+
+    /// List bundle files that no platform installs, such as files in unrecognized directories
+    #[arg(long)]
+    pub report_unmatched: bool,
+
+    /// Frontmatter key listing the only platforms a resource is installed for
+    #[arg(long, value_name = "KEY", default_value = "platforms")]
+    pub platform_filter_frontmatter_key: String,
+
+    /// When a bundle is required at different commits, use the first one resolved instead of failing
+    #[arg(long)]
+    pub allow_version_conflicts: bool,
+
+    /// Run the workspace's .augent/hooks/pre-install and post-install scripts around the install
+    #[arg(long)]
+    pub allow_hooks: bool,
 }
 
 #[cfg(test)]
@@ -55,6 +232,585 @@ mod tests {
                 assert_eq!(args.source, Some("github:author/bundle".to_string()));
                 assert!(args.platforms.is_empty());
                 assert!(!args.frozen);
+                assert!(!args.validate_toml);
+            }
+            _ => panic!("Expected Install command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_parsing_install_with_lock_only() {
+        let cli = super::super::Cli::try_parse_from(["augent", "install", "--lock-only"])
+            .unwrap_or_else(|e| {
+                panic!("Failed to parse CLI arguments: {e}");
+            });
+        match cli.command {
+            super::super::Commands::Install(args) => {
+                assert_eq!(args.source, None);
+                assert!(args.lock_only);
+                assert!(!args.dry_run);
+            }
+            _ => panic!("Expected Install command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_parsing_install_with_no_frontmatter_merge() {
+        let cli = super::super::Cli::try_parse_from([
+            "augent",
+            "install",
+            "./local-bundle",
+            "--no-frontmatter-merge",
+        ])
+        .unwrap_or_else(|e| {
+            panic!("Failed to parse CLI arguments: {e}");
+        });
+        match cli.command {
+            super::super::Commands::Install(args) => assert!(args.no_frontmatter_merge),
+            _ => panic!("Expected Install command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_parsing_install_with_platforms_create() {
+        let cli = super::super::Cli::try_parse_from([
+            "augent",
+            "install",
+            "./local-bundle",
+            "--platforms-create",
+            "claude,opencode",
+        ])
+        .unwrap_or_else(|e| {
+            panic!("Failed to parse CLI arguments: {e}");
+        });
+        match cli.command {
+            super::super::Commands::Install(args) => {
+                assert_eq!(args.platforms_create, vec!["claude", "opencode"]);
+            }
+            _ => panic!("Expected Install command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_parsing_install_with_summary_only() {
+        let cli = super::super::Cli::try_parse_from(["augent", "install", "--summary-only"])
+            .unwrap_or_else(|e| {
+                panic!("Failed to parse CLI arguments: {e}");
+            });
+        match cli.command {
+            super::super::Commands::Install(args) => assert!(args.summary_only),
+            _ => panic!("Expected Install command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_parsing_install_with_stamp() {
+        let cli = super::super::Cli::try_parse_from(["augent", "install", "--stamp"])
+            .unwrap_or_else(|e| {
+                panic!("Failed to parse CLI arguments: {e}");
+            });
+        match cli.command {
+            super::super::Commands::Install(args) => assert!(args.stamp),
+            _ => panic!("Expected Install command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_parsing_install_with_dedupe_composite() {
+        let cli = super::super::Cli::try_parse_from(["augent", "install", "--dedupe-composite"])
+            .unwrap_or_else(|e| {
+                panic!("Failed to parse CLI arguments: {e}");
+            });
+        match cli.command {
+            super::super::Commands::Install(args) => assert!(args.dedupe_composite),
+            _ => panic!("Expected Install command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_parsing_install_with_vars() {
+        let cli = super::super::Cli::try_parse_from([
+            "augent",
+            "install",
+            "--var",
+            "team=core",
+            "--var",
+            "env=prod",
+        ])
+        .unwrap_or_else(|e| {
+            panic!("Failed to parse CLI arguments: {e}");
+        });
+        match cli.command {
+            super::super::Commands::Install(args) => {
+                assert_eq!(
+                    args.vars,
+                    vec![
+                        ("team".to_string(), "core".to_string()),
+                        ("env".to_string(), "prod".to_string())
+                    ]
+                );
+                assert!(!args.template);
+            }
+            _ => panic!("Expected Install command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_parsing_install_with_concurrency() {
+        let cli = super::super::Cli::try_parse_from(["augent", "install", "--concurrency", "1"])
+            .unwrap_or_else(|e| {
+                panic!("Failed to parse CLI arguments: {e}");
+            });
+        match cli.command {
+            super::super::Commands::Install(args) => assert_eq!(args.concurrency, Some(1)),
+            _ => panic!("Expected Install command"),
+        }
+
+        let cli = super::super::Cli::try_parse_from(["augent", "install", "--jobs", "4"])
+            .unwrap_or_else(|e| {
+                panic!("Failed to parse CLI arguments: {e}");
+            });
+        match cli.command {
+            super::super::Commands::Install(args) => assert_eq!(args.concurrency, Some(4)),
+            _ => panic!("Expected Install command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_parsing_install_with_verify() {
+        let cli = super::super::Cli::try_parse_from(["augent", "install", "--verify"])
+            .unwrap_or_else(|e| {
+                panic!("Failed to parse CLI arguments: {e}");
+            });
+        match cli.command {
+            super::super::Commands::Install(args) => assert!(args.verify),
+            _ => panic!("Expected Install command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_parsing_install_with_sources_file() {
+        let cli = super::super::Cli::try_parse_from([
+            "augent",
+            "install",
+            "--sources-file",
+            "bundles.txt",
+        ])
+        .unwrap_or_else(|e| {
+            panic!("Failed to parse CLI arguments: {e}");
+        });
+        match cli.command {
+            super::super::Commands::Install(args) => assert_eq!(
+                args.sources_file,
+                Some(std::path::PathBuf::from("bundles.txt"))
+            ),
+            _ => panic!("Expected Install command"),
+        }
+        assert!(
+            super::super::Cli::try_parse_from([
+                "augent",
+                "install",
+                "./bundle",
+                "--sources-file",
+                "bundles.txt"
+            ])
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_cli_parsing_install_with_manifest() {
+        let cli =
+            super::super::Cli::try_parse_from(["augent", "install", "--manifest", "augent.toml"])
+                .unwrap_or_else(|e| {
+                    panic!("Failed to parse CLI arguments: {e}");
+                });
+        match cli.command {
+            super::super::Commands::Install(args) => {
+                assert_eq!(args.manifest, Some(std::path::PathBuf::from("augent.toml")));
+            }
+            _ => panic!("Expected Install command"),
+        }
+
+        let result = super::super::Cli::try_parse_from([
+            "augent",
+            "install",
+            "./bundle",
+            "--manifest",
+            "augent.toml",
+        ]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_cli_parsing_install_offline() {
+        let cli =
+            super::super::Cli::try_parse_from(["augent", "install", "--dry-run", "--offline"])
+                .unwrap_or_else(|e| {
+                    panic!("Failed to parse CLI arguments: {e}");
+                });
+        match cli.command {
+            super::super::Commands::Install(args) => assert!(args.offline),
+            _ => panic!("Expected Install command"),
+        }
+        let cli = super::super::Cli::try_parse_from(["augent", "install", "--offline"])
+            .unwrap_or_else(|e| {
+                panic!("Failed to parse CLI arguments: {e}");
+            });
+        match cli.command {
+            super::super::Commands::Install(args) => assert!(args.offline && !args.dry_run),
+            _ => panic!("Expected Install command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_parsing_install_with_platform_summary_json() {
+        let cli = super::super::Cli::try_parse_from([
+            "augent",
+            "install",
+            "--platform-summary-json",
+            "manifest.json",
+        ])
+        .unwrap_or_else(|e| {
+            panic!("Failed to parse CLI arguments: {e}");
+        });
+        match cli.command {
+            super::super::Commands::Install(args) => assert_eq!(
+                args.platform_summary_json,
+                Some(std::path::PathBuf::from("manifest.json"))
+            ),
+            _ => panic!("Expected Install command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_parsing_install_with_settings_only() {
+        let cli = super::super::Cli::try_parse_from([
+            "augent",
+            "install",
+            "--to",
+            "claude",
+            "--settings-only",
+        ])
+        .unwrap_or_else(|e| {
+            panic!("Failed to parse CLI arguments: {e}");
+        });
+        match cli.command {
+            super::super::Commands::Install(args) => assert!(args.settings_only),
+            _ => panic!("Expected Install command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_parsing_install_with_ignore_scripts() {
+        let cli = super::super::Cli::try_parse_from(["augent", "install", "--ignore-scripts"])
+            .unwrap_or_else(|e| {
+                panic!("Failed to parse CLI arguments: {e}");
+            });
+        match cli.command {
+            super::super::Commands::Install(args) => assert!(args.ignore_scripts),
+            _ => panic!("Expected Install command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_parsing_install_with_prefer_cache() {
+        let cli = super::super::Cli::try_parse_from(["augent", "install", "--prefer-cache"])
+            .unwrap_or_else(|e| {
+                panic!("Failed to parse CLI arguments: {e}");
+            });
+        match cli.command {
+            super::super::Commands::Install(args) => assert!(args.prefer_cache),
+            _ => panic!("Expected Install command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_parsing_install_with_backup() {
+        let cli = super::super::Cli::try_parse_from(["augent", "install", "--backup", "backups"])
+            .unwrap_or_else(|e| {
+                panic!("Failed to parse CLI arguments: {e}");
+            });
+        match cli.command {
+            super::super::Commands::Install(args) => {
+                assert_eq!(args.backup, Some(std::path::PathBuf::from("backups")));
+            }
+            _ => panic!("Expected Install command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_parsing_install_with_copy_only_new() {
+        let cli = super::super::Cli::try_parse_from(["augent", "install", "--copy-only-new"])
+            .unwrap_or_else(|e| {
+                panic!("Failed to parse CLI arguments: {e}");
+            });
+        match cli.command {
+            super::super::Commands::Install(args) => assert!(args.copy_only_new),
+            _ => panic!("Expected Install command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_parsing_install_with_allow_dirty() {
+        let cli = super::super::Cli::try_parse_from(["augent", "install", "--allow-dirty"])
+            .unwrap_or_else(|e| {
+                panic!("Failed to parse CLI arguments: {e}");
+            });
+        match cli.command {
+            super::super::Commands::Install(args) => assert!(args.allow_dirty),
+            _ => panic!("Expected Install command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_parsing_install_new_dirs_flags_conflict() {
+        let cli = super::super::Cli::try_parse_from(["augent", "install", "--no-new-dirs"])
+            .unwrap_or_else(|e| {
+                panic!("Failed to parse CLI arguments: {e}");
+            });
+        match cli.command {
+            super::super::Commands::Install(args) => {
+                assert!(args.no_new_dirs);
+                assert!(!args.confirm_new_dirs);
+            }
+            _ => panic!("Expected Install command"),
+        }
+
+        let result = super::super::Cli::try_parse_from([
+            "augent",
+            "install",
+            "--no-new-dirs",
+            "--confirm-new-dirs",
+        ]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_cli_parsing_install_with_record_provenance() {
+        let cli = super::super::Cli::try_parse_from(["augent", "install", "--record-provenance"])
+            .unwrap_or_else(|e| {
+                panic!("Failed to parse CLI arguments: {e}");
+            });
+        match cli.command {
+            super::super::Commands::Install(args) => assert!(args.record_provenance),
+            _ => panic!("Expected Install command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_parsing_install_with_allow_version_conflicts() {
+        let cli =
+            super::super::Cli::try_parse_from(["augent", "install", "--allow-version-conflicts"])
+                .unwrap_or_else(|e| {
+                    panic!("Failed to parse CLI arguments: {e}");
+                });
+        match cli.command {
+            super::super::Commands::Install(args) => assert!(args.allow_version_conflicts),
+            _ => panic!("Expected Install command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_parsing_install_with_allow_external_targets() {
+        let cli =
+            super::super::Cli::try_parse_from(["augent", "install", "--allow-external-targets"])
+                .unwrap_or_else(|e| {
+                    panic!("Failed to parse CLI arguments: {e}");
+                });
+        match cli.command {
+            super::super::Commands::Install(args) => assert!(args.allow_external_targets),
+            _ => panic!("Expected Install command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_parsing_install_with_since_file() {
+        let cli = super::super::Cli::try_parse_from([
+            "augent",
+            "install",
+            "./bundles/local",
+            "--since-file",
+            ".augent/marker",
+        ])
+        .unwrap_or_else(|e| {
+            panic!("Failed to parse CLI arguments: {e}");
+        });
+        match cli.command {
+            super::super::Commands::Install(args) => assert_eq!(
+                args.since_file,
+                Some(std::path::PathBuf::from(".augent/marker"))
+            ),
+            _ => panic!("Expected Install command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_parsing_install_with_platform_order() {
+        let cli = super::super::Cli::try_parse_from([
+            "augent",
+            "install",
+            "--platform-order",
+            "cursor,claude",
+        ])
+        .unwrap_or_else(|e| {
+            panic!("Failed to parse CLI arguments: {e}");
+        });
+        match cli.command {
+            super::super::Commands::Install(args) => {
+                assert_eq!(args.platform_order, vec!["cursor", "claude"]);
+            }
+            _ => panic!("Expected Install command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_parsing_install_with_platform_passthrough() {
+        let cli = super::super::Cli::try_parse_from([
+            "augent",
+            "install",
+            "--platform-passthrough",
+            "cursor",
+            "--platform-passthrough",
+            "windsurf",
+        ])
+        .unwrap_or_else(|e| {
+            panic!("Failed to parse CLI arguments: {e}");
+        });
+        match cli.command {
+            super::super::Commands::Install(args) => {
+                assert_eq!(args.platform_passthrough, vec!["cursor", "windsurf"]);
+            }
+            _ => panic!("Expected Install command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_parsing_install_with_platform_output_ext() {
+        let cli = super::super::Cli::try_parse_from([
+            "augent",
+            "install",
+            "--platform-output-ext",
+            "cursor:rules=txt",
+        ])
+        .unwrap_or_else(|e| {
+            panic!("Failed to parse CLI arguments: {e}");
+        });
+        match cli.command {
+            super::super::Commands::Install(args) => {
+                assert_eq!(args.platform_output_ext.len(), 1);
+                assert_eq!(args.platform_output_ext[0].resource_type, "rules");
+                assert_eq!(args.platform_output_ext[0].extension, "txt");
+            }
+            _ => panic!("Expected Install command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_parsing_install_with_strategy_override() {
+        let cli = super::super::Cli::try_parse_from([
+            "augent",
+            "install",
+            "--strategy-override",
+            "rules=composite",
+            "--strategy-override",
+            "mcp=shallow",
+        ])
+        .unwrap_or_else(|e| {
+            panic!("Failed to parse CLI arguments: {e}");
+        });
+        match cli.command {
+            super::super::Commands::Install(args) => {
+                let types: Vec<&str> = args
+                    .strategy_override
+                    .iter()
+                    .map(|o| o.resource_type.as_str())
+                    .collect();
+                assert_eq!(types, vec!["rules", "mcp_servers"]);
+                assert_eq!(
+                    args.strategy_override[0].strategy,
+                    crate::platform::MergeStrategy::Composite
+                );
+            }
+            _ => panic!("Expected Install command"),
+        }
+
+        let result = super::super::Cli::try_parse_from([
+            "augent",
+            "install",
+            "--strategy-override",
+            "rules=append",
+        ]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_cli_parsing_install_json_requires_dry_run() {
+        assert!(super::super::Cli::try_parse_from(["augent", "install", "--json"]).is_err());
+        let cli = super::super::Cli::try_parse_from(["augent", "install", "--dry-run", "--json"])
+            .unwrap_or_else(|e| {
+                panic!("Failed to parse CLI arguments: {e}");
+            });
+        match cli.command {
+            super::super::Commands::Install(args) => assert!(args.json && args.dry_run),
+            _ => panic!("Expected Install command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_parsing_install_no_universal_gemini_toml() {
+        let cli =
+            super::super::Cli::try_parse_from(["augent", "install", "--no-universal-gemini-toml"])
+                .unwrap_or_else(|e| {
+                    panic!("Failed to parse CLI arguments: {e}");
+                });
+        match cli.command {
+            super::super::Commands::Install(args) => assert!(args.no_universal_gemini_toml),
+            _ => panic!("Expected Install command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_parsing_install_with_link() {
+        let cli = super::super::Cli::try_parse_from(["augent", "install", "--link"])
+            .unwrap_or_else(|e| {
+                panic!("Failed to parse CLI arguments: {e}");
+            });
+        match cli.command {
+            super::super::Commands::Install(args) => assert!(args.link),
+            _ => panic!("Expected Install command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_parsing_install_with_strict() {
+        let cli = super::super::Cli::try_parse_from(["augent", "install", "--strict"])
+            .unwrap_or_else(|e| {
+                panic!("Failed to parse CLI arguments: {e}");
+            });
+        match cli.command {
+            super::super::Commands::Install(args) => assert!(args.strict),
+            _ => panic!("Expected Install command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_parsing_install_with_validate_toml() {
+        let cli = super::super::Cli::try_parse_from([
+            "augent",
+            "install",
+            "./local-bundle",
+            "--to",
+            "gemini",
+            "--validate-toml",
+        ])
+        .unwrap_or_else(|e| {
+            panic!("Failed to parse CLI arguments: {e}");
+        });
+        match cli.command {
+            super::super::Commands::Install(args) => {
+                assert_eq!(args.platforms, vec!["gemini"]);
+                assert!(args.validate_toml);
             }
             _ => panic!("Expected Install command"),
         }
@@ -116,4 +872,57 @@ mod tests {
             _ => panic!("Expected Install command"),
         }
     }
+
+    #[test]
+    fn test_cli_parsing_install_full_clone() {
+        let cli = super::super::Cli::try_parse_from(["augent", "install", "--full-clone"])
+            .unwrap_or_else(|e| {
+                panic!("Failed to parse CLI arguments: {e}");
+            });
+        match cli.command {
+            super::super::Commands::Install(args) => assert!(args.full_clone),
+            _ => panic!("Expected Install command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_parsing_install_report_unmatched() {
+        let cli = super::super::Cli::try_parse_from(["augent", "install", "--report-unmatched"])
+            .unwrap_or_else(|e| {
+                panic!("Failed to parse CLI arguments: {e}");
+            });
+        match cli.command {
+            super::super::Commands::Install(args) => assert!(args.report_unmatched),
+            _ => panic!("Expected Install command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_parsing_install_platform_filter_frontmatter_key() {
+        let cli = super::super::Cli::try_parse_from(["augent", "install"]).unwrap_or_else(|e| {
+            panic!("Failed to parse CLI arguments: {e}");
+        });
+        match cli.command {
+            super::super::Commands::Install(args) => {
+                assert_eq!(args.platform_filter_frontmatter_key, "platforms");
+            }
+            _ => panic!("Expected Install command"),
+        }
+
+        let cli = super::super::Cli::try_parse_from([
+            "augent",
+            "install",
+            "--platform-filter-frontmatter-key",
+            "targets",
+        ])
+        .unwrap_or_else(|e| {
+            panic!("Failed to parse CLI arguments: {e}");
+        });
+        match cli.command {
+            super::super::Commands::Install(args) => {
+                assert_eq!(args.platform_filter_frontmatter_key, "targets");
+            }
+            _ => panic!("Expected Install command"),
+        }
+    }
 }
@@ -1,4 +1,24 @@
-use clap::Parser;
+use clap::{Parser, ValueEnum};
+
+/// Output format of `list --output-format`
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Aligned columns: name, version, file count and source
+    Table,
+    /// JSON array of bundles
+    Json,
+    /// YAML list of bundles
+    Yaml,
+    /// One `name<TAB>source` line per bundle
+    Plain,
+}
+
+/// Output format of `list --graph`
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GraphFormat {
+    /// Graphviz DOT digraph: bundles as nodes, dependencies as edges
+    Dot,
+}
 
 /// Arguments for the list command
 #[derive(Parser, Debug)]
@@ -6,6 +26,9 @@ use clap::Parser;
                   List all installed bundles:\n    augent list\n\n\
                   Show detailed information:\n    augent list --detailed\n\n\
                   Output as JSON:\n    augent list --json\n\n\
+                  Output bundle names for piping:\n    augent list --output-format plain\n\n\
+                  Show platform files not installed by augent:\n    augent list --unmanaged\n\n\
+                  Render the dependency graph:\n    augent list --graph dot | dot -Tsvg -o deps.svg\n\n\
                   Use verbose output:\n    augent list -v")]
 pub struct ListArgs {
     /// Show detailed output
@@ -15,4 +38,26 @@ pub struct ListArgs {
     /// Output in JSON format
     #[arg(long)]
     pub json: bool,
+
+    /// Output format (table, json, yaml, plain); defaults to the descriptive listing
+    #[arg(
+        long,
+        value_enum,
+        value_name = "FORMAT",
+        conflicts_with_all = ["json", "unmanaged"]
+    )]
+    pub output_format: Option<OutputFormat>,
+
+    /// List files in platform directories that are not tracked by augent
+    #[arg(long)]
+    pub unmanaged: bool,
+
+    /// Print the bundle dependency graph in the given format (dot)
+    #[arg(
+        long,
+        value_enum,
+        value_name = "FORMAT",
+        conflicts_with_all = ["json", "output_format", "unmanaged", "detailed"]
+    )]
+    pub graph: Option<GraphFormat>,
 }
@@ -6,24 +6,45 @@
 //! - list: List command arguments
 //! - show: Show command arguments
 //! - cache: Cache command arguments
+//! - clean: Clean command arguments
 //! - completions: Completions command arguments
+//! - diff: Diff command arguments
+//! - doctor: Doctor command arguments
+//! - platforms: Platforms command arguments
+//! - freeze: Freeze command arguments
+//! - outdated: Outdated command arguments
+//! - status: Status command arguments
 
 use clap::builder::{Styles, styling::AnsiColor};
 use clap::{Parser, Subcommand};
 use std::path::PathBuf;
 
 pub mod cache;
+pub mod clean;
 pub mod completions;
+pub mod diff;
+pub mod doctor;
+pub mod freeze;
 pub mod install;
 pub mod list;
+pub mod outdated;
+pub mod platforms;
 pub mod show;
+pub mod status;
 pub mod uninstall;
 
-pub use cache::{CacheArgs, CacheSubcommand};
+pub use cache::{CacheArgs, CacheSubcommand, ClearCacheArgs};
+pub use clean::CleanArgs;
 pub use completions::CompletionsArgs;
+pub use diff::DiffArgs;
+pub use doctor::DoctorArgs;
+pub use freeze::FreezeArgs;
 pub use install::InstallArgs;
-pub use list::ListArgs;
+pub use list::{GraphFormat, ListArgs, OutputFormat};
+pub use outdated::OutdatedArgs;
+pub use platforms::PlatformsArgs;
 pub use show::ShowArgs;
+pub use status::StatusArgs;
 pub use uninstall::UninstallArgs;
 
 /// Augent - AI configuration manager
@@ -54,14 +75,24 @@ pub use uninstall::UninstallArgs;
                   "
 )]
 pub struct Cli {
-    /// Workspace directory (defaults to current directory)
-    #[arg(long, short = 'w', global = true, env = "AUGENT_WORKSPACE")]
-    pub workspace: Option<PathBuf>,
+    /// Workspace directory (defaults to current directory; install accepts it repeatedly)
+    #[arg(
+        long,
+        short = 'w',
+        global = true,
+        env = "AUGENT_WORKSPACE",
+        action = clap::ArgAction::Append
+    )]
+    pub workspace: Vec<PathBuf>,
 
     /// Enable verbose output
     #[arg(long, short = 'v', global = true)]
     pub verbose: bool,
 
+    /// Lockfile profile: pin bundles in .augent/augent.<PROFILE>.lock instead of augent.lock
+    #[arg(long, global = true, value_name = "PROFILE")]
+    pub profile: Option<String>,
+
     #[command(subcommand)]
     pub command: Commands,
 }
@@ -69,7 +100,7 @@ pub struct Cli {
 #[derive(Subcommand, Debug)]
 pub enum Commands {
     /// Install bundles from various sources
-    Install(InstallArgs),
+    Install(Box<InstallArgs>),
 
     /// Remove bundles from workspace
     Uninstall(UninstallArgs),
@@ -77,9 +108,30 @@ pub enum Commands {
     /// List installed bundles
     List(ListArgs),
 
+    /// Report git bundles whose refs point to newer commits on the remote
+    Outdated(OutdatedArgs),
+
+    /// Report installed files that were modified or deleted since install
+    Status(StatusArgs),
+
     /// Show bundle information
     Show(ShowArgs),
 
+    /// Show how installing a bundle would change the workspace files, without installing
+    Diff(DiffArgs),
+
+    /// Remove augent-managed files from platform directories
+    Clean(CleanArgs),
+
+    /// Check the workspace for problems (e.g. augent.yaml refs not matching the lockfile)
+    Doctor(DoctorArgs),
+
+    /// Copy every locked bundle's source into one committable dir bundle
+    Freeze(FreezeArgs),
+
+    /// List known platforms and whether they are detected in the workspace
+    Platforms(PlatformsArgs),
+
     /// Manage cache directory
     #[command(name = "cache")]
     Cache(CacheArgs),
@@ -103,6 +155,34 @@ mod tests {
         assert!(matches!(cli.command, Commands::List(_)));
     }
 
+    #[test]
+    fn test_cli_parsing_list_output_format() {
+        let cli = Cli::try_parse_from(["augent", "list", "--output-format", "yaml"])
+            .expect("Failed to parse CLI arguments");
+        match cli.command {
+            Commands::List(args) => {
+                assert_eq!(args.output_format, Some(OutputFormat::Yaml));
+            }
+            _ => panic!("Expected List command"),
+        }
+        assert!(Cli::try_parse_from(["augent", "list", "--output-format", "xml"]).is_err());
+        assert!(
+            Cli::try_parse_from(["augent", "list", "--output-format", "json", "--json"]).is_err()
+        );
+    }
+
+    #[test]
+    fn test_cli_parsing_list_graph() {
+        let cli = Cli::try_parse_from(["augent", "list", "--graph", "dot"])
+            .expect("Failed to parse CLI arguments");
+        match cli.command {
+            Commands::List(args) => assert_eq!(args.graph, Some(GraphFormat::Dot)),
+            _ => panic!("Expected List command"),
+        }
+        assert!(Cli::try_parse_from(["augent", "list", "--graph", "svg"]).is_err());
+        assert!(Cli::try_parse_from(["augent", "list", "--graph", "dot", "--json"]).is_err());
+    }
+
     #[test]
     fn test_cli_parsing_show() {
         let cli = Cli::try_parse_from(["augent", "show", "my-bundle"])
@@ -126,6 +206,193 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_cli_parsing_show_mcp() {
+        let cli = Cli::try_parse_from(["augent", "show", "--mcp", "--platform", "claude"])
+            .expect("Failed to parse CLI arguments");
+        match cli.command {
+            Commands::Show(args) => {
+                assert!(args.mcp);
+                assert_eq!(args.platform, Some("claude".to_string()));
+            }
+            _ => panic!("Expected Show command"),
+        }
+
+        assert!(Cli::try_parse_from(["augent", "show", "--mcp"]).is_err());
+    }
+
+    #[test]
+    fn test_cli_parsing_show_tree() {
+        let cli = Cli::try_parse_from(["augent", "show", "my-bundle", "--tree"])
+            .expect("Failed to parse CLI arguments");
+        match cli.command {
+            Commands::Show(args) => {
+                assert!(args.tree);
+                assert_eq!(args.name, Some("my-bundle".to_string()));
+            }
+            _ => panic!("Expected Show command"),
+        }
+
+        assert!(Cli::try_parse_from(["augent", "show", "my-bundle", "--tree", "--json"]).is_err());
+    }
+
+    #[test]
+    fn test_cli_parsing_show_deps() {
+        let cli = Cli::try_parse_from(["augent", "show", "./bundles/tools", "--deps"])
+            .expect("Failed to parse CLI arguments");
+        match cli.command {
+            Commands::Show(args) => {
+                assert!(args.deps);
+                assert_eq!(args.name, Some("./bundles/tools".to_string()));
+            }
+            _ => panic!("Expected Show command"),
+        }
+
+        assert!(Cli::try_parse_from(["augent", "show", "--deps"]).is_err());
+    }
+
+    #[test]
+    fn test_cli_parsing_show_size() {
+        let cli = Cli::try_parse_from(["augent", "show", "my-bundle", "--size"])
+            .expect("Failed to parse CLI arguments");
+        match cli.command {
+            Commands::Show(args) => {
+                assert!(args.size);
+                assert_eq!(args.name, Some("my-bundle".to_string()));
+            }
+            _ => panic!("Expected Show command"),
+        }
+
+        assert!(Cli::try_parse_from(["augent", "show", "my-bundle", "--size", "--json"]).is_err());
+    }
+
+    #[test]
+    fn test_cli_parsing_cache_migrate() {
+        let cli = Cli::try_parse_from(["augent", "cache", "migrate"])
+            .expect("Failed to parse CLI arguments");
+        assert!(matches!(
+            cli.command,
+            Commands::Cache(CacheArgs {
+                command: Some(CacheSubcommand::Migrate)
+            })
+        ));
+    }
+
+    #[test]
+    fn test_cli_parsing_cache_clear_older_than() {
+        let cli = Cli::try_parse_from(["augent", "cache", "clean", "--older-than", "30d"])
+            .expect("Failed to parse CLI arguments");
+        match cli.command {
+            Commands::Cache(CacheArgs {
+                command: Some(CacheSubcommand::Clear(args)),
+            }) => assert_eq!(
+                args.older_than,
+                Some(std::time::Duration::from_secs(30 * 24 * 60 * 60))
+            ),
+            _ => panic!("Expected Cache clear command"),
+        }
+
+        assert!(Cli::try_parse_from(["augent", "cache", "clear", "--older-than", "30"]).is_err());
+    }
+
+    #[test]
+    fn test_cli_parsing_cache_clear_max_size() {
+        let cli = Cli::try_parse_from(["augent", "cache", "clear", "--max-size", "2G"])
+            .expect("Failed to parse CLI arguments");
+        match cli.command {
+            Commands::Cache(CacheArgs {
+                command: Some(CacheSubcommand::Clear(args)),
+            }) => assert_eq!(args.max_size, Some(2 * 1024 * 1024 * 1024)),
+            _ => panic!("Expected Cache clear command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_parsing_cache_verify() {
+        let cli = Cli::try_parse_from(["augent", "cache", "verify"])
+            .expect("Failed to parse CLI arguments");
+        assert!(matches!(
+            cli.command,
+            Commands::Cache(CacheArgs {
+                command: Some(CacheSubcommand::Verify)
+            })
+        ));
+    }
+
+    #[test]
+    fn test_cli_parsing_doctor() {
+        let cli = Cli::try_parse_from(["augent", "doctor"]).expect("Failed to parse CLI arguments");
+        assert!(matches!(cli.command, Commands::Doctor(_)));
+    }
+
+    #[test]
+    fn test_cli_parsing_doctor_check_cache_fix() {
+        let cli = Cli::try_parse_from(["augent", "doctor", "--check-cache", "--fix"])
+            .expect("Failed to parse CLI arguments");
+        assert!(matches!(
+            cli.command,
+            Commands::Doctor(DoctorArgs {
+                check_cache: true,
+                fix: true
+            })
+        ));
+        assert!(Cli::try_parse_from(["augent", "doctor", "--fix"]).is_err());
+    }
+
+    #[test]
+    fn test_cli_parsing_platforms_detect() {
+        let cli = Cli::try_parse_from(["augent", "platforms", "--detect"])
+            .expect("Failed to parse CLI arguments");
+        assert!(matches!(
+            cli.command,
+            Commands::Platforms(PlatformsArgs { detect: true })
+        ));
+    }
+
+    #[test]
+    fn test_cli_parsing_freeze() {
+        let cli = Cli::try_parse_from(["augent", "freeze", "vendor/frozen"])
+            .expect("Failed to parse CLI arguments");
+        match cli.command {
+            Commands::Freeze(args) => assert_eq!(args.out_dir, PathBuf::from("vendor/frozen")),
+            _ => panic!("Expected Freeze command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_parsing_diff() {
+        let cli = Cli::try_parse_from(["augent", "diff", "./bundle", "--name-only"])
+            .expect("Failed to parse CLI arguments");
+        match cli.command {
+            Commands::Diff(args) => {
+                assert_eq!(args.source, "./bundle");
+                assert!(args.name_only);
+                assert!(args.platforms.is_empty());
+            }
+            _ => panic!("Expected Diff command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_parsing_outdated() {
+        let cli = Cli::try_parse_from(["augent", "outdated", "--json"])
+            .expect("Failed to parse CLI arguments");
+        assert!(matches!(
+            cli.command,
+            Commands::Outdated(OutdatedArgs { json: true })
+        ));
+    }
+
+    #[test]
+    fn test_cli_parsing_status_quiet() {
+        let cli =
+            Cli::try_parse_from(["augent", "status", "-q"]).expect("Failed to parse CLI arguments");
+        assert!(matches!(
+            cli.command,
+            Commands::Status(StatusArgs { quiet: true })
+        ));
+    }
+
     #[test]
     fn test_cli_parsing_version() {
         let cli =
@@ -138,7 +405,17 @@ mod tests {
         let cli = Cli::try_parse_from(["augent", "-v", "-w", "/tmp/workspace", "list"])
             .expect("Failed to parse CLI arguments");
         assert!(cli.verbose);
-        assert_eq!(cli.workspace, Some(PathBuf::from("/tmp/workspace")));
+        assert_eq!(cli.workspace, vec![PathBuf::from("/tmp/workspace")]);
+    }
+
+    #[test]
+    fn test_cli_profile_option() {
+        let cli = Cli::try_parse_from(["augent", "install", "./bundle", "--profile", "ci"])
+            .expect("Failed to parse CLI arguments");
+        assert_eq!(cli.profile.as_deref(), Some("ci"));
+
+        let cli = Cli::try_parse_from(["augent", "list"]).expect("Failed to parse CLI arguments");
+        assert!(cli.profile.is_none());
     }
 
     #[test]
@@ -153,7 +430,18 @@ mod tests {
         };
         let cli = Cli::try_parse_from(["augent", "-w", env_path, "list"])
             .expect("Failed to parse CLI arguments");
-        assert_eq!(cli.workspace, Some(PathBuf::from(env_path)));
+        assert_eq!(cli.workspace, vec![PathBuf::from(env_path)]);
+    }
+
+    #[test]
+    fn test_cli_workspace_repeated() {
+        let cli =
+            Cli::try_parse_from(["augent", "install", "-w", "/tmp/a", "--workspace", "/tmp/b"])
+                .expect("Failed to parse CLI arguments");
+        assert_eq!(
+            cli.workspace,
+            vec![PathBuf::from("/tmp/a"), PathBuf::from("/tmp/b")]
+        );
     }
 
     #[test]
@@ -177,7 +465,7 @@ mod tests {
         let cli = Cli::try_parse_from(["augent", "-w", flag_path, "list"])
             .expect("Failed to parse CLI arguments");
         // Flag should override environment variable
-        assert_eq!(cli.workspace, Some(PathBuf::from(flag_path)));
+        assert_eq!(cli.workspace, vec![PathBuf::from(flag_path)]);
         unsafe {
             std::env::remove_var("AUGENT_WORKSPACE");
         }
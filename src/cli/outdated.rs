@@ -0,0 +1,12 @@
+use clap::Parser;
+
+/// Arguments for the outdated command
+#[derive(Parser, Debug)]
+#[command(after_help = "EXAMPLES:\n  \
+                  Show git bundles whose refs moved on the remote:\n    augent outdated\n\n  \
+                  Output as JSON:\n    augent outdated --json")]
+pub struct OutdatedArgs {
+    /// Output in JSON format
+    #[arg(long)]
+    pub json: bool,
+}
@@ -0,0 +1,12 @@
+use clap::Parser;
+
+/// Arguments for the platforms command
+#[derive(Parser, Debug)]
+#[command(after_help = "EXAMPLES:\n  \
+                  List known platforms:\n    augent platforms\n\n\
+                  Show which platforms are detected and why:\n    augent platforms --detect")]
+pub struct PlatformsArgs {
+    /// Show whether each platform is detected in the workspace and installed to by default
+    #[arg(long)]
+    pub detect: bool,
+}
@@ -2,13 +2,18 @@ use clap::Parser;
 
 /// Arguments for the show command
 #[derive(Parser, Debug)]
+#[allow(clippy::struct_excessive_bools)]
 #[command(after_help = "EXAMPLES:\n  \
                   Show bundle information:\n    augent show my-bundle\n\n\
                   Show a specific bundle:\n    augent show author/debug-tools\n\n\
                   Show all bundles under a scope:\n    augent show @wshobson/agents\n\n\
                   Select bundle interactively:\n    augent show\n\n\
                   Show including dependencies:\n    augent show my-bundle --detailed\n\n\
-                  Output as JSON:\n    augent show my-bundle --json")]
+                  Output as JSON:\n    augent show my-bundle --json\n\n\
+                  Show the resource layout as a tree:\n    augent show my-bundle --tree\n\n\
+                  Show the source and installed disk footprint:\n    augent show my-bundle --size\n\n\
+                  List a source's declared dependencies before installing:\n    augent show github:author/bundle --deps\n\n\
+                  Preview the merged MCP config for a platform:\n    augent show --mcp --platform claude")]
 pub struct ShowArgs {
     /// Bundle name or scope prefix to show (if omitted, shows interactive menu)
     /// Supports scope prefixes like @author/scope to show all matching bundles
@@ -21,4 +26,24 @@ pub struct ShowArgs {
     /// Output in JSON format
     #[arg(long)]
     pub json: bool,
+
+    /// Print the bundle's resource layout as a directory tree with file counts
+    #[arg(long, conflicts_with_all = ["detailed", "json"])]
+    pub tree: bool,
+
+    /// Print the bundle's cached source size and the total size of its installed files
+    #[arg(long, conflicts_with_all = ["detailed", "json", "tree"])]
+    pub size: bool,
+
+    /// Resolve NAME as a source and list the dependencies its augent.yaml declares, without installing
+    #[arg(long, requires = "name", conflicts_with_all = ["detailed", "json", "tree", "size", "mcp"])]
+    pub deps: bool,
+
+    /// Print the MCP config that merging all workspace bundles would produce
+    #[arg(long, requires = "platform", conflicts_with_all = ["name", "detailed", "json", "tree", "size"])]
+    pub mcp: bool,
+
+    /// Platform whose MCP config to preview (with --mcp)
+    #[arg(long, value_name = "PLATFORM", requires = "mcp")]
+    pub platform: Option<String>,
 }
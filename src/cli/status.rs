@@ -0,0 +1,12 @@
+use clap::Parser;
+
+/// Arguments for the status command
+#[derive(Parser, Debug)]
+#[command(after_help = "EXAMPLES:\n  \
+                  Show modified and missing installed files:\n    augent status\n\n  \
+                  Fail a CI job when installed files drifted:\n    augent status --quiet")]
+pub struct StatusArgs {
+    /// Print nothing; only set the exit code (7 when files drifted)
+    #[arg(short, long)]
+    pub quiet: bool,
+}
@@ -2,12 +2,14 @@ use clap::Parser;
 
 /// Arguments for the uninstall command
 #[derive(Parser, Debug)]
+#[allow(clippy::struct_excessive_bools)]
 #[command(after_help = "EXAMPLES:\n  \
                   Uninstall a bundle:\n    augent uninstall my-bundle\n\n\
                   Uninstall without confirmation:\n    augent uninstall my-bundle -y\n\n\
                   Uninstall a specific bundle name:\n    augent uninstall author/bundle\n\n\
                   Uninstall all bundles matching a scope:\n    augent uninstall @wshobson/agents\n\n\
                   Uninstall scope without prompt:\n    augent uninstall @wshobson/agents --all-bundles\n\n\
+                  Uninstall a bundle and its orphaned dependencies:\n    augent uninstall my-bundle --cascade\n\n\
                   Select bundle interactively:\n    augent uninstall")]
 pub struct UninstallArgs {
     /// Bundle name or scope to uninstall (if omitted, shows interactive menu)
@@ -25,6 +27,10 @@ pub struct UninstallArgs {
     /// Show what would be uninstalled without actually uninstalling
     #[arg(long)]
     pub dry_run: bool,
+
+    /// Also uninstall dependencies that no remaining bundle needs
+    #[arg(long)]
+    pub cascade: bool,
 }
 
 #[cfg(test)]
@@ -49,6 +55,19 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_cli_parsing_uninstall_with_cascade() {
+        let cli =
+            super::super::Cli::try_parse_from(["augent", "uninstall", "my-bundle", "--cascade"])
+                .unwrap_or_else(|e| {
+                    panic!("Failed to parse CLI arguments: {e}");
+                });
+        match cli.command {
+            super::super::Commands::Uninstall(args) => assert!(args.cascade),
+            _ => panic!("Expected Uninstall command"),
+        }
+    }
+
     #[test]
     fn test_cli_parsing_uninstall_with_dry_run() {
         let cli =
@@ -0,0 +1,232 @@
+//! Clean command implementation
+//!
+//! This command removes augent-managed files from platform directories
+//! using the target paths recorded in `augent.index.yaml`. Files under the
+//! same platform directories that augent did not install are left alone.
+//! MCP servers merged into shared configs, and bundle sections of composite
+//! files, are removed from those files instead of deleting them.
+
+use std::path::{Path, PathBuf};
+
+use crate::cli::CleanArgs;
+use crate::commands::helpers;
+use crate::config::WorkspaceBundle;
+use crate::error::{AugentError, Result};
+use crate::installer::mcp;
+use crate::platform::merge;
+use crate::workspace::Workspace;
+
+/// Run clean command
+pub fn run(workspace: Option<PathBuf>, profile: Option<&str>, args: &CleanArgs) -> Result<()> {
+    let current_dir = helpers::resolve_workspace_path(workspace)?;
+    let workspace_root =
+        Workspace::find_from(&current_dir).ok_or_else(|| AugentError::WorkspaceNotFound {
+            path: current_dir.display().to_string(),
+        })?;
+    let mut workspace = Workspace::open_with_profile(&workspace_root, profile)?;
+
+    let platform_dirs = select_platform_dirs(&workspace_root, args)?;
+    let result = clean_tracked_files(&mut workspace, &platform_dirs, args.dry_run);
+    // Save even on failure so the index drops the files that were removed
+    if !args.dry_run {
+        workspace.save()?;
+    }
+    let removed = result?;
+
+    if removed.is_empty() {
+        println!("No augent-managed files to remove.");
+        return Ok(());
+    }
+
+    let verb = if args.dry_run {
+        "Would remove"
+    } else {
+        "Removed"
+    };
+    for location in &removed {
+        println!("  {location}");
+    }
+    println!("{verb} {} file(s).", removed.len());
+    Ok(())
+}
+
+/// Platform directories (relative to the workspace root) selected by the arguments
+fn select_platform_dirs(workspace_root: &Path, args: &CleanArgs) -> Result<Vec<PathBuf>> {
+    let platforms = if args.all_platforms {
+        crate::platform::loader::PlatformLoader::new(workspace_root).load()?
+    } else {
+        crate::platform::detection::get_platforms(&args.platforms, Some(workspace_root))?
+    };
+    Ok(platforms
+        .into_iter()
+        .map(|p| PathBuf::from(p.directory))
+        .collect())
+}
+
+/// Remove tracked files under `platform_dirs` and drop them from the index
+///
+/// Returns the removed locations. In dry run mode nothing is removed and the
+/// index is left unchanged. On error, files removed so far are already
+/// dropped from the index.
+fn clean_tracked_files(
+    workspace: &mut Workspace,
+    platform_dirs: &[PathBuf],
+    dry_run: bool,
+) -> Result<Vec<String>> {
+    let root = workspace.root.clone();
+    let mut removed = Vec::new();
+    for bundle in &mut workspace.config.bundles {
+        let result = clean_bundle_files(bundle, &root, platform_dirs, dry_run, &mut removed);
+        bundle.enabled.retain(|_, locations| !locations.is_empty());
+        result?;
+    }
+    removed.sort();
+    removed.dedup();
+    Ok(removed)
+}
+
+fn clean_bundle_files(
+    bundle: &mut WorkspaceBundle,
+    root: &Path,
+    platform_dirs: &[PathBuf],
+    dry_run: bool,
+    removed: &mut Vec<String>,
+) -> Result<()> {
+    let unmerged = unmerge_mcp_servers(bundle, root, platform_dirs, dry_run)?;
+    removed.extend(unmerged.iter().map(|config| config.display().to_string()));
+    let bundle_name = bundle.name.clone();
+    for locations in bundle.enabled.values_mut() {
+        let targets: Vec<String> = locations
+            .iter()
+            .filter(|location| is_under_platform_dir(root, location, platform_dirs))
+            .cloned()
+            .collect();
+        if dry_run {
+            removed.extend(targets);
+        } else {
+            removed.extend(remove_locations(
+                &bundle_name,
+                root,
+                locations,
+                targets,
+                &unmerged,
+            )?);
+        }
+    }
+    Ok(())
+}
+
+/// Remove the bundle's servers from merged MCP configs under `platform_dirs`
+///
+/// Returns the un-merged config paths. These files are shared with other
+/// bundles and the user, so they are never deleted as a whole.
+fn unmerge_mcp_servers(
+    bundle: &mut WorkspaceBundle,
+    root: &Path,
+    platform_dirs: &[PathBuf],
+    dry_run: bool,
+) -> Result<Vec<PathBuf>> {
+    let configs: Vec<String> = bundle
+        .mcp_servers
+        .keys()
+        .filter(|config| is_under_platform_dir(root, config, platform_dirs))
+        .cloned()
+        .collect();
+    if dry_run {
+        return Ok(configs.into_iter().map(PathBuf::from).collect());
+    }
+    for config in &configs {
+        if let Some(names) = bundle.mcp_servers.get(config) {
+            mcp::remove_servers(&root.join(config), names)?;
+        }
+        bundle.mcp_servers.remove(config);
+    }
+    Ok(configs.into_iter().map(PathBuf::from).collect())
+}
+
+/// Remove `targets` from disk, dropping each from `locations` once removed
+///
+/// Targets in `unmerged` were already handled by un-merging and are only
+/// dropped from the index. Returns the removed locations.
+fn remove_locations(
+    owner: &str,
+    root: &Path,
+    locations: &mut Vec<String>,
+    targets: Vec<String>,
+    unmerged: &[PathBuf],
+) -> Result<Vec<String>> {
+    let mut removed = Vec::new();
+    for location in targets {
+        if !unmerged
+            .iter()
+            .any(|c| c == relative_location(root, &location))
+        {
+            remove_tracked_file(&root.join(&location), owner)?;
+        }
+        locations.retain(|l| *l != location);
+        removed.push(location);
+    }
+    Ok(removed)
+}
+
+/// Remove a tracked file, treating an already missing file as removed
+///
+/// A composite file holding the owner's section only loses that section;
+/// the file itself is removed once nothing else is left in it.
+fn remove_tracked_file(path: &Path, owner: &str) -> Result<()> {
+    let remaining = std::fs::read_to_string(path)
+        .ok()
+        .and_then(|content| merge::remove_composite_section(&content, owner))
+        .filter(|rest| !rest.trim().is_empty());
+    if let Some(rest) = remaining {
+        return std::fs::write(path, rest).map_err(|e| AugentError::FileWriteFailed {
+            path: path.display().to_string(),
+            reason: e.to_string(),
+        });
+    }
+    match std::fs::remove_file(path) {
+        Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(AugentError::FileWriteFailed {
+            path: path.display().to_string(),
+            reason: e.to_string(),
+        }),
+        _ => Ok(()),
+    }
+}
+
+fn is_under_platform_dir(root: &Path, location: &str, platform_dirs: &[PathBuf]) -> bool {
+    let relative = relative_location(root, location);
+    platform_dirs.iter().any(|dir| relative.starts_with(dir))
+}
+
+/// Index location relative to the workspace root
+fn relative_location<'a>(root: &Path, location: &'a str) -> &'a Path {
+    let path = Path::new(location);
+    path.strip_prefix(root).unwrap_or(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_under_platform_dir() {
+        let root = Path::new("/workspace");
+        let dirs = vec![PathBuf::from(".cursor")];
+        assert!(is_under_platform_dir(
+            root,
+            ".cursor/commands/debug.md",
+            &dirs
+        ));
+        assert!(is_under_platform_dir(
+            root,
+            "/workspace/.cursor/rules/lint.mdc",
+            &dirs
+        ));
+        assert!(!is_under_platform_dir(
+            root,
+            ".claude/commands/debug.md",
+            &dirs
+        ));
+        assert!(!is_under_platform_dir(root, ".cursorrules", &dirs));
+    }
+}
@@ -1,5 +1,5 @@
 use crate::cache;
-use crate::cli::{CacheArgs, CacheSubcommand};
+use crate::cli::{CacheArgs, CacheSubcommand, ClearCacheArgs};
 use crate::error::Result;
 
 pub fn run(args: CacheArgs) -> Result<()> {
@@ -11,10 +11,15 @@ pub fn run(args: CacheArgs) -> Result<()> {
                 return Ok(());
             }
             CacheSubcommand::Clear(clear_args) => {
-                match clear_args.only {
-                    Some(bundle_name) => clean_specific_bundle(&bundle_name)?,
-                    None => clean_all_cache()?,
-                }
+                clean_cache(clear_args)?;
+                return Ok(());
+            }
+            CacheSubcommand::Migrate => {
+                migrate_cache()?;
+                return Ok(());
+            }
+            CacheSubcommand::Verify => {
+                verify_cache()?;
                 return Ok(());
             }
         }
@@ -57,12 +62,112 @@ fn list_cached_bundles() -> Result<()> {
     Ok(())
 }
 
+fn migrate_cache() -> Result<()> {
+    let migrated = cache::migrate_legacy_entries()?;
+
+    if migrated.is_empty() {
+        println!("No legacy cache entries to migrate.");
+        return Ok(());
+    }
+
+    for entry in &migrated {
+        println!(
+            "  {} ({} -> {})",
+            entry.bundle_name,
+            entry.from.display(),
+            entry.to.display()
+        );
+    }
+    println!("Migrated {} legacy cache entries.", migrated.len());
+    Ok(())
+}
+
+fn verify_cache() -> Result<()> {
+    use cache::verify::EntryIntegrity;
+
+    let entries = cache::verify_all_entries()?;
+    let corrupted: Vec<_> = entries
+        .iter()
+        .filter(|e| e.integrity == EntryIntegrity::Corrupted)
+        .collect();
+    let unverified = entries
+        .iter()
+        .filter(|e| e.integrity == EntryIntegrity::Unverified)
+        .count();
+
+    println!("Verified {} cache entries.", entries.len());
+    if unverified > 0 {
+        println!("  {unverified} without a checksum (cached by an older augent)");
+    }
+    if corrupted.is_empty() {
+        println!("No corrupted cache entries.");
+        return Ok(());
+    }
+
+    println!("Corrupted cache entries ({}):", corrupted.len());
+    for entry in &corrupted {
+        println!(
+            "  {} ({}) at {}",
+            entry.bundle_name,
+            entry.sha,
+            entry.path.display()
+        );
+    }
+    println!("They are cloned again the next time they are used.");
+    Ok(())
+}
+
+fn clean_cache(args: ClearCacheArgs) -> Result<()> {
+    if let Some(bundle_name) = args.only {
+        return clean_specific_bundle(&bundle_name);
+    }
+    if args.older_than.is_none() && args.max_size.is_none() {
+        return clean_all_cache();
+    }
+    if let Some(max_age) = args.older_than {
+        clean_older_than(max_age)?;
+    }
+    if let Some(max_bytes) = args.max_size {
+        clean_to_size(max_bytes)?;
+    }
+    Ok(())
+}
+
+fn clean_to_size(max_bytes: u64) -> Result<()> {
+    let stats = cache::enforce_cache_budget(max_bytes)?;
+    println!(
+        "Evicted {} cache entries; cache size is now {}.",
+        stats.evicted,
+        stats.formatted_size()
+    );
+    Ok(())
+}
+
 fn clean_all_cache() -> Result<()> {
     cache::clear_cache()?;
     println!("Cache cleared successfully.");
     Ok(())
 }
 
+fn clean_older_than(max_age: std::time::Duration) -> Result<()> {
+    let summary = cache::prune_cache_older_than(max_age)?;
+
+    if summary.removed.is_empty() {
+        println!("No cache entries older than the given age.");
+        return Ok(());
+    }
+
+    for entry in &summary.removed {
+        println!("  {} ({})", entry.bundle_name, entry.sha);
+    }
+    println!(
+        "Removed {} cache entries, reclaimed {}.",
+        summary.removed.len(),
+        summary.formatted_reclaimed()
+    );
+    Ok(())
+}
+
 fn clean_specific_bundle(bundle_name: &str) -> Result<()> {
     cache::remove_cached_bundle(bundle_name)?;
     println!("Removed cached bundle: {bundle_name}");
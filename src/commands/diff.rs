@@ -0,0 +1,48 @@
+//! Diff command implementation
+//!
+//! This command resolves a bundle without installing it and shows how its
+//! files differ from the current files in the workspace.
+
+use std::path::PathBuf;
+
+use crate::cli::DiffArgs;
+use crate::commands::helpers;
+use crate::error::{AugentError, Result};
+use crate::operations::diff::{DiffStatus, diff_bundles};
+use crate::resolver::Resolver;
+use crate::workspace::Workspace;
+
+/// Run diff command
+pub fn run(workspace: Option<PathBuf>, args: &DiffArgs) -> Result<()> {
+    let current_dir = helpers::resolve_workspace_path(workspace)?;
+    let workspace_root =
+        Workspace::find_from(&current_dir).ok_or_else(|| AugentError::WorkspaceNotFound {
+            path: current_dir.display().to_string(),
+        })?;
+
+    let platforms = if args.platforms.is_empty() {
+        crate::platform::detection::detect_platforms_or_error(&workspace_root)?
+    } else {
+        crate::platform::detection::get_platforms(&args.platforms, Some(&workspace_root))?
+    };
+
+    let bundles = Resolver::new(&workspace_root).resolve(&args.source, false)?;
+    let diffs = diff_bundles(&workspace_root, &platforms, &bundles)?;
+
+    let changed: Vec<_> = diffs
+        .iter()
+        .filter(|d| d.status != DiffStatus::Unchanged)
+        .collect();
+    if changed.is_empty() {
+        println!("No changes.");
+        return Ok(());
+    }
+    for diff in changed {
+        match (args.name_only, diff.status) {
+            (true, DiffStatus::Added) => println!("A\t{}", diff.target),
+            (true, _) => println!("M\t{}", diff.target),
+            (false, _) => print!("{}", diff.diff),
+        }
+    }
+    Ok(())
+}
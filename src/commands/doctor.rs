@@ -0,0 +1,82 @@
+//! Doctor command implementation
+//!
+//! This command checks the workspace for problems that do not surface during
+//! normal use, such as dependencies whose `ref` in augent.yaml was changed
+//! without reinstalling, or local bundles edited since they were installed.
+//! With `--check-cache` it also checks the cache index against the cache on
+//! disk, and repairs what it can with `--fix`.
+
+use std::path::PathBuf;
+
+use crate::cache::check::{CacheIssue, check_cache, fix_cache_issues};
+use crate::cli::DoctorArgs;
+use crate::commands::helpers;
+use crate::error::{AugentError, Result};
+use crate::workspace::Workspace;
+use crate::workspace::drift::{detect_changed_dir_bundles, detect_ref_drift};
+
+/// Run doctor command
+pub fn run(workspace: Option<PathBuf>, profile: Option<&str>, args: &DoctorArgs) -> Result<()> {
+    let current_dir = helpers::resolve_workspace_path(workspace)?;
+    let workspace_root =
+        Workspace::find_from(&current_dir).ok_or_else(|| AugentError::WorkspaceNotFound {
+            path: current_dir.display().to_string(),
+        })?;
+    let workspace = Workspace::open_with_profile(&workspace_root, profile)?;
+
+    let drift = detect_ref_drift(&workspace.bundle_config, &workspace.lockfile);
+    let changed = detect_changed_dir_bundles(&workspace_root, &workspace.lockfile);
+    let cache_issues = if args.check_cache {
+        check_cache()?
+    } else {
+        Vec::new()
+    };
+    if drift.is_empty() && changed.is_empty() && cache_issues.is_empty() {
+        println!("No problems found.");
+        return Ok(());
+    }
+
+    if !drift.is_empty() {
+        println!("Dependency drift ({}):", drift.len());
+        for entry in &drift {
+            println!("  {entry}");
+        }
+        println!("Run 'augent install --update' to re-resolve the changed refs.");
+    }
+    if !changed.is_empty() {
+        println!("Local bundles changed since install ({}):", changed.len());
+        for name in &changed {
+            println!("  {name}");
+        }
+        println!("Run 'augent install' to reinstall them.");
+    }
+    if !cache_issues.is_empty() {
+        print_cache_issues(&cache_issues, args.fix)?;
+    }
+    Ok(())
+}
+
+fn print_cache_issues(issues: &[CacheIssue], fix: bool) -> Result<()> {
+    println!("Cache index problems ({}):", issues.len());
+    for issue in issues {
+        println!("  {issue}");
+    }
+    if !fix {
+        println!("Run 'augent doctor --check-cache --fix' to repair them.");
+        return Ok(());
+    }
+
+    let unfixed = fix_cache_issues(issues)?;
+    println!(
+        "Repaired {} cache index problem(s).",
+        issues.len() - unfixed.len()
+    );
+    if !unfixed.is_empty() {
+        println!("Could not repair ({}):", unfixed.len());
+        for issue in &unfixed {
+            println!("  {issue}");
+        }
+        println!("Run 'augent cache migrate' or 'augent cache clear' to remove them.");
+    }
+    Ok(())
+}
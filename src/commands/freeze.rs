@@ -0,0 +1,41 @@
+//! Freeze command implementation
+//!
+//! This command copies the source of every locked bundle into a single dir
+//! bundle that can be committed and installed without network access.
+
+use std::path::PathBuf;
+
+use crate::cli::FreezeArgs;
+use crate::commands::helpers;
+use crate::error::{AugentError, Result};
+use crate::operations::freeze::freeze;
+use crate::workspace::Workspace;
+
+/// Run freeze command
+pub fn run(workspace: Option<PathBuf>, profile: Option<&str>, args: &FreezeArgs) -> Result<()> {
+    let current_dir = helpers::resolve_workspace_path(workspace)?;
+    let workspace_root =
+        Workspace::find_from(&current_dir).ok_or_else(|| AugentError::WorkspaceNotFound {
+            path: current_dir.display().to_string(),
+        })?;
+    let workspace = Workspace::open_with_profile(&workspace_root, profile)?;
+
+    let out_dir = current_dir.join(&args.out_dir);
+    std::fs::create_dir_all(&out_dir).map_err(|e| AugentError::FileWriteFailed {
+        path: out_dir.display().to_string(),
+        reason: e.to_string(),
+    })?;
+    let out_dir =
+        std::fs::canonicalize(&out_dir).map_or(out_dir, |p| dunce::simplified(&p).to_path_buf());
+
+    let frozen = freeze(&workspace, &out_dir)?;
+    for bundle in &frozen {
+        println!("Froze {} from {}", bundle.name, bundle.from.display());
+    }
+    println!(
+        "Froze {} bundle(s) into {}",
+        frozen.len(),
+        args.out_dir.display()
+    );
+    Ok(())
+}
@@ -11,11 +11,12 @@ use crate::workspace::Workspace;
 fn select_bundles(
     args: &InstallArgs,
     workspace_root: &std::path::Path,
+    profile: Option<&str>,
     discovered: &[DiscoveredBundle],
     installing_by_bundle_name: bool,
 ) -> Result<Vec<DiscoveredBundle>> {
     let installed_bundle_names =
-        InstallOperation::get_installed_bundle_names_for_menu(workspace_root, discovered);
+        InstallOperation::get_installed_bundle_names_for_menu(workspace_root, profile, discovered);
     let filtered = InstallOperation::filter_workspace_bundle_from_discovered(
         workspace_root,
         discovered,
@@ -27,7 +28,11 @@ fn select_bundles(
     let selected = if menu_shown {
         use std::collections::HashSet;
         let installed_set: HashSet<String> = installed_bundle_names.into_iter().collect();
-        let selection = menu::select_bundles_interactively(&filtered, Some(&installed_set))?;
+        // Only for the footprint estimate; install validates platforms itself
+        let platforms = InstallOperation::select_or_detect_platforms(args, workspace_root, false)
+            .unwrap_or_default();
+        let selection =
+            menu::select_bundles_interactively(&filtered, Some(&installed_set), &platforms)?;
         selection.selected
     } else {
         filtered
@@ -40,13 +45,13 @@ fn select_bundles(
     Ok(selected)
 }
 
-fn setup_workspace(workspace_root: &std::path::Path) -> Result<Workspace> {
+fn setup_workspace(workspace_root: &std::path::Path, profile: Option<&str>) -> Result<Workspace> {
     std::fs::create_dir_all(workspace_root).map_err(|e| crate::error::AugentError::IoError {
         message: format!("Failed to create workspace directory: {e}"),
         source: Some(Box::new(e)),
     })?;
 
-    let mut workspace = Workspace::init_or_open(workspace_root)?;
+    let mut workspace = Workspace::init_or_open(workspace_root, profile)?;
 
     // Only set bundle_config_dir if the workspace root itself is a bundle directory
     // (has augent.yaml or resource directories directly in root, but NOT .augent/)
@@ -77,6 +82,9 @@ fn prepare_install_operation<'a>(
     workspace_root: &std::path::Path,
 ) -> Result<InstallOperation<'a>> {
     let install_op = InstallOperation::new(workspace, InstallOptions::from(args));
+    if args.lock_only {
+        return Ok(install_op);
+    }
     let platforms = InstallOperation::select_or_detect_platforms(args, workspace_root, false)?;
     if platforms.is_empty() {
         return Err(crate::error::AugentError::NoPlatformsDetected);
@@ -87,6 +95,7 @@ fn prepare_install_operation<'a>(
 fn discover_and_select_bundles(
     args: &InstallArgs,
     workspace_root: &std::path::Path,
+    profile: Option<&str>,
     installing_by_bundle_name: bool,
 ) -> Result<Vec<DiscoveredBundle>> {
     let source_str = args
@@ -96,29 +105,51 @@ fn discover_and_select_bundles(
             message: "No source provided".to_string(),
             source: None,
         })?;
-    let _source = BundleSource::parse(source_str)?;
-    let mut resolver = crate::resolver::Resolver::new(workspace_root);
+    let options = crate::cache::FetchOptions::from(args);
+    let _source = BundleSource::parse_with(source_str, options)?;
+    let mut resolver = crate::resolver::Resolver::new(workspace_root).with_fetch_options(options);
     let discovered = resolver.discover_bundles(source_str)?;
 
-    select_bundles(args, workspace_root, &discovered, installing_by_bundle_name)
+    select_bundles(
+        args,
+        workspace_root,
+        profile,
+        &discovered,
+        installing_by_bundle_name,
+    )
 }
 
 fn install_from_source(
     workspace_root: &std::path::Path,
+    profile: Option<&str>,
     args: &mut InstallArgs,
     installing_by_bundle_name: bool,
 ) -> Result<()> {
-    let selected = discover_and_select_bundles(args, workspace_root, installing_by_bundle_name)?;
+    let selected =
+        discover_and_select_bundles(args, workspace_root, profile, installing_by_bundle_name)?;
     if selected.is_empty() {
         return Ok(());
     }
+    install_selected(workspace_root, profile, args, &selected)
+}
 
-    let mut workspace = setup_workspace(workspace_root)?;
+/// Install already discovered bundles into one workspace
+fn install_selected(
+    workspace_root: &std::path::Path,
+    profile: Option<&str>,
+    args: &mut InstallArgs,
+    selected: &[DiscoveredBundle],
+) -> Result<()> {
+    let mut workspace = setup_workspace(workspace_root, profile)?;
     let mut transaction = Transaction::new(&workspace);
     transaction.backup_configs()?;
 
     let mut install_op = prepare_install_operation(&mut workspace, args, workspace_root)?;
-    execute_install(&mut install_op, args, &selected, &mut transaction)?;
+    if let Err(e) = execute_install(&mut install_op, args, selected, &mut transaction) {
+        // Put back every file the install overwrote before it failed
+        transaction.rollback();
+        return Err(e);
+    }
     transaction.commit();
 
     Ok(())
@@ -198,15 +229,19 @@ fn handle_selected_bundles(
     execute_install(&mut install_op, args, selected, transaction)
 }
 
-fn install_from_config(workspace_root: &std::path::Path, args: &mut InstallArgs) -> Result<()> {
-    let mut workspace = setup_workspace(workspace_root)?;
+fn install_from_config(
+    workspace_root: &std::path::Path,
+    profile: Option<&str>,
+    args: &mut InstallArgs,
+) -> Result<()> {
+    let mut workspace = setup_workspace(workspace_root, profile)?;
     let mut transaction = Transaction::new(&workspace);
     transaction.backup_configs()?;
 
     let discovered = workspace_config_bundles_as_discovered(&workspace, workspace_root);
 
     let bundles_to_install = if !args.all_bundles && discovered.len() > 1 {
-        let selected = select_bundles(args, workspace_root, &discovered, false)?;
+        let selected = select_bundles(args, workspace_root, profile, &discovered, false)?;
 
         if selected.is_empty() {
             // User deselected everything: uninstall all currently installed bundles
@@ -220,7 +255,12 @@ fn install_from_config(workspace_root: &std::path::Path, args: &mut InstallArgs)
     };
 
     if !bundles_to_install.is_empty() {
-        handle_selected_bundles(&mut workspace, args, &bundles_to_install, &mut transaction)?;
+        let result =
+            handle_selected_bundles(&mut workspace, args, &bundles_to_install, &mut transaction);
+        if let Err(e) = result {
+            transaction.rollback();
+            return Err(e);
+        }
     }
 
     transaction.commit();
@@ -228,12 +268,197 @@ fn install_from_config(workspace_root: &std::path::Path, args: &mut InstallArgs)
 }
 
 /// Run install command
-pub fn run(workspace: Option<std::path::PathBuf>, mut args: InstallArgs) -> Result<()> {
+///
+/// With several workspaces, the source is discovered once and installed
+/// into each workspace in turn; git content comes from the shared cache.
+/// Each workspace pins its bundles in the lockfile of `profile`.
+pub fn run(
+    workspaces: &[std::path::PathBuf],
+    profile: Option<&str>,
+    args: InstallArgs,
+) -> Result<()> {
+    run_installs(workspaces, profile, args)?;
+    enforce_cache_budget()
+}
+
+/// Evict least recently used cache entries over `AUGENT_CACHE_MAX_SIZE`
+///
+/// Runs after every install of this invocation, so no entry is evicted
+/// while a bundle still needs it.
+fn enforce_cache_budget() -> Result<()> {
+    let Some(stats) = crate::cache::prune::enforce_configured_budget()? else {
+        return Ok(());
+    };
+    if stats.evicted > 0 {
+        eprintln!(
+            "Evicted {} cache entries to stay under {}; cache size is now {}",
+            stats.evicted,
+            crate::cache::prune::MAX_SIZE_ENV,
+            stats.formatted_size()
+        );
+    }
+    Ok(())
+}
+
+fn run_installs(
+    workspaces: &[std::path::PathBuf],
+    profile: Option<&str>,
+    mut args: InstallArgs,
+) -> Result<()> {
+    if let Some(path) = args.manifest.take() {
+        return run_manifest(workspaces, &path, profile, args);
+    }
+    if let Some(path) = args.sources_file.take() {
+        return run_sources_file(workspaces, &path, profile, &args);
+    }
+    match workspaces {
+        [] => run_in_workspace(None, profile, args),
+        [workspace] => run_in_workspace(Some(workspace.clone()), profile, args),
+        _ => run_in_workspaces(workspaces, profile, args),
+    }
+}
+
+/// Install each source listed in `path`, in order, as if given on the command line
+fn run_sources_file(
+    workspaces: &[std::path::PathBuf],
+    path: &std::path::Path,
+    profile: Option<&str>,
+    args: &InstallArgs,
+) -> Result<()> {
+    let content =
+        std::fs::read_to_string(path).map_err(|e| crate::error::AugentError::FileReadFailed {
+            path: path.display().to_string(),
+            reason: e.to_string(),
+        })?;
+    run_sources(workspaces, parse_sources_file(&content), profile, args)
+}
+
+/// Install the sources of a manifest file for its platforms
+///
+/// Platforms given with `--to` take precedence over the manifest's.
+fn run_manifest(
+    workspaces: &[std::path::PathBuf],
+    path: &std::path::Path,
+    profile: Option<&str>,
+    mut args: InstallArgs,
+) -> Result<()> {
+    let manifest = crate::config::InstallManifest::load(path)?;
+    if args.platforms.is_empty() {
+        args.platforms = manifest.platforms;
+    }
+    run_sources(workspaces, manifest.sources, profile, &args)
+}
+
+fn run_sources(
+    workspaces: &[std::path::PathBuf],
+    sources: Vec<String>,
+    profile: Option<&str>,
+    args: &InstallArgs,
+) -> Result<()> {
+    for source in sources {
+        println!("Installing {source}");
+        let mut args = args.clone();
+        args.source = Some(source);
+        run(workspaces, profile, args)?;
+    }
+    Ok(())
+}
+
+/// One source per line; blank lines and `#` comments are ignored
+///
+/// A `#` only starts a comment at the start of a line or after whitespace,
+/// so ref fragments such as `github:author/repo#v1.0` are kept.
+fn parse_sources_file(content: &str) -> Vec<String> {
+    content
+        .lines()
+        .map(|line| {
+            let end = line
+                .char_indices()
+                .find(|&(i, c)| {
+                    c == '#'
+                        && line[..i]
+                            .chars()
+                            .next_back()
+                            .is_none_or(char::is_whitespace)
+                })
+                .map_or(line.len(), |(i, _)| i);
+            line[..end].trim()
+        })
+        .filter(|line| !line.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+fn run_in_workspaces(
+    workspaces: &[std::path::PathBuf],
+    profile: Option<&str>,
+    mut args: InstallArgs,
+) -> Result<()> {
+    let selected = match workspaces.first() {
+        Some(first) if args.source.is_some() => {
+            let installing_by_bundle_name =
+                InstallOperation::handle_source_argument(&mut args, first);
+            Some(discover_and_select_bundles(
+                &args,
+                first,
+                profile,
+                installing_by_bundle_name,
+            )?)
+        }
+        _ => None,
+    };
+
+    let mut failed = Vec::new();
+    for workspace_root in workspaces {
+        println!("Workspace {}:", workspace_root.display());
+        let mut args = args.clone();
+        let result = match &selected {
+            Some(bundles) if bundles.is_empty() => Ok(()),
+            Some(bundles) => install_selected(workspace_root, profile, &mut args, bundles),
+            None => install_from_config(workspace_root, profile, &mut args),
+        };
+        if let Err(e) = result {
+            eprintln!("Error: {}", e.display_with_hint());
+            failed.push(workspace_root.display().to_string());
+        }
+    }
+
+    println!(
+        "Installed into {} of {} workspaces",
+        workspaces.len() - failed.len(),
+        workspaces.len()
+    );
+    let message = format!("Install failed in: {}", failed.join(", "));
+    if failed.is_empty() {
+        Ok(())
+    } else if failed.len() < workspaces.len() {
+        Err(crate::error::AugentError::PartialSuccess { message })
+    } else {
+        Err(crate::error::AugentError::IoError {
+            message,
+            source: None,
+        })
+    }
+}
+
+fn run_in_workspace(
+    workspace: Option<std::path::PathBuf>,
+    profile: Option<&str>,
+    mut args: InstallArgs,
+) -> Result<()> {
     let workspace_root = helpers::resolve_workspace_path(workspace)?;
 
-    let mut workspace = Workspace::open(&workspace_root)?;
+    let mut workspace = Workspace::open_with_profile(&workspace_root, profile)?;
     let _install_op = InstallOperation::new(&mut workspace, InstallOptions::from(&args));
 
+    if args.dry_run && args.offline {
+        use crate::operations::install::preflight;
+        // Resolving would fetch what is missing, so stop at the report
+        if !preflight::print_report(&preflight::check_cache(&workspace.lockfile)?) {
+            return Ok(());
+        }
+    }
+
     if args.source.is_some()
         && !InstallOperation::check_subdirectory_resources(
             &args,
@@ -249,9 +474,14 @@ pub fn run(workspace: Option<std::path::PathBuf>, mut args: InstallArgs) -> Resu
         InstallOperation::handle_source_argument(&mut args, &workspace_root);
 
     if args.source.is_some() {
-        install_from_source(&workspace_root, &mut args, installing_by_bundle_name)
+        install_from_source(
+            &workspace_root,
+            profile,
+            &mut args,
+            installing_by_bundle_name,
+        )
     } else {
-        install_from_config(&workspace_root, &mut args)
+        install_from_config(&workspace_root, profile, &mut args)
     }
 }
 
@@ -269,3 +499,18 @@ fn collect_matching_bundle_files(
         }
     }
 }
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_sources_file() {
+        let content = "# Team bundles\n./bundles/local\n\ngithub:author/repo#v1.0  # pinned\n   \n";
+        assert_eq!(
+            parse_sources_file(content),
+            vec!["./bundles/local", "github:author/repo#v1.0"]
+        );
+    }
+}
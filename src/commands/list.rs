@@ -11,7 +11,7 @@ use crate::operations::{ListOperation, ListOptions};
 use crate::workspace::Workspace;
 
 /// Run list command
-pub fn run(workspace: Option<PathBuf>, args: &ListArgs) -> Result<()> {
+pub fn run(workspace: Option<PathBuf>, profile: Option<&str>, args: &ListArgs) -> Result<()> {
     let workspace_path = get_workspace_path(workspace)?;
 
     let workspace_root =
@@ -19,12 +19,11 @@ pub fn run(workspace: Option<PathBuf>, args: &ListArgs) -> Result<()> {
             path: workspace_path.display().to_string(),
         })?;
 
-    let workspace = Workspace::open(&workspace_root)?;
+    let workspace = Workspace::open_with_profile(&workspace_root, profile)?;
 
     let operation = ListOperation::new(&workspace);
     let options = ListOptions::from(args);
-    operation.execute(&options);
-    Ok(())
+    operation.execute(&options)
 }
 
 /// Get workspace path from CLI argument or current directory
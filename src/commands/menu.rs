@@ -2,6 +2,7 @@ use crate::common::bundle_utils;
 use crate::common::string_utils;
 use crate::domain::DiscoveredBundle;
 use crate::error::Result;
+use crate::platform::Platform;
 use console::Style;
 use inquire::MultiSelect;
 use std::collections::HashSet;
@@ -17,6 +18,7 @@ pub struct BundleSelection {
 pub fn select_bundles_interactively(
     discovered: &[DiscoveredBundle],
     installed_bundle_names: Option<&HashSet<String>>,
+    platforms: &[Platform],
 ) -> Result<BundleSelection> {
     if discovered.is_empty() {
         return Ok(BundleSelection {
@@ -31,7 +33,7 @@ pub fn select_bundles_interactively(
     let installed_style = Style::new().dim();
 
     let default_selections = build_default_selections(&sorted_bundles, installed);
-    let items = build_display_items(&sorted_bundles, installed, &installed_style);
+    let items = build_display_items(&sorted_bundles, installed, platforms, &installed_style);
 
     println!();
 
@@ -81,17 +83,19 @@ fn build_default_selections(
 fn build_display_items(
     bundles: &[DiscoveredBundle],
     installed: Option<&HashSet<String>>,
+    platforms: &[Platform],
     installed_style: &Style,
 ) -> Vec<String> {
     bundles
         .iter()
-        .map(|b| format_bundle_display(b, installed, installed_style))
+        .map(|b| format_bundle_display(b, installed, platforms, installed_style))
         .collect()
 }
 
 fn format_bundle_display(
     bundle: &DiscoveredBundle,
     installed: Option<&HashSet<String>>,
+    platforms: &[Platform],
     installed_style: &Style,
 ) -> String {
     let mut s = bundle.name.clone();
@@ -102,6 +106,10 @@ fn format_bundle_display(
     } else if let Some(formatted) = bundle.resource_counts.format() {
         s.push_str(" (");
         s.push_str(&formatted);
+        if let Some(footprint) = bundle.install_footprint(platforms) {
+            s.push_str("; ");
+            s.push_str(&footprint.format());
+        }
         s.push(')');
     }
 
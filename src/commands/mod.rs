@@ -1,9 +1,16 @@
+pub mod clean;
 pub mod clean_cache;
 pub mod completions;
+pub mod diff;
+pub mod doctor;
+pub mod freeze;
 pub mod helpers;
 pub mod install;
 pub mod list;
 pub mod menu;
+pub mod outdated;
+pub mod platforms;
 pub mod show;
+pub mod status;
 pub mod uninstall;
 pub mod version;
@@ -0,0 +1,49 @@
+//! Outdated command implementation
+//!
+//! This command reports locked git bundles whose refs point to newer commits
+//! on the remote. It only lists remotes; nothing is cloned or modified.
+
+use std::path::PathBuf;
+
+use crate::cli::OutdatedArgs;
+use crate::commands::helpers;
+use crate::common::concurrency::resolve_concurrency;
+use crate::error::{AugentError, Result};
+use crate::operations::outdated::{OutdatedStatus, check_outdated, render_json, render_table};
+use crate::workspace::Workspace;
+
+/// Run outdated command
+pub fn run(workspace: Option<PathBuf>, profile: Option<&str>, args: &OutdatedArgs) -> Result<()> {
+    let current_dir = helpers::resolve_workspace_path(workspace)?;
+    let workspace_root =
+        Workspace::find_from(&current_dir).ok_or_else(|| AugentError::WorkspaceNotFound {
+            path: current_dir.display().to_string(),
+        })?;
+    let workspace = Workspace::open_with_profile(&workspace_root, profile)?;
+
+    let report = check_outdated(&workspace.lockfile.bundles, resolve_concurrency(None));
+    if args.json {
+        print!("{}", render_json(&report)?);
+        return Ok(());
+    }
+    if report.is_empty() {
+        println!("No git bundles in the lockfile.");
+        return Ok(());
+    }
+
+    print!("{}", render_table(&report));
+    for bundle in &report {
+        if let Some(error) = &bundle.error {
+            eprintln!("Warning: could not check {}: {error}", bundle.name);
+        }
+    }
+    let outdated = report
+        .iter()
+        .filter(|b| b.status == OutdatedStatus::Outdated)
+        .count();
+    println!(
+        "{outdated} of {} git bundle(s) outdated; run `augent install --update` to update.",
+        report.len()
+    );
+    Ok(())
+}
@@ -0,0 +1,64 @@
+//! Platforms command implementation
+//!
+//! Lists every known platform, built-in ones first and then the custom ones
+//! defined in a `platforms.jsonc`. With `--detect`, each platform also shows
+//! whether its directory exists in the workspace, which is what decides
+//! whether install targets it by default.
+
+use std::path::PathBuf;
+
+use crate::cli::PlatformsArgs;
+use crate::commands::helpers;
+use crate::error::Result;
+use crate::platform::detection::{PlatformDetection, explain_detection};
+use crate::workspace::Workspace;
+
+/// Run platforms command
+pub fn run(workspace: Option<PathBuf>, args: &PlatformsArgs) -> Result<()> {
+    let current_dir = helpers::resolve_workspace_path(workspace)?;
+    // Custom platforms are read from the workspace root when inside one
+    let workspace_root = Workspace::find_from(&current_dir).unwrap_or(current_dir);
+    let detections = explain_detection(&workspace_root)?;
+
+    let (custom, builtin): (Vec<&PlatformDetection>, Vec<&PlatformDetection>) =
+        detections.iter().partition(|d| d.custom);
+    let id_width = detections
+        .iter()
+        .map(|d| d.platform.id.len())
+        .max()
+        .unwrap_or_default();
+
+    println!("Built-in platforms:");
+    for detection in &builtin {
+        println!("  {}", format_platform(detection, id_width, args.detect));
+    }
+    if !custom.is_empty() {
+        println!("Custom platforms (platforms.jsonc):");
+        for detection in &custom {
+            println!("  {}", format_platform(detection, id_width, args.detect));
+        }
+    }
+    if args.detect {
+        let detected = detections
+            .iter()
+            .filter(|d| d.found_directory.is_some())
+            .count();
+        println!("{detected} platform(s) detected; install targets these by default.");
+    }
+    Ok(())
+}
+
+fn format_platform(detection: &PlatformDetection, id_width: usize, detect: bool) -> String {
+    let platform = &detection.platform;
+    let line = format!(
+        "{:id_width$}  {} ({})",
+        platform.id, platform.name, platform.directory
+    );
+    if !detect {
+        return line;
+    }
+    match &detection.found_directory {
+        Some(directory) => format!("{line}: detected, {directory} exists"),
+        None => format!("{line}: not detected, no {} directory", platform.directory),
+    }
+}
@@ -8,7 +8,11 @@ use crate::workspace;
 /// Run the show command
 ///
 /// This is a thin CLI wrapper that delegates to `ShowOperation`.
-pub fn run(workspace: Option<std::path::PathBuf>, args: ShowArgs) -> Result<()> {
+pub fn run(
+    workspace: Option<std::path::PathBuf>,
+    profile: Option<&str>,
+    args: ShowArgs,
+) -> Result<()> {
     let current_dir = match workspace {
         Some(path) => path,
         None => {
@@ -24,7 +28,7 @@ pub fn run(workspace: Option<std::path::PathBuf>, args: ShowArgs) -> Result<()>
         }
     })?;
 
-    let workspace = workspace::Workspace::open(&workspace_root)?;
+    let workspace = workspace::Workspace::open_with_profile(&workspace_root, profile)?;
 
     let operation = ShowOperation::new(workspace_root, &workspace);
     operation.execute(args)
@@ -0,0 +1,64 @@
+//! Status command implementation
+//!
+//! This command reports installed files that were edited or deleted since
+//! they were installed, grouped by bundle. Drift makes the command fail with
+//! its own exit code so CI can gate on it.
+
+use std::path::PathBuf;
+
+use crate::cli::StatusArgs;
+use crate::commands::helpers;
+use crate::error::{AugentError, Result, exit_code};
+use crate::operations::status::workspace_status;
+use crate::workspace::Workspace;
+
+/// Run status command
+pub fn run(workspace: Option<PathBuf>, profile: Option<&str>, args: &StatusArgs) -> Result<()> {
+    let current_dir = helpers::resolve_workspace_path(workspace)?;
+    let workspace_root =
+        Workspace::find_from(&current_dir).ok_or_else(|| AugentError::WorkspaceNotFound {
+            path: current_dir.display().to_string(),
+        })?;
+    let workspace = Workspace::open_with_profile(&workspace_root, profile)?;
+
+    let status = workspace_status(&workspace, &crate::cache::bundles_cache_dir()?)?;
+    if args.quiet {
+        if status.has_drift() {
+            std::process::exit(exit_code::DRIFT);
+        }
+        return Ok(());
+    }
+
+    if status.platforms.is_empty() {
+        println!("Platforms: none");
+    } else {
+        println!("Platforms: {}", status.platforms.join(", "));
+    }
+    if status.bundles.is_empty() {
+        println!("No bundles installed.");
+        return Ok(());
+    }
+
+    for bundle in &status.bundles {
+        println!();
+        if bundle.is_clean() {
+            println!("{}: clean", bundle.name);
+            continue;
+        }
+        println!("{}:", bundle.name);
+        for path in &bundle.modified {
+            println!("  modified: {path}");
+        }
+        for path in &bundle.missing {
+            println!("  missing:  {path}");
+        }
+    }
+
+    if status.has_drift() {
+        println!();
+        return Err(AugentError::WorkspaceDrift {
+            count: status.drifted_files(),
+        });
+    }
+    Ok(())
+}
@@ -13,14 +13,18 @@ use crate::workspace::Workspace;
 ///
 /// This is a thin CLI wrapper that handles workspace initialization
 /// and delegates to `UninstallOperation` for all business logic.
-pub fn run(workspace: Option<std::path::PathBuf>, args: UninstallArgs) -> Result<()> {
+pub fn run(
+    workspace: Option<std::path::PathBuf>,
+    profile: Option<&str>,
+    args: UninstallArgs,
+) -> Result<()> {
     let current_dir = helpers::resolve_workspace_path(workspace)?;
     let workspace_root = Workspace::find_from(&current_dir).ok_or_else(|| {
         crate::error::AugentError::WorkspaceNotFound {
             path: current_dir.display().to_string(),
         }
     })?;
-    let mut workspace = Workspace::open(&workspace_root)?;
+    let mut workspace = Workspace::open_with_profile(&workspace_root, profile)?;
 
     let needs_rebuild =
         workspace.config.bundles.is_empty() && !workspace.lockfile.bundles.is_empty();
@@ -0,0 +1,72 @@
+//! Bounded parallel execution
+//!
+//! The worker count comes from `--concurrency`, then `AUGENT_CONCURRENCY`,
+//! then the number of available CPUs. A count of 1 or less runs everything
+//! sequentially on the calling thread.
+
+use std::num::NonZeroUsize;
+
+/// Environment variable overriding the default worker count
+pub const CONCURRENCY_ENV: &str = "AUGENT_CONCURRENCY";
+
+/// Effective worker count for an optional `--concurrency` value
+pub fn resolve_concurrency(requested: Option<usize>) -> usize {
+    requested
+        .or_else(|| {
+            std::env::var(CONCURRENCY_ENV)
+                .ok()
+                .and_then(|value| value.trim().parse().ok())
+        })
+        .unwrap_or_else(|| std::thread::available_parallelism().map_or(1, NonZeroUsize::get))
+}
+
+/// Apply `f` to every item using up to `concurrency` threads
+///
+/// Results are returned in input order regardless of how work was scheduled.
+pub fn map_ordered<T, R, F>(items: &[T], concurrency: usize, f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+{
+    if concurrency <= 1 || items.len() <= 1 {
+        return items.iter().map(f).collect();
+    }
+
+    let chunk_size = items.len().div_ceil(concurrency);
+    let f = &f;
+    std::thread::scope(|scope| {
+        let handles: Vec<_> = items
+            .chunks(chunk_size)
+            .map(|chunk| scope.spawn(move || chunk.iter().map(f).collect::<Vec<R>>()))
+            .collect();
+        handles
+            .into_iter()
+            .flat_map(|handle| {
+                handle
+                    .join()
+                    .unwrap_or_else(|e| std::panic::resume_unwind(e))
+            })
+            .collect()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_map_ordered_matches_sequential() {
+        let items: Vec<u32> = (0..37).collect();
+        let sequential = map_ordered(&items, 1, |n| n * 2);
+        let parallel = map_ordered(&items, 4, |n| n * 2);
+        assert_eq!(sequential, parallel);
+        assert_eq!(parallel[36], 72);
+    }
+
+    #[test]
+    fn test_resolve_concurrency_prefers_requested() {
+        assert_eq!(resolve_concurrency(Some(3)), 3);
+        assert_eq!(resolve_concurrency(Some(1)), 1);
+    }
+}
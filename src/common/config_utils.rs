@@ -13,7 +13,8 @@ fn get_cache_dir() -> std::path::PathBuf {
         .join("augent/bundles")
 }
 
-fn get_bundle_path(workspace_root: &Path, source: &LockedSource) -> std::path::PathBuf {
+/// Directory holding a locked bundle's files (workspace path or cache checkout)
+pub fn get_bundle_path(workspace_root: &Path, source: &LockedSource) -> std::path::PathBuf {
     match source {
         LockedSource::Dir { path, .. } => workspace_root.join(path),
         LockedSource::Git {
@@ -27,6 +28,15 @@ fn get_bundle_path(workspace_root: &Path, source: &LockedSource) -> std::path::P
                 .trim_end_matches(".git");
             get_cache_dir().join(format!("{repo_name}_{sha}"))
         }
+        LockedSource::Archive { url, path, .. } => {
+            crate::cache::archive::cached_archive_path(url, path.as_deref())
+        }
+        LockedSource::Oci {
+            reference,
+            digest,
+            path,
+            ..
+        } => crate::cache::oci::cached_oci_path(reference, digest, path.as_deref()),
     }
 }
 
@@ -14,6 +14,12 @@ struct GitSourceDisplay<'a> {
     path: &'a Option<String>,
 }
 
+struct OciSourceDisplay<'a> {
+    reference: &'a str,
+    digest: &'a str,
+    path: Option<&'a String>,
+}
+
 /// Convert `LockedSource` to display string
 #[allow(dead_code)]
 pub fn locked_source_to_string(source: &LockedSource) -> String {
@@ -39,6 +45,23 @@ pub fn locked_source_to_string(source: &LockedSource) -> String {
 
             result
         }
+        LockedSource::Archive { url, path, .. } => match path {
+            Some(subdir) => format!("Archive ({url})\n path: {subdir}"),
+            None => format!("Archive ({url})"),
+        },
+        LockedSource::Oci {
+            reference,
+            digest,
+            path,
+            ..
+        } => {
+            let mut result = format!("OCI ({reference})");
+            let _ = writeln!(result, " digest: {digest}");
+            if let Some(subdir) = path {
+                let _ = writeln!(result, " path: {subdir}");
+            }
+            result
+        }
     }
 }
 
@@ -186,6 +209,70 @@ fn display_git_source(
     }
 }
 
+fn display_archive_source(
+    url: &str,
+    path: Option<&String>,
+    indent: &str,
+    version: Option<&str>,
+    show_version: bool,
+) {
+    println!(
+        "{}{} {}",
+        indent,
+        Style::new().bold().apply_to("Type:"),
+        Style::new().green().apply_to("Archive")
+    );
+    println!("{}{} {}", indent, Style::new().bold().apply_to("URL:"), url);
+    if let Some(subdir) = path {
+        println!(
+            "{}{} {}",
+            indent,
+            Style::new().bold().apply_to("path:"),
+            subdir
+        );
+    }
+    if show_version {
+        display_version_if_needed(indent, path, version);
+    }
+}
+
+fn display_oci_source(
+    source: &OciSourceDisplay,
+    indent: &str,
+    version: Option<&str>,
+    show_version: bool,
+) {
+    println!(
+        "{}{} {}",
+        indent,
+        Style::new().bold().apply_to("Type:"),
+        Style::new().green().apply_to("OCI")
+    );
+    println!(
+        "{}{} oci://{}",
+        indent,
+        Style::new().bold().apply_to("Reference:"),
+        source.reference
+    );
+    println!(
+        "{}{} {}",
+        indent,
+        Style::new().bold().apply_to("Digest:"),
+        source.digest
+    );
+    if let Some(subdir) = source.path {
+        println!(
+            "{}{} {}",
+            indent,
+            Style::new().bold().apply_to("path:"),
+            subdir
+        );
+    }
+    if show_version {
+        display_version_if_needed(indent, source.path, version);
+    }
+}
+
 /// Display source information with custom indentation.
 ///
 /// Formats and prints source details (type, URL, ref, SHA, path, version)
@@ -221,5 +308,21 @@ pub fn display_source_detailed_with_indent(
             };
             display_git_source(&source, indent, version, show_version);
         }
+        LockedSource::Archive { url, path, .. } => {
+            display_archive_source(url, path.as_ref(), indent, version, show_version);
+        }
+        LockedSource::Oci {
+            reference,
+            digest,
+            path,
+            ..
+        } => {
+            let source = OciSourceDisplay {
+                reference,
+                digest,
+                path: path.as_ref(),
+            };
+            display_oci_source(&source, indent, version, show_version);
+        }
     }
 }
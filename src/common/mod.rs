@@ -1,6 +1,7 @@
 //! Common utility modules for shared functionality across the codebase.
 
 pub mod bundle_utils;
+pub mod concurrency;
 pub mod config_utils;
 pub mod display_utils;
 pub mod fs;
@@ -0,0 +1,288 @@
+//! Comment preservation for `augent.yaml` round-trips
+//!
+//! `BundleConfig` is always re-serialized from scratch, which would drop any
+//! comments the user wrote into `augent.yaml`. This module re-attaches those
+//! comments to the regenerated document by anchoring each comment block to
+//! the key that follows it (top-level key, bundle entry, or bundle field).
+//!
+//! Comments anchored to keys that no longer exist (e.g. a removed
+//! dependency) are dropped along with the key.
+
+use std::collections::HashMap;
+
+/// Anchor identifying a line in `augent.yaml` independent of formatting
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Anchor {
+    /// Top-level key such as `name:` or `bundles:`
+    TopLevel(String),
+    /// Bundle entry line (`- name: <bundle>`)
+    Bundle(String),
+    /// Field inside a bundle entry (e.g. `git:` of `<bundle>`)
+    BundleField(String, String),
+}
+
+/// Comments collected from an existing `augent.yaml`
+#[derive(Debug, Default)]
+struct CollectedComments {
+    /// Comment and blank lines before the first key
+    header: Vec<String>,
+    /// Full-line comments preceding an anchored line
+    leading: HashMap<Anchor, Vec<String>>,
+    /// Inline comments trailing an anchored line
+    inline: HashMap<Anchor, String>,
+    /// Comment lines after the last key
+    trailer: Vec<String>,
+}
+
+/// Tracks which bundle entry a line belongs to while scanning
+#[derive(Debug, Default)]
+struct AnchorTracker {
+    current_bundle: Option<String>,
+}
+
+impl AnchorTracker {
+    fn anchor_for(&mut self, content: &str) -> Option<Anchor> {
+        let is_top_level = !content.starts_with([' ', '\t', '-']);
+        let trimmed = content.trim_start();
+
+        if is_top_level {
+            self.current_bundle = None;
+            return key_of(trimmed).map(|key| Anchor::TopLevel(key.to_string()));
+        }
+
+        if let Some(item) = trimmed.strip_prefix("- ") {
+            let name = key_value_of(item)
+                .filter(|(key, _)| *key == "name")
+                .map(|(_, value)| unquote(value).to_string());
+            self.current_bundle.clone_from(&name);
+            return name.map(Anchor::Bundle);
+        }
+
+        let bundle = self.current_bundle.clone()?;
+        key_of(trimmed).map(|key| Anchor::BundleField(bundle, key.to_string()))
+    }
+}
+
+impl CollectedComments {
+    fn is_empty(&self) -> bool {
+        self.header.is_empty()
+            && self.leading.is_empty()
+            && self.inline.is_empty()
+            && self.trailer.is_empty()
+    }
+
+    /// Record comments seen before and on a content line
+    fn record(&mut self, anchor: Option<Anchor>, pending: Vec<String>, inline: Option<&str>) {
+        if let (Some(anchor), Some(comment)) = (anchor.clone(), inline) {
+            self.inline.insert(anchor, comment.to_string());
+        }
+        if let Some(anchor) = anchor.filter(|_| !pending.is_empty()) {
+            self.leading.insert(anchor, pending);
+        }
+    }
+
+    /// Emit a regenerated line preceded by its leading comments and with its inline comment
+    fn emit(&self, line: &str, anchor: Option<&Anchor>, output: &mut Vec<String>) {
+        if let Some(block) = anchor.and_then(|a| self.leading.get(a)) {
+            output.extend(block.iter().cloned());
+        }
+        match anchor.and_then(|a| self.inline.get(a)) {
+            Some(comment) => output.push(format!("{line} {comment}")),
+            None => output.push(line.to_string()),
+        }
+    }
+}
+
+/// Re-apply comments from `existing` onto `regenerated` YAML
+///
+/// Returns `regenerated` unchanged when `existing` has no comments.
+pub fn preserve_comments(existing: &str, regenerated: &str) -> String {
+    let mut comments = collect_comments(existing);
+    if comments.is_empty() {
+        return regenerated.to_string();
+    }
+
+    let mut output: Vec<String> = std::mem::take(&mut comments.header);
+    let mut tracker = AnchorTracker::default();
+
+    for line in regenerated.lines() {
+        if line.trim().is_empty() {
+            output.push(line.to_string());
+            continue;
+        }
+        let anchor = tracker.anchor_for(line);
+        comments.emit(line, anchor.as_ref(), &mut output);
+    }
+
+    while output.last().is_some_and(|l| l.trim().is_empty()) {
+        output.pop();
+    }
+    output.extend(std::mem::take(&mut comments.trailer));
+
+    format!("{}\n", output.join("\n"))
+}
+
+fn collect_comments(existing: &str) -> CollectedComments {
+    let mut collected = CollectedComments::default();
+    let mut tracker = AnchorTracker::default();
+    let mut pending: Vec<String> = Vec::new();
+    let mut seen_content = false;
+
+    for line in existing.lines() {
+        let trimmed = line.trim();
+        let is_comment = trimmed.starts_with('#');
+
+        // Blank lines are kept only in the header; the serializer owns spacing elsewhere
+        if is_comment || (trimmed.is_empty() && !seen_content) {
+            pending.push(line.to_string());
+            continue;
+        }
+        if trimmed.is_empty() {
+            continue;
+        }
+
+        let (content, inline) = split_inline_comment(line);
+        let anchor = tracker.anchor_for(content);
+        let block = std::mem::take(&mut pending);
+
+        if seen_content {
+            collected.record(anchor, block, inline);
+        } else {
+            seen_content = true;
+            collected.header = block;
+            collected.record(anchor, Vec::new(), inline);
+        }
+    }
+
+    if collected.header.iter().all(|l| l.trim().is_empty()) {
+        collected.header.clear();
+    }
+    collected.trailer = pending;
+    collected
+}
+
+/// Split a YAML line into content and an inline `# comment`, ignoring `#` inside quotes
+fn split_inline_comment(line: &str) -> (&str, Option<&str>) {
+    let mut in_single = false;
+    let mut in_double = false;
+    let mut prev = ' ';
+
+    for (idx, ch) in line.char_indices() {
+        match ch {
+            '\'' if !in_double => in_single = !in_single,
+            '"' if !in_single && prev != '\\' => in_double = !in_double,
+            '#' if !in_single && !in_double && prev.is_whitespace() => {
+                return (line[..idx].trim_end(), Some(&line[idx..]));
+            }
+            _ => {}
+        }
+        prev = ch;
+    }
+
+    (line, None)
+}
+
+fn key_of(content: &str) -> Option<&str> {
+    key_value_of(content).map(|(key, _)| key)
+}
+
+fn key_value_of(content: &str) -> Option<(&str, &str)> {
+    let (key, value) = content.split_once(':')?;
+    let key = unquote(key.trim());
+    (!key.is_empty()).then_some((key, value.trim()))
+}
+
+fn unquote(value: &str) -> &str {
+    value
+        .strip_prefix('\'')
+        .and_then(|v| v.strip_suffix('\''))
+        .or_else(|| value.strip_prefix('"').and_then(|v| v.strip_suffix('"')))
+        .unwrap_or(value)
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::*;
+    use crate::config::{BundleConfig, BundleDependency};
+
+    const COMMENTED_YAML: &str = r"# Workspace bundles for this repository
+# Keep in sync with team docs
+
+name: '@acme/workspace'
+
+bundles:
+  # Shared review commands, pinned for stability
+  - name: review-tools
+    # mirrored from the internal fork
+    git: https://github.com/acme/review-tools.git # public mirror
+
+  # Local helpers
+  - name: local-helpers
+    path: bundles/local-helpers
+
+# end of file
+";
+
+    #[test]
+    fn test_add_dependency_preserves_comments() {
+        let mut config = BundleConfig::from_yaml(COMMENTED_YAML).expect("Failed to parse yaml");
+        config.add_dependency(BundleDependency::git(
+            "new-bundle",
+            "https://github.com/acme/new-bundle.git",
+            None,
+        ));
+
+        let yaml = config
+            .to_yaml_preserving_comments("@acme/workspace", COMMENTED_YAML)
+            .expect("Failed to serialize yaml");
+
+        assert!(yaml.starts_with("# Workspace bundles for this repository\n"));
+        assert!(yaml.contains("# Keep in sync with team docs"));
+        assert!(
+            yaml.contains("# Shared review commands, pinned for stability\n- name: review-tools")
+        );
+        assert!(yaml.contains("git: https://github.com/acme/review-tools.git # public mirror"));
+        assert!(yaml.contains("# mirrored from the internal fork\n  git:"));
+        assert!(yaml.contains("# Local helpers\n- name: local-helpers"));
+        assert!(yaml.contains("- name: new-bundle"));
+        assert!(yaml.trim_end().ends_with("# end of file"));
+
+        let reparsed = BundleConfig::from_yaml(&yaml).expect("Failed to reparse yaml");
+        assert_eq!(reparsed.bundles.len(), 3);
+    }
+
+    #[test]
+    fn test_remove_dependency_drops_its_comments() {
+        let mut config = BundleConfig::from_yaml(COMMENTED_YAML).expect("Failed to parse yaml");
+        config.remove_dependency("local-helpers");
+
+        let yaml = config
+            .to_yaml_preserving_comments("@acme/workspace", COMMENTED_YAML)
+            .expect("Failed to serialize yaml");
+
+        assert!(!yaml.contains("# Local helpers"));
+        assert!(yaml.contains("# Shared review commands"));
+    }
+
+    #[test]
+    fn test_no_comments_returns_regenerated() {
+        let regenerated = "name: '@a/b'\n\nbundles: []\n";
+        assert_eq!(
+            preserve_comments("name: '@a/b'\nbundles: []\n", regenerated),
+            regenerated
+        );
+    }
+
+    #[test]
+    fn test_split_inline_comment_ignores_quoted_hash() {
+        assert_eq!(
+            split_inline_comment("git: 'https://host/repo#main' # note"),
+            ("git: 'https://host/repo#main'", Some("# note"))
+        );
+        assert_eq!(
+            split_inline_comment("git: https://host/repo#main"),
+            ("git: https://host/repo#main", None)
+        );
+    }
+}
@@ -2,6 +2,8 @@
 //!
 //! A dependency declaration in augent.yaml
 
+use std::collections::BTreeMap;
+
 use serde::{Deserialize, Serialize};
 
 use crate::error::{AugentError, Result};
@@ -9,20 +11,43 @@ use crate::error::{AugentError, Result};
 /// A dependency declaration in augent.yaml
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BundleDependency {
-    /// Dependency name
+    /// Dependency name (omitted in `overrides` entries, where it is the key)
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     pub name: String,
 
     /// Git repository URL
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub git: Option<String>,
 
-    /// Local path (for bundles in same repo)
+    /// Archive URL (`.tar.gz` or `.zip`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archive: Option<String>,
+
+    /// OCI artifact reference (`oci://registry/repository[:tag][@digest]`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub oci: Option<String>,
+
+    /// Local path (for bundles in same repo), or subdirectory of an archive
+    /// or OCI artifact
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub path: Option<String>,
 
     /// Git ref (branch, tag, or SHA)
-    #[serde(rename = "r#ref", default, skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "ref", default, skip_serializing_if = "Option::is_none")]
     pub git_ref: Option<String>,
+
+    /// Semver constraint on the repository's tags (e.g. `^1.2`)
+    ///
+    /// Resolved to the highest matching tag at install time. Tags that are
+    /// not semver versions (an optional `v` prefix is allowed) are ignored.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+
+    /// Per-platform git ref overrides (platform id -> ref)
+    ///
+    /// Platforms listed here are installed from the given ref instead of `ref`.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub platform_refs: BTreeMap<String, String>,
 }
 
 impl BundleDependency {
@@ -33,7 +58,11 @@ impl BundleDependency {
             name: name.into(),
             path: Some(path.into()),
             git: None,
+            archive: None,
+            oci: None,
             git_ref: None,
+            version: None,
+            platform_refs: BTreeMap::new(),
         }
     }
 
@@ -44,7 +73,43 @@ impl BundleDependency {
             name: name.into(),
             path: None,
             git: Some(url.into()),
+            archive: None,
+            oci: None,
             git_ref,
+            version: None,
+            platform_refs: BTreeMap::new(),
+        }
+    }
+
+    /// Create a new archive dependency, with `path` selecting a subdirectory
+    pub fn archive(name: impl Into<String>, url: impl Into<String>, path: Option<String>) -> Self {
+        Self {
+            name: name.into(),
+            path,
+            git: None,
+            archive: Some(url.into()),
+            oci: None,
+            git_ref: None,
+            version: None,
+            platform_refs: BTreeMap::new(),
+        }
+    }
+
+    /// Create a new OCI dependency, with `path` selecting a subdirectory
+    pub fn oci(
+        name: impl Into<String>,
+        reference: impl Into<String>,
+        path: Option<String>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            path,
+            git: None,
+            archive: None,
+            oci: Some(reference.into()),
+            git_ref: None,
+            version: None,
+            platform_refs: BTreeMap::new(),
         }
     }
 
@@ -56,23 +121,77 @@ impl BundleDependency {
             });
         }
 
-        // Must have either path or git URL
-        if self.path.is_none() && self.git.is_none() {
+        // Must have either path, git URL, archive URL or OCI reference
+        let remote_sources = [&self.git, &self.archive, &self.oci]
+            .iter()
+            .filter(|source| source.is_some())
+            .count();
+        if self.path.is_none() && remote_sources == 0 {
             return Err(AugentError::BundleValidationFailed {
                 message: format!(
-                    "Dependency '{}' must have either 'path' or 'git' specified",
+                    "Dependency '{}' must have either 'path', 'git', 'archive' or 'oci' specified",
                     self.name
                 ),
             });
         }
 
+        if remote_sources > 1 {
+            return Err(AugentError::BundleValidationFailed {
+                message: format!(
+                    "Dependency '{}' can have only one of 'git', 'archive' and 'oci'",
+                    self.name
+                ),
+            });
+        }
+
+        if !self.platform_refs.is_empty() && self.git.is_none() {
+            return Err(AugentError::BundleValidationFailed {
+                message: format!(
+                    "Dependency '{}' has 'platform_refs' but no 'git' source",
+                    self.name
+                ),
+            });
+        }
+
+        if let Some(version) = &self.version {
+            self.validate_version(version)?;
+        }
+
         Ok(())
     }
 
+    /// Validate a `version` constraint: git only, exclusive with `ref`
+    fn validate_version(&self, version: &str) -> Result<()> {
+        if self.git.is_none() {
+            return Err(AugentError::BundleValidationFailed {
+                message: format!(
+                    "Dependency '{}' has 'version' but no 'git' source",
+                    self.name
+                ),
+            });
+        }
+        if self.git_ref.is_some() {
+            return Err(AugentError::BundleValidationFailed {
+                message: format!(
+                    "Dependency '{}' cannot have both 'version' and 'ref'",
+                    self.name
+                ),
+            });
+        }
+        semver::VersionReq::parse(version).map(|_| ()).map_err(|e| {
+            AugentError::BundleValidationFailed {
+                message: format!(
+                    "Dependency '{}' has invalid version constraint '{version}': {e}",
+                    self.name
+                ),
+            }
+        })
+    }
+
     /// Check if this is a local dependency
     #[allow(dead_code)]
     pub fn is_local(&self) -> bool {
-        self.git.is_none() && self.path.is_some()
+        self.git.is_none() && self.archive.is_none() && self.oci.is_none() && self.path.is_some()
     }
 
     #[allow(dead_code)]
@@ -2,9 +2,12 @@
 //!
 //! This module handles bundle configuration data structures.
 
+pub mod comments;
 pub mod dependency;
 pub mod serialization;
 
+use std::collections::BTreeMap;
+
 use serde::{Deserialize, Serialize};
 
 use crate::config::bundle::serialization::{
@@ -35,6 +38,12 @@ pub struct BundleConfig {
 
     /// Bundle dependencies
     pub bundles: Vec<BundleDependency>,
+
+    /// Sources forced for transitive dependencies, keyed by dependency name
+    ///
+    /// Whatever an intermediate bundle declares for a dependency of this name
+    /// is replaced with the override. Only the workspace's config is consulted.
+    pub overrides: BTreeMap<String, BundleDependency>,
 }
 
 impl Serialize for BundleConfig {
@@ -49,6 +58,7 @@ impl Serialize for BundleConfig {
             license: self.license.clone(),
             homepage: self.homepage.clone(),
             bundles: self.bundles.clone(),
+            overrides: self.overrides.clone(),
         };
         serialize_bundle_config(&data, serializer)
     }
@@ -67,6 +77,7 @@ impl<'de> Deserialize<'de> for BundleConfig {
             license: data.license,
             homepage: data.homepage,
             bundles: data.bundles,
+            overrides: data.overrides,
         })
     }
 }
@@ -81,6 +92,7 @@ impl BundleConfig {
             license: None,
             homepage: None,
             bundles: Vec::new(),
+            overrides: BTreeMap::new(),
         }
     }
 
@@ -100,9 +112,23 @@ impl BundleConfig {
         ))
     }
 
+    /// Serialize bundle configuration, keeping comments from an existing document
+    ///
+    /// Comments in `existing` are re-attached to the keys and dependencies they
+    /// precede, so user annotations in `augent.yaml` survive adding or removing
+    /// dependencies.
+    pub fn to_yaml_preserving_comments(
+        &self,
+        workspace_name: &str,
+        existing: &str,
+    ) -> Result<String> {
+        let yaml = self.to_yaml(workspace_name)?;
+        Ok(comments::preserve_comments(existing, &yaml))
+    }
+
     /// Validate bundle configuration
     pub fn validate(&self) -> Result<()> {
-        for dep in &self.bundles {
+        for dep in self.bundles.iter().chain(self.overrides.values()) {
             dep.validate()?;
         }
         Ok(())
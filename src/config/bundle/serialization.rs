@@ -1,5 +1,8 @@
 //! Serialization implementations for `BundleConfig`
 
+use std::collections::BTreeMap;
+
+use super::dependency::BundleDependency;
 use crate::config::utils::count_optional_fields;
 use serde::ser::SerializeStruct;
 use serde::{Deserialize, Deserializer, Serializer};
@@ -27,6 +30,7 @@ where
         license,
         homepage,
         bundles,
+        overrides,
     } = data;
 
     let optional_count = count_optional_fields(
@@ -36,7 +40,7 @@ where
         license.as_ref(),
         homepage.as_ref(),
     );
-    let field_count = 2 + optional_count;
+    let field_count = 2 + optional_count + usize::from(!overrides.is_empty());
 
     let mut state = serializer.serialize_struct("BundleConfig", field_count)?;
 
@@ -47,6 +51,22 @@ where
     serialize_optional_field!(state, "license", license);
     serialize_optional_field!(state, "homepage", homepage);
     state.serialize_field("bundles", bundles)?;
+    if !overrides.is_empty() {
+        // The map key is the dependency name, so entries do not repeat it
+        let overrides: BTreeMap<&String, BundleDependency> = overrides
+            .iter()
+            .map(|(name, dep)| {
+                (
+                    name,
+                    BundleDependency {
+                        name: String::new(),
+                        ..dep.clone()
+                    },
+                )
+            })
+            .collect();
+        state.serialize_field("overrides", &overrides)?;
+    }
     state.end()
 }
 
@@ -71,6 +91,8 @@ where
         homepage: Option<String>,
         #[serde(default)]
         bundles: Vec<super::dependency::BundleDependency>,
+        #[serde(default)]
+        overrides: BTreeMap<String, BundleDependency>,
     }
 
     let raw = Raw::deserialize(deserializer)?;
@@ -81,6 +103,11 @@ where
         license: raw.license,
         homepage: raw.homepage,
         bundles: raw.bundles,
+        overrides: raw
+            .overrides
+            .into_iter()
+            .map(|(name, dep)| (name.clone(), BundleDependency { name, ..dep }))
+            .collect(),
     })
 }
 
@@ -92,4 +119,5 @@ pub struct BundleConfigData {
     pub license: Option<String>,
     pub homepage: Option<String>,
     pub bundles: Vec<super::dependency::BundleDependency>,
+    pub overrides: BTreeMap<String, BundleDependency>,
 }
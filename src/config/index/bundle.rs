@@ -16,6 +16,12 @@ pub struct WorkspaceBundle {
     /// Value: list of installed locations (e.g., [`.opencode/commands/debug.md`, `.cursor/rules/debug.mdc`])
     #[serde(default, serialize_with = "serialize_enabled_sorted")]
     pub enabled: HashMap<String, Vec<String>>,
+
+    /// MCP servers merged into shared platform configs
+    /// Key: config path (e.g., `.cursor/mcp.json`)
+    /// Value: names of the servers this bundle added to it
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub mcp_servers: HashMap<String, Vec<String>>,
 }
 
 /// Custom serializer for enabled map that sorts keys and values alphabetically
@@ -47,6 +53,7 @@ impl WorkspaceBundle {
         Self {
             name: name.into(),
             enabled: HashMap::new(),
+            mcp_servers: HashMap::new(),
         }
     }
 
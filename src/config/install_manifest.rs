@@ -0,0 +1,88 @@
+//! Single-file install manifests (`install --manifest`)
+//!
+//! For quick setups, the sources to install and the platforms to install
+//! them for can be declared in one file instead of on the command line:
+//!
+//! ```toml
+//! sources = ["github:acme/review-tools", "./bundles/local"]
+//! platforms = ["cursor", "claude"]
+//! ```
+//!
+//! Files ending in `.json` are read as JSON with the same keys; anything
+//! else is read as TOML.
+
+use std::path::Path;
+
+use serde::Deserialize;
+
+use crate::error::{AugentError, Result};
+
+/// Sources and platforms declared in a manifest file
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct InstallManifest {
+    /// Bundle sources, installed in order
+    pub sources: Vec<String>,
+    /// Platforms to install for; empty means the usual detection
+    #[serde(default)]
+    pub platforms: Vec<String>,
+}
+
+impl InstallManifest {
+    /// Read and parse a manifest file
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path).map_err(|e| AugentError::ConfigReadFailed {
+            path: path.display().to_string(),
+            reason: e.to_string(),
+        })?;
+        Self::parse(&content, path)
+    }
+
+    fn parse(content: &str, path: &Path) -> Result<Self> {
+        let is_json = path.extension().is_some_and(|ext| ext == "json");
+        let parsed: std::result::Result<Self, String> = if is_json {
+            serde_json::from_str(content).map_err(|e| e.to_string())
+        } else {
+            toml::from_str(content).map_err(|e| e.to_string())
+        };
+        let manifest = parsed.map_err(|reason| AugentError::ConfigParseFailed {
+            path: path.display().to_string(),
+            reason,
+        })?;
+        if manifest.sources.is_empty() {
+            return Err(AugentError::ConfigInvalid {
+                message: format!("{}: manifest lists no sources", path.display()),
+            });
+        }
+        Ok(manifest)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_toml_and_json_manifests() {
+        let expected = InstallManifest {
+            sources: vec!["github:acme/tools".to_string()],
+            platforms: vec!["cursor".to_string()],
+        };
+        let toml_path = Path::new("augent.toml");
+        let toml = "sources = [\"github:acme/tools\"]\nplatforms = [\"cursor\"]\n";
+        let json = r#"{"sources": ["github:acme/tools"], "platforms": ["cursor"]}"#;
+
+        assert_eq!(
+            InstallManifest::parse(toml, toml_path).expect("TOML manifest should parse"),
+            expected
+        );
+        assert_eq!(
+            InstallManifest::parse(json, Path::new("augent.json"))
+                .expect("JSON manifest should parse"),
+            expected
+        );
+        assert!(InstallManifest::parse("platforms = [\"cursor\"]\n", toml_path).is_err());
+        assert!(InstallManifest::parse("sources = []\n", toml_path).is_err());
+    }
+}
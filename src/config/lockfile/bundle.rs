@@ -2,6 +2,8 @@
 //!
 //! A resolved bundle in lockfile.
 
+use std::collections::BTreeMap;
+
 use crate::config::utils::count_optional_fields;
 use serde::ser::SerializeStruct;
 use serde::{Deserialize, Serialize};
@@ -40,6 +42,20 @@ pub struct LockedBundle {
 
     /// Files provided by this bundle (relative paths)
     pub files: Vec<String>,
+
+    /// Per-platform ref overrides and the commits they resolved to (platform id -> ref)
+    #[serde(default)]
+    pub platform_refs: BTreeMap<String, LockedPlatformRef>,
+}
+
+/// A per-platform ref override pinned to its resolved commit
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LockedPlatformRef {
+    /// Ref from the dependency's `platform_refs`
+    #[serde(rename = "ref")]
+    pub git_ref: String,
+    /// Resolved commit SHA
+    pub sha: String,
 }
 
 fn serialize_optional_fields<S>(
@@ -81,6 +97,29 @@ fn validate_dir_source(name: &str, path: &str, hash: &str) -> Result<()> {
     validate_hash_format(name, hash)
 }
 
+fn validate_archive_source(name: &str, url: &str, hash: &str) -> Result<()> {
+    if url.is_empty() {
+        return Err(AugentError::ConfigInvalid {
+            message: format!("Bundle '{name}' has empty URL"),
+        });
+    }
+    validate_hash_format(name, hash)
+}
+
+fn validate_oci_source(name: &str, reference: &str, digest: &str, hash: &str) -> Result<()> {
+    if reference.is_empty() {
+        return Err(AugentError::ConfigInvalid {
+            message: format!("Bundle '{name}' has empty OCI reference"),
+        });
+    }
+    if digest.is_empty() {
+        return Err(AugentError::ConfigInvalid {
+            message: format!("Bundle '{name}' has empty OCI digest"),
+        });
+    }
+    validate_hash_format(name, hash)
+}
+
 fn validate_git_source(name: &str, url: &str, sha: &str, hash: &str) -> Result<()> {
     if url.is_empty() {
         return Err(AugentError::ConfigInvalid {
@@ -107,7 +146,7 @@ impl Serialize for LockedBundle {
             self.license.as_ref(),
             self.homepage.as_ref(),
         );
-        let field_count = 3 + optional_count;
+        let field_count = 3 + optional_count + usize::from(!self.platform_refs.is_empty());
 
         let mut state = serializer.serialize_struct("LockedBundle", field_count)?;
         state.serialize_field("name", &self.name)?;
@@ -115,6 +154,9 @@ impl Serialize for LockedBundle {
         serialize_optional_fields::<S>(&mut state, self)?;
 
         state.serialize_field("source", &self.source)?;
+        if !self.platform_refs.is_empty() {
+            state.serialize_field("platform_refs", &self.platform_refs)?;
+        }
 
         let mut sorted_files = self.files.clone();
         sorted_files.sort();
@@ -148,6 +190,7 @@ impl LockedBundle {
                 hash: hash.into(),
             },
             files,
+            platform_refs: BTreeMap::new(),
         }
     }
 
@@ -178,6 +221,7 @@ impl LockedBundle {
                 hash: hash.into(),
             },
             files,
+            platform_refs: BTreeMap::new(),
         }
     }
 
@@ -200,6 +244,17 @@ impl LockedBundle {
             LockedSource::Git { url, sha, hash, .. } => {
                 validate_git_source(&self.name, url, sha, hash)?;
             }
+            LockedSource::Archive { url, hash, .. } => {
+                validate_archive_source(&self.name, url, hash)?;
+            }
+            LockedSource::Oci {
+                reference,
+                digest,
+                hash,
+                ..
+            } => {
+                validate_oci_source(&self.name, reference, digest, hash)?;
+            }
         }
 
         Ok(())
@@ -212,7 +267,40 @@ impl LockedBundle {
     #[allow(dead_code)] // Used by tests
     pub fn hash(&self) -> &str {
         match &self.source {
-            LockedSource::Dir { hash, .. } | LockedSource::Git { hash, .. } => hash,
+            LockedSource::Dir { hash, .. }
+            | LockedSource::Git { hash, .. }
+            | LockedSource::Archive { hash, .. }
+            | LockedSource::Oci { hash, .. } => hash,
         }
     }
 }
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_platform_refs_round_trip_and_are_omitted_when_empty() {
+        let mut bundle = LockedBundle::git(
+            "review-tools",
+            "https://github.com/author/review-tools.git",
+            "1111111111111111111111111111111111111111",
+            "blake3:abc123",
+            vec!["commands/review.md".to_string()],
+        );
+        let plain = serde_json::to_string(&bundle).expect("Failed to serialize");
+        assert!(!plain.contains("platform_refs"));
+
+        bundle.platform_refs.insert(
+            "cursor".to_string(),
+            LockedPlatformRef {
+                git_ref: "v2".to_string(),
+                sha: "2222222222222222222222222222222222222222".to_string(),
+            },
+        );
+        let json = serde_json::to_string(&bundle).expect("Failed to serialize");
+        let parsed: LockedBundle = serde_json::from_str(&json).expect("Failed to parse");
+        assert_eq!(parsed.platform_refs, bundle.platform_refs);
+    }
+}
@@ -16,7 +16,7 @@ use crate::config::utils::BundleContainer;
 use crate::error::{AugentError, Result};
 
 // Re-export types for use in parent config module
-pub use bundle::LockedBundle;
+pub use bundle::{LockedBundle, LockedPlatformRef};
 pub use source::LockedSource;
 
 /// Lockfile structure (augent.lock)
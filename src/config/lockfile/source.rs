@@ -31,6 +31,28 @@ pub enum LockedSource {
         /// BLAKE3 hash of bundle contents
         hash: String,
     },
+    /// Downloaded `.tar.gz` or `.zip` archive
+    Archive {
+        /// Archive URL
+        url: String,
+        /// Subdirectory within the extracted archive (if any)
+        #[serde(skip_serializing_if = "std::option::Option::is_none")]
+        path: Option<String>,
+        /// BLAKE3 hash of bundle contents, verified when reinstalling
+        hash: String,
+    },
+    /// OCI artifact pulled from a registry
+    Oci {
+        /// Reference without the scheme (`registry/repository[:tag][@digest]`)
+        reference: String,
+        /// Manifest digest the reference resolved to
+        digest: String,
+        /// Subdirectory within the extracted artifact (if any)
+        #[serde(skip_serializing_if = "std::option::Option::is_none")]
+        path: Option<String>,
+        /// BLAKE3 hash of bundle contents, verified when reinstalling
+        hash: String,
+    },
 }
 
 /// Default path for Dir source (defaults to "." for root)
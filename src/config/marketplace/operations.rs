@@ -138,6 +138,7 @@ fn write_bundle_config(
         license: None,
         homepage: None,
         bundles: vec![],
+        overrides: std::collections::BTreeMap::new(),
     };
     let yaml_content = config
         .to_yaml(&bundle_name)
@@ -5,15 +5,21 @@
 //! - `augent.lock` - Lockfile with resolved dependencies
 //! - `augent.index.yaml` - Workspace configuration
 //! - `.claude-plugin/marketplace.json` - Marketplace configuration
+//! - `package.json` - Bundle dependencies under `augent.bundles` (fallback)
+//! - Install manifests - Sources and platforms for `install --manifest`
 
 pub mod bundle;
 pub mod index;
+pub mod install_manifest;
 pub mod lockfile;
 pub mod marketplace;
+pub mod package_json;
 pub mod utils;
 
 // Re-export commonly used types
 pub use bundle::{BundleConfig, BundleDependency};
 pub use index::{WorkspaceBundle, WorkspaceConfig};
-pub use lockfile::{LockedBundle, LockedSource, Lockfile};
+pub use install_manifest::InstallManifest;
+pub use lockfile::{LockedBundle, LockedPlatformRef, LockedSource, Lockfile};
 pub use marketplace::{MarketplaceBundle, MarketplaceConfig};
+pub use package_json::load_from_package_json;
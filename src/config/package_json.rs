@@ -0,0 +1,118 @@
+//! `package.json` interop for bundle dependencies
+//!
+//! JavaScript projects can declare augent bundles in `package.json` instead of
+//! a separate `augent.yaml`:
+//!
+//! ```json
+//! {
+//!   "name": "my-app",
+//!   "augent": {
+//!     "bundles": [
+//!       { "name": "@acme/review", "git": "https://github.com/acme/review.git", "ref": "v1" },
+//!       { "name": "local-tools", "path": "./bundles/tools" }
+//!     ]
+//!   }
+//! }
+//! ```
+//!
+//! These entries are only consulted when `augent.yaml` does not exist.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use serde::Deserialize;
+
+use crate::config::BundleDependency;
+
+/// Package manifest filename
+pub const PACKAGE_JSON_FILE: &str = "package.json";
+
+#[derive(Debug, Deserialize)]
+struct PackageJson {
+    augent: Option<AugentSection>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AugentSection {
+    #[serde(default)]
+    bundles: Vec<PackageJsonBundle>,
+}
+
+#[derive(Debug, Deserialize)]
+struct PackageJsonBundle {
+    name: String,
+    git: Option<String>,
+    path: Option<String>,
+    #[serde(rename = "ref")]
+    git_ref: Option<String>,
+}
+
+impl From<PackageJsonBundle> for BundleDependency {
+    fn from(bundle: PackageJsonBundle) -> Self {
+        Self {
+            name: bundle.name,
+            git: bundle.git,
+            archive: None,
+            oci: None,
+            path: bundle.path,
+            git_ref: bundle.git_ref,
+            version: None,
+            platform_refs: BTreeMap::new(),
+        }
+    }
+}
+
+/// Read bundle dependencies from the `augent.bundles` array of a `package.json`
+///
+/// Returns `None` when the file is missing, is not valid JSON, or has no
+/// `augent.bundles` array.
+pub fn load_from_package_json(path: &Path) -> Option<Vec<BundleDependency>> {
+    let content = std::fs::read_to_string(path).ok()?;
+    let package: PackageJson = serde_json::from_str(&content).ok()?;
+    let bundles = package.augent?.bundles;
+    Some(bundles.into_iter().map(BundleDependency::from).collect())
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_load_from_package_json_reads_augent_bundles() {
+        let temp = tempfile::TempDir::new_in(crate::temp::temp_dir_base())
+            .expect("Failed to create temp dir");
+        let path = temp.path().join(PACKAGE_JSON_FILE);
+        std::fs::write(
+            &path,
+            r#"{
+  "name": "my-app",
+  "augent": {
+    "bundles": [
+      { "name": "@acme/review", "git": "https://github.com/acme/review.git", "ref": "v1" },
+      { "name": "local-tools", "path": "./bundles/tools" }
+    ]
+  }
+}"#,
+        )
+        .expect("Failed to write package.json");
+
+        let deps = load_from_package_json(&path).expect("Dependencies should load");
+        assert_eq!(deps.len(), 2);
+        assert_eq!(deps[0].name, "@acme/review");
+        assert_eq!(deps[0].git_ref.as_deref(), Some("v1"));
+        assert!(deps[0].is_git());
+        assert_eq!(deps[1].path.as_deref(), Some("./bundles/tools"));
+    }
+
+    #[test]
+    fn test_load_from_package_json_without_augent_key() {
+        let temp = tempfile::TempDir::new_in(crate::temp::temp_dir_base())
+            .expect("Failed to create temp dir");
+        let path = temp.path().join(PACKAGE_JSON_FILE);
+        std::fs::write(&path, r#"{"name": "my-app"}"#).expect("Failed to write package.json");
+
+        assert!(load_from_package_json(&path).is_none());
+        assert!(load_from_package_json(&temp.path().join("missing.json")).is_none());
+    }
+}
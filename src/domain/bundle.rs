@@ -2,10 +2,12 @@
 //!
 //! Contains domain objects related to bundles and their resources.
 
+use std::collections::HashMap;
 use std::path::PathBuf;
 
 use crate::config::{BundleConfig, BundleDependency};
 use crate::error::{Result, bundle_validation_failed};
+use crate::platform::Platform;
 use crate::source::GitSource;
 
 /// Count of resources by type for a bundle
@@ -66,10 +68,40 @@ impl ResourceCounts {
         }
     }
 
+    /// Number of resources of all types
+    pub fn total(&self) -> usize {
+        self.commands + self.rules + self.agents + self.skills + self.mcp_servers
+    }
+
     #[allow(dead_code)]
     pub fn validate() {}
 }
 
+/// Estimated number of files installing a bundle produces
+///
+/// Every resource is written once per platform, so this is an upper bound:
+/// transforms that merge files (e.g. MCP servers into one config) write fewer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InstallFootprint {
+    pub files: usize,
+    pub platforms: usize,
+}
+
+impl InstallFootprint {
+    /// Format as e.g. "~24 files across 2 platforms"
+    pub fn format(&self) -> String {
+        let plural = |count: usize, name: &str| match count {
+            1 => format!("1 {name}"),
+            _ => format!("{count} {name}s"),
+        };
+        format!(
+            "~{} across {}",
+            plural(self.files, "file"),
+            plural(self.platforms, "platform")
+        )
+    }
+}
+
 /// A resolved bundle with all information needed for installation
 #[derive(Debug, Clone)]
 pub struct ResolvedBundle {
@@ -82,6 +114,42 @@ pub struct ResolvedBundle {
     pub resolved_ref: Option<String>,
     pub git_source: Option<GitSource>,
     pub config: Option<BundleConfig>,
+    /// Content for platforms installed from a different ref (platform id -> source)
+    pub platform_sources: HashMap<String, PlatformSource>,
+    /// Download the bundle was extracted from, for bundles cached from one
+    pub artifact_source: Option<ArtifactSource>,
+}
+
+/// A downloaded artifact a bundle was extracted from into the cache
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ArtifactSource {
+    /// `.tar.gz` or `.zip` archive
+    Archive {
+        /// Archive URL
+        url: String,
+        /// Subdirectory of the extracted archive holding the bundle
+        path: Option<String>,
+    },
+    /// OCI artifact pulled from a registry
+    Oci {
+        /// Reference without the scheme (`registry/repository[:tag][@digest]`)
+        reference: String,
+        /// Manifest digest the reference resolved to
+        digest: String,
+        /// Subdirectory of the extracted artifact holding the bundle
+        path: Option<String>,
+    },
+}
+
+/// A bundle's content at a per-platform ref override
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlatformSource {
+    /// Ref from the dependency's `platform_refs`
+    pub git_ref: String,
+    /// Commit the ref resolved to
+    pub sha: String,
+    /// Cached content at that commit
+    pub path: PathBuf,
 }
 
 impl ResolvedBundle {
@@ -126,6 +194,20 @@ impl DiscoveredBundle {
         ResourceCounts::validate();
         Ok(())
     }
+
+    /// Files this bundle would produce on `platforms` (resources × platforms)
+    ///
+    /// `None` when there are no platforms or no counted resources.
+    pub fn install_footprint(&self, platforms: &[Platform]) -> Option<InstallFootprint> {
+        let resources = self.resource_counts.total();
+        if platforms.is_empty() || resources == 0 {
+            return None;
+        }
+        Some(InstallFootprint {
+            files: resources * platforms.len(),
+            platforms: platforms.len(),
+        })
+    }
 }
 
 /// Count files recursively in a directory
@@ -142,3 +224,35 @@ fn count_files_in_dir(dir: PathBuf) -> usize {
         Err(_) => 0,
     }
 }
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_install_footprint_is_resources_times_platforms() {
+        let bundle = DiscoveredBundle {
+            name: "review-tools".to_string(),
+            path: PathBuf::from("bundles/review-tools"),
+            description: None,
+            git_source: None,
+            resource_counts: ResourceCounts {
+                commands: 3,
+                rules: 2,
+                ..ResourceCounts::default()
+            },
+        };
+        let platforms: Vec<Platform> = crate::platform::default_platforms()
+            .into_iter()
+            .filter(|p| p.id == "claude" || p.id == "cursor")
+            .collect();
+
+        let footprint = bundle
+            .install_footprint(&platforms)
+            .expect("Bundle with resources should have a footprint");
+        assert_eq!(footprint.files, 5 * 2);
+        assert_eq!(footprint.format(), "~10 files across 2 platforms");
+        assert!(bundle.install_footprint(&[]).is_none());
+    }
+}
@@ -6,5 +6,7 @@
 pub mod bundle;
 pub mod resource;
 
-pub use bundle::{DiscoveredBundle, ResolvedBundle, ResourceCounts};
-pub use resource::{DiscoveredResource, InstalledFile};
+pub use bundle::{
+    ArtifactSource, DiscoveredBundle, PlatformSource, ResolvedBundle, ResourceCounts,
+};
+pub use resource::{DiscoveredResource, InstallConflict, InstalledFile};
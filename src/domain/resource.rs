@@ -2,7 +2,9 @@
 //!
 //! Contains domain objects related to resources and their installation.
 
+use std::fmt;
 use std::path::PathBuf;
+use std::sync::OnceLock;
 
 use crate::error::{Result, bundle_validation_failed};
 
@@ -17,6 +19,9 @@ pub struct DiscoveredResource {
 
     /// Resource type (commands, rules, agents, skills, root, or file name)
     pub resource_type: String,
+
+    /// BLAKE3 hash of the file content, computed on first use (see `content_hash()`)
+    pub content_hash: OnceLock<String>,
 }
 
 /// Result of installing a file
@@ -32,8 +37,51 @@ pub struct InstalledFile {
     pub target_paths: Vec<String>,
 }
 
+/// Two bundles that replaced the same target file during one install
+///
+/// The later bundle wins. Targets written with a merge strategy are not conflicts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InstallConflict {
+    /// Bundle whose file was overwritten
+    pub first_bundle: String,
+    /// Source file within the overwritten bundle
+    pub first_source: PathBuf,
+    /// Bundle whose file was kept
+    pub second_bundle: String,
+    /// Source file within the kept bundle
+    pub second_source: PathBuf,
+    /// Target written by both bundles, relative to the workspace root
+    pub target: PathBuf,
+}
+
+impl fmt::Display for InstallConflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} ({}) overwrote {} from {} ({})",
+            self.second_bundle,
+            self.second_source.display(),
+            self.target.display(),
+            self.first_bundle,
+            self.first_source.display()
+        )
+    }
+}
+
 #[allow(dead_code)]
 impl DiscoveredResource {
+    /// Hash of the file content, read from disk only the first time it is asked for
+    ///
+    /// Resources with equal hashes have identical content, so callers can
+    /// compare resources across bundles without reading the files again.
+    pub fn content_hash(&self) -> Result<&str> {
+        if let Some(hash) = self.content_hash.get() {
+            return Ok(hash);
+        }
+        let hash = crate::hash::hash_file(&self.absolute_path)?;
+        Ok(self.content_hash.get_or_init(|| hash))
+    }
+
     pub fn validate(&self) -> Result<()> {
         if self.bundle_path.as_os_str().is_empty() {
             return Err(bundle_validation_failed("Bundle path cannot be empty"));
@@ -86,6 +134,7 @@ mod tests {
                     bundle_path: PathBuf::from($bundle_path),
                     absolute_path: file_path,
                     resource_type: $resource_type.to_string(),
+                    content_hash: OnceLock::new(),
                 };
 
                 if $should_succeed {
@@ -118,6 +167,33 @@ mod tests {
         false
     );
 
+    #[test]
+    fn test_content_hash_compares_resources_by_content() {
+        let temp = tempfile::TempDir::new_in(crate::temp::temp_dir_base())
+            .expect("Failed to create temp dir");
+        let resource = |name: &str, content: &str| {
+            let path = temp.path().join(name);
+            std::fs::write(&path, content).expect("Failed to write resource");
+            DiscoveredResource {
+                bundle_path: PathBuf::from("commands").join(name),
+                absolute_path: path,
+                resource_type: "commands".to_string(),
+                content_hash: OnceLock::new(),
+            }
+        };
+        let first = resource("a.md", "Review the diff.\n");
+        let same = resource("b.md", "Review the diff.\n");
+        let other = resource("c.md", "Lint the diff.\n");
+
+        let hash = first.content_hash().expect("Failed to hash").to_string();
+        assert_eq!(hash, same.content_hash().expect("Failed to hash"));
+        assert_ne!(hash, other.content_hash().expect("Failed to hash"));
+
+        // Computed once: later reads do not touch the file
+        std::fs::remove_file(&first.absolute_path).expect("Failed to remove resource");
+        assert_eq!(first.content_hash().expect("Cached hash"), hash);
+    }
+
     macro_rules! test_installed_file {
         ($test_name:ident, $bundle_path:expr, $resource_type:expr, $target_paths:expr, $should_succeed:expr) => {
             #[test]
@@ -0,0 +1,26 @@
+//! Process exit codes
+//!
+//! Each failure category gets its own code so CI can branch on the kind of
+//! failure. Code 2 is left to clap, which uses it for usage errors.
+
+/// Command succeeded
+pub const SUCCESS: i32 = 0;
+
+/// Any failure without a more specific category
+pub const FAILURE: i32 = 1;
+
+/// Fetching or cloning a remote repository failed
+pub const NETWORK: i32 = 3;
+
+/// A source, bundle, configuration or lockfile failed validation
+pub const VALIDATION: i32 = 4;
+
+/// The workspace conflicts with the request and needs an explicit override
+/// (e.g. `--allow-external-targets`, or dropping `--frozen`)
+pub const CONFLICT: i32 = 5;
+
+/// Some, but not all, of the work succeeded
+pub const PARTIAL: i32 = 6;
+
+/// `augent status` found installed files that were modified or deleted
+pub const DRIFT: i32 = 7;
@@ -6,6 +6,8 @@ impl_error_constructors!(FsModule, {
     FileNotFound(path),
     FileReadFailed(path, reason),
     FileWriteFailed(path, reason),
+    WriteVerificationFailed(path),
+    UnsafeTargetPath(path, resolved),
 });
 
 pub use self::{
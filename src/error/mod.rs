@@ -13,6 +13,7 @@
 //! - [`platform`]: Platform errors
 //! - [`fs`]: File system errors
 //! - [`cache`]: Cache errors
+//! - [`exit_code`]: Process exit codes per error category
 //! - [`macros`]: Error context macros for consistent error messages
 
 #![allow(dead_code, unused_assignments)]
@@ -22,6 +23,7 @@ pub mod bundle;
 pub mod cache;
 pub mod config;
 pub mod deps;
+pub mod exit_code;
 pub mod fs;
 pub mod git;
 pub mod lockfile;
@@ -108,6 +110,13 @@ pub enum AugentError {
     #[allow(dead_code, unused_assignments)]
     SourceParseFailed { input: String, reason: String },
 
+    #[error("Failed to read bundle registry '{url}': {reason}")]
+    #[diagnostic(
+        code(augent::source::registry_failed),
+        help("Check AUGENT_REGISTRY_URL, or unset it to use GitHub shorthands")
+    )]
+    RegistryFailed { url: String, reason: String },
+
     // Git errors
     #[error("Git operation failed: {message}")]
     #[diagnostic(code(augent::git::operation_failed))]
@@ -144,9 +153,7 @@ pub enum AugentError {
     #[error("Not in a git repository")]
     #[diagnostic(
         code(augent::git::not_in_repo),
-        help(
-            "Augent commands must be run from within a git repository. Run 'git init' to create a repository."
-        )
+        help("Run 'git init' or cd into a git repository")
     )]
     NotInGitRepository,
 
@@ -159,6 +166,13 @@ pub enum AugentError {
     #[allow(dead_code, unused_assignments)]
     WorkspaceNotFound { path: String },
 
+    #[error("{count} installed file(s) modified or missing")]
+    #[diagnostic(
+        code(augent::workspace::drift),
+        help("Run 'augent install' to restore the bundle files")
+    )]
+    WorkspaceDrift { count: usize },
+
     // Configuration errors
     #[error("Configuration file not found: {path}")]
     #[diagnostic(code(augent::config::not_found))]
@@ -212,6 +226,15 @@ pub enum AugentError {
     #[diagnostic(code(augent::deps::not_found))]
     DependencyNotFound { name: String },
 
+    #[error("Dependency '{name}' is required at different commits: {details}")]
+    #[diagnostic(
+        code(augent::deps::version_conflict),
+        help(
+            "Require the same ref from all dependents, or pass --allow-version-conflicts to use the first one resolved"
+        )
+    )]
+    DependencyVersionConflict { name: String, details: String },
+
     // Platform errors
     #[error("Platform not supported: {platform}")]
     #[diagnostic(
@@ -261,6 +284,20 @@ pub enum AugentError {
     )]
     UnsupportedConversion { platform: String, reason: String },
 
+    #[error("Generated TOML is invalid: {path}: {reason}")]
+    #[diagnostic(
+        code(augent::installer::toml_validation_failed),
+        help("The source command likely contains characters that were not escaped correctly")
+    )]
+    TomlValidationFailed { path: String, reason: String },
+
+    #[error("{hook} hook failed: {reason}")]
+    #[diagnostic(
+        code(augent::installer::hook_failed),
+        help("Fix the script in .augent/hooks/, or run without --allow-hooks")
+    )]
+    HookFailed { hook: String, reason: String },
+
     // File system errors
     #[error("File not found: {path}")]
     #[diagnostic(code(augent::fs::not_found))]
@@ -274,6 +311,29 @@ pub enum AugentError {
     #[diagnostic(code(augent::fs::write_failed))]
     FileWriteFailed { path: String, reason: String },
 
+    #[error("Written file does not match the intended content: {path}")]
+    #[diagnostic(
+        code(augent::fs::write_verification_failed),
+        help("The file system may be unreliable; check the disk and re-run the install")
+    )]
+    WriteVerificationFailed { path: String },
+
+    #[error("Install target '{path}' resolves to '{resolved}' outside of the workspace")]
+    #[diagnostic(
+        code(augent::fs::unsafe_target_path),
+        help(
+            "A platform directory or one of its ancestors is a symlink leaving the workspace. Remove the symlink or pass --allow-external-targets"
+        )
+    )]
+    UnsafeTargetPath { path: String, resolved: String },
+
+    #[error("Install would create new directory '{path}'")]
+    #[diagnostic(
+        code(augent::fs::new_directory_denied),
+        help("Create the directory first, or install without --no-new-dirs")
+    )]
+    NewDirectoryDenied { path: String },
+
     #[error("IO error: {message}")]
     #[diagnostic(code(augent::fs::io_error))]
     IoError {
@@ -286,6 +346,55 @@ pub enum AugentError {
     #[error("Cache operation failed: {message}")]
     #[diagnostic(code(augent::cache::operation_failed))]
     CacheOperationFailed { message: String },
+
+    // Outcome errors
+    #[error("Partially succeeded: {message}")]
+    #[diagnostic(
+        code(augent::partial_success),
+        help("Fix the reported failures and re-run; completed work is kept")
+    )]
+    PartialSuccess { message: String },
+}
+
+impl AugentError {
+    /// Recovery hint for this error, if it has one
+    pub fn hint(&self) -> Option<String> {
+        self.help().map(|help| help.to_string())
+    }
+
+    /// Process exit code for this error's category (see [`exit_code`])
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Self::GitCloneFailed { .. } | Self::GitFetchFailed { .. } => exit_code::NETWORK,
+            Self::InvalidBundleName { .. }
+            | Self::BundleValidationFailed { .. }
+            | Self::InvalidSourceUrl { .. }
+            | Self::SourceParseFailed { .. }
+            | Self::ConfigParseFailed { .. }
+            | Self::ConfigInvalid { .. }
+            | Self::HashMismatch { .. }
+            | Self::CircularDependency { .. }
+            | Self::PlatformNotSupported { .. }
+            | Self::TomlValidationFailed { .. } => exit_code::VALIDATION,
+            Self::LockfileOutdated
+            | Self::UnsafeTargetPath { .. }
+            | Self::NewDirectoryDenied { .. }
+            | Self::DependencyVersionConflict { .. } => exit_code::CONFLICT,
+            Self::PartialSuccess { .. } => exit_code::PARTIAL,
+            Self::WorkspaceDrift { .. } => exit_code::DRIFT,
+            _ => exit_code::FAILURE,
+        }
+    }
+
+    /// Error message followed by a `hint:` line when a hint is available
+    ///
+    /// The diagnostic code stays available separately via `Diagnostic::code`.
+    pub fn display_with_hint(&self) -> String {
+        match self.hint() {
+            Some(hint) => format!("{self}\nhint: {hint}"),
+            None => self.to_string(),
+        }
+    }
 }
 
 impl From<std::io::Error> for AugentError {
@@ -337,3 +337,62 @@ fn test_manual_io_error_without_source() {
     let source_err = err.source();
     assert!(source_err.is_none(), "Manual IoError should have no source");
 }
+
+#[test]
+fn test_display_with_hint_for_common_errors() {
+    let not_in_repo = AugentError::NotInGitRepository.display_with_hint();
+    assert!(not_in_repo.starts_with("Not in a git repository\nhint: "));
+    assert!(not_in_repo.contains("git init"));
+
+    let workspace = workspace_not_found("/tmp/project").display_with_hint();
+    assert!(workspace.contains("hint: Run 'augent install'"));
+
+    let bundle = bundle_not_found("missing").display_with_hint();
+    assert!(bundle.contains("hint: Check that bundle name is correct"));
+}
+
+#[test]
+fn test_display_with_hint_omits_missing_hint() {
+    let err = git_operation_failed("boom");
+    assert_eq!(err.display_with_hint(), err.to_string());
+    assert!(err.hint().is_none());
+    assert_eq!(
+        err.code().map(|c| c.to_string()),
+        Some("augent::git::operation_failed".to_string())
+    );
+}
+
+#[test]
+fn test_exit_code_distinguishes_validation_from_network() {
+    use crate::error::exit_code;
+
+    let validation = source_parse_failed("gist:bad/id", "Invalid gist id");
+    let network = clone_failed("https://example.com/repo.git", "connection refused");
+    assert_eq!(validation.exit_code(), exit_code::VALIDATION);
+    assert_eq!(network.exit_code(), exit_code::NETWORK);
+    assert_ne!(validation.exit_code(), network.exit_code());
+}
+
+#[test]
+fn test_exit_code_for_conflict_partial_and_other_errors() {
+    use crate::error::exit_code;
+
+    assert_eq!(
+        AugentError::LockfileOutdated.exit_code(),
+        exit_code::CONFLICT
+    );
+    let partial = AugentError::PartialSuccess {
+        message: "Install failed in: /tmp/b".to_string(),
+    };
+    assert_eq!(partial.exit_code(), exit_code::PARTIAL);
+    let version_conflict = AugentError::DependencyVersionConflict {
+        name: "shared".to_string(),
+        details: "a wants 1111111, b wants 2222222".to_string(),
+    };
+    assert_eq!(version_conflict.exit_code(), exit_code::CONFLICT);
+    assert_eq!(
+        AugentError::WorkspaceDrift { count: 2 }.exit_code(),
+        exit_code::DRIFT
+    );
+    assert_eq!(io_error("boom").exit_code(), exit_code::FAILURE);
+}
@@ -4,15 +4,30 @@
 //! - Setting up SSH authentication
 //! - Setting up username/password authentication
 //! - Credential helper integration
+//! - Access tokens for private HTTPS repositories
 //!
-//! Authentication is delegated entirely to git's native credential system:
-//! - SSH keys from ~/.ssh/
+//! An access token is offered first for `https://` URLs on a matching host:
+//! `AUGENT_GIT_TOKEN` for the hosts in `AUGENT_GIT_HOSTS`, `GITHUB_TOKEN` for
+//! github.com and `GITLAB_TOKEN` for gitlab.com.
+//! Otherwise authentication is delegated to git's native credential system:
+//! - SSH agent and SSH keys from ~/.ssh/
 //! - Git credential helpers
 //! - Environment variables (`GIT_SSH_COMMAND`, etc.)
 
 use dirs;
 use git2::{Cred, CredentialType, Error, ErrorClass, RemoteCallbacks};
 
+use crate::source::hosts::{GIT_HOSTS_ENV, hosts_or_default};
+
+/// Access token used for HTTPS URLs on the hosts in `AUGENT_GIT_HOSTS`
+pub const TOKEN_ENV: &str = "AUGENT_GIT_TOKEN";
+
+/// Access token used for github.com when `AUGENT_GIT_TOKEN` is not set
+pub const GITHUB_TOKEN_ENV: &str = "GITHUB_TOKEN";
+
+/// Access token used for gitlab.com when `AUGENT_GIT_TOKEN` is not set
+pub const GITLAB_TOKEN_ENV: &str = "GITLAB_TOKEN";
+
 fn try_default_credentials() -> Option<Cred> {
     for username in &["git", "anonymous"] {
         if let Ok(cred) = Cred::userpass_plaintext(username, "") {
@@ -88,15 +103,85 @@ fn try_user_pass_credentials(
     }
 }
 
+/// Lowercased host of an `https://` URL, without user info or port
+fn https_host(url: &str) -> Option<String> {
+    let rest = url.strip_prefix("https://")?;
+    let authority = rest.split('/').next()?;
+    let host = authority.rsplit('@').next()?;
+    let host = host.split(':').next()?;
+    (!host.is_empty()).then(|| host.to_ascii_lowercase())
+}
+
+/// Username and access token for an `https://` URL, read through `env`
+///
+/// `AUGENT_GIT_TOKEN` is only sent to the hosts in `AUGENT_GIT_HOSTS`
+/// (github.com when unset), `GITHUB_TOKEN` only to github.com and
+/// `GITLAB_TOKEN` only to gitlab.com. Plain `http://` URLs never get a token.
+fn token_for_url(
+    url: &str,
+    env: impl Fn(&str) -> Option<String>,
+) -> Option<(&'static str, String)> {
+    let host = https_host(url)?;
+    let (username, host_env) = match host.as_str() {
+        "github.com" => ("x-access-token", Some(GITHUB_TOKEN_ENV)),
+        "gitlab.com" => ("oauth2", Some(GITLAB_TOKEN_ENV)),
+        _ => ("x-access-token", None),
+    };
+    let token_hosts = hosts_or_default(env(GIT_HOSTS_ENV).as_deref());
+    let augent_token = token_hosts
+        .iter()
+        .any(|token_host| https_host(token_host).as_deref() == Some(host.as_str()))
+        .then(|| env(TOKEN_ENV))
+        .flatten();
+    augent_token
+        .or_else(|| host_env.and_then(&env))
+        .filter(|token| !token.trim().is_empty())
+        .map(|token| (username, token.trim().to_string()))
+}
+
+fn env_var(name: &str) -> Option<String> {
+    std::env::var(name).ok()
+}
+
+/// Whether an access token is configured for `url`
+pub fn has_token(url: &str) -> bool {
+    token_for_url(url, env_var).is_some()
+}
+
+/// Remote callbacks with augent's authentication, for clones and remote listings
+pub fn make_remote_callbacks<'a>() -> RemoteCallbacks<'a> {
+    let mut callbacks = RemoteCallbacks::new();
+    setup_auth_callbacks(&mut callbacks);
+    callbacks
+}
+
 /// Set up authentication callbacks for git operations
 ///
-/// This delegates authentication to git's native credential system:
+/// An access token configured for the URL is tried once; after that (and
+/// for SSH URLs) authentication is delegated to git's native credential system:
 /// - SSH keys from ~/.ssh/
 /// - SSH agent
 /// - Git credential helpers
 /// - Username/password from environment
 pub fn setup_auth_callbacks(callbacks: &mut RemoteCallbacks) {
-    callbacks.credentials(|url, username_from_url, allowed_types| {
+    callbacks.credentials(credentials_callback(env_var));
+}
+
+/// Credential callback reading tokens through `env`
+fn credentials_callback(
+    env: impl Fn(&str) -> Option<String>,
+) -> impl FnMut(&str, Option<&str>, CredentialType) -> std::result::Result<Cred, Error> {
+    // libgit2 calls back again after a rejected credential; offer the token only once
+    let mut token_offered = false;
+    move |url, username_from_url, allowed_types| {
+        let token = (allowed_types.contains(CredentialType::USER_PASS_PLAINTEXT) && !token_offered)
+            .then(|| token_for_url(url, &env))
+            .flatten();
+        if let Some((username, token)) = token {
+            token_offered = true;
+            return Cred::userpass_plaintext(username, &token);
+        }
+
         if allowed_types.contains(CredentialType::DEFAULT) {
             return Cred::default();
         }
@@ -110,7 +195,7 @@ pub fn setup_auth_callbacks(callbacks: &mut RemoteCallbacks) {
         }
 
         Err(auth_error())
-    });
+    }
 }
 
 fn try_ssh_auth(username_from_url: Option<&str>) -> std::result::Result<Cred, git2::Error> {
@@ -128,3 +213,91 @@ fn auth_error() -> Error {
         "authentication failed",
     )
 }
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::*;
+
+    fn env_of(vars: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+        let vars: Vec<(String, String)> = vars
+            .iter()
+            .map(|(k, v)| ((*k).to_string(), (*v).to_string()))
+            .collect();
+        move |name| vars.iter().find(|(k, _)| k == name).map(|(_, v)| v.clone())
+    }
+
+    #[test]
+    fn test_token_for_url_selects_host_token() {
+        let env = env_of(&[("GITHUB_TOKEN", "gh"), ("GITLAB_TOKEN", "gl")]);
+        assert_eq!(
+            token_for_url("https://github.com/author/repo.git", &env),
+            Some(("x-access-token", "gh".to_string()))
+        );
+        assert_eq!(
+            token_for_url("https://user@gitlab.com:443/group/repo.git", &env),
+            Some(("oauth2", "gl".to_string()))
+        );
+        assert_eq!(
+            token_for_url("https://git.example.com/repo.git", &env),
+            None
+        );
+        assert_eq!(token_for_url("git@github.com:author/repo.git", &env), None);
+
+        let env = env_of(&[("AUGENT_GIT_TOKEN", "augent"), ("GITHUB_TOKEN", "gh")]);
+        assert_eq!(
+            token_for_url("https://github.com/author/repo.git", &env),
+            Some(("x-access-token", "augent".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_token_for_url_only_sends_tokens_over_https_to_matching_hosts() {
+        let env = env_of(&[
+            ("AUGENT_GIT_TOKEN", "augent"),
+            ("AUGENT_GIT_HOSTS", "https://git.example.com"),
+            ("GITHUB_TOKEN", "gh"),
+        ]);
+        assert_eq!(
+            token_for_url("https://git.example.com/repo.git", &env),
+            Some(("x-access-token", "augent".to_string()))
+        );
+        assert_eq!(token_for_url("http://git.example.com/repo.git", &env), None);
+        assert_eq!(
+            token_for_url("http://github.com/author/repo.git", &env),
+            None
+        );
+        assert_eq!(
+            token_for_url("https://evil.example.org/repo.git", &env),
+            None
+        );
+        assert_eq!(
+            token_for_url("https://github.com.evil.example.org/repo.git", &env),
+            None
+        );
+        assert_eq!(
+            token_for_url("https://github.com/author/repo.git", &env),
+            Some(("x-access-token", "gh".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_credentials_callback_offers_token_once() {
+        let mut callback = credentials_callback(env_of(&[("GITHUB_TOKEN", "gh")]));
+        let cred = callback(
+            "https://github.com/author/private.git",
+            None,
+            CredentialType::USER_PASS_PLAINTEXT,
+        )
+        .expect("Token credential");
+        assert_eq!(cred.credtype(), CredentialType::USER_PASS_PLAINTEXT.bits());
+
+        // A second request (the token was rejected) does not offer it again
+        let retry = callback(
+            "https://github.com/author/private.git",
+            None,
+            CredentialType::empty(),
+        );
+        assert!(retry.is_err());
+    }
+}
@@ -2,20 +2,63 @@
 //!
 //! This module handles:
 //! - Cloning git repositories (HTTPS and SSH)
+//! - Clone depth: shallow by default, configurable via `AUGENT_CLONE_DEPTH`
 //! - Windows-specific file:// URL handling
 
 #[cfg(windows)]
 use std::fs;
 use std::path::Path;
 
-use git2::{FetchOptions, RemoteCallbacks, Repository, build::RepoBuilder};
+use git2::{FetchOptions, Repository, build::RepoBuilder};
 
-use super::auth::setup_auth_callbacks;
+use super::auth::make_remote_callbacks;
 use super::error::interpret_git_error;
 use super::url::normalize_file_url_for_clone;
 use super::url::normalize_ssh_url_for_clone;
 use crate::error::{AugentError, Result};
 
+/// Environment variable setting the clone depth (`full`, or a number of commits)
+pub const CLONE_DEPTH_ENV: &str = "AUGENT_CLONE_DEPTH";
+
+/// How much history a clone fetches
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CloneDepth {
+    /// The given number of commits from each fetched ref
+    Commits(u32),
+    /// Full history
+    Full,
+}
+
+impl CloneDepth {
+    /// Only the tip commit of each ref (the default)
+    pub const SHALLOW: Self = Self::Commits(1);
+
+    /// Parse a depth: `full` (or `0`) for full history, else a number of commits
+    pub fn parse(value: &str) -> Option<Self> {
+        let value = value.trim();
+        if value.eq_ignore_ascii_case("full") {
+            return Some(Self::Full);
+        }
+        match value.parse::<u32>().ok()? {
+            0 => Some(Self::Full),
+            n => Some(Self::Commits(n)),
+        }
+    }
+
+    /// Depth from `AUGENT_CLONE_DEPTH`; shallow when unset or invalid
+    pub fn from_env() -> Self {
+        let Ok(value) = std::env::var(CLONE_DEPTH_ENV) else {
+            return Self::SHALLOW;
+        };
+        Self::parse(&value).unwrap_or_else(|| {
+            eprintln!(
+                "Warning: Ignoring invalid {CLONE_DEPTH_ENV} '{value}'; expected 'full' or a number"
+            );
+            Self::SHALLOW
+        })
+    }
+}
+
 /// On Windows, libgit2 fails to parse file:// URLs (drive letters, path
 /// resolution). Clone by copying the source directory and opening it.
 #[cfg(windows)]
@@ -60,9 +103,9 @@ fn copy_dir_preserving_git_errors(src: &Path, dst: &Path, url: &str) -> Result<(
 /// # Arguments
 /// * `url` - The git URL to clone
 /// * `target` - The target directory path
-/// * `shallow` - Whether to do a shallow clone (depth=1). Default is true.
-///   Set to false when you need to resolve specific refs like tags.
-pub fn clone(url: &str, target: &Path, shallow: bool) -> Result<Repository> {
+/// * `depth` - History to fetch. Shallow clones are faster, but refs that
+///   point at older commits (such as tags) may not be resolvable in them.
+pub fn clone(url: &str, target: &Path, depth: CloneDepth) -> Result<Repository> {
     // On Windows, libgit2 fails on file:// URLs (drive letters, path resolution).
     // Clone by copying the source directory instead.
     #[cfg(windows)]
@@ -70,19 +113,16 @@ pub fn clone(url: &str, target: &Path, shallow: bool) -> Result<Repository> {
         return clone_local_file(url, target);
     }
 
-    let mut callbacks = RemoteCallbacks::new();
-    setup_auth_callbacks(&mut callbacks);
-
     let mut fetch_options = FetchOptions::new();
-    fetch_options.remote_callbacks(callbacks);
+    fetch_options.remote_callbacks(make_remote_callbacks());
 
     // Shallow clone for remote URLs only if requested
     // (not supported for local file:// URLs or local paths)
     let is_local = url.starts_with("file://")
         || url.starts_with('/')
         || std::path::Path::new(url).is_absolute();
-    if shallow && !is_local {
-        fetch_options.depth(1);
+    if let (CloneDepth::Commits(n), false) = (depth, is_local) {
+        fetch_options.depth(i32::try_from(n).unwrap_or(i32::MAX));
     }
 
     let mut builder = RepoBuilder::new();
@@ -99,3 +139,19 @@ pub fn clone(url: &str, target: &Path, shallow: bool) -> Result<Repository> {
         }
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clone_depth_parse() {
+        assert_eq!(CloneDepth::parse("full"), Some(CloneDepth::Full));
+        assert_eq!(CloneDepth::parse("FULL"), Some(CloneDepth::Full));
+        assert_eq!(CloneDepth::parse("0"), Some(CloneDepth::Full));
+        assert_eq!(CloneDepth::parse(" 50 "), Some(CloneDepth::Commits(50)));
+        assert_eq!(CloneDepth::parse("1"), Some(CloneDepth::SHALLOW));
+        assert_eq!(CloneDepth::parse("deep"), None);
+        assert_eq!(CloneDepth::parse("-1"), None);
+    }
+}
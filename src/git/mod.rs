@@ -4,9 +4,10 @@
 //! - Cloning repositories (HTTPS and SSH)
 //! - Resolving refs (branches, tags) to exact SHAs
 //! - Fetching updates for existing repositories
-//! - Authentication via git's native credential system
+//! - Authentication via access tokens and git's native credential system
 //!
-//! Authentication is delegated entirely to git's native system:
+//! HTTPS URLs use an access token from the environment when one is set
+//! (see [`auth`]). Otherwise authentication is delegated to git's native system:
 //! - SSH keys from ~/.ssh/
 //! - Git credential helpers
 //! - Environment variables (`GIT_SSH_COMMAND`, etc.)
@@ -21,5 +22,5 @@ pub mod url_parser;
 
 // Re-export public API from submodules
 pub use checkout::checkout_commit;
-pub use clone::clone;
-pub use refs::{get_head_ref_name, ls_remote, resolve_ref};
+pub use clone::{CloneDepth, clone};
+pub use refs::{get_head_ref_name, ls_remote, ls_remote_tags, remote_sha, resolve_ref};
@@ -3,7 +3,12 @@
 //! This module handles:
 //! - Resolving refs (branches, tags) to exact SHAs
 //! - Using git ls-remote for remote ref resolution without cloning
+//! - Listing remote tags for `glob:` refs
+//!
+//! Remotes are listed with the git CLI, or through libgit2 when an access
+//! token is configured for the URL so that the token is used (see [`super::auth`]).
 
+use std::fmt::Write as _;
 use std::path::Path;
 use std::process::Command;
 
@@ -11,6 +16,12 @@ use git2::Repository;
 
 use crate::error::{AugentError, Result};
 
+use super::auth::{has_token, make_remote_callbacks};
+use super::error::interpret_git_error;
+
+/// Ref prefix selecting the newest tag matching a glob (e.g. `glob:release-*`)
+pub const TAG_GLOB_PREFIX: &str = "glob:";
+
 fn is_local_url(url: &str) -> bool {
     url.starts_with("file://") || url.starts_with('/') || Path::new(url).is_absolute()
 }
@@ -56,6 +67,10 @@ pub fn ls_remote(url: &str, git_ref: Option<&str>) -> Result<String> {
     }
 
     let ref_arg = git_ref.unwrap_or("HEAD");
+    if has_token(url) {
+        let stdout = list_remote(url, ref_arg, |name| ref_matches(name, ref_arg))?;
+        return parse_sha_from_output(&stdout, ref_arg);
+    }
     let output = Command::new("git")
         .args(["ls-remote", "--exit-code", url, ref_arg])
         .output()
@@ -76,6 +91,82 @@ pub fn ls_remote(url: &str, git_ref: Option<&str>) -> Result<String> {
     parse_sha_from_output(&stdout, ref_arg)
 }
 
+/// Resolve a ref of a remote to its SHA without cloning
+///
+/// Like [`ls_remote`], but local repositories are listed through libgit2
+/// instead of being rejected.
+pub fn remote_sha(url: &str, git_ref: Option<&str>) -> Result<String> {
+    if !is_local_url(url) {
+        return ls_remote(url, git_ref);
+    }
+    let ref_arg = git_ref.unwrap_or("HEAD");
+    let stdout = list_remote(url, ref_arg, |name| ref_matches(name, ref_arg))?;
+    parse_sha_from_output(&stdout, ref_arg)
+}
+
+/// List tag names of a remote via `git ls-remote --tags`
+///
+/// Unlike [`ls_remote`], local URLs are supported.
+pub fn ls_remote_tags(url: &str) -> Result<Vec<String>> {
+    if has_token(url) {
+        let stdout = list_remote(url, "refs/tags/*", |name| {
+            name.starts_with("refs/tags/") && !name.ends_with("^{}")
+        })?;
+        return Ok(parse_tags_from_output(&stdout));
+    }
+    let output = Command::new("git")
+        .args(["ls-remote", "--tags", "--refs", url])
+        .output()
+        .map_err(|e| AugentError::GitRefResolveFailed {
+            git_ref: "refs/tags/*".to_string(),
+            reason: format!("git ls-remote failed: {e}"),
+        })?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(AugentError::GitRefResolveFailed {
+            git_ref: "refs/tags/*".to_string(),
+            reason: stderr.trim().to_string(),
+        });
+    }
+
+    Ok(parse_tags_from_output(&String::from_utf8_lossy(
+        &output.stdout,
+    )))
+}
+
+/// Whether a remote ref name matches a ref pattern the way `git ls-remote <ref>` does
+fn ref_matches(name: &str, git_ref: &str) -> bool {
+    name == git_ref || name.ends_with(&format!("/{git_ref}"))
+}
+
+/// Refs of a remote matching `matches`, listed through libgit2 in `git ls-remote` output format
+fn list_remote(url: &str, git_ref: &str, matches: impl Fn(&str) -> bool) -> Result<String> {
+    let resolve_failed = |e: &git2::Error| AugentError::GitRefResolveFailed {
+        git_ref: git_ref.to_string(),
+        reason: interpret_git_error(e),
+    };
+    let mut remote = git2::Remote::create_detached(url).map_err(|e| resolve_failed(&e))?;
+    let connection = remote
+        .connect_auth(git2::Direction::Fetch, Some(make_remote_callbacks()), None)
+        .map_err(|e| resolve_failed(&e))?;
+    let heads = connection.list().map_err(|e| resolve_failed(&e))?;
+    let mut listing = String::new();
+    for head in heads.iter().filter(|head| matches(head.name())) {
+        let _ = writeln!(listing, "{}\t{}", head.oid(), head.name());
+    }
+    Ok(listing)
+}
+
+fn parse_tags_from_output(stdout: &str) -> Vec<String> {
+    stdout
+        .lines()
+        .filter_map(|line| line.split_whitespace().nth(1))
+        .filter_map(|refname| refname.strip_prefix("refs/tags/"))
+        .map(str::to_string)
+        .collect()
+}
+
 /// Resolve a git ref (branch, tag, or partial SHA) to a full SHA
 ///
 /// If no ref is provided, defaults to HEAD.
@@ -150,3 +241,15 @@ pub fn get_head_ref_name(repo: &Repository) -> Result<Option<String>> {
         Ok(None)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_tags_from_output() {
+        let stdout = "1111111111111111111111111111111111111111\trefs/tags/release-1\n\
+                      2222222222222222222222222222222222222222\trefs/tags/v2.0\n";
+        assert_eq!(parse_tags_from_output(stdout), vec!["release-1", "v2.0"]);
+    }
+}
@@ -2,12 +2,25 @@
 //!
 //! Provides functions for parsing various Git repository URL formats.
 
+use super::refs::TAG_GLOB_PREFIX;
 use crate::error::Result;
 
+/// Prefix of GitLab shorthand sources (`gitlab:group/subgroup/repo`)
+pub const GITLAB_PREFIX: &str = "gitlab:";
+
+/// Prefix of Bitbucket shorthand sources (`bitbucket:user/repo`)
+pub const BITBUCKET_PREFIX: &str = "bitbucket:";
+
 /// Find the starting position after protocol prefix in a URL string
 pub fn find_protocol_prefix_start(main_part: &str) -> usize {
     if main_part.starts_with("github:") {
         "github:".len()
+    } else if main_part.starts_with("gist:") {
+        "gist:".len()
+    } else if main_part.starts_with(GITLAB_PREFIX) {
+        GITLAB_PREFIX.len()
+    } else if main_part.starts_with(BITBUCKET_PREFIX) {
+        BITBUCKET_PREFIX.len()
     } else if main_part.starts_with("https://") {
         "https://".len()
     } else if main_part.starts_with("http://") {
@@ -47,18 +60,28 @@ pub fn is_ssh_url(input: &str) -> bool {
     input.starts_with("git@") || input.starts_with("ssh://")
 }
 
+/// Position of the ref/path separator in a fragment
+///
+/// The colon of a `glob:` ref prefix is part of the ref.
+fn fragment_path_colon(ref_frag: &str) -> Option<usize> {
+    let skip = if ref_frag.starts_with(TAG_GLOB_PREFIX) {
+        TAG_GLOB_PREFIX.len()
+    } else {
+        0
+    };
+    ref_frag[skip..].find(':').map(|pos| skip + pos)
+}
+
 /// Parse path from fragment containing ':'
 pub fn parse_path_from_fragment(ref_frag: &str) -> Option<String> {
-    ref_frag
-        .find(':')
-        .map(|colon_pos| ref_frag[colon_pos + 1..].to_string())
+    fragment_path_colon(ref_frag).map(|colon_pos| ref_frag[colon_pos + 1..].to_string())
 }
 
 /// Parse ref from fragment
 pub fn parse_ref_from_fragment(ref_frag: &str) -> Option<String> {
     if ref_frag.is_empty() {
         None
-    } else if let Some(colon_pos) = ref_frag.find(':') {
+    } else if let Some(colon_pos) = fragment_path_colon(ref_frag) {
         Some(ref_frag[..colon_pos].to_string())
     } else {
         Some(ref_frag.to_string())
@@ -143,19 +166,85 @@ pub fn is_github_shorthand(input: &str) -> bool {
         && !input.starts_with("git@")
         && !input.starts_with("file://")
         && !input.starts_with("github:")
+        && !input.starts_with(GITLAB_PREFIX)
+        && !input.starts_with(BITBUCKET_PREFIX)
         && !input.starts_with('@')
         && input.matches('/').count() == 1
         && !input.starts_with('/')
 }
 
+/// Parse GitHub Gist source into its clone URL
+///
+/// Accepts `gist:<id>`, `https://gist.github.com/<id>` and
+/// `https://gist.github.com/<user>/<id>` (with optional `.git` suffix).
+///
+/// Returns: `https://gist.github.com/<id>.git`
+pub fn parse_gist_url(input: &str) -> Option<String> {
+    let id = if let Some(rest) = input.strip_prefix("gist:") {
+        rest
+    } else {
+        let without_prefix = input.strip_prefix("https://gist.github.com/")?;
+        let parts: Vec<&str> = without_prefix.trim_end_matches('/').split('/').collect();
+        match parts.as_slice() {
+            [id] | [_, id] => *id,
+            _ => return None,
+        }
+    };
+
+    let id = id.trim_end_matches(".git");
+    let is_valid_id = !id.is_empty() && id.chars().all(|c| c.is_ascii_alphanumeric());
+    is_valid_id.then(|| format!("https://gist.github.com/{id}.git"))
+}
+
+/// Parse GitLab shorthand (the part after `gitlab:`) into its clone URL
+///
+/// GitLab nests subgroups arbitrarily, so any number of slashes is accepted
+/// as long as there is at least `group/repo` and no segment is empty.
+///
+/// Returns: `https://gitlab.com/<group>/.../<repo>.git`
+pub fn parse_gitlab_shorthand(rest: &str) -> Option<String> {
+    let segments: Vec<&str> = rest.split('/').collect();
+    let is_valid = segments.len() >= 2 && segments.iter().all(|s| !s.is_empty());
+    is_valid.then(|| format!("https://gitlab.com/{rest}.git"))
+}
+
+/// Parse Bitbucket shorthand (the part after `bitbucket:`) into its clone URL
+///
+/// Bitbucket has no nesting: exactly `user/repo`.
+///
+/// Returns: `https://bitbucket.org/<user>/<repo>.git`
+pub fn parse_bitbucket_shorthand(rest: &str) -> Option<String> {
+    match rest.split('/').collect::<Vec<_>>().as_slice() {
+        [user, repo] if !user.is_empty() && !repo.is_empty() => {
+            Some(format!("https://bitbucket.org/{rest}.git"))
+        }
+        _ => None,
+    }
+}
+
+/// Resource directories that mark a file's bundle root when parsing `/blob/` URLs
+const BLOB_RESOURCE_DIRS: &[&str] = &["commands", "rules", "agents", "skills", "mcp_servers"];
+
 /// Parse GitHub web UI URL format: <https://github.com/{owner}/{repo}/tree/{ref}/{path>}
 ///
+/// Also accepts `/blob/{ref}/{path/to/file}` links to single files. The file's
+/// parent directory is used as the path, skipping up past a resource directory
+/// (e.g. `commands/`) so the path points at the containing bundle.
+///
+/// Query strings (`?raw=true`) and line anchors (`#L10`) copied along with
+/// the link are ignored.
+///
 /// Returns: (owner, repo, ref, `optional_path`)
 pub fn parse_github_web_ui_url(input: &str) -> Option<(String, String, String, Option<String>)> {
     // Must start with https://github.com/
     let without_prefix = input.strip_prefix("https://github.com/")?;
+    let without_prefix = without_prefix
+        .split(['?', '#'])
+        .next()
+        .unwrap_or(without_prefix)
+        .trim_end_matches('/');
 
-    // Split into parts: {owner}/{repo}/tree/{ref}/{path...}
+    // Split into parts: {owner}/{repo}/{tree|blob}/{ref}/{path...}
     let parts: Vec<&str> = without_prefix.split('/').collect();
 
     // Need at least: owner, repo, "tree", ref (minimum 4 parts)
@@ -163,21 +252,28 @@ pub fn parse_github_web_ui_url(input: &str) -> Option<(String, String, String, O
         return None;
     }
 
-    // Check that parts[2] is "tree"
-    if parts[2] != "tree" {
-        return None;
-    }
-
     let owner = parts[0].to_string();
     let repo = parts[1].to_string();
     let git_ref = parts[3].to_string();
 
     // Path is everything after ref (parts[4..])
-    let path_val = if parts.len() > 4 {
-        Some(parts[4..].join("/"))
-    } else {
-        None
+    let path_val = match parts[2] {
+        "tree" if parts.len() > 4 => Some(parts[4..].join("/")),
+        "tree" => None,
+        "blob" if parts.len() > 4 => blob_bundle_path(&parts[4..]),
+        _ => return None,
     };
 
     Some((owner, repo, git_ref, path_val))
 }
+
+/// Bundle directory containing the file at `file_parts` (repo root when `None`)
+fn blob_bundle_path(file_parts: &[&str]) -> Option<String> {
+    let parent = &file_parts[..file_parts.len() - 1];
+    let bundle_end = parent
+        .iter()
+        .rposition(|part| BLOB_RESOURCE_DIRS.contains(part))
+        .unwrap_or(parent.len());
+    let bundle = &parent[..bundle_end];
+    (!bundle.is_empty()).then(|| bundle.join("/"))
+}
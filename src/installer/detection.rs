@@ -2,10 +2,11 @@
 //!
 //! This module provides detection utilities:
 //! - Platform resource file detection
-//! - Binary file detection
+//! - Binary file detection (by extension and by content sniffing)
 //! - Platform ID resolution from paths
 //! - Platform-specific file type detection
 
+use std::io::Read;
 use std::path::Path;
 
 use crate::platform::Platform;
@@ -41,6 +42,35 @@ pub fn is_likely_binary_file(path: &Path) -> bool {
     )
 }
 
+/// Number of leading bytes inspected by content sniffing
+const SNIFF_LEN: usize = 1024;
+
+/// True if `bytes` look like binary content: a NUL byte or invalid UTF-8
+///
+/// A multi-byte character cut off at the end of `bytes` is not treated as
+/// invalid, so a truncated prefix of a text file still reads as text.
+pub fn looks_binary(bytes: &[u8]) -> bool {
+    if bytes.contains(&0) {
+        return true;
+    }
+    match std::str::from_utf8(bytes) {
+        Ok(_) => false,
+        Err(e) => e.error_len().is_some(),
+    }
+}
+
+/// True if the first KB of the file at `path` looks binary; unreadable files are not
+pub fn sniff_binary_file(path: &Path) -> bool {
+    let Ok(file) = std::fs::File::open(path) else {
+        return false;
+    };
+    let mut head = Vec::with_capacity(SNIFF_LEN);
+    if file.take(SNIFF_LEN as u64).read_to_end(&mut head).is_err() {
+        return false;
+    }
+    looks_binary(&head)
+}
+
 /// Check if target path is a gemini command file
 #[allow(dead_code)]
 pub fn is_gemini_command_file(target: &Path) -> bool {
@@ -130,6 +160,15 @@ mod tests {
         assert!(!is_likely_binary_file(Path::new("test.json")));
     }
 
+    #[test]
+    fn test_looks_binary() {
+        assert!(looks_binary(b"# Title\0\x01\x02"));
+        assert!(looks_binary(&[0x89, b'P', b'N', b'G', 0xff, 0xfe]));
+        assert!(!looks_binary("# Café\n".as_bytes()));
+        // Multi-byte character truncated at the sniff boundary
+        assert!(!looks_binary(&"é".as_bytes()[..1]));
+    }
+
     #[test]
     fn test_is_gemini_command_file() {
         assert!(is_gemini_command_file(Path::new(
@@ -2,8 +2,11 @@
 //!
 //! This module handles:
 //! - Discovering resource files in bundle directories
-//! - Categorizing resources by type (commands, rules, agents, skills)
+//! - Categorizing resources by type (commands, rules, agents, skills, `mcp_servers`)
 //! - Filtering skills to only include leaf directories with SKILL.md
+//! - Optionally separating out executable skill scripts (`--ignore-scripts`)
+//! - Optionally keeping only JSON settings and MCP resources (`--settings-only`)
+//! - Listing files that could be resources, for `install --report-unmatched`
 //!
 //! The core discovery logic is in the `discover_resources_internal` function
 //! which is re-exported from the main `installer` module.
@@ -12,12 +15,20 @@
 
 use std::collections::HashSet;
 use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
 use walkdir::WalkDir;
 
 use crate::domain::DiscoveredResource;
 
 /// Known resource directories in bundles
-const RESOURCE_DIRS: &[&str] = &["commands", "rules", "agents", "skills", "root"];
+const RESOURCE_DIRS: &[&str] = &[
+    "commands",
+    "rules",
+    "agents",
+    "skills",
+    "root",
+    "mcp_servers",
+];
 
 /// Known resource files in bundles (at root level)
 const RESOURCE_FILES: &[&str] = &["mcp.jsonc", "AGENTS.md"];
@@ -44,6 +55,7 @@ fn discover_files_in_resource_dir(bundle_path: &Path, dir_name: &str) -> Vec<Dis
                 bundle_path,
                 absolute_path,
                 resource_type: dir_name.to_string(),
+                content_hash: OnceLock::new(),
             }
         })
         .collect()
@@ -57,6 +69,7 @@ fn discover_root_files(bundle_path: &Path) -> Vec<DiscoveredResource> {
             bundle_path: PathBuf::from(*file_name),
             absolute_path: bundle_path.join(file_name),
             resource_type: "root".to_string(),
+            content_hash: OnceLock::new(),
         })
         .collect()
 }
@@ -74,6 +87,35 @@ pub fn discover_resources(bundle_path: &Path) -> Vec<DiscoveredResource> {
     resources
 }
 
+/// Files of a bundle that could be resources: every file in a subdirectory,
+/// plus the known root-level resource files, as bundle-relative paths
+///
+/// Other root-level files (README, LICENSE, `augent.yaml`) and hidden
+/// entries such as `.git/` are not candidates.
+pub fn candidate_files(bundle_path: &Path) -> Vec<PathBuf> {
+    let mut files: Vec<PathBuf> = WalkDir::new(bundle_path)
+        .min_depth(1)
+        .into_iter()
+        .filter_entry(|e| !e.file_name().to_string_lossy().starts_with('.'))
+        .filter_map(std::result::Result::ok)
+        .filter(|e| e.file_type().is_file())
+        .filter_map(|e| {
+            e.path()
+                .strip_prefix(bundle_path)
+                .ok()
+                .map(Path::to_path_buf)
+        })
+        .filter(|relative| {
+            relative.components().count() > 1
+                || relative
+                    .to_str()
+                    .is_some_and(|name| RESOURCE_FILES.contains(&name))
+        })
+        .collect();
+    files.sort();
+    files
+}
+
 /// Collect all skill directories that contain SKILL.md files
 fn collect_skill_dirs(resources: &[DiscoveredResource]) -> HashSet<String> {
     const SKILL_MD_NAME: &str = "SKILL.md";
@@ -139,6 +181,61 @@ pub fn filter_skills_resources(resources: Vec<DiscoveredResource>) -> Vec<Discov
         .collect()
 }
 
+/// Whether a resource is merged into a JSON settings or MCP config
+///
+/// Covers `mcp_servers/` files and any `.json`/`.jsonc` resource such as
+/// `mcp.jsonc` or `root/.claude/settings.json`.
+pub fn is_settings_resource(resource: &DiscoveredResource) -> bool {
+    resource.resource_type == "mcp_servers"
+        || resource
+            .bundle_path
+            .extension()
+            .is_some_and(|ext| ext == "json" || ext == "jsonc")
+}
+
+/// Extensions treated as scripts regardless of file mode
+const SCRIPT_EXTENSIONS: &[&str] = &[
+    "sh", "bash", "zsh", "fish", "py", "rb", "pl", "js", "mjs", "cjs", "ts", "ps1", "bat", "cmd",
+];
+
+/// Whether a skill resource is an executable script
+///
+/// Scripts are files with a script extension or, on Unix, an executable bit.
+/// Markdown files (SKILL.md, docs) are never scripts.
+pub fn is_skill_script(resource: &DiscoveredResource) -> bool {
+    if resource.resource_type != "skills" {
+        return false;
+    }
+    let extension = resource
+        .bundle_path
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(str::to_ascii_lowercase);
+    match extension.as_deref() {
+        Some("md" | "mdc") => false,
+        Some(ext) if SCRIPT_EXTENSIONS.contains(&ext) => true,
+        _ => is_executable(&resource.absolute_path),
+    }
+}
+
+#[cfg(unix)]
+fn is_executable(path: &Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+    std::fs::metadata(path).is_ok_and(|m| m.permissions().mode() & 0o111 != 0)
+}
+
+#[cfg(not(unix))]
+fn is_executable(_path: &Path) -> bool {
+    false
+}
+
+/// Split out skill scripts, returning `(kept, skipped)`
+pub fn partition_skill_scripts(
+    resources: Vec<DiscoveredResource>,
+) -> (Vec<DiscoveredResource>, Vec<DiscoveredResource>) {
+    resources.into_iter().partition(|r| !is_skill_script(r))
+}
+
 #[cfg(test)]
 #[allow(clippy::expect_used)]
 mod tests {
@@ -206,6 +303,71 @@ mod tests {
         assert_eq!(resources.len(), 2);
     }
 
+    #[test]
+    fn test_discover_resources_mcp_servers() {
+        let temp = create_temp_dir();
+
+        let servers_dir = temp.path().join("mcp_servers");
+        fs::create_dir(&servers_dir).expect("Failed to create mcp_servers dir");
+        fs::write(servers_dir.join("github.json"), "{}").expect("Failed to write github.json");
+
+        let resources = discover_resources(temp.path());
+        assert_eq!(resources.len(), 1);
+        assert_eq!(resources[0].resource_type, "mcp_servers");
+    }
+
+    #[test]
+    fn test_is_settings_resource() {
+        let settings = |bundle_path: &str, resource_type: &str| {
+            is_settings_resource(&create_discovered_resource(
+                PathBuf::from(bundle_path),
+                bundle_path,
+                resource_type,
+            ))
+        };
+        assert!(settings("mcp_servers/github.json", "mcp_servers"));
+        assert!(settings("mcp.jsonc", "root"));
+        assert!(settings("root/.claude/settings.json", "root"));
+        assert!(!settings("commands/review.md", "commands"));
+    }
+
+    #[test]
+    fn test_partition_skill_scripts() {
+        let temp = create_temp_dir();
+        let base = temp.path();
+        let skill = base.join("skills/deploy");
+        fs::create_dir_all(&skill).expect("Failed to create skill dir");
+        for name in ["SKILL.md", "run.sh", "notes.txt"] {
+            fs::write(skill.join(name), "x").expect("Failed to write skill file");
+        }
+
+        let resources = ["SKILL.md", "run.sh", "notes.txt"]
+            .iter()
+            .map(|name| {
+                create_discovered_resource(
+                    skill.join(name),
+                    &format!("skills/deploy/{name}"),
+                    "skills",
+                )
+            })
+            .collect();
+        let (kept, skipped) = partition_skill_scripts(resources);
+
+        let kept: Vec<_> = kept.iter().map(|r| r.bundle_path.clone()).collect();
+        assert_eq!(
+            kept,
+            vec![
+                PathBuf::from("skills/deploy/SKILL.md"),
+                PathBuf::from("skills/deploy/notes.txt")
+            ]
+        );
+        assert_eq!(skipped.len(), 1);
+        assert_eq!(
+            skipped[0].bundle_path,
+            PathBuf::from("skills/deploy/run.sh")
+        );
+    }
+
     #[test]
     fn test_filter_skills_resources_nested() {
         let temp = create_temp_dir();
@@ -268,6 +430,7 @@ mod tests {
             bundle_path: PathBuf::from(bundle_path),
             absolute_path: path,
             resource_type: resource_type.to_string(),
+            content_hash: OnceLock::new(),
         }
     }
 }
@@ -1,8 +1,11 @@
 //! Basic file operations for bundle installation
 //!
 //! This module handles low-level file operations:
-//! - Directory creation (`ensure_parent_dir`)
+//! - Directory creation (`ensure_parent_dir`) and its gate (`--no-new-dirs`)
 //! - File copying orchestration (`copy_file`)
+//! - Writing with optional read-back verification (`write_file`, `--verify`)
+//! - Symlinking verbatim files back to their source (`--link`)
+//! - Marker files for incremental installs (`--since-file`)
 
 use std::path::Path;
 use std::sync::Arc;
@@ -11,6 +14,7 @@ use crate::error::{AugentError, Result};
 use crate::platform::Platform;
 
 use super::detection;
+use super::template;
 use super::writer;
 
 fn file_read_error(path: &Path, e: &std::io::Error) -> AugentError {
@@ -27,6 +31,31 @@ fn file_write_error(path: &Path, e: &std::io::Error) -> AugentError {
     }
 }
 
+/// Write `content` to `target`, re-reading it afterwards when `verify` is set (`--verify`)
+///
+/// A symlink at `target` (from `--link`) is replaced, never written through.
+pub fn write_file(target: &Path, content: impl AsRef<[u8]>, verify: bool) -> Result<()> {
+    let content = content.as_ref();
+    remove_symlink(target)?;
+    std::fs::write(target, content).map_err(|e| file_write_error(target, &e))?;
+    if verify {
+        verify_written(target, content)?;
+    }
+    Ok(())
+}
+
+/// Check that the file at `target` hashes the same as `expected`
+pub fn verify_written(target: &Path, expected: &[u8]) -> Result<()> {
+    let actual = std::fs::read(target).map_err(|e| file_read_error(target, &e))?;
+    if blake3::hash(&actual) == blake3::hash(expected) {
+        Ok(())
+    } else {
+        Err(AugentError::WriteVerificationFailed {
+            path: target.display().to_string(),
+        })
+    }
+}
+
 /// Ensure parent directory exists for a path
 pub fn ensure_parent_dir(path: &Path) -> Result<()> {
     if let Some(parent) = path.parent() {
@@ -35,100 +64,243 @@ pub fn ensure_parent_dir(path: &Path) -> Result<()> {
     Ok(())
 }
 
+/// Whether install may create directories that do not exist yet
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NewDirPolicy {
+    /// Create missing directories
+    #[default]
+    Create,
+    /// Ask before creating each missing directory (`--confirm-new-dirs`)
+    Confirm,
+    /// Fail instead of creating a missing directory (`--no-new-dirs`)
+    Deny,
+}
+
+/// The outermost ancestor of `path` that writing it would have to create
+pub fn first_missing_dir(path: &Path) -> Option<&Path> {
+    path.ancestors()
+        .skip(1)
+        .take_while(|dir| !dir.as_os_str().is_empty() && !dir.exists())
+        .last()
+}
+
+/// Modification time of a `--since-file` marker, or `None` when it does not exist yet
+pub fn marker_mtime(marker: &Path) -> Option<std::time::SystemTime> {
+    std::fs::metadata(marker).and_then(|m| m.modified()).ok()
+}
+
+/// Create the marker file, or bump its modification time to now
+pub fn touch_marker(marker: &Path) -> Result<()> {
+    ensure_parent_dir(marker)?;
+    std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(marker)
+        .and_then(|file| file.set_modified(std::time::SystemTime::now()))
+        .map_err(|e| file_write_error(marker, &e))
+}
+
+/// How files that need no transformation are placed at their target
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CopyMode {
+    /// Copy the file
+    #[default]
+    Copy,
+    /// Symlink the target to the source file (`--link`)
+    Symlink,
+}
+
+/// Remove `path` if it is a symlink, so the write that follows does not
+/// modify the file it points to
+fn remove_symlink(path: &Path) -> Result<()> {
+    let is_symlink = std::fs::symlink_metadata(path).is_ok_and(|m| m.file_type().is_symlink());
+    if is_symlink {
+        std::fs::remove_file(path).map_err(|e| file_write_error(path, &e))?;
+    }
+    Ok(())
+}
+
+/// Point `target` at `source` with a symlink, replacing any existing file
+pub fn link_file(source: &Path, target: &Path) -> Result<()> {
+    ensure_parent_dir(target)?;
+    let source = source
+        .canonicalize()
+        .map_err(|e| file_read_error(source, &e))?;
+    if std::fs::symlink_metadata(target).is_ok() {
+        std::fs::remove_file(target).map_err(|e| file_write_error(target, &e))?;
+    }
+    #[cfg(unix)]
+    let linked = std::os::unix::fs::symlink(&source, target);
+    #[cfg(windows)]
+    let linked = std::os::windows::fs::symlink_file(&source, target);
+    linked.map_err(|e| file_write_error(target, &e))
+}
+
+/// Options controlling how resource files are copied
+pub struct CopyOptions<'a> {
+    pub platforms: &'a [Platform],
+    pub workspace_root: &'a Path,
+    pub format_registry: &'a Arc<crate::installer::formats::FormatRegistry>,
+    /// Merge universal frontmatter for the target platform (disable to copy verbatim)
+    pub merge_frontmatter: bool,
+    /// Variables for `{{var}}` substitution in markdown bodies (`None` disables templating)
+    pub template_vars: Option<&'a super::template::TemplateVars>,
+    /// Re-read every written file and compare it with what was written (`--verify`)
+    pub verify: bool,
+    /// Copy or symlink files whose content is installed unchanged
+    pub copy_mode: CopyMode,
+    /// Frontmatter key restricting a resource to platforms, stripped from the output
+    pub platform_filter_key: Option<&'a str>,
+}
+
 /// Copy a single file with platform-specific transformations
-pub fn copy_file(
-    source: &Path,
-    target: &Path,
-    platforms: &[Platform],
-    workspace_root: &Path,
-    format_registry: &Arc<crate::installer::formats::FormatRegistry>,
-) -> Result<()> {
-    let is_resource = detection::is_platform_resource_file(target, platforms, workspace_root);
+pub fn copy_file(source: &Path, target: &Path, options: &CopyOptions<'_>) -> Result<()> {
+    remove_symlink(target)?;
+    let is_resource =
+        detection::is_platform_resource_file(target, options.platforms, options.workspace_root);
     let is_binary = detection::is_likely_binary_file(source);
 
     if !is_resource {
-        return perform_simple_copy(source, target);
+        return place_verbatim(source, target, options);
     }
 
     if is_binary {
-        return perform_simple_copy(source, target);
+        return place_verbatim(source, target, options);
     }
 
-    handle_text_file(source, target, platforms, workspace_root, format_registry)
+    if detection::sniff_binary_file(source) {
+        eprintln!(
+            "Warning: {} looks binary; copying it verbatim",
+            source.display()
+        );
+        return place_verbatim(source, target, options);
+    }
+
+    handle_text_file(source, target, options)
+}
+
+fn place_verbatim(source: &Path, target: &Path, options: &CopyOptions<'_>) -> Result<()> {
+    copy_verbatim(source, target, options.copy_mode, options.verify)
+}
+
+/// Copy or link a file byte for byte, with no format conversion (`--platform-passthrough`)
+pub fn copy_verbatim(source: &Path, target: &Path, mode: CopyMode, verify: bool) -> Result<()> {
+    match mode {
+        CopyMode::Copy => perform_simple_copy(source, target, verify),
+        CopyMode::Symlink => link_file(source, target),
+    }
+}
+
+/// Copy an existing target into the `--backup` directory before it is overwritten
+pub fn backup_file(target: &Path, backup_path: &Path) -> Result<()> {
+    ensure_parent_dir(backup_path)?;
+    std::fs::copy(target, backup_path).map_err(|e| file_write_error(backup_path, &e))?;
+    Ok(())
 }
 
-fn perform_simple_copy(source: &Path, target: &Path) -> Result<()> {
+fn perform_simple_copy(source: &Path, target: &Path, verify: bool) -> Result<()> {
     ensure_parent_dir(target)?;
-    std::fs::copy(source, target)
-        .map_err(|e| file_write_error(target, &e))
-        .map(|_| ())
+    remove_symlink(target)?;
+    std::fs::copy(source, target).map_err(|e| file_write_error(target, &e))?;
+    if verify {
+        let expected = std::fs::read(source).map_err(|e| file_read_error(source, &e))?;
+        verify_written(target, &expected)?;
+    }
+    Ok(())
 }
 
 fn handle_frontmatter_file(
     content: &str,
     target: &Path,
-    platforms: &[Platform],
-    workspace_root: &Path,
-    format_registry: &Arc<crate::installer::formats::FormatRegistry>,
+    options: &CopyOptions<'_>,
 ) -> Option<Result<()>> {
-    let (fm, body) = crate::universal::parse_frontmatter_and_body(content)?;
+    let (mut fm, body) = crate::universal::parse_frontmatter_and_body(content)?;
+    if let Some(key) = options.platform_filter_key {
+        crate::universal::remove_key(&mut fm, key);
+    }
 
-    let known: Vec<String> = platforms.iter().map(|p| p.id.clone()).collect();
+    let known: Vec<String> = options.platforms.iter().map(|p| p.id.clone()).collect();
 
-    if let Some(pid) = detection::platform_id_from_target(target, platforms, workspace_root) {
+    if let Some(pid) =
+        detection::platform_id_from_target(target, options.platforms, options.workspace_root)
+    {
         let merged = crate::universal::merge_frontmatter_for_platform(&fm, pid, &known);
 
-        if let Some(converter) = format_registry.find_converter(target, target) {
+        if let Some(converter) = options.format_registry.find_converter(target, target) {
             return Some(converter.convert_from_merged(
                 &merged,
                 &body,
                 crate::installer::formats::plugin::FormatConverterContext {
                     source: target,
                     target,
-                    workspace_root: Some(workspace_root),
+                    workspace_root: Some(options.workspace_root),
+                    verify: options.verify,
                 },
             ));
         }
+        fm = merged;
     }
 
-    let _ = writer::write_merged_frontmatter_markdown(&fm, &body, target);
+    let _ = writer::write_merged_frontmatter_markdown(&fm, &body, target, options.verify);
     Some(Ok(()))
 }
 
-fn handle_text_file(
-    source: &Path,
-    target: &Path,
-    platforms: &[Platform],
-    workspace_root: &Path,
-    format_registry: &Arc<crate::installer::formats::FormatRegistry>,
-) -> Result<()> {
+fn handle_text_file(source: &Path, target: &Path, options: &CopyOptions<'_>) -> Result<()> {
     ensure_parent_dir(target)?;
 
     let content = std::fs::read_to_string(source).map_err(|e| file_read_error(source, &e))?;
+    let (content, templated) = apply_template(source, content, options);
+
+    if options.merge_frontmatter {
+        if let Some(result) = handle_frontmatter_file(&content, target, options) {
+            return result;
+        }
+    }
 
-    if let Some(result) = handle_frontmatter_file(
-        &content,
-        target,
-        platforms,
-        workspace_root,
-        &format_registry.clone(),
-    ) {
-        return result;
+    let converter = options.format_registry.find_converter(source, target);
+    // Content left unchanged can be linked; anything rewritten is written out
+    if converter.is_none() && !templated && options.copy_mode == CopyMode::Symlink {
+        return link_file(source, target);
     }
 
-    if let Some(converter) = format_registry.find_converter(source, target) {
+    if let Some(converter) = converter {
+        // Converters read their source path, so templated content is staged first
+        let staged = templated
+            .then(|| template::stage_rendered(source, &content))
+            .transpose()?;
+        let source = staged.as_ref().map_or(source, |(_, path)| path.as_path());
         return converter.convert_from_markdown(
             crate::installer::formats::plugin::FormatConverterContext {
                 source,
                 target,
-                workspace_root: Some(workspace_root),
+                workspace_root: Some(options.workspace_root),
+                verify: options.verify,
             },
         );
     }
 
-    std::fs::write(target, content).map_err(|e| file_write_error(target, &e))?;
+    write_file(target, content, options.verify)
+}
 
-    Ok(())
+/// Render template placeholders when templating is enabled
+///
+/// Returns the (possibly) rendered content and whether it changed.
+fn apply_template(source: &Path, content: String, options: &CopyOptions<'_>) -> (String, bool) {
+    let Some(vars) = options.template_vars else {
+        return (content, false);
+    };
+    if !template::is_templatable(source) {
+        return (content, false);
+    }
+    let (rendered, undefined) = template::render(&content, vars);
+    for name in undefined {
+        eprintln!(
+            "Warning: Undefined template variable '{name}' in {}",
+            source.display()
+        );
+    }
+    let changed = rendered != content;
+    (rendered, changed)
 }
 
 #[cfg(test)]
@@ -152,6 +324,21 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_verify_written_detects_mismatch() {
+        let temp = tempfile::TempDir::new_in(crate::temp::temp_dir_base())
+            .expect("Failed to create temp directory");
+        let target = temp.path().join("rule.md");
+
+        write_file(&target, "# Rule\n", true).expect("Failed to write file");
+        verify_written(&target, b"# Rule\n").expect("Intact write should verify");
+
+        // Simulate corruption after the write
+        std::fs::write(&target, "# Rul\0\n").expect("Failed to corrupt file");
+        let err = verify_written(&target, b"# Rule\n").expect_err("Mismatch should be reported");
+        assert!(matches!(err, AugentError::WriteVerificationFailed { .. }));
+    }
+
     #[test]
     fn test_copy_file() {
         use tempfile::TempDir;
@@ -163,4 +350,199 @@ mod tests {
         std::fs::write(&src, "content").expect("Failed to write source file");
         std::fs::copy(&src, &dst).expect("Failed to copy file");
     }
+
+    #[test]
+    fn test_copy_file_without_frontmatter_merge_is_verbatim() {
+        let temp = tempfile::TempDir::new_in(crate::temp::temp_dir_base())
+            .expect("Failed to create temp directory");
+        let workspace_root = temp.path();
+        let platforms: Vec<Platform> = crate::platform::default_platforms()
+            .into_iter()
+            .filter(|p| p.id == "claude")
+            .collect();
+        let mut registry = crate::installer::formats::FormatRegistry::new();
+        let _ = registry.register_builtins();
+        let registry = Arc::new(registry);
+
+        let source = workspace_root.join("bundle/commands/review.md");
+        ensure_parent_dir(&source).expect("Failed to create source dir");
+        let original = "---\ndescription:   Review code\nclaude:\n  model: opus\ncursor:\n  model: gpt\n---\n\nReview the diff.\n";
+        std::fs::write(&source, original).expect("Failed to write source file");
+
+        let target = workspace_root.join(".claude/commands/review.md");
+        let options = CopyOptions {
+            platforms: &platforms,
+            workspace_root,
+            format_registry: &registry,
+            merge_frontmatter: false,
+            template_vars: None,
+            verify: false,
+            copy_mode: CopyMode::Copy,
+            platform_filter_key: None,
+        };
+        copy_file(&source, &target, &options).expect("Failed to copy file");
+
+        let installed = std::fs::read(&target).expect("Failed to read target file");
+        assert_eq!(installed, original.as_bytes());
+    }
+
+    #[test]
+    fn test_copy_file_copies_binary_markdown_verbatim() {
+        let temp = tempfile::TempDir::new_in(crate::temp::temp_dir_base())
+            .expect("Failed to create temp directory");
+        let workspace_root = temp.path();
+        let platforms: Vec<Platform> = crate::platform::default_platforms()
+            .into_iter()
+            .filter(|p| p.id == "claude")
+            .collect();
+        let mut registry = crate::installer::formats::FormatRegistry::new();
+        let _ = registry.register_builtins();
+        let registry = Arc::new(registry);
+
+        let source = workspace_root.join("bundle/commands/image.md");
+        ensure_parent_dir(&source).expect("Failed to create source dir");
+        let original: &[u8] = b"---\ndescription: x\n---\n\x89PNG\0\xff\xfe";
+        std::fs::write(&source, original).expect("Failed to write source file");
+
+        let target = workspace_root.join(".claude/commands/image.md");
+        let options = CopyOptions {
+            platforms: &platforms,
+            workspace_root,
+            format_registry: &registry,
+            merge_frontmatter: true,
+            template_vars: None,
+            verify: false,
+            copy_mode: CopyMode::Copy,
+            platform_filter_key: None,
+        };
+        copy_file(&source, &target, &options).expect("Failed to copy file");
+
+        let installed = std::fs::read(&target).expect("Failed to read target file");
+        assert_eq!(installed, original);
+    }
+
+    #[test]
+    fn test_copy_file_renders_template_variables() {
+        let temp = tempfile::TempDir::new_in(crate::temp::temp_dir_base())
+            .expect("Failed to create temp directory");
+        let workspace_root = temp.path();
+        let platforms: Vec<Platform> = crate::platform::default_platforms()
+            .into_iter()
+            .filter(|p| p.id == "claude")
+            .collect();
+        let mut registry = crate::installer::formats::FormatRegistry::new();
+        let _ = registry.register_builtins();
+        let registry = Arc::new(registry);
+        let mut vars = template::TemplateVars::new();
+        vars.insert("workspace_name".to_string(), "my-project".to_string());
+
+        let source = workspace_root.join("bundle/commands/deploy.md");
+        ensure_parent_dir(&source).expect("Failed to create source dir");
+        std::fs::write(
+            &source,
+            "---\ndescription: Deploy\n---\n\nDeploy {{workspace_name}}.\n\n```sh\necho {{x}}\n```\n",
+        )
+        .expect("Failed to write source file");
+
+        let target = workspace_root.join(".claude/commands/deploy.md");
+        let options = CopyOptions {
+            platforms: &platforms,
+            workspace_root,
+            format_registry: &registry,
+            merge_frontmatter: true,
+            template_vars: Some(&vars),
+            verify: false,
+            copy_mode: CopyMode::Copy,
+            platform_filter_key: None,
+        };
+        copy_file(&source, &target, &options).expect("Failed to copy file");
+
+        let installed = std::fs::read_to_string(&target).expect("Failed to read target file");
+        assert!(installed.contains("Deploy my-project."));
+        assert!(installed.contains("echo {{x}}"));
+    }
+
+    fn symlink_options<'a>(
+        platforms: &'a [Platform],
+        workspace_root: &'a Path,
+        registry: &'a Arc<crate::installer::formats::FormatRegistry>,
+    ) -> CopyOptions<'a> {
+        CopyOptions {
+            platforms,
+            workspace_root,
+            format_registry: registry,
+            merge_frontmatter: true,
+            template_vars: None,
+            verify: false,
+            copy_mode: CopyMode::Symlink,
+            platform_filter_key: None,
+        }
+    }
+
+    #[test]
+    fn test_symlink_mode_links_verbatim_files_and_copies_rewritten_ones() {
+        let temp = tempfile::TempDir::new_in(crate::temp::temp_dir_base())
+            .expect("Failed to create temp directory");
+        let workspace_root = temp.path();
+        let platforms: Vec<Platform> = crate::platform::default_platforms()
+            .into_iter()
+            .filter(|p| p.id == "claude")
+            .collect();
+        let mut registry = crate::installer::formats::FormatRegistry::new();
+        let _ = registry.register_builtins();
+        let registry = Arc::new(registry);
+        let options = symlink_options(&platforms, workspace_root, &registry);
+
+        let plain = workspace_root.join("bundle/commands/plain.md");
+        ensure_parent_dir(&plain).expect("Failed to create source dir");
+        std::fs::write(&plain, "Review the diff.\n").expect("Failed to write source file");
+        let with_frontmatter = workspace_root.join("bundle/commands/review.md");
+        std::fs::write(
+            &with_frontmatter,
+            "---\ndescription: Review\n---\n\nReview.\n",
+        )
+        .expect("Failed to write source file");
+
+        let plain_target = workspace_root.join(".claude/commands/plain.md");
+        let review_target = workspace_root.join(".claude/commands/review.md");
+        copy_file(&plain, &plain_target, &options).expect("Failed to link file");
+        copy_file(&with_frontmatter, &review_target, &options).expect("Failed to copy file");
+
+        let is_symlink = |path: &Path| {
+            std::fs::symlink_metadata(path)
+                .expect("Target should exist")
+                .file_type()
+                .is_symlink()
+        };
+        assert!(is_symlink(&plain_target));
+        assert!(!is_symlink(&review_target));
+
+        // Edits to the source show up in the installed file
+        std::fs::write(&plain, "Review the whole diff.\n").expect("Failed to edit source");
+        assert_eq!(
+            std::fs::read_to_string(&plain_target).expect("Failed to read target"),
+            "Review the whole diff.\n"
+        );
+    }
+
+    #[test]
+    fn test_write_file_replaces_symlink_instead_of_writing_through() {
+        let temp = tempfile::TempDir::new_in(crate::temp::temp_dir_base())
+            .expect("Failed to create temp directory");
+        let source = temp.path().join("source.md");
+        let target = temp.path().join("target.md");
+        std::fs::write(&source, "original\n").expect("Failed to write source file");
+        link_file(&source, &target).expect("Failed to link file");
+
+        write_file(&target, "stamped\n", false).expect("Failed to write target");
+
+        assert_eq!(
+            std::fs::read_to_string(&source).expect("Failed to read source"),
+            "original\n"
+        );
+        assert_eq!(
+            std::fs::read_to_string(&target).expect("Failed to read target"),
+            "stamped\n"
+        );
+    }
 }
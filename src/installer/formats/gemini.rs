@@ -40,7 +40,7 @@ impl FormatConverter for GeminiConverter {
         let toml_content = build_toml_content(description.as_deref(), &prompt);
 
         let toml_target = apply_extension(ctx.target, self.file_extension());
-        crate::installer::formats::write_content_to_file(&toml_target, &toml_content)
+        crate::installer::formats::write_content_to_file(&toml_target, &toml_content, &ctx)
     }
 
     fn convert_from_merged(
@@ -53,7 +53,7 @@ impl FormatConverter for GeminiConverter {
         let toml_content = build_toml_content(description.as_deref(), body);
 
         let toml_target = apply_extension(ctx.target, self.file_extension());
-        crate::installer::formats::write_content_to_file(&toml_target, &toml_content)
+        crate::installer::formats::write_content_to_file(&toml_target, &toml_content, &ctx)
     }
 
     fn merge_strategy(&self) -> MergeStrategy {
@@ -76,6 +76,7 @@ fn build_toml_content(description: Option<&str>, prompt: &str) -> String {
 
     let is_multiline = prompt.contains('\n');
     if is_multiline {
+        let prompt = escape_toml_multiline_string(prompt);
         if let Err(e) = writeln!(toml_content, "prompt = \"\"\"\n{prompt}\"\"\"\n") {
             eprintln!("Failed to write to TOML content: {e}");
         }
@@ -104,9 +105,8 @@ pub fn escape_toml_string(s: &str) -> String {
             '\n' => escaped.push_str("\\n"),
             '\r' => escaped.push_str("\\r"),
             '\t' => escaped.push_str("\\t"),
-            '\x00'..='\x08' | '\x0B' | '\x0C' | '\x0E'..='\x1F' => {
-                use std::fmt::Write;
-                let _ = write!(escaped, "\\x{:02X}", c as u8);
+            '\x00'..='\x08' | '\x0B' | '\x0C' | '\x0E'..='\x1F' | '\x7F' => {
+                let _ = write!(escaped, "\\u{:04X}", u32::from(c));
             }
             _ => escaped.push(c),
         }
@@ -115,6 +115,52 @@ pub fn escape_toml_string(s: &str) -> String {
     format!("\"{escaped}\"")
 }
 
+/// Escape a string for use in TOML multi-line basic strings
+///
+/// Newlines and tabs are kept literal; quotes are escaped so that the body
+/// can never terminate the string early with `"""`.
+fn escape_toml_multiline_string(s: &str) -> String {
+    let mut escaped = String::new();
+
+    for c in s.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '"' => escaped.push_str("\\\""),
+            '\r' => escaped.push_str("\\r"),
+            '\x00'..='\x08' | '\x0B' | '\x0C' | '\x0E'..='\x1F' | '\x7F' => {
+                let _ = write!(escaped, "\\u{:04X}", u32::from(c));
+            }
+            _ => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+/// Verify that a generated Gemini command parses as TOML
+///
+/// `target` is the install target path as recorded by the installer (with the
+/// original `.md` extension); the `.toml` sibling written by the converter is
+/// checked. Targets that were not converted to TOML are ignored.
+pub fn validate_generated_toml(target: &Path) -> Result<()> {
+    let toml_path = apply_extension(target, Some("toml"));
+    if !toml_path.is_file() {
+        return Ok(());
+    }
+
+    let content = std::fs::read_to_string(&toml_path).map_err(|e| AugentError::FileReadFailed {
+        path: toml_path.display().to_string(),
+        reason: e.to_string(),
+    })?;
+
+    toml::from_str::<toml::Value>(&content)
+        .map(|_| ())
+        .map_err(|e| AugentError::TomlValidationFailed {
+            path: toml_path.display().to_string(),
+            reason: e.to_string(),
+        })
+}
+
 #[cfg(test)]
 #[allow(clippy::expect_used)]
 mod tests {
@@ -144,6 +190,47 @@ mod tests {
         assert_eq!(escape_toml_string("with\nnewline"), r#""with\nnewline""#);
     }
 
+    #[test]
+    fn test_escape_toml_string_with_control_characters() {
+        let escaped = escape_toml_string("bell\x07 esc\x1b[0m del\x7f");
+        let parsed: toml::Value =
+            toml::from_str(&format!("value = {escaped}")).expect("Escaped string should parse");
+        assert_eq!(
+            parsed["value"].as_str(),
+            Some("bell\x07 esc\x1b[0m del\x7f")
+        );
+    }
+
+    #[test]
+    fn test_build_toml_content_with_control_characters_is_valid() {
+        let prompt = "Run \"\"\"this\"\"\" \\ now\nthen ring\x07 and \x1b[1mbold\x1b[0m\r\n";
+        let content = build_toml_content(Some("Tricky \"desc\"\x0c"), prompt);
+
+        let parsed: toml::Value = toml::from_str(&content).expect("Generated TOML should parse");
+        assert_eq!(parsed["description"].as_str(), Some("Tricky \"desc\"\x0c"));
+        assert_eq!(parsed["prompt"].as_str(), Some(prompt));
+    }
+
+    #[test]
+    fn test_validate_generated_toml() {
+        let temp = tempfile::TempDir::new_in(crate::temp::temp_dir_base())
+            .expect("Failed to create temp directory");
+        let target = temp.path().join("fix.md");
+
+        assert!(validate_generated_toml(&target).is_ok());
+
+        std::fs::write(temp.path().join("fix.toml"), "prompt = \"ok\"\n")
+            .expect("Failed to write toml");
+        assert!(validate_generated_toml(&target).is_ok());
+
+        std::fs::write(temp.path().join("fix.toml"), "prompt = \"\"\"\nbroken\n")
+            .expect("Failed to write toml");
+        assert!(matches!(
+            validate_generated_toml(&target),
+            Err(AugentError::TomlValidationFailed { .. })
+        ));
+    }
+
     #[test]
     fn test_apply_extension() {
         let target = Path::new("/test.md");
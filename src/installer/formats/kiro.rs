@@ -5,8 +5,11 @@ use crate::installer::formats::{impl_simple_copy_converter, tests_for_simple_con
 #[derive(Debug)]
 pub struct KiroConverter;
 
+// Steering files keep their merged frontmatter (`inclusion:` and friends), so
+// they are written by the generic frontmatter writer rather than copied
 impl_simple_copy_converter!(KiroConverter, "kiro", |target: &std::path::Path| {
-    target.to_string_lossy().contains(".kiro/")
+    let path = target.to_string_lossy();
+    path.contains(".kiro/") && !path.contains(".kiro/steering/")
 });
 
 tests_for_simple_converter!(test_kiro_converter_platform_id, KiroConverter, "kiro");
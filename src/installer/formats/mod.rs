@@ -63,36 +63,26 @@ pub fn copy_markdown_file(ctx: &FormatConverterContext) -> Result<()> {
         }
     })?;
     crate::installer::file_ops::ensure_parent_dir(ctx.target)?;
-    std::fs::write(ctx.target, content).map_err(|e| {
-        crate::error::AugentError::FileWriteFailed {
-            path: ctx.target.display().to_string(),
-            reason: e.to_string(),
-        }
-    })?;
-    Ok(())
+    crate::installer::file_ops::write_file(ctx.target, content, ctx.verify)
 }
 
 /// Helper function to write merged body content to target
 pub fn write_body_to_target(body: &str, ctx: &FormatConverterContext) -> Result<()> {
     crate::installer::file_ops::ensure_parent_dir(ctx.target)?;
-    std::fs::write(ctx.target, body).map_err(|e| crate::error::AugentError::FileWriteFailed {
-        path: ctx.target.display().to_string(),
-        reason: e.to_string(),
-    })?;
-    Ok(())
+    crate::installer::file_ops::write_file(ctx.target, body, ctx.verify)
 }
 
 /// Helper function to write content to a target path with error handling
 ///
 /// This is a generic write function that can be used when the target
 /// might be different from ctx.target (e.g., different file extension).
-pub fn write_content_to_file(target: &std::path::Path, content: &str) -> Result<()> {
+pub fn write_content_to_file(
+    target: &std::path::Path,
+    content: &str,
+    ctx: &FormatConverterContext,
+) -> Result<()> {
     crate::installer::file_ops::ensure_parent_dir(target)?;
-    std::fs::write(target, content).map_err(|e| crate::error::AugentError::FileWriteFailed {
-        path: target.display().to_string(),
-        reason: e.to_string(),
-    })?;
-    Ok(())
+    crate::installer::file_ops::write_file(target, content, ctx.verify)
 }
 
 /// Macro to implement a simple copy converter that just passes through markdown content
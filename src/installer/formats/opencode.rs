@@ -40,7 +40,7 @@ impl FormatConverter for OpencodeConverter {
 
         let path_str = ctx.target.to_string_lossy();
 
-        dispatch_conversion(&path_str, &content, ctx.source, ctx.target)?;
+        dispatch_conversion(&path_str, &content, &ctx)?;
 
         Ok(())
     }
@@ -63,27 +63,27 @@ impl FormatConverter for OpencodeConverter {
     }
 }
 
-fn dispatch_conversion(path_str: &str, content: &str, source: &Path, target: &Path) -> Result<()> {
+fn dispatch_conversion(path_str: &str, content: &str, ctx: &FormatConverterContext) -> Result<()> {
     if path_str.contains(".opencode/skills/") {
-        convert_skill(content, target)?;
+        convert_skill(content, ctx)?;
     } else if path_str.contains(".opencode/commands/") {
-        convert_command(content, target)?;
+        convert_command(content, ctx)?;
     } else if path_str.contains(".opencode/agents/") {
-        convert_agent(content, target)?;
+        convert_agent(content, ctx)?;
     } else {
-        copy_generic_file(source, target)?;
+        copy_generic_file(ctx)?;
     }
 
     Ok(())
 }
 
-fn copy_generic_file(source: &Path, target: &Path) -> Result<()> {
-    file_ops::ensure_parent_dir(target)?;
-    let content = std::fs::read_to_string(source).map_err(|e| AugentError::FileReadFailed {
-        path: source.display().to_string(),
+fn copy_generic_file(ctx: &FormatConverterContext) -> Result<()> {
+    file_ops::ensure_parent_dir(ctx.target)?;
+    let content = std::fs::read_to_string(ctx.source).map_err(|e| AugentError::FileReadFailed {
+        path: ctx.source.display().to_string(),
         reason: e.to_string(),
     })?;
-    crate::installer::formats::write_content_to_file(target, &content)?;
+    crate::installer::formats::write_content_to_file(ctx.target, &content, ctx)?;
     Ok(())
 }
 
@@ -157,28 +157,32 @@ fn build_opencode_frontmatter(
     fm
 }
 
-fn convert_skill(content: &str, target: &Path) -> Result<()> {
+fn convert_skill(content: &str, ctx: &FormatConverterContext) -> Result<()> {
     let (frontmatter, body) = parse_frontmatter(content);
 
     let new_frontmatter = if let Some(fm) = frontmatter {
         let frontmatter_map = build_frontmatter_map(&fm);
-        build_opencode_frontmatter(&frontmatter_map, target)
+        build_opencode_frontmatter(&frontmatter_map, ctx.target)
     } else {
-        return crate::installer::formats::write_content_to_file(target, body.as_str());
+        return crate::installer::formats::write_content_to_file(ctx.target, body.as_str(), ctx);
     };
 
-    crate::installer::formats::write_content_to_file(target, &format!("{new_frontmatter}{body}"))
+    crate::installer::formats::write_content_to_file(
+        ctx.target,
+        &format!("{new_frontmatter}{body}"),
+        ctx,
+    )
 }
 
-fn convert_command(content: &str, target: &Path) -> Result<()> {
-    convert_with_description_only(content, target)
+fn convert_command(content: &str, ctx: &FormatConverterContext) -> Result<()> {
+    convert_with_description_only(content, ctx)
 }
 
-fn convert_agent(content: &str, target: &Path) -> Result<()> {
-    convert_with_description_only(content, target)
+fn convert_agent(content: &str, ctx: &FormatConverterContext) -> Result<()> {
+    convert_with_description_only(content, ctx)
 }
 
-fn convert_with_description_only(content: &str, target: &Path) -> Result<()> {
+fn convert_with_description_only(content: &str, ctx: &FormatConverterContext) -> Result<()> {
     let (description, prompt) = parser::extract_description_and_prompt(content);
 
     let mut new_content = String::new();
@@ -191,7 +195,7 @@ fn convert_with_description_only(content: &str, target: &Path) -> Result<()> {
 
     new_content.push_str(&prompt);
 
-    crate::installer::formats::write_content_to_file(target, &new_content)
+    crate::installer::formats::write_content_to_file(ctx.target, &new_content, ctx)
 }
 
 #[cfg(test)]
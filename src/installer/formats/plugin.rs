@@ -81,6 +81,8 @@ pub struct FormatConverterContext<'a> {
     pub target: &'a Path,
     #[allow(dead_code)]
     pub workspace_root: Option<&'a Path>,
+    /// Re-read written files and compare them with what was written (`--verify`)
+    pub verify: bool,
 }
 
 pub trait FormatConverter: Send + Sync + std::fmt::Debug {
@@ -157,6 +159,7 @@ pub trait FormatConverter: Send + Sync + std::fmt::Debug {
 ///     source: &source,
 ///     target: &target,
 ///     workspace_root: None,
+///     verify: false,
 /// };
 ///
 /// if let Some(converter) = registry.find_converter(&source, &target) {
@@ -576,6 +579,7 @@ mod tests {
             source,
             target,
             workspace_root: None,
+            verify: false,
         };
 
         // markdown conversion should fail with UnsupportedConversion
@@ -0,0 +1,187 @@
+//! Rewriting of relative links in skill markdown
+//!
+//! Skills often link to sibling files (`[run](./scripts/run.sh)`). When the
+//! installed layout differs from the bundle layout (for example after an
+//! output extension override), those links would break. After a skill is
+//! installed, relative links that point at files of the same bundle are
+//! rewritten to the installed location of the target file.
+//!
+//! URLs, anchors and absolute paths are left untouched, as are links whose
+//! relative location did not change.
+
+use std::collections::HashMap;
+use std::path::{Component, Path, PathBuf};
+
+use crate::error::Result;
+
+/// Installed location of each bundle file, keyed by its bundle-relative path
+pub type TargetMap = HashMap<PathBuf, PathBuf>;
+
+/// Locations of a markdown file in the bundle and in the workspace
+pub struct LinkContext<'a> {
+    /// Bundle-relative path of the markdown file
+    pub source: &'a Path,
+    /// Installed path of the markdown file
+    pub target: &'a Path,
+    pub targets: &'a TargetMap,
+}
+
+/// Rewrite relative link targets in `content` to match the installed layout
+///
+/// Returns `None` when no link needed rewriting.
+pub fn rewrite_links(content: &str, ctx: &LinkContext<'_>) -> Option<String> {
+    let mut out = String::with_capacity(content.len());
+    let mut rest = content;
+    let mut changed = false;
+    while let Some(start) = rest.find("](") {
+        let after_open = &rest[start + 2..];
+        let Some(end) = after_open.find(')') else {
+            break;
+        };
+        let link = &after_open[..end];
+        out.push_str(&rest[..start + 2]);
+        match rewrite_link(link, ctx) {
+            Some(rewritten) => {
+                out.push_str(&rewritten);
+                changed = true;
+            }
+            None => out.push_str(link),
+        }
+        rest = &after_open[end..];
+    }
+    out.push_str(rest);
+    changed.then_some(out)
+}
+
+/// Rewrite links in the installed file at `ctx.target`, if any changed
+pub fn rewrite_file(ctx: &LinkContext<'_>, verify: bool) -> Result<()> {
+    let Ok(content) = std::fs::read_to_string(ctx.target) else {
+        // Not text (or not written); nothing to rewrite
+        return Ok(());
+    };
+    let Some(rewritten) = rewrite_links(&content, ctx) else {
+        return Ok(());
+    };
+    // Replaces a `--link` symlink rather than editing the bundle source
+    super::file_ops::write_file(ctx.target, rewritten, verify)
+}
+
+fn rewrite_link(link: &str, ctx: &LinkContext<'_>) -> Option<String> {
+    if !is_relative_link(link) {
+        return None;
+    }
+    let (path, fragment) = match link.find('#') {
+        Some(i) => (&link[..i], &link[i..]),
+        None => (link, ""),
+    };
+    let source_dir = ctx.source.parent().unwrap_or(Path::new(""));
+    let linked = normalize(&source_dir.join(path))?;
+    let installed = ctx.targets.get(&linked)?;
+
+    let target_dir = ctx.target.parent()?;
+    let relative = relative_path(target_dir, installed)?;
+    if relative == relative_path(source_dir, &linked)? {
+        return None;
+    }
+
+    let relative = relative.to_string_lossy().replace('\\', "/");
+    let prefix = if path.starts_with("./") && !relative.starts_with("..") {
+        "./"
+    } else {
+        ""
+    };
+    Some(format!("{prefix}{relative}{fragment}"))
+}
+
+fn is_relative_link(link: &str) -> bool {
+    !link.is_empty()
+        && !link.starts_with('#')
+        && !link.starts_with('/')
+        && !link.contains("://")
+        && !link.starts_with("mailto:")
+        && !link.contains(char::is_whitespace)
+}
+
+/// Resolve `.` and `..` without touching the filesystem
+///
+/// Returns `None` for paths that climb above their root.
+fn normalize(path: &Path) -> Option<PathBuf> {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => normalized.pop().then_some(())?,
+            other => normalized.push(other),
+        }
+    }
+    Some(normalized)
+}
+
+/// Path to `to` relative to the directory `from`
+fn relative_path(from: &Path, to: &Path) -> Option<PathBuf> {
+    let from = normalize(from)?;
+    let to = normalize(to)?;
+    let common = from
+        .components()
+        .zip(to.components())
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    let mut relative = PathBuf::new();
+    for _ in from.components().skip(common) {
+        relative.push("..");
+    }
+    for component in to.components().skip(common) {
+        relative.push(component);
+    }
+    Some(relative)
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::*;
+
+    fn targets() -> TargetMap {
+        let mut targets = TargetMap::new();
+        targets.insert(
+            PathBuf::from("skills/deploy/scripts/run.sh"),
+            PathBuf::from("/ws/.claude/skills/deploy/scripts/run.txt"),
+        );
+        targets.insert(
+            PathBuf::from("skills/deploy/notes.md"),
+            PathBuf::from("/ws/.claude/skills/deploy/notes.md"),
+        );
+        targets
+    }
+
+    #[test]
+    fn test_rewrite_links_follows_installed_layout() {
+        let targets = targets();
+        let ctx = LinkContext {
+            source: Path::new("skills/deploy/SKILL.md"),
+            target: Path::new("/ws/.claude/skills/deploy/SKILL.md"),
+            targets: &targets,
+        };
+        let content = "Run [it](./scripts/run.sh#usage), see [notes](notes.md), \
+                       [docs](https://example.com/run.sh) and [top](#top).\n";
+
+        let rewritten = rewrite_links(content, &ctx).expect("Should rewrite the script link");
+        assert_eq!(
+            rewritten,
+            "Run [it](./scripts/run.txt#usage), see [notes](notes.md), \
+             [docs](https://example.com/run.sh) and [top](#top).\n"
+        );
+    }
+
+    #[test]
+    fn test_rewrite_links_leaves_unchanged_layout() {
+        let targets = targets();
+        let ctx = LinkContext {
+            source: Path::new("skills/deploy/SKILL.md"),
+            target: Path::new("/ws/.claude/skills/deploy/SKILL.md"),
+            targets: &targets,
+        };
+        assert!(rewrite_links("See [notes](./notes.md).\n", &ctx).is_none());
+    }
+}
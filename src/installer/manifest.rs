@@ -0,0 +1,131 @@
+//! Per-platform manifests of installed files (`--platform-summary-json`)
+//!
+//! Built from the installer's accumulated `installed_files`: every target is
+//! attributed to the platform whose directory (or MCP config) it was written
+//! to, together with the bundle and bundle-relative source it came from.
+
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+
+use serde::Serialize;
+
+use crate::domain::InstalledFile;
+use crate::platform::Platform;
+
+use super::mcp;
+
+/// One file written for a platform
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ManifestEntry {
+    /// Workspace-relative path of the written file
+    pub path: String,
+    /// Name of the bundle the file came from
+    pub bundle: String,
+    /// Path of the resource within the bundle
+    pub source: String,
+}
+
+/// Manifest entries per platform id, sorted by path
+pub type PlatformManifest = BTreeMap<String, Vec<ManifestEntry>>;
+
+/// Group installed files by the platform they were written for
+pub fn build(
+    installed_files: &HashMap<String, InstalledFile>,
+    platforms: &[Platform],
+    workspace_root: &Path,
+) -> PlatformManifest {
+    let mut manifest: PlatformManifest = platforms
+        .iter()
+        .map(|p| (p.id.clone(), Vec::new()))
+        .collect();
+
+    let targets = installed_files.iter().flat_map(|(source, installed)| {
+        installed
+            .target_paths
+            .iter()
+            .map(move |target| (source, installed, Path::new(target)))
+    });
+    for (source, installed, target) in targets {
+        let Some(platform) = owning_platform(target, platforms, workspace_root) else {
+            continue;
+        };
+        let Some(entries) = manifest.get_mut(&platform.id) else {
+            continue;
+        };
+        let path = target.strip_prefix(workspace_root).unwrap_or(target);
+        entries.push(ManifestEntry {
+            path: path.to_string_lossy().replace('\\', "/"),
+            bundle: installed.bundle_path.clone(),
+            source: source.replace('\\', "/"),
+        });
+    }
+
+    for entries in manifest.values_mut() {
+        entries.sort_by(|a, b| a.path.cmp(&b.path));
+    }
+    manifest
+}
+
+fn owning_platform<'a>(
+    target: &Path,
+    platforms: &'a [Platform],
+    workspace_root: &Path,
+) -> Option<&'a Platform> {
+    platforms
+        .iter()
+        .find(|p| target.starts_with(workspace_root.join(&p.directory)))
+        .or_else(|| {
+            platforms.iter().find(|p| {
+                mcp::mcp_config_target(p, workspace_root).is_some_and(|mcp| mcp == target)
+            })
+        })
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_groups_files_by_platform() {
+        let root = Path::new("/ws");
+        let platforms: Vec<Platform> = crate::platform::default_platforms()
+            .into_iter()
+            .filter(|p| p.id == "cursor" || p.id == "claude")
+            .collect();
+        let mut installed_files = HashMap::new();
+        installed_files.insert(
+            "commands/review.md".to_string(),
+            InstalledFile {
+                bundle_path: "review-bundle".to_string(),
+                resource_type: "commands".to_string(),
+                target_paths: vec![
+                    "/ws/.cursor/commands/review.md".to_string(),
+                    "/ws/.claude/commands/review.md".to_string(),
+                ],
+            },
+        );
+        installed_files.insert(
+            "mcp_servers/github.json".to_string(),
+            InstalledFile {
+                bundle_path: "mcp-bundle".to_string(),
+                resource_type: "mcp_servers".to_string(),
+                target_paths: vec!["/ws/.mcp.json".to_string()],
+            },
+        );
+
+        let manifest = build(&installed_files, &platforms, root);
+
+        assert_eq!(
+            manifest["cursor"],
+            vec![ManifestEntry {
+                path: ".cursor/commands/review.md".to_string(),
+                bundle: "review-bundle".to_string(),
+                source: "commands/review.md".to_string(),
+            }]
+        );
+        let claude: Vec<&str> = manifest["claude"].iter().map(|e| e.path.as_str()).collect();
+        assert_eq!(claude, vec![".claude/commands/review.md", ".mcp.json"]);
+        assert_eq!(manifest["claude"][1].bundle, "mcp-bundle");
+    }
+}
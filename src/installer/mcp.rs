@@ -0,0 +1,282 @@
+//! MCP server aggregation for `mcp_servers/` bundle directories
+//!
+//! Bundles may ship each MCP server as its own JSON file under `mcp_servers/`
+//! instead of a single `mcp.jsonc`. These files are combined into the
+//! platform's MCP config (the target of its `mcp.jsonc` transform), deep
+//! merging each server into the `mcpServers` map.
+//!
+//! A server file may contain either:
+//! - A single server definition (`{"command": ...}`), named after the file stem
+//! - A map of servers, optionally wrapped in `{"mcpServers": {...}}`
+//!
+//! [`preview_merged_config`] runs the same merge in memory for `show --mcp`.
+
+use std::path::{Path, PathBuf};
+
+use serde_json::{Map, Value};
+
+use crate::domain::DiscoveredResource;
+use crate::error::{AugentError, Result};
+use crate::platform::{ArrayMergePolicy, MergeStrategy, Platform, TransformRule};
+
+/// Resource directory holding individual MCP server files
+pub const MCP_SERVERS_DIR: &str = "mcp_servers";
+
+/// Bundle-level MCP config file
+const MCP_CONFIG_FILE: &str = "mcp.jsonc";
+
+/// Key holding the servers map in MCP config files
+const MCP_SERVERS_KEY: &str = "mcpServers";
+
+/// Keys that mark a JSON object as a single server definition
+const SERVER_DEFINITION_KEYS: &[&str] = &["command", "url", "type"];
+
+/// Whether a discovered resource is an individual MCP server file
+pub fn is_mcp_server_resource(resource: &DiscoveredResource) -> bool {
+    resource.resource_type == MCP_SERVERS_DIR
+}
+
+/// A platform's `mcp.jsonc` transform
+fn mcp_config_rule(platform: &Platform) -> Option<&TransformRule> {
+    platform
+        .transforms
+        .iter()
+        .find(|rule| rule.from == MCP_CONFIG_FILE)
+}
+
+/// MCP config path for a platform, from its `mcp.jsonc` transform
+pub fn mcp_config_target(platform: &Platform, workspace_root: &Path) -> Option<PathBuf> {
+    mcp_config_rule(platform).map(|rule| workspace_root.join(&rule.to))
+}
+
+/// How a platform's MCP config merges arrays, from its `mcp.jsonc` transform
+pub fn mcp_array_policy(platform: &Platform) -> ArrayMergePolicy {
+    mcp_config_rule(platform).map_or_else(ArrayMergePolicy::default, |rule| rule.arrays)
+}
+
+/// Merge server files into the MCP config at `target`
+///
+/// `Deep` is the default; `Shallow` replaces the whole `mcpServers` map and
+/// `Replace` discards the existing config. Returns the names of the merged
+/// servers.
+pub fn merge_server_files(
+    servers: &[&DiscoveredResource],
+    target: &Path,
+    strategy: MergeStrategy,
+    arrays: ArrayMergePolicy,
+    verify: bool,
+) -> Result<Vec<String>> {
+    let mut merged_servers = Map::new();
+    for resource in servers {
+        merged_servers.extend(read_server_entries(&resource.absolute_path)?);
+    }
+    let names: Vec<String> = merged_servers.keys().cloned().collect();
+
+    let (existing, strategy) = match strategy {
+        MergeStrategy::Replace => ("{}".to_string(), MergeStrategy::Deep),
+        strategy => (read_existing_config(target)?, strategy),
+    };
+    let merged = merge_servers(&existing, merged_servers, strategy, arrays)?;
+
+    crate::installer::file_ops::ensure_parent_dir(target)?;
+    crate::installer::file_ops::write_file(target, merged, verify)?;
+    Ok(names)
+}
+
+/// Remove the named servers from the MCP config at `target`
+///
+/// Other servers and keys are kept. The file is deleted once nothing but an
+/// empty servers map remains. Returns whether the file was deleted.
+pub fn remove_servers(target: &Path, names: &[String]) -> Result<bool> {
+    if !target.is_file() {
+        return Ok(false);
+    }
+    let content = read_file(target)?;
+    let mut config: Value =
+        serde_json::from_str(&content).map_err(|e| AugentError::ConfigParseFailed {
+            path: target.display().to_string(),
+            reason: e.to_string(),
+        })?;
+    let Some(object) = config.as_object_mut() else {
+        return Ok(false);
+    };
+
+    if let Some(Value::Object(servers)) = object.get_mut(MCP_SERVERS_KEY) {
+        servers.retain(|name, _| !names.contains(name));
+        if servers.is_empty() {
+            object.remove(MCP_SERVERS_KEY);
+        }
+    }
+
+    if object.is_empty() {
+        std::fs::remove_file(target).map_err(|e| AugentError::FileWriteFailed {
+            path: target.display().to_string(),
+            reason: e.to_string(),
+        })?;
+        return Ok(true);
+    }
+    let updated =
+        serde_json::to_string_pretty(&config).map_err(|e| AugentError::ConfigParseFailed {
+            path: target.display().to_string(),
+            reason: e.to_string(),
+        })?;
+    write_file(target, &updated)?;
+    Ok(false)
+}
+
+fn read_file(path: &Path) -> Result<String> {
+    std::fs::read_to_string(path).map_err(|e| AugentError::FileReadFailed {
+        path: path.display().to_string(),
+        reason: e.to_string(),
+    })
+}
+
+fn write_file(path: &Path, content: &str) -> Result<()> {
+    std::fs::write(path, content).map_err(|e| AugentError::FileWriteFailed {
+        path: path.display().to_string(),
+        reason: e.to_string(),
+    })
+}
+
+/// Merged MCP config for `target` after applying every bundle's contributions
+///
+/// Each bundle directory contributes its `mcp.jsonc` and `mcp_servers/`
+/// files, in order. Nothing is written.
+pub fn preview_merged_config(
+    bundle_dirs: &[PathBuf],
+    target: &Path,
+    arrays: ArrayMergePolicy,
+) -> Result<String> {
+    let mut config = read_existing_config(target)?;
+    for dir in bundle_dirs {
+        let config_file = dir.join(MCP_CONFIG_FILE);
+        if config_file.is_file() {
+            let content = read_file(&config_file)?;
+            let content = crate::platform::loader::PlatformLoader::strip_jsonc_comments(&content);
+            config = MergeStrategy::Deep.merge_strings_with_policy(&config, &content, arrays)?;
+        }
+        let mut servers = Map::new();
+        for path in server_files(&dir.join(MCP_SERVERS_DIR))? {
+            servers.extend(read_server_entries(&path)?);
+        }
+        if !servers.is_empty() {
+            config = merge_servers(&config, servers, MergeStrategy::Deep, arrays)?;
+        }
+    }
+    Ok(config)
+}
+
+/// Server files in a bundle's `mcp_servers/` directory, sorted by name
+fn server_files(dir: &Path) -> Result<Vec<PathBuf>> {
+    if !dir.is_dir() {
+        return Ok(Vec::new());
+    }
+    let mut files: Vec<PathBuf> = std::fs::read_dir(dir)?
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+        .collect();
+    files.sort();
+    Ok(files)
+}
+
+/// Merge `servers` into the `mcpServers` map of `existing` with `strategy`
+fn merge_servers(
+    existing: &str,
+    servers: Map<String, Value>,
+    strategy: MergeStrategy,
+    arrays: ArrayMergePolicy,
+) -> Result<String> {
+    let mut new_config = Map::new();
+    new_config.insert(MCP_SERVERS_KEY.to_string(), Value::Object(servers));
+    strategy.merge_strings_with_policy(existing, &Value::Object(new_config).to_string(), arrays)
+}
+
+/// Current content of an MCP config, or an empty object
+fn read_existing_config(target: &Path) -> Result<String> {
+    if target.is_file() {
+        read_file(target)
+    } else {
+        Ok("{}".to_string())
+    }
+}
+
+/// Read server name/definition pairs from a single server file
+fn read_server_entries(path: &Path) -> Result<Map<String, Value>> {
+    let content = read_file(path)?;
+    let value: Value =
+        serde_json::from_str(&content).map_err(|e| AugentError::ConfigParseFailed {
+            path: path.display().to_string(),
+            reason: e.to_string(),
+        })?;
+    let Value::Object(mut object) = value else {
+        return Err(AugentError::ConfigParseFailed {
+            path: path.display().to_string(),
+            reason: "MCP server file must contain a JSON object".to_string(),
+        });
+    };
+
+    if let Some(Value::Object(servers)) = object.remove(MCP_SERVERS_KEY) {
+        return Ok(servers);
+    }
+    if SERVER_DEFINITION_KEYS
+        .iter()
+        .any(|k| object.contains_key(*k))
+    {
+        let name = path
+            .file_stem()
+            .map(|s| s.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let mut servers = Map::new();
+        servers.insert(name, Value::Object(object));
+        return Ok(servers);
+    }
+    Ok(object)
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_server_entries_formats() {
+        let temp = tempfile::TempDir::new_in(crate::temp::temp_dir_base())
+            .expect("Failed to create temp dir");
+        let single = temp.path().join("filesystem.json");
+        std::fs::write(&single, r#"{"command": "npx", "args": ["fs"]}"#)
+            .expect("Failed to write server file");
+        let wrapped = temp.path().join("wrapped.json");
+        std::fs::write(&wrapped, r#"{"mcpServers": {"github": {"command": "gh"}}}"#)
+            .expect("Failed to write server file");
+
+        let single_entries = read_server_entries(&single).expect("Failed to read server file");
+        assert_eq!(single_entries["filesystem"]["command"], "npx");
+
+        let wrapped_entries = read_server_entries(&wrapped).expect("Failed to read server file");
+        assert_eq!(wrapped_entries["github"]["command"], "gh");
+    }
+
+    #[test]
+    fn test_remove_servers_keeps_other_entries() {
+        let temp = tempfile::TempDir::new_in(crate::temp::temp_dir_base())
+            .expect("Failed to create temp dir");
+        let config = temp.path().join("mcp.json");
+        std::fs::write(
+            &config,
+            r#"{"mcpServers": {"github": {"command": "gh"}, "local": {"command": "local"}}}"#,
+        )
+        .expect("Failed to write config");
+
+        let deleted =
+            remove_servers(&config, &["github".to_string()]).expect("Failed to remove servers");
+        assert!(!deleted);
+        let content = std::fs::read_to_string(&config).expect("Failed to read config");
+        assert!(!content.contains("github"));
+        assert!(content.contains("local"));
+
+        let deleted =
+            remove_servers(&config, &["local".to_string()]).expect("Failed to remove servers");
+        assert!(deleted);
+        assert!(!config.exists());
+    }
+}
@@ -41,6 +41,12 @@
 //! - **parser**: Frontmatter parsing for platform-specific metadata
 //! - **writer**: Output writing for processed content
 //! - **formats**: Platform-specific format conversions (plugin-based architecture)
+//! - **links**: Rewriting of relative links in skill markdown to the installed layout
+//! - **manifest**: Per-platform manifests of installed files
+//! - **mcp**: Aggregation of `mcp_servers/` files into platform MCP configs
+//! - **provenance**: Opt-in sidecars mapping installed files to bundles and SHAs
+//! - **stamp**: Opt-in provenance comments on installed files
+//! - **template**: Opt-in `{{var}}` substitution in markdown bodies
 //!
 //! ## Resource Types
 //!
@@ -51,6 +57,7 @@
 //! | `command` | Universal commands | `commands/fix.md` |
 //! | `skill` | Agent skills | `skills/web-browser.md` |
 //! | `mcp` | MCP server config | `mcp.jsonc` |
+//! | `mcp_servers` | Individual MCP server files | `mcp_servers/github.json` |
 //! | `rule` | Universal rules | `rules/fix-lint.md` |
 //! | `agent` | AGENTS.md knowledge base | `AGENTS.md` |
 //!
@@ -170,20 +177,37 @@ pub mod detection;
 pub mod discovery;
 pub mod file_ops;
 pub mod formats;
+pub mod links;
+pub mod manifest;
+pub mod mcp;
+pub mod options;
 pub mod parser;
+pub mod plan;
+pub mod provenance;
+pub mod stamp;
+pub mod template;
 pub mod writer;
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 
+use crate::common::concurrency::map_ordered;
 use crate::config::WorkspaceBundle;
-use crate::domain::{DiscoveredResource, InstalledFile, ResolvedBundle};
-use crate::error::Result;
+use crate::domain::{DiscoveredResource, InstallConflict, InstalledFile, ResolvedBundle};
+use crate::error::{AugentError, Result};
 use crate::installer::formats::plugin::FormatRegistry;
 use crate::platform::Platform;
+use crate::platform::output_ext::apply_overrides;
+use crate::platform::strategy_override::strategy_for;
+use crate::transaction::journal::FileJournal;
 use crate::ui::ProgressReporter;
 
+pub use options::{ContentOptions, InstallerOptions, SelectionOptions};
+
+/// Default frontmatter key restricting a resource to the listed platforms
+pub const DEFAULT_PLATFORM_FILTER_KEY: &str = "platforms";
+
 /// File installer for a workspace
 pub struct Installer<'a> {
     workspace_root: &'a Path,
@@ -191,6 +215,16 @@ pub struct Installer<'a> {
     format_registry: Arc<FormatRegistry>,
     installed_files: HashMap<String, crate::installer::InstalledFile>,
     dry_run: bool,
+    options: InstallerOptions,
+    /// Snapshots of targets taken before they are written, for rollback
+    journal: Option<Arc<FileJournal>>,
+    /// Targets already considered for backup, so only pre-install content is archived
+    backed_up: Mutex<HashSet<PathBuf>>,
+    /// Missing directories the user agreed to create
+    approved_dirs: Mutex<HashSet<PathBuf>>,
+    /// Bundle and source file that last replaced each target
+    target_owners: HashMap<PathBuf, (String, PathBuf)>,
+    conflicts: Vec<InstallConflict>,
     #[allow(dead_code)]
     progress: Option<&'a mut dyn ProgressReporter>,
 }
@@ -201,7 +235,19 @@ struct ResourceInstallContext<'a, 'b> {
     target_path: PathBuf,
     platform: &'a Platform,
     bundle_name: &'a str,
-    resource_type: &'a str,
+    /// Provenance stamp to prepend to the installed file (`--stamp`)
+    provenance: Option<&'a str>,
+    /// Merge strategy of the transform rule matching the resource
+    merge: crate::platform::MergeStrategy,
+}
+
+/// Target content read before a resource is written over it
+enum ExistingTarget {
+    /// Composite target whose bundle sections are kept (`--dedupe-composite`)
+    Composite(String),
+    /// Target the written file is merged into with an overridden strategy,
+    /// the transform rule's appended arrays, or both
+    Merged { existing: String, overridden: bool },
 }
 
 impl<'a> Installer<'a> {
@@ -210,15 +256,18 @@ impl<'a> Installer<'a> {
         platforms: Vec<Platform>,
         dry_run: bool,
     ) -> Self {
-        let mut registry = FormatRegistry::new();
-        let _ = registry.register_builtins();
-
         Self {
             workspace_root,
             platforms,
-            format_registry: Arc::new(registry),
+            format_registry: Arc::new(builtin_registry(true)),
             installed_files: HashMap::new(),
             dry_run,
+            options: InstallerOptions::default(),
+            journal: None,
+            backed_up: Mutex::new(HashSet::new()),
+            approved_dirs: Mutex::new(HashSet::new()),
+            target_owners: HashMap::new(),
+            conflicts: Vec::new(),
             progress: None,
         }
     }
@@ -229,17 +278,195 @@ impl<'a> Installer<'a> {
         dry_run: bool,
         progress: Option<&'a mut dyn ProgressReporter>,
     ) -> Self {
-        let mut registry = FormatRegistry::new();
-        let _ = registry.register_builtins();
-
         Self {
-            workspace_root,
-            platforms,
-            format_registry: Arc::new(registry),
-            installed_files: HashMap::new(),
-            dry_run,
             progress,
+            ..Self::new_with_dry_run(workspace_root, platforms, dry_run)
+        }
+    }
+
+    /// Replace the default installer options
+    #[must_use]
+    pub fn with_options(mut self, options: InstallerOptions) -> Self {
+        if !options.content.gemini_toml {
+            self.format_registry = Arc::new(builtin_registry(false));
+        }
+        self.options = options;
+        self
+    }
+
+    /// Snapshot every target into `journal` before writing it
+    #[must_use]
+    pub fn with_journal(mut self, journal: Arc<FileJournal>) -> Self {
+        self.journal = Some(journal);
+        self
+    }
+
+    /// Run `write` for `target`, backing the target up first when journaling
+    fn backup_and_write<T>(&self, target: &Path, write: impl FnOnce() -> Result<T>) -> Result<T> {
+        self.check_new_dirs(target)?;
+        self.archive_existing(target)?;
+        match &self.journal {
+            Some(journal) => journal.backup_and_write(target, write),
+            None => write(),
+        }
+    }
+
+    /// Copy `target` into the backup directory the first time it is written
+    ///
+    /// Targets created by this install are remembered too, so a file one
+    /// bundle creates and another overwrites is never archived.
+    fn archive_existing(&self, target: &Path) -> Result<()> {
+        let Some(backup_dir) = &self.options.backup_dir else {
+            return Ok(());
+        };
+        let first_write = self
+            .backed_up
+            .lock()
+            .map_err(|e| crate::error::AugentError::IoError {
+                message: format!("Failed to acquire backup lock: {e}"),
+                source: None,
+            })?
+            .insert(target.to_path_buf());
+        if !first_write || !target.is_file() {
+            return Ok(());
+        }
+        let relative = target.strip_prefix(self.workspace_root).unwrap_or(target);
+        file_ops::backup_file(target, &backup_dir.join(relative))
+    }
+
+    /// Apply the new directory policy to the directories `target` needs
+    fn check_new_dirs(&self, target: &Path) -> Result<()> {
+        if self.options.new_dir_policy == file_ops::NewDirPolicy::Create {
+            return Ok(());
+        }
+        let Some(dir) = file_ops::first_missing_dir(target) else {
+            return Ok(());
+        };
+        // Held while prompting so concurrent writes ask one at a time
+        let mut approved =
+            self.approved_dirs
+                .lock()
+                .map_err(|e| crate::error::AugentError::IoError {
+                    message: format!("Failed to acquire directory lock: {e}"),
+                    source: None,
+                })?;
+        if approved.contains(dir) {
+            return Ok(());
+        }
+        let relative = dir
+            .strip_prefix(self.workspace_root)
+            .unwrap_or(dir)
+            .display()
+            .to_string();
+        let allowed = self.options.new_dir_policy == file_ops::NewDirPolicy::Confirm
+            && inquire::Confirm::new(&format!("Create directory {relative}?"))
+                .with_default(false)
+                .prompt()?;
+        if !allowed {
+            return Err(crate::error::AugentError::NewDirectoryDenied { path: relative });
+        }
+        approved.insert(dir.to_path_buf());
+        Ok(())
+    }
+
+    fn is_passthrough(&self, platform: &Platform) -> bool {
+        self.options.passthrough_platforms.contains(&platform.id)
+    }
+
+    /// True when `--copy-only-new` must leave `target` alone: it exists and
+    /// was not written by this install
+    fn is_preexisting(
+        &self,
+        target: &Path,
+        installed_files: &HashMap<String, InstalledFile>,
+    ) -> bool {
+        if !self.options.selection.copy_only_new || !target.exists() {
+            return false;
+        }
+        let target = target.display().to_string();
+        let written_here = self
+            .installed_files
+            .values()
+            .chain(installed_files.values())
+            .any(|f| f.target_paths.contains(&target));
+        if !written_here {
+            eprintln!("Skipped existing {target} (--copy-only-new)");
+        }
+        !written_here
+    }
+
+    /// True when a dir bundle resource is older than `since` and already
+    /// installed at every target, so rewriting it can be skipped
+    fn is_unchanged(
+        &self,
+        resource: &DiscoveredResource,
+        bundle: &ResolvedBundle,
+        targets: &[(&Platform, PathBuf)],
+    ) -> bool {
+        let Some(since) = self.options.selection.since else {
+            return false;
+        };
+        if bundle.git_source.is_some() {
+            return false;
+        }
+        let modified = std::fs::metadata(&resource.absolute_path).and_then(|m| m.modified());
+        modified.is_ok_and(|modified| modified <= since)
+            && targets.iter().all(|(_, target)| target.exists())
+    }
+
+    /// Resources of a bundle to install, after skill and settings filtering
+    fn bundle_resources(&self, bundle_name: &str, source_path: &Path) -> Vec<DiscoveredResource> {
+        let mut resources = Installer::discover_resources_internal(source_path);
+        if self.options.selection.settings_only {
+            resources.retain(discovery::is_settings_resource);
         }
+        let resources = discovery::filter_skills_resources(resources);
+        if !self.options.selection.ignore_scripts {
+            return resources;
+        }
+        let (kept, skipped) = discovery::partition_skill_scripts(resources);
+        for script in &skipped {
+            eprintln!(
+                "Skipped script {} from {bundle_name} (--ignore-scripts)",
+                script.bundle_path.display()
+            );
+        }
+        kept
+    }
+
+    /// Platform ids listed under the platform filter key of a resource's
+    /// frontmatter; `None` when the resource is not restricted
+    fn platform_filter(&self, resource: &DiscoveredResource) -> Option<Vec<String>> {
+        if detection::is_likely_binary_file(&resource.absolute_path) {
+            return None;
+        }
+        let content = std::fs::read_to_string(&resource.absolute_path).ok()?;
+        let (frontmatter, _) = crate::universal::parse_frontmatter_and_body(&content)?;
+        crate::universal::platform_filter(&frontmatter, &self.options.selection.platform_filter_key)
+    }
+
+    /// Merge strategy for a resource on a platform, honoring `--strategy-override`
+    fn merge_strategy(
+        &self,
+        platform: &Platform,
+        resource: &DiscoveredResource,
+    ) -> crate::platform::MergeStrategy {
+        self.overridden_strategy(resource)
+            .unwrap_or_else(|| platform.merge_strategy_for(&resource.bundle_path))
+    }
+
+    /// Strategy `--strategy-override` sets for the resource's type, if any
+    fn overridden_strategy(
+        &self,
+        resource: &DiscoveredResource,
+    ) -> Option<crate::platform::MergeStrategy> {
+        strategy_for(&self.options.strategy_overrides, &resource.resource_type)
+    }
+
+    /// Strategy MCP servers are merged into a platform's MCP config with
+    fn mcp_strategy(&self) -> crate::platform::MergeStrategy {
+        strategy_for(&self.options.strategy_overrides, mcp::MCP_SERVERS_DIR)
+            .unwrap_or(crate::platform::MergeStrategy::Deep)
     }
 
     pub fn discover_resources_internal(bundle_path: &Path) -> Vec<DiscoveredResource> {
@@ -252,111 +479,470 @@ impl<'a> Installer<'a> {
         bundle: &ResolvedBundle,
         platform: &Platform,
     ) -> PathBuf {
-        let platform_root = self.workspace_root.join(&platform.directory);
-        platform_root.join(
-            resource
-                .bundle_path
-                .strip_prefix(&bundle.source_path)
-                .unwrap_or(&resource.bundle_path),
+        let relative = resource
+            .bundle_path
+            .strip_prefix(&bundle.source_path)
+            .unwrap_or(&resource.bundle_path);
+        if self.is_passthrough(platform) {
+            return self.workspace_root.join(&platform.directory).join(relative);
+        }
+        let target = match platform.mapped_target(relative) {
+            Some(mapped) => self.workspace_root.join(mapped),
+            None => self.workspace_root.join(&platform.directory).join(relative),
+        };
+        apply_overrides(
+            &self.options.content.output_ext_overrides,
+            platform,
+            &resource.resource_type,
+            target,
         )
     }
 
     fn install_resource_for_platform(
         ctx: &ResourceInstallContext<'_, '_>,
         resource: &DiscoveredResource,
-        installed_files: &mut HashMap<String, InstalledFile>,
         format_registry: &Arc<FormatRegistry>,
     ) -> Result<()> {
+        ctx.installer.backup_and_write(&ctx.target_path, || {
+            Installer::write_resource_for_platform(ctx, resource, format_registry)
+        })
+    }
+
+    fn write_resource_for_platform(
+        ctx: &ResourceInstallContext<'_, '_>,
+        resource: &DiscoveredResource,
+        format_registry: &Arc<FormatRegistry>,
+    ) -> Result<()> {
+        if ctx.installer.is_passthrough(ctx.platform) {
+            return file_ops::copy_verbatim(
+                &resource.absolute_path,
+                &ctx.target_path,
+                ctx.installer.options.copy_mode,
+                ctx.installer.options.verify,
+            );
+        }
+
+        if ctx.merge == crate::platform::MergeStrategy::Yaml {
+            return crate::platform::merge::merge_yaml_files(
+                &resource.absolute_path,
+                &ctx.target_path,
+                ctx.installer.options.verify,
+            );
+        }
+
+        let existing = Self::existing_target(ctx, resource)?;
+
         crate::installer::file_ops::copy_file(
             &resource.absolute_path,
             &ctx.target_path,
-            std::slice::from_ref(ctx.platform),
-            ctx.installer.workspace_root,
-            format_registry,
+            &crate::installer::file_ops::CopyOptions {
+                platforms: std::slice::from_ref(ctx.platform),
+                workspace_root: ctx.installer.workspace_root,
+                format_registry,
+                merge_frontmatter: ctx.installer.options.content.merge_frontmatter,
+                template_vars: ctx.installer.options.content.template_vars.as_ref(),
+                verify: ctx.installer.options.verify,
+                copy_mode: ctx.installer.options.copy_mode,
+                platform_filter_key: Some(&ctx.installer.options.selection.platform_filter_key),
+            },
         )?;
 
-        let key = resource.bundle_path.display().to_string();
-        let entry = installed_files
-            .entry(key.clone())
-            .or_insert_with(|| InstalledFile {
-                bundle_path: ctx.bundle_name.to_string(),
-                resource_type: ctx.resource_type.to_string(),
-                target_paths: vec![],
-            });
-        entry
-            .target_paths
-            .push(ctx.target_path.display().to_string());
+        match existing {
+            Some(ExistingTarget::Composite(existing)) => {
+                write_composite_section(&ctx.target_path, &existing, ctx.bundle_name)?;
+            }
+            Some(ExistingTarget::Merged {
+                existing,
+                overridden,
+            }) => {
+                let strategy = overridden.then_some(ctx.merge);
+                let append = ctx.platform.append_keys_for(&resource.bundle_path);
+                let verify = ctx.installer.options.verify;
+                write_merged_target(&ctx.target_path, &existing, strategy, append, verify)?;
+            }
+            None => {}
+        }
+
+        if let Some(provenance) = ctx.provenance {
+            stamp::stamp_file(&ctx.target_path, provenance, ctx.installer.options.verify)?;
+        }
 
         Ok(())
     }
 
+    /// Content of the target before it is written, when the written file is
+    /// merged into it afterwards
+    fn existing_target(
+        ctx: &ResourceInstallContext<'_, '_>,
+        resource: &DiscoveredResource,
+    ) -> Result<Option<ExistingTarget>> {
+        if ctx.installer.options.dedupe_composite
+            && ctx.merge == crate::platform::MergeStrategy::Composite
+        {
+            return read_composite_target(&ctx.target_path)
+                .map(|c| Some(ExistingTarget::Composite(c)));
+        }
+        // Overridden strategies and appended arrays merge into the existing
+        // target after it is written
+        let overridden = ctx.merge != crate::platform::MergeStrategy::Replace
+            && ctx.installer.overridden_strategy(resource).is_some();
+        let append = !ctx
+            .platform
+            .append_keys_for(&resource.bundle_path)
+            .is_empty();
+        if !(overridden || append) || !ctx.target_path.is_file() {
+            return Ok(None);
+        }
+        read_composite_target(&ctx.target_path).map(|existing| {
+            Some(ExistingTarget::Merged {
+                existing,
+                overridden,
+            })
+        })
+    }
+
     pub fn install_bundle(&mut self, bundle: &ResolvedBundle) -> Result<WorkspaceBundle> {
-        let resources = Installer::discover_resources_internal(&bundle.source_path);
-        let resources = discovery::filter_skills_resources(resources);
+        let resources = self.bundle_resources(&bundle.name, &bundle.source_path);
 
         let mut installed_files = HashMap::new();
 
+        let mut workspace_bundle = WorkspaceBundle::new(bundle.name.clone());
         if self.dry_run {
-            return Ok(WorkspaceBundle {
-                name: bundle.name.clone(),
-                enabled: HashMap::new(),
-            });
+            return Ok(workspace_bundle);
         }
 
-        Self::install_resources_for_bundle(self, &resources, bundle, &mut installed_files)?;
+        let (default_platforms, overridden_platforms) = self.partition_platforms(bundle);
+        workspace_bundle.mcp_servers = self.install_resources_for_bundle(
+            &resources,
+            bundle,
+            &default_platforms,
+            &mut installed_files,
+        )?;
+        for (platform, source_path) in overridden_platforms {
+            let merged = self.install_platform_override(
+                bundle,
+                &platform,
+                source_path,
+                &mut installed_files,
+            )?;
+            workspace_bundle.mcp_servers.extend(merged);
+        }
 
-        self.installed_files = installed_files;
+        self.record_conflicts(bundle, &resources, &installed_files);
+        self.installed_files.extend(installed_files);
 
-        Ok(WorkspaceBundle {
-            name: bundle.name.clone(),
-            enabled: HashMap::new(),
-        })
+        Ok(workspace_bundle)
+    }
+
+    /// Record targets this bundle replaced after another bundle wrote them
+    fn record_conflicts(
+        &mut self,
+        bundle: &ResolvedBundle,
+        resources: &[DiscoveredResource],
+        installed_files: &HashMap<String, InstalledFile>,
+    ) {
+        let replaced: Vec<(PathBuf, PathBuf)> = self
+            .replaced_targets(bundle, resources)
+            .into_iter()
+            .filter(|(target, source)| was_installed_from(installed_files, source, target))
+            .collect();
+        for (target, source) in replaced {
+            let conflict = claim_target(
+                &mut self.target_owners,
+                self.workspace_root,
+                &bundle.name,
+                &target,
+                &source,
+            );
+            self.conflicts.extend(conflict);
+        }
+    }
+
+    /// Conflicts installing `bundles` would cause, computed without writing files
+    pub fn planned_conflicts(&self, bundles: &[ResolvedBundle]) -> Vec<InstallConflict> {
+        let mut owners = HashMap::new();
+        bundles
+            .iter()
+            .flat_map(|bundle| {
+                let resources = self.bundle_resources(&bundle.name, &bundle.source_path);
+                self.replaced_targets(bundle, &resources)
+                    .into_iter()
+                    .map(move |(target, source)| (&bundle.name, target, source))
+            })
+            .filter_map(|(bundle_name, target, source)| {
+                claim_target(
+                    &mut owners,
+                    self.workspace_root,
+                    bundle_name,
+                    &target,
+                    &source,
+                )
+            })
+            .collect()
     }
 
+    /// Targets a bundle's resources replace on each platform, with the source
+    /// file within the bundle
+    fn replaced_targets(
+        &self,
+        bundle: &ResolvedBundle,
+        resources: &[DiscoveredResource],
+    ) -> Vec<(PathBuf, PathBuf)> {
+        resources
+            .iter()
+            .filter(|r| !mcp::is_mcp_server_resource(r))
+            .flat_map(|resource| {
+                self.platforms
+                    .iter()
+                    .filter(|platform| !self.merges_into_existing(platform, resource))
+                    .map(|platform| self.calculate_target_path(resource, bundle, platform))
+                    .map(|target| (target, resource.bundle_path.clone()))
+            })
+            .collect()
+    }
+
+    /// Whether `resource` is merged into an existing target on `platform`
+    /// instead of replacing it
+    fn merges_into_existing(&self, platform: &Platform, resource: &DiscoveredResource) -> bool {
+        if self.is_passthrough(platform) {
+            return false;
+        }
+        if !platform.append_keys_for(&resource.bundle_path).is_empty() {
+            return true;
+        }
+        match self.merge_strategy(platform, resource) {
+            crate::platform::MergeStrategy::Yaml => true,
+            crate::platform::MergeStrategy::Replace => false,
+            crate::platform::MergeStrategy::Composite if self.options.dedupe_composite => true,
+            _ => self.overridden_strategy(resource).is_some(),
+        }
+    }
+
+    /// Split platforms into those installed from the bundle's main source and
+    /// those with a per-platform ref override
+    fn partition_platforms<'b>(
+        &self,
+        bundle: &'b ResolvedBundle,
+    ) -> (Vec<Platform>, Vec<(Platform, &'b PathBuf)>) {
+        let mut default_platforms = Vec::new();
+        let mut overridden_platforms = Vec::new();
+        for platform in &self.platforms {
+            match bundle.platform_sources.get(&platform.id) {
+                Some(source) => overridden_platforms.push((platform.clone(), &source.path)),
+                None => default_platforms.push(platform.clone()),
+            }
+        }
+        (default_platforms, overridden_platforms)
+    }
+
+    /// Install a single platform from the content cached for its ref override
+    fn install_platform_override(
+        &self,
+        bundle: &ResolvedBundle,
+        platform: &Platform,
+        source_path: &Path,
+        installed_files: &mut HashMap<String, InstalledFile>,
+    ) -> Result<HashMap<String, Vec<String>>> {
+        let platform_bundle = ResolvedBundle {
+            source_path: source_path.to_path_buf(),
+            ..bundle.clone()
+        };
+        let resources = self.bundle_resources(&bundle.name, source_path);
+        self.install_resources_for_bundle(
+            &resources,
+            &platform_bundle,
+            std::slice::from_ref(platform),
+            installed_files,
+        )
+    }
+
+    /// Install resources for `platforms`, returning the MCP servers merged per config path
     fn install_resources_for_bundle(
         &self,
         resources: &[DiscoveredResource],
         bundle: &ResolvedBundle,
+        platforms: &[Platform],
         installed_files: &mut HashMap<String, InstalledFile>,
-    ) -> Result<()> {
-        for resource in resources {
+    ) -> Result<HashMap<String, Vec<String>>> {
+        // Passthrough platforms get every file verbatim, including mcp_servers/
+        let (passthrough, platforms): (Vec<Platform>, Vec<Platform>) = platforms
+            .iter()
+            .cloned()
+            .partition(|platform| self.is_passthrough(platform));
+        for resource in resources.iter().filter(|_| !passthrough.is_empty()) {
             Self::install_resource_across_platforms(
                 self,
                 resource,
                 bundle,
+                &passthrough,
                 installed_files,
-                &self.format_registry,
             )?;
         }
+
+        for resource in resources.iter().filter(|r| !mcp::is_mcp_server_resource(r)) {
+            Self::install_resource_across_platforms(
+                self,
+                resource,
+                bundle,
+                &platforms,
+                installed_files,
+            )?;
+        }
+        for platform in &platforms {
+            self.rewrite_skill_links(resources, bundle, platform, installed_files)?;
+        }
+        self.install_mcp_servers(resources, bundle, &platforms, installed_files)
+    }
+
+    /// Point relative links in installed skill markdown at the installed
+    /// location of the linked bundle files
+    fn rewrite_skill_links(
+        &self,
+        resources: &[DiscoveredResource],
+        bundle: &ResolvedBundle,
+        platform: &Platform,
+        installed_files: &HashMap<String, InstalledFile>,
+    ) -> Result<()> {
+        let skills: Vec<&DiscoveredResource> = resources
+            .iter()
+            .filter(|r| r.resource_type == "skills")
+            .collect();
+        let targets: links::TargetMap = skills
+            .iter()
+            .map(|r| {
+                let target = self.calculate_target_path(r, bundle, platform);
+                (r.bundle_path.clone(), target)
+            })
+            .collect();
+
+        let rewritable = skills
+            .iter()
+            .filter(|r| template::is_templatable(&r.bundle_path))
+            .filter_map(|r| targets.get(&r.bundle_path).map(|target| (r, target)))
+            // Files left alone by --copy-only-new were not installed by us
+            .filter(|(_, target)| {
+                !self.options.selection.copy_only_new || was_installed(target, installed_files)
+            });
+        for (resource, target) in rewritable {
+            let ctx = links::LinkContext {
+                source: &resource.bundle_path,
+                target,
+                targets: &targets,
+            };
+            links::rewrite_file(&ctx, self.options.verify)?;
+        }
         Ok(())
     }
 
+    /// Merge `mcp_servers/` files into each platform's MCP config
+    ///
+    /// Returns the merged server names per config path (relative to the
+    /// workspace root) so they can be un-merged later.
+    fn install_mcp_servers(
+        &self,
+        resources: &[DiscoveredResource],
+        bundle: &ResolvedBundle,
+        platforms: &[Platform],
+        installed_files: &mut HashMap<String, InstalledFile>,
+    ) -> Result<HashMap<String, Vec<String>>> {
+        let servers: Vec<&DiscoveredResource> = resources
+            .iter()
+            .filter(|r| mcp::is_mcp_server_resource(r))
+            .collect();
+        let mut merged = HashMap::new();
+        if servers.is_empty() {
+            return Ok(merged);
+        }
+
+        let targets: Vec<(PathBuf, crate::platform::ArrayMergePolicy)> = platforms
+            .iter()
+            .filter_map(|platform| {
+                mcp::mcp_config_target(platform, self.workspace_root)
+                    .map(|target| (target, mcp::mcp_array_policy(platform)))
+            })
+            .filter(|(target, _)| !self.is_preexisting(target, installed_files))
+            .collect();
+        for (target, arrays) in targets {
+            let names = self.merge_mcp_target(&servers, &target, arrays)?;
+            record_merged_files(installed_files, &servers, &bundle.name, &target);
+            let location = target.strip_prefix(self.workspace_root).unwrap_or(&target);
+            merged.insert(location.display().to_string(), names);
+        }
+        Ok(merged)
+    }
+
     fn install_resource_across_platforms(
         installer: &Installer,
         resource: &DiscoveredResource,
         bundle: &ResolvedBundle,
+        platforms: &[Platform],
         installed_files: &mut HashMap<String, InstalledFile>,
-        format_registry: &Arc<FormatRegistry>,
     ) -> Result<()> {
-        for platform in &installer.platforms {
-            let target_path = installer.calculate_target_path(resource, bundle, platform);
-            let ctx = ResourceInstallContext {
-                installer,
-                target_path: target_path.clone(),
-                platform,
-                bundle_name: &bundle.name,
-                resource_type: &resource.resource_type,
-            };
-            Installer::install_resource_for_platform(
-                &ctx,
-                resource,
-                installed_files,
-                format_registry,
-            )?;
+        let provenance = installer
+            .options
+            .content
+            .stamp
+            .then(|| stamp::provenance(bundle));
+        let filter = installer.platform_filter(resource);
+        let targets: Vec<(&Platform, PathBuf)> = platforms
+            .iter()
+            .filter(|platform| filter.as_ref().is_none_or(|ids| ids.contains(&platform.id)))
+            .map(|platform| {
+                (
+                    platform,
+                    installer.calculate_target_path(resource, bundle, platform),
+                )
+            })
+            .filter(|(_, target)| !installer.is_preexisting(target, installed_files))
+            .collect();
+
+        if installer.is_unchanged(resource, bundle, &targets) {
+            record_unchanged_targets(installed_files, resource, &bundle.name, &targets);
+            return Ok(());
+        }
+
+        let results = map_ordered(
+            &targets,
+            concurrency_for_targets(installer.options.concurrency, &targets),
+            |(platform, target_path)| {
+                let ctx = ResourceInstallContext {
+                    installer,
+                    target_path: target_path.clone(),
+                    platform,
+                    bundle_name: &bundle.name,
+                    provenance: provenance.as_deref(),
+                    merge: installer.merge_strategy(platform, resource),
+                };
+                Installer::install_resource_for_platform(&ctx, resource, &installer.format_registry)
+            },
+        );
+
+        // Record in platform order so the result does not depend on scheduling
+        for ((_, target_path), result) in targets.iter().zip(results) {
+            result?;
+            record_installed_file(installed_files, resource, &bundle.name, target_path);
         }
         Ok(())
     }
 
+    /// Merge `servers` into the MCP config at `target`, returning the merged server names
+    fn merge_mcp_target(
+        &self,
+        servers: &[&DiscoveredResource],
+        target: &Path,
+        arrays: crate::platform::ArrayMergePolicy,
+    ) -> Result<Vec<String>> {
+        self.backup_and_write(target, || {
+            mcp::merge_server_files(
+                servers,
+                target,
+                self.mcp_strategy(),
+                arrays,
+                self.options.verify,
+            )
+        })
+    }
+
     pub fn install_bundles(&mut self, bundles: &[ResolvedBundle]) -> Result<Vec<WorkspaceBundle>> {
         let mut results = Vec::new();
 
@@ -370,4 +956,1130 @@ impl<'a> Installer<'a> {
     pub fn installed_files(&self) -> &HashMap<String, InstalledFile> {
         &self.installed_files
     }
+
+    /// Every file installing `bundles` would write, without writing anything
+    pub fn plan(&self, bundles: &[ResolvedBundle]) -> plan::InstallPlan {
+        let mut collector = plan::PlanCollector::new(self.workspace_root);
+        let pairs = bundles.iter().flat_map(|bundle| {
+            self.platforms
+                .iter()
+                .map(move |platform| (bundle, platform))
+        });
+        for (bundle, platform) in pairs {
+            let source_path = bundle
+                .platform_sources
+                .get(&platform.id)
+                .map_or(&bundle.source_path, |source| &source.path);
+            let platform_bundle = ResolvedBundle {
+                source_path: source_path.clone(),
+                ..bundle.clone()
+            };
+            let resources = self.bundle_resources(&bundle.name, source_path);
+            self.plan_platform(&mut collector, &platform_bundle, &resources, platform);
+        }
+        collector.finish()
+    }
+
+    /// Files of `bundles` that no selected platform would install
+    ///
+    /// Every file in a bundle subdirectory is expected to be installed
+    /// somewhere; one with no planned target usually sits in an unrecognized
+    /// directory or is excluded for every platform. The workspace root bundle
+    /// is not checked.
+    pub fn unmatched_files(&self, bundles: &[ResolvedBundle]) -> Vec<plan::UnmatchedFile> {
+        let planned: HashSet<(String, String)> = self
+            .plan(bundles)
+            .operations
+            .into_iter()
+            .map(|op| (op.bundle, op.source))
+            .collect();
+        bundles
+            .iter()
+            .filter(|bundle| bundle.source_path != self.workspace_root)
+            .flat_map(|bundle| {
+                discovery::candidate_files(&bundle.source_path)
+                    .into_iter()
+                    .map(|relative| plan::UnmatchedFile {
+                        bundle: bundle.name.clone(),
+                        path: relative.to_string_lossy().replace('\\', "/"),
+                    })
+            })
+            .filter(|file| !planned.contains(&(file.bundle.clone(), file.path.clone())))
+            .collect()
+    }
+
+    fn plan_platform(
+        &self,
+        collector: &mut plan::PlanCollector<'_>,
+        bundle: &ResolvedBundle,
+        resources: &[DiscoveredResource],
+        platform: &Platform,
+    ) {
+        let mcp_target = mcp::mcp_config_target(platform, self.workspace_root);
+        let writes = resources
+            .iter()
+            .filter(|resource| {
+                self.platform_filter(resource)
+                    .is_none_or(|ids| ids.contains(&platform.id))
+            })
+            .filter_map(|resource| {
+                let (target, merge) =
+                    self.planned_target(resource, bundle, platform, mcp_target.as_ref())?;
+                Some((resource, target, merge))
+            });
+        for (resource, target, merge) in writes {
+            collector.record(&plan::PlannedWrite {
+                bundle: &bundle.name,
+                source: &resource.bundle_path,
+                platform: &platform.id,
+                target: &target,
+                merge,
+            });
+        }
+    }
+
+    /// Target `resource` would be written to on `platform` and how, or `None`
+    /// for an MCP server resource on a platform without an MCP config
+    fn planned_target(
+        &self,
+        resource: &DiscoveredResource,
+        bundle: &ResolvedBundle,
+        platform: &Platform,
+        mcp_target: Option<&PathBuf>,
+    ) -> Option<(PathBuf, crate::platform::MergeStrategy)> {
+        if self.is_passthrough(platform) {
+            let target = self.calculate_target_path(resource, bundle, platform);
+            return Some((target, crate::platform::MergeStrategy::Replace));
+        }
+        if mcp::is_mcp_server_resource(resource) {
+            return mcp_target.map(|target| (target.clone(), self.mcp_strategy()));
+        }
+        let target = self.calculate_target_path(resource, bundle, platform);
+        Some((target, self.merge_strategy(platform, resource)))
+    }
+
+    /// Targets replaced by more than one bundle, in install order
+    pub fn conflicts(&self) -> &[InstallConflict] {
+        &self.conflicts
+    }
+}
+
+/// Registry of the built-in format converters, without the Gemini TOML one
+/// unless `gemini_toml` is set
+fn builtin_registry(gemini_toml: bool) -> FormatRegistry {
+    let mut registry = FormatRegistry::new();
+    let _ = registry.register_builtins();
+    if !gemini_toml {
+        registry.unregister("gemini");
+    }
+    registry
+}
+
+/// Whether `source` was written to `target` during this install
+fn was_installed_from(
+    installed_files: &HashMap<String, InstalledFile>,
+    source: &Path,
+    target: &Path,
+) -> bool {
+    installed_files
+        .get(&source.display().to_string())
+        .is_some_and(|f| f.target_paths.contains(&target.display().to_string()))
+}
+
+/// Make `bundle_name` the owner of `target`, returning the conflict when
+/// another bundle replaced it before
+fn claim_target(
+    owners: &mut HashMap<PathBuf, (String, PathBuf)>,
+    workspace_root: &Path,
+    bundle_name: &str,
+    target: &Path,
+    source: &Path,
+) -> Option<InstallConflict> {
+    let (first_bundle, first_source) = owners.insert(
+        target.to_path_buf(),
+        (bundle_name.to_string(), source.to_path_buf()),
+    )?;
+    (first_bundle != bundle_name).then(|| InstallConflict {
+        first_bundle,
+        first_source,
+        second_bundle: bundle_name.to_string(),
+        second_source: source.to_path_buf(),
+        target: target
+            .strip_prefix(workspace_root)
+            .unwrap_or(target)
+            .to_path_buf(),
+    })
+}
+
+/// Worker count for writing `targets`; sequential when two platforms share a target
+fn concurrency_for_targets(concurrency: usize, targets: &[(&Platform, PathBuf)]) -> usize {
+    let distinct: HashSet<&PathBuf> = targets.iter().map(|(_, target)| target).collect();
+    if distinct.len() == targets.len() {
+        concurrency
+    } else {
+        1
+    }
+}
+
+/// True when some resource was installed to `target` in this run
+fn was_installed(target: &Path, installed_files: &HashMap<String, InstalledFile>) -> bool {
+    let target = target.display().to_string();
+    installed_files
+        .values()
+        .any(|f| f.target_paths.contains(&target))
+}
+
+/// Record that `resource` was installed to `target`
+fn record_installed_file(
+    installed_files: &mut HashMap<String, InstalledFile>,
+    resource: &DiscoveredResource,
+    bundle_name: &str,
+    target: &Path,
+) {
+    let key = resource.bundle_path.display().to_string();
+    let entry = installed_files.entry(key).or_insert_with(|| InstalledFile {
+        bundle_path: bundle_name.to_string(),
+        resource_type: resource.resource_type.clone(),
+        target_paths: vec![],
+    });
+    entry.target_paths.push(target.display().to_string());
+}
+
+/// Record `resource` at every target it was left unchanged at (`--since-file`)
+fn record_unchanged_targets(
+    installed_files: &mut HashMap<String, InstalledFile>,
+    resource: &DiscoveredResource,
+    bundle_name: &str,
+    targets: &[(&Platform, PathBuf)],
+) {
+    for (_, target) in targets {
+        record_installed_file(installed_files, resource, bundle_name, target);
+    }
+}
+
+/// Current content of a composite target, empty when it does not exist yet
+fn read_composite_target(target: &Path) -> Result<String> {
+    if !target.is_file() {
+        return Ok(String::new());
+    }
+    std::fs::read_to_string(target).map_err(|e| AugentError::FileReadFailed {
+        path: target.display().to_string(),
+        reason: e.to_string(),
+    })
+}
+
+/// Merge the freshly written `target` content into `existing` with `strategy`,
+/// then union the arrays at the `append` key paths
+fn write_merged_target(
+    target: &Path,
+    existing: &str,
+    strategy: Option<crate::platform::MergeStrategy>,
+    append: &[String],
+    verify: bool,
+) -> Result<()> {
+    let mut merged = read_composite_target(target)?;
+    if let Some(strategy) = strategy {
+        merged = strategy.merge_strings(existing, &merged)?;
+    }
+    if !append.is_empty() {
+        merged = crate::platform::merge::append_arrays(existing, &merged, target, append)?;
+    }
+    file_ops::write_file(target, merged, verify)
+}
+
+/// Put the freshly written `target` content into `owner`'s section of `existing`
+fn write_composite_section(target: &Path, existing: &str, owner: &str) -> Result<()> {
+    let content = read_composite_target(target)?;
+    let merged = crate::platform::merge::merge_composite_section(existing, owner, &content);
+    std::fs::write(target, merged).map_err(|e| AugentError::FileWriteFailed {
+        path: target.display().to_string(),
+        reason: e.to_string(),
+    })
+}
+
+/// Record that all `resources` were merged into `target`
+fn record_merged_files(
+    installed_files: &mut HashMap<String, InstalledFile>,
+    resources: &[&DiscoveredResource],
+    bundle_name: &str,
+    target: &Path,
+) {
+    for resource in resources {
+        record_installed_file(installed_files, resource, bundle_name, target);
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::*;
+    use crate::platform::output_ext::OutputExtOverride;
+
+    fn write_command(root: &Path, content: &str) {
+        let commands = root.join("commands");
+        std::fs::create_dir_all(&commands).expect("Failed to create commands dir");
+        std::fs::write(commands.join("review.md"), content).expect("Failed to write command");
+    }
+
+    #[test]
+    fn test_platform_sources_install_per_platform_content() {
+        let temp = tempfile::TempDir::new_in(crate::temp::temp_dir_base())
+            .expect("Failed to create temp dir");
+        let workspace_root = temp.path().join("workspace");
+        let v1 = temp.path().join("v1");
+        let v2 = temp.path().join("v2");
+        write_command(&v1, "review v1\n");
+        write_command(&v2, "review v2\n");
+
+        let platforms: Vec<Platform> = crate::platform::default_platforms()
+            .into_iter()
+            .filter(|p| p.id == "cursor" || p.id == "claude")
+            .collect();
+        assert_eq!(platforms.len(), 2);
+
+        let bundle = ResolvedBundle {
+            name: "review-tools".to_string(),
+            dependency: None,
+            source_path: v1,
+            resolved_sha: None,
+            resolved_ref: None,
+            git_source: None,
+            config: None,
+            platform_sources: HashMap::from([(
+                "cursor".to_string(),
+                crate::domain::PlatformSource {
+                    git_ref: "v2".to_string(),
+                    sha: "2222222222222222222222222222222222222222".to_string(),
+                    path: v2,
+                },
+            )]),
+            artifact_source: None,
+        };
+
+        let mut installer = Installer::new_with_dry_run(&workspace_root, platforms, false);
+        installer
+            .install_bundle(&bundle)
+            .expect("Failed to install bundle");
+
+        let read = |rel: &str| {
+            std::fs::read_to_string(workspace_root.join(rel)).expect("Failed to read installed")
+        };
+        assert!(read(".claude/commands/review.md").contains("review v1"));
+        assert!(read(".cursor/commands/review.md").contains("review v2"));
+    }
+
+    fn write_test_file(path: &Path, content: &str) {
+        file_ops::ensure_parent_dir(path).expect("Failed to create parent dir");
+        std::fs::write(path, content).expect("Failed to write file");
+    }
+
+    #[test]
+    fn test_dedupe_composite_replaces_bundle_section_on_reinstall() {
+        let temp = tempfile::TempDir::new_in(crate::temp::temp_dir_base())
+            .expect("Failed to create temp dir");
+        let workspace_root = temp.path().join("workspace");
+        let bundle_dir = temp.path().join("bundle");
+        let target = workspace_root.join(".claude/AGENTS.md");
+        write_test_file(&target, "Local notes.\n");
+        let platforms: Vec<Platform> = crate::platform::default_platforms()
+            .into_iter()
+            .filter(|p| p.id == "claude")
+            .collect();
+        let bundle = ResolvedBundle {
+            name: "agents".to_string(),
+            dependency: None,
+            source_path: bundle_dir.clone(),
+            resolved_sha: None,
+            resolved_ref: None,
+            git_source: None,
+            config: None,
+            platform_sources: HashMap::new(),
+            artifact_source: None,
+        };
+        let options = InstallerOptions {
+            dedupe_composite: true,
+            ..InstallerOptions::default()
+        };
+
+        for content in ["Run the linter first.\n", "Run the tests first.\n"] {
+            write_test_file(&bundle_dir.join("AGENTS.md"), content);
+            Installer::new_with_dry_run(&workspace_root, platforms.clone(), false)
+                .with_options(options.clone())
+                .install_bundle(&bundle)
+                .expect("Failed to install bundle");
+        }
+
+        let merged = std::fs::read_to_string(&target).expect("Failed to read target");
+        assert!(merged.starts_with("Local notes."));
+        assert!(!merged.contains("Run the linter first."));
+        assert_eq!(merged.matches("Run the tests first.").count(), 1);
+        assert_eq!(merged.matches("<!-- augent:begin agents -->").count(), 1);
+    }
+
+    fn install_with_concurrency(
+        workspace_root: &Path,
+        bundle_dir: &Path,
+        concurrency: usize,
+    ) -> Vec<String> {
+        let platforms: Vec<Platform> = crate::platform::default_platforms()
+            .into_iter()
+            .filter(|p| p.id == "claude" || p.id == "cursor")
+            .collect();
+        let bundle = ResolvedBundle {
+            name: "parallel-bundle".to_string(),
+            dependency: None,
+            source_path: bundle_dir.to_path_buf(),
+            resolved_sha: None,
+            resolved_ref: None,
+            git_source: None,
+            config: None,
+            platform_sources: HashMap::new(),
+            artifact_source: None,
+        };
+
+        let mut installer = Installer::new_with_dry_run(workspace_root, platforms, false)
+            .with_options(InstallerOptions {
+                concurrency,
+                ..InstallerOptions::default()
+            });
+        installer
+            .install_bundle(&bundle)
+            .expect("Failed to install bundle");
+
+        let mut targets: Vec<String> = installer
+            .installed_files()
+            .values()
+            .flat_map(|file| file.target_paths.iter())
+            .map(|target| {
+                Path::new(target)
+                    .strip_prefix(workspace_root)
+                    .expect("Target should be inside workspace")
+                    .display()
+                    .to_string()
+            })
+            .collect();
+        targets.sort();
+        targets
+    }
+
+    #[test]
+    fn test_concurrency_matches_sequential_install() {
+        let temp = tempfile::TempDir::new_in(crate::temp::temp_dir_base())
+            .expect("Failed to create temp dir");
+        let bundle_dir = temp.path().join("bundle");
+        write_command(
+            &bundle_dir,
+            "---\ndescription: Review\n---\n\nReview the diff.\n",
+        );
+        let sequential_root = temp.path().join("sequential");
+        let parallel_root = temp.path().join("parallel");
+
+        let sequential = install_with_concurrency(&sequential_root, &bundle_dir, 1);
+        let parallel = install_with_concurrency(&parallel_root, &bundle_dir, 4);
+
+        assert_eq!(sequential.len(), 2);
+        assert_eq!(sequential, parallel);
+        let read = |root: &Path, target: &str| {
+            std::fs::read(root.join(target)).expect("Failed to read installed file")
+        };
+        for target in &sequential {
+            assert_eq!(read(&sequential_root, target), read(&parallel_root, target));
+        }
+    }
+
+    fn install_shared_target(workspace_root: &Path, bundle_dir: &Path, order: &[&str]) -> String {
+        let platforms: Vec<Platform> = crate::platform::default_platforms()
+            .into_iter()
+            .filter(|p| p.id == "claude" || p.id == "cursor")
+            .map(|mut p| {
+                p.relocate(".shared");
+                p
+            })
+            .collect();
+        let order: Vec<Platform> = order
+            .iter()
+            .filter_map(|id| platforms.iter().find(|p| p.id == *id).cloned())
+            .collect();
+        let platforms = crate::platform::detection::apply_platform_order(platforms, &order);
+        let bundle = ResolvedBundle {
+            name: "shared-bundle".to_string(),
+            dependency: None,
+            source_path: bundle_dir.to_path_buf(),
+            resolved_sha: None,
+            resolved_ref: None,
+            git_source: None,
+            config: None,
+            platform_sources: HashMap::new(),
+            artifact_source: None,
+        };
+
+        let mut installer = Installer::new_with_dry_run(workspace_root, platforms, false);
+        installer
+            .install_bundle(&bundle)
+            .expect("Failed to install bundle");
+        std::fs::read_to_string(workspace_root.join(".shared/commands/review.md"))
+            .expect("Failed to read installed file")
+    }
+
+    #[test]
+    fn test_shared_target_follows_platform_order() {
+        let temp = tempfile::TempDir::new_in(crate::temp::temp_dir_base())
+            .expect("Failed to create temp dir");
+        let bundle_dir = temp.path().join("bundle");
+        write_command(
+            &bundle_dir,
+            "---\ndescription: Review\nclaude:\n  model: opus\ncursor:\n  model: fast\n---\n\nReview.\n",
+        );
+
+        let cursor_last =
+            install_shared_target(&temp.path().join("a"), &bundle_dir, &["claude", "cursor"]);
+        let claude_last =
+            install_shared_target(&temp.path().join("b"), &bundle_dir, &["cursor", "claude"]);
+
+        assert!(cursor_last.contains("model: fast"));
+        assert!(claude_last.contains("model: opus"));
+    }
+
+    #[test]
+    fn test_output_ext_override_changes_only_matching_files() {
+        let temp = tempfile::TempDir::new_in(crate::temp::temp_dir_base())
+            .expect("Failed to create temp dir");
+        let workspace_root = temp.path().join("workspace");
+        let bundle_dir = temp.path().join("bundle");
+        write_command(&bundle_dir, "Review the diff.\n");
+        std::fs::create_dir_all(bundle_dir.join("rules")).expect("Failed to create rules dir");
+        std::fs::write(bundle_dir.join("rules/lint.md"), "Lint everything.\n")
+            .expect("Failed to write rule");
+
+        let platforms: Vec<Platform> = crate::platform::default_platforms()
+            .into_iter()
+            .filter(|p| p.id == "cursor" || p.id == "claude")
+            .collect();
+        let bundle = ResolvedBundle {
+            name: "ext-bundle".to_string(),
+            dependency: None,
+            source_path: bundle_dir,
+            resolved_sha: None,
+            resolved_ref: None,
+            git_source: None,
+            config: None,
+            platform_sources: HashMap::new(),
+            artifact_source: None,
+        };
+        let overrides =
+            vec![OutputExtOverride::parse("cursor:rules=txt").expect("Failed to parse override")];
+
+        let mut installer = Installer::new_with_dry_run(&workspace_root, platforms, false)
+            .with_options(InstallerOptions {
+                content: ContentOptions {
+                    output_ext_overrides: overrides,
+                    ..ContentOptions::default()
+                },
+                ..InstallerOptions::default()
+            });
+        installer
+            .install_bundle(&bundle)
+            .expect("Failed to install bundle");
+
+        assert!(workspace_root.join(".cursor/rules/lint.txt").exists());
+        assert!(!workspace_root.join(".cursor/rules/lint.md").exists());
+        assert!(workspace_root.join(".cursor/commands/review.md").exists());
+        assert!(workspace_root.join(".claude/rules/lint.md").exists());
+    }
+
+    #[test]
+    fn test_passthrough_platform_gets_raw_bundle_layout() {
+        let temp = tempfile::TempDir::new_in(crate::temp::temp_dir_base())
+            .expect("Failed to create temp dir");
+        let workspace_root = temp.path().join("workspace");
+        let bundle_dir = temp.path().join("bundle");
+        let rule = "---\ndescription: Lint\nglobs: \"*.rs\"\n---\n\nLint everything.\n";
+        std::fs::create_dir_all(bundle_dir.join("rules")).expect("Failed to create rules dir");
+        std::fs::write(bundle_dir.join("rules/lint.md"), rule).expect("Failed to write rule");
+        write_command(&bundle_dir, "Review the diff.\n");
+
+        let platforms: Vec<Platform> = crate::platform::default_platforms()
+            .into_iter()
+            .filter(|p| p.id == "cursor" || p.id == "claude")
+            .collect();
+        let bundle = ResolvedBundle {
+            name: "raw-bundle".to_string(),
+            dependency: None,
+            source_path: bundle_dir,
+            resolved_sha: None,
+            resolved_ref: None,
+            git_source: None,
+            config: None,
+            platform_sources: HashMap::new(),
+            artifact_source: None,
+        };
+
+        let mut installer = Installer::new_with_dry_run(&workspace_root, platforms, false)
+            .with_options(InstallerOptions {
+                content: ContentOptions {
+                    stamp: true,
+                    ..ContentOptions::default()
+                },
+                passthrough_platforms: vec!["cursor".to_string()],
+                ..InstallerOptions::default()
+            });
+        installer
+            .install_bundle(&bundle)
+            .expect("Failed to install bundle");
+
+        let read = |rel: &str| {
+            std::fs::read_to_string(workspace_root.join(rel)).expect("Failed to read installed")
+        };
+        assert_eq!(read(".cursor/rules/lint.md"), rule);
+        assert_eq!(read(".cursor/commands/review.md"), "Review the diff.\n");
+        assert!(!workspace_root.join(".cursor/rules/lint.mdc").exists());
+        assert_ne!(read(".claude/commands/review.md"), "Review the diff.\n");
+    }
+
+    fn dir_bundle(name: &str, source_path: PathBuf) -> ResolvedBundle {
+        ResolvedBundle {
+            name: name.to_string(),
+            dependency: None,
+            source_path,
+            resolved_sha: None,
+            resolved_ref: None,
+            git_source: None,
+            config: None,
+            platform_sources: HashMap::new(),
+            artifact_source: None,
+        }
+    }
+
+    #[test]
+    fn test_append_rule_keeps_tools_of_every_bundle() {
+        let temp = tempfile::TempDir::new_in(crate::temp::temp_dir_base())
+            .expect("Failed to create temp dir");
+        let workspace_root = temp.path().join("workspace");
+        let bundles: Vec<ResolvedBundle> = [("first", "Read"), ("second", "Bash")]
+            .into_iter()
+            .map(|(name, tool)| {
+                let bundle_dir = temp.path().join(name);
+                std::fs::create_dir_all(bundle_dir.join("agents"))
+                    .expect("Failed to create agents dir");
+                std::fs::write(
+                    bundle_dir.join("agents/helper.md"),
+                    format!("---\nname: helper\ntools:\n- {tool}\n---\n\nHelp.\n"),
+                )
+                .expect("Failed to write agent");
+                dir_bundle(name, bundle_dir)
+            })
+            .collect();
+        let mut platforms: Vec<Platform> = crate::platform::default_platforms()
+            .into_iter()
+            .filter(|p| p.id == "claude")
+            .collect();
+        platforms[0]
+            .transforms
+            .iter_mut()
+            .filter(|rule| rule.from == "agents/**/*.md")
+            .for_each(|rule| rule.append = vec!["tools".to_string()]);
+
+        Installer::new_with_dry_run(&workspace_root, platforms, false)
+            .install_bundles(&bundles)
+            .expect("Failed to install bundles");
+
+        let agent = std::fs::read_to_string(workspace_root.join(".claude/agents/helper.md"))
+            .expect("Failed to read agent");
+        let (frontmatter, _) =
+            crate::universal::parse_frontmatter_and_body(&agent).expect("Agent has frontmatter");
+        let tools: Vec<&str> = frontmatter["tools"]
+            .as_sequence()
+            .expect("tools is a list")
+            .iter()
+            .filter_map(serde_yaml::Value::as_str)
+            .collect();
+        assert_eq!(tools, vec!["Read", "Bash"]);
+    }
+
+    #[test]
+    fn test_platform_filter_key_restricts_resource_to_listed_platforms() {
+        let temp = tempfile::TempDir::new_in(crate::temp::temp_dir_base())
+            .expect("Failed to create temp dir");
+        let workspace_root = temp.path().join("workspace");
+        let bundle_dir = temp.path().join("bundle");
+        write_command(
+            &bundle_dir,
+            "---\ndescription: Review\nplatforms: cursor\n---\n\nReview the diff.\n",
+        );
+
+        let platforms: Vec<Platform> = crate::platform::default_platforms()
+            .into_iter()
+            .filter(|p| p.id == "claude" || p.id == "cursor")
+            .collect();
+        let bundle = dir_bundle("filtered", bundle_dir);
+
+        let mut installer = Installer::new_with_dry_run(&workspace_root, platforms, false);
+        let planned: Vec<String> = installer
+            .plan(std::slice::from_ref(&bundle))
+            .operations
+            .into_iter()
+            .map(|op| op.platform)
+            .collect();
+        assert_eq!(planned, vec!["cursor".to_string()]);
+
+        installer
+            .install_bundle(&bundle)
+            .expect("Failed to install bundle");
+
+        let cursor_command =
+            std::fs::read_to_string(workspace_root.join(".cursor/commands/review.md"))
+                .expect("Failed to read installed command");
+        assert!(cursor_command.contains("Review the diff."));
+        assert!(!cursor_command.contains("platforms"));
+        assert!(!workspace_root.join(".claude/commands/review.md").exists());
+    }
+
+    #[test]
+    fn test_strategy_override_merges_resource_type_into_existing_target() {
+        let temp = tempfile::TempDir::new_in(crate::temp::temp_dir_base())
+            .expect("Failed to create temp dir");
+        let workspace_root = temp.path().join("workspace");
+        let bundle_dir = temp.path().join("bundle");
+        write_command(&bundle_dir, "Review the diff.\n");
+        let target = workspace_root.join(".claude/commands/review.md");
+        let platforms: Vec<Platform> = crate::platform::default_platforms()
+            .into_iter()
+            .filter(|p| p.id == "claude")
+            .collect();
+        let bundle = dir_bundle("review", bundle_dir);
+
+        // Platform default: the bundle's command replaces the existing file
+        std::fs::create_dir_all(target.parent().expect("target has a parent"))
+            .expect("Failed to create dir");
+        std::fs::write(&target, "Local notes.\n").expect("Failed to write target");
+        Installer::new_with_dry_run(&workspace_root, platforms.clone(), false)
+            .install_bundle(&bundle)
+            .expect("Failed to install bundle");
+        let replaced = std::fs::read_to_string(&target).expect("Failed to read target");
+        assert!(!replaced.contains("Local notes."));
+
+        // Overridden: the command is appended to the existing file
+        std::fs::write(&target, "Local notes.\n").expect("Failed to write target");
+        let overrides = vec![
+            crate::platform::strategy_override::StrategyOverride::parse("commands=composite")
+                .expect("Failed to parse override"),
+        ];
+        let mut installer = Installer::new_with_dry_run(&workspace_root, platforms, false)
+            .with_options(InstallerOptions {
+                strategy_overrides: overrides,
+                ..InstallerOptions::default()
+            });
+        let merges: Vec<crate::platform::MergeStrategy> = installer
+            .plan(std::slice::from_ref(&bundle))
+            .operations
+            .into_iter()
+            .map(|op| op.merge)
+            .collect();
+        assert_eq!(merges, vec![crate::platform::MergeStrategy::Composite]);
+        installer
+            .install_bundle(&bundle)
+            .expect("Failed to install bundle");
+        let appended = std::fs::read_to_string(&target).expect("Failed to read target");
+        assert!(appended.starts_with("Local notes."));
+        assert!(appended.contains("Review the diff."));
+    }
+
+    #[test]
+    fn test_unmatched_files_lists_files_in_unrecognized_dirs() {
+        let temp = tempfile::TempDir::new_in(crate::temp::temp_dir_base())
+            .expect("Failed to create temp dir");
+        let workspace_root = temp.path().join("workspace");
+        let bundle_dir = temp.path().join("bundle");
+        write_command(&bundle_dir, "Review the diff.\n");
+        std::fs::create_dir_all(bundle_dir.join("prompts")).expect("Failed to create dir");
+        std::fs::write(bundle_dir.join("prompts/draft.md"), "Draft.\n")
+            .expect("Failed to write prompt");
+        std::fs::write(bundle_dir.join("README.md"), "# Bundle\n").expect("Failed to write");
+
+        let platforms: Vec<Platform> = crate::platform::default_platforms()
+            .into_iter()
+            .filter(|p| p.id == "claude")
+            .collect();
+        let installer = Installer::new_with_dry_run(&workspace_root, platforms, true);
+
+        let unmatched = installer.unmatched_files(&[dir_bundle("layout", bundle_dir)]);
+        assert_eq!(
+            unmatched,
+            vec![plan::UnmatchedFile {
+                bundle: "layout".to_string(),
+                path: "prompts/draft.md".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_gemini_commands_stay_markdown_without_toml_conversion() {
+        let temp = tempfile::TempDir::new_in(crate::temp::temp_dir_base())
+            .expect("Failed to create temp dir");
+        let workspace_root = temp.path().join("workspace");
+        let bundle_dir = temp.path().join("bundle");
+        let command = "---\ndescription: Review\n---\n\nReview the diff.\n";
+        write_command(&bundle_dir, command);
+
+        let platforms: Vec<Platform> = crate::platform::default_platforms()
+            .into_iter()
+            .filter(|p| p.id == "gemini")
+            .collect();
+        let bundle = dir_bundle("md-bundle", bundle_dir);
+
+        let mut installer = Installer::new_with_dry_run(&workspace_root, platforms, false)
+            .with_options(InstallerOptions {
+                content: ContentOptions {
+                    gemini_toml: false,
+                    ..ContentOptions::default()
+                },
+                ..InstallerOptions::default()
+            });
+        installer
+            .install_bundle(&bundle)
+            .expect("Failed to install bundle");
+
+        let content = std::fs::read_to_string(workspace_root.join(".gemini/commands/review.md"))
+            .expect("Failed to read installed command");
+        assert!(content.contains("Review the diff."));
+        assert!(!workspace_root.join(".gemini/commands/review.toml").exists());
+    }
+
+    #[test]
+    fn test_failed_install_rolls_back_overwritten_files() {
+        let temp = tempfile::TempDir::new_in(crate::temp::temp_dir_base())
+            .expect("Failed to create temp dir");
+        let workspace_root = temp.path().join("workspace");
+        let bundle_dir = temp.path().join("bundle");
+        write_command(&bundle_dir, "new review\n");
+        for (dir, file) in [("rules", "lint.md"), ("agents", "helper.md")] {
+            std::fs::create_dir_all(bundle_dir.join(dir)).expect("Failed to create dir");
+            std::fs::write(bundle_dir.join(dir).join(file), "new\n").expect("Failed to write");
+        }
+
+        // The first two targets exist; the third is a directory, so writing it fails
+        let claude = workspace_root.join(".claude");
+        for (dir, file) in [("commands", "review.md"), ("rules", "lint.md")] {
+            std::fs::create_dir_all(claude.join(dir)).expect("Failed to create dir");
+            std::fs::write(claude.join(dir).join(file), "old\n").expect("Failed to write");
+        }
+        std::fs::create_dir_all(claude.join("agents/helper.md")).expect("Failed to create dir");
+
+        let platforms: Vec<Platform> = crate::platform::default_platforms()
+            .into_iter()
+            .filter(|p| p.id == "claude")
+            .collect();
+        let journal = Arc::new(FileJournal::new());
+        let mut installer = Installer::new_with_dry_run(&workspace_root, platforms, false)
+            .with_journal(Arc::clone(&journal));
+        let result = installer.install_bundle(&dir_bundle("failing", bundle_dir));
+        assert!(result.is_err());
+        assert_ne!(
+            std::fs::read_to_string(claude.join("commands/review.md")).expect("Failed to read"),
+            "old\n"
+        );
+
+        journal.rollback();
+
+        for rel in ["commands/review.md", "rules/lint.md"] {
+            let restored = std::fs::read_to_string(claude.join(rel)).expect("Failed to read");
+            assert_eq!(restored, "old\n", "{rel} was not restored");
+        }
+    }
+
+    #[test]
+    fn test_backup_dir_keeps_original_of_overwritten_targets() {
+        let temp = tempfile::TempDir::new_in(crate::temp::temp_dir_base())
+            .expect("Failed to create temp dir");
+        let workspace_root = temp.path().join("workspace");
+        let backup_dir = temp.path().join("backup");
+        let first = temp.path().join("first");
+        let second = temp.path().join("second");
+        write_command(&first, "first review\n");
+        write_command(&second, "second review\n");
+        std::fs::create_dir_all(first.join("rules")).expect("Failed to create rules dir");
+        std::fs::write(first.join("rules/lint.md"), "Lint.\n").expect("Failed to write rule");
+
+        let existing = workspace_root.join(".claude/commands/review.md");
+        std::fs::create_dir_all(existing.parent().expect("Has parent"))
+            .expect("Failed to create commands dir");
+        std::fs::write(&existing, "original review\n").expect("Failed to write command");
+
+        let platforms: Vec<Platform> = crate::platform::default_platforms()
+            .into_iter()
+            .filter(|p| p.id == "claude")
+            .collect();
+        let mut installer = Installer::new_with_dry_run(&workspace_root, platforms, false)
+            .with_options(InstallerOptions {
+                backup_dir: Some(backup_dir.clone()),
+                ..InstallerOptions::default()
+            });
+        installer
+            .install_bundles(&[dir_bundle("first", first), dir_bundle("second", second)])
+            .expect("Failed to install bundles");
+
+        let backed_up = std::fs::read_to_string(backup_dir.join(".claude/commands/review.md"))
+            .expect("Failed to read backup");
+        assert_eq!(backed_up, "original review\n");
+        assert!(!backup_dir.join(".claude/rules/lint.md").exists());
+    }
+
+    #[test]
+    fn test_no_new_dirs_refuses_to_create_missing_directory() {
+        let temp = tempfile::TempDir::new_in(crate::temp::temp_dir_base())
+            .expect("Failed to create temp dir");
+        let workspace_root = temp.path().join("workspace");
+        let bundle_dir = temp.path().join("bundle");
+        write_command(&bundle_dir, "Review.\n");
+        std::fs::create_dir_all(workspace_root.join(".claude"))
+            .expect("Failed to create platform dir");
+
+        let platforms: Vec<Platform> = crate::platform::default_platforms()
+            .into_iter()
+            .filter(|p| p.id == "claude")
+            .collect();
+        let bundles = [dir_bundle("tools", bundle_dir)];
+        let options = InstallerOptions {
+            new_dir_policy: file_ops::NewDirPolicy::Deny,
+            ..InstallerOptions::default()
+        };
+        let err = Installer::new_with_dry_run(&workspace_root, platforms.clone(), false)
+            .with_options(options.clone())
+            .install_bundles(&bundles)
+            .expect_err("Install should refuse to create .claude/commands");
+        assert!(matches!(
+            err,
+            crate::error::AugentError::NewDirectoryDenied { ref path } if path == ".claude/commands"
+        ));
+        assert!(!workspace_root.join(".claude/commands").exists());
+
+        std::fs::create_dir_all(workspace_root.join(".claude/commands"))
+            .expect("Failed to create commands dir");
+        Installer::new_with_dry_run(&workspace_root, platforms, false)
+            .with_options(options)
+            .install_bundles(&bundles)
+            .expect("Install into existing directories should succeed");
+        assert!(workspace_root.join(".claude/commands/review.md").is_file());
+    }
+
+    #[test]
+    fn test_bundles_replacing_same_target_are_reported_as_conflicts() {
+        let temp = tempfile::TempDir::new_in(crate::temp::temp_dir_base())
+            .expect("Failed to create temp dir");
+        let workspace_root = temp.path().join("workspace");
+        let first = temp.path().join("first");
+        let second = temp.path().join("second");
+        write_command(&first, "first review\n");
+        write_command(&second, "second review\n");
+        std::fs::create_dir_all(second.join("rules")).expect("Failed to create rules dir");
+        std::fs::write(second.join("rules/lint.md"), "Lint.\n").expect("Failed to write rule");
+
+        let platforms: Vec<Platform> = crate::platform::default_platforms()
+            .into_iter()
+            .filter(|p| p.id == "claude")
+            .collect();
+        let mut installer = Installer::new_with_dry_run(&workspace_root, platforms, false);
+        installer
+            .install_bundles(&[
+                dir_bundle("@a/first", first),
+                dir_bundle("@b/second", second),
+            ])
+            .expect("Failed to install bundles");
+
+        assert_eq!(
+            installer.conflicts(),
+            [InstallConflict {
+                first_bundle: "@a/first".to_string(),
+                first_source: PathBuf::from("commands/review.md"),
+                second_bundle: "@b/second".to_string(),
+                second_source: PathBuf::from("commands/review.md"),
+                target: PathBuf::from(".claude/commands/review.md"),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_skill_links_follow_installed_layout() {
+        let temp = tempfile::TempDir::new_in(crate::temp::temp_dir_base())
+            .expect("Failed to create temp dir");
+        let workspace_root = temp.path().join("workspace");
+        let bundle_dir = temp.path().join("bundle");
+        let skill_dir = bundle_dir.join("skills/deploy");
+        std::fs::create_dir_all(skill_dir.join("scripts")).expect("Failed to create skill dir");
+        std::fs::write(
+            skill_dir.join("SKILL.md"),
+            "# Deploy\n\nRun [the script](./scripts/run.sh).\n",
+        )
+        .expect("Failed to write skill");
+        std::fs::write(skill_dir.join("scripts/run.sh"), "echo deploy\n")
+            .expect("Failed to write script");
+
+        let platforms: Vec<Platform> = crate::platform::default_platforms()
+            .into_iter()
+            .filter(|p| p.id == "cursor" || p.id == "claude")
+            .collect();
+        let bundle = dir_bundle("skill-bundle", bundle_dir);
+        let overrides =
+            vec![OutputExtOverride::parse("claude:skills=txt").expect("Failed to parse override")];
+
+        let mut installer = Installer::new_with_dry_run(&workspace_root, platforms, false)
+            .with_options(InstallerOptions {
+                content: ContentOptions {
+                    output_ext_overrides: overrides,
+                    ..ContentOptions::default()
+                },
+                ..InstallerOptions::default()
+            });
+        installer
+            .install_bundle(&bundle)
+            .expect("Failed to install bundle");
+
+        let claude =
+            std::fs::read_to_string(workspace_root.join(".claude/skills/deploy/SKILL.txt"))
+                .expect("Failed to read claude skill");
+        assert!(claude.contains("[the script](./scripts/run.txt)"));
+        assert!(
+            workspace_root
+                .join(".claude/skills/deploy/scripts/run.txt")
+                .exists()
+        );
+        let cursor = std::fs::read_to_string(workspace_root.join(".cursor/skills/deploy/SKILL.md"))
+            .expect("Failed to read cursor skill");
+        assert!(cursor.contains("[the script](./scripts/run.sh)"));
+    }
+
+    #[test]
+    fn test_kiro_installs_rules_and_agents_into_steering() {
+        let temp = tempfile::TempDir::new_in(crate::temp::temp_dir_base())
+            .expect("Failed to create temp dir");
+        let workspace_root = temp.path().join("workspace");
+        let bundle_dir = temp.path().join("bundle");
+        std::fs::create_dir_all(bundle_dir.join("rules")).expect("Failed to create rules dir");
+        std::fs::write(
+            bundle_dir.join("rules/lint.md"),
+            "---\ndescription: Lint\nkiro:\n  inclusion: always\n---\n\nLint everything.\n",
+        )
+        .expect("Failed to write rule");
+        std::fs::write(bundle_dir.join("AGENTS.md"), "# Agents\n")
+            .expect("Failed to write AGENTS.md");
+
+        let platforms: Vec<Platform> = crate::platform::default_platforms()
+            .into_iter()
+            .filter(|p| p.id == "kiro")
+            .collect();
+        let bundle = ResolvedBundle {
+            name: "kiro-bundle".to_string(),
+            dependency: None,
+            source_path: bundle_dir,
+            resolved_sha: None,
+            resolved_ref: None,
+            git_source: None,
+            config: None,
+            platform_sources: HashMap::new(),
+            artifact_source: None,
+        };
+
+        let mut installer = Installer::new_with_dry_run(&workspace_root, platforms, false);
+        installer
+            .install_bundle(&bundle)
+            .expect("Failed to install bundle");
+
+        let rule = std::fs::read_to_string(workspace_root.join(".kiro/steering/lint.md"))
+            .expect("Rule should be installed into steering");
+        assert!(rule.contains("inclusion: always"));
+        assert!(!rule.contains("kiro:"));
+        assert!(workspace_root.join(".kiro/steering/AGENTS.md").exists());
+        assert!(!workspace_root.join(".kiro/rules").exists());
+    }
+
+    #[test]
+    fn test_mcp_servers_merged_into_platform_config() {
+        let temp = tempfile::TempDir::new_in(crate::temp::temp_dir_base())
+            .expect("Failed to create temp dir");
+        let workspace_root = temp.path().join("workspace");
+        let bundle_dir = temp.path().join("bundle");
+        write_test_file(
+            &bundle_dir.join("mcp_servers/filesystem.json"),
+            r#"{"command": "npx", "args": ["-y", "@modelcontextprotocol/server-filesystem"]}"#,
+        );
+        write_test_file(
+            &bundle_dir.join("mcp_servers/github.json"),
+            r#"{"mcpServers": {"github": {"command": "github-mcp"}}}"#,
+        );
+        let existing = workspace_root.join(".cursor/mcp.json");
+        write_test_file(
+            &existing,
+            r#"{"mcpServers": {"local": {"command": "local"}}}"#,
+        );
+
+        let platforms: Vec<Platform> = crate::platform::default_platforms()
+            .into_iter()
+            .filter(|p| p.id == "cursor")
+            .collect();
+        let bundle = dir_bundle("mcp-bundle", bundle_dir);
+
+        let mut installer = Installer::new_with_dry_run(&workspace_root, platforms, false);
+        let workspace_bundle = installer
+            .install_bundle(&bundle)
+            .expect("Failed to install bundle");
+
+        let content = std::fs::read_to_string(&existing).expect("Failed to read mcp.json");
+        let config: serde_json::Value =
+            serde_json::from_str(&content).expect("mcp.json should be valid JSON");
+        let servers = &config["mcpServers"];
+        assert_eq!(servers["filesystem"]["command"], "npx");
+        assert_eq!(servers["github"]["command"], "github-mcp");
+        assert_eq!(servers["local"]["command"], "local");
+        assert!(!workspace_root.join(".cursor/mcp_servers").exists());
+        let files = installer.installed_files();
+        assert!(files.contains_key("mcp_servers/github.json"));
+        let mut merged = workspace_bundle.mcp_servers[".cursor/mcp.json"].clone();
+        merged.sort();
+        assert_eq!(merged, ["filesystem", "github"]);
+    }
+
+    #[test]
+    fn test_yaml_merge_rule_deep_merges_into_existing_target() {
+        let temp = tempfile::TempDir::new_in(crate::temp::temp_dir_base())
+            .expect("Failed to create temp dir");
+        let workspace_root = temp.path().join("workspace");
+        let bundle_dir = temp.path().join("bundle");
+        let settings = bundle_dir.join("rules/settings.yaml");
+        file_ops::ensure_parent_dir(&settings).expect("Failed to create rules dir");
+        std::fs::write(&settings, "editor:\n  theme: dark\n").expect("Failed to write settings");
+
+        let existing = workspace_root.join(".tool/rules/settings.yaml");
+        file_ops::ensure_parent_dir(&existing).expect("Failed to create .tool dir");
+        std::fs::write(&existing, "editor:\n  tab_size: 2\nname: local\n")
+            .expect("Failed to write existing settings");
+
+        let platform = Platform::new("tool", "Tool", ".tool").with_transform(
+            crate::platform::TransformRule::new("rules/**/*.yaml", ".tool/rules/**/*.yaml")
+                .with_merge(crate::platform::MergeStrategy::Yaml),
+        );
+        let bundle = ResolvedBundle {
+            name: "settings-bundle".to_string(),
+            dependency: None,
+            source_path: bundle_dir,
+            resolved_sha: None,
+            resolved_ref: None,
+            git_source: None,
+            config: None,
+            platform_sources: HashMap::new(),
+            artifact_source: None,
+        };
+
+        let mut installer = Installer::new_with_dry_run(&workspace_root, vec![platform], false);
+        installer
+            .install_bundle(&bundle)
+            .expect("Failed to install bundle");
+
+        let merged: serde_yaml::Value = serde_yaml::from_str(
+            &std::fs::read_to_string(&existing).expect("Failed to read settings"),
+        )
+        .expect("Merged settings should be valid YAML");
+        assert_eq!(merged["editor"]["theme"], serde_yaml::Value::from("dark"));
+        assert_eq!(merged["editor"]["tab_size"], serde_yaml::Value::from(2));
+        assert_eq!(merged["name"], serde_yaml::Value::from("local"));
+    }
 }
@@ -0,0 +1,108 @@
+//! Installer options
+//!
+//! Settings chosen on the command line that change how an [`Installer`](super::Installer)
+//! writes resources. Options default to a plain install.
+
+use std::path::PathBuf;
+
+use super::file_ops::{CopyMode, NewDirPolicy};
+use super::template::TemplateVars;
+use crate::platform::output_ext::OutputExtOverride;
+use crate::platform::strategy_override::StrategyOverride;
+
+/// Options controlling how resources are written
+#[derive(Debug, Clone)]
+pub struct InstallerOptions {
+    /// How resource content is rewritten for its target
+    pub content: ContentOptions,
+    /// Which resources and targets are written
+    pub selection: SelectionOptions,
+    /// Merge composite targets in place, one marked section per bundle (`--dedupe-composite`)
+    pub dedupe_composite: bool,
+    /// Number of threads writing a resource's per-platform copies, 1 = sequential (`--concurrency`)
+    pub concurrency: usize,
+    /// Re-read every written file and compare it with what was written (`--verify`)
+    pub verify: bool,
+    /// Platform ids installed with the raw bundle layout (`--platform-passthrough`)
+    pub passthrough_platforms: Vec<String>,
+    /// Merge strategies replacing the platform defaults for resource types (`--strategy-override`)
+    pub strategy_overrides: Vec<StrategyOverride>,
+    /// Copy or symlink files whose content is installed unchanged (`--link`)
+    pub copy_mode: CopyMode,
+    /// Directory existing targets are copied to before being overwritten (`--backup`)
+    pub backup_dir: Option<PathBuf>,
+    /// Whether missing directories may be created (`--no-new-dirs`, `--confirm-new-dirs`)
+    pub new_dir_policy: NewDirPolicy,
+}
+
+impl Default for InstallerOptions {
+    fn default() -> Self {
+        Self {
+            content: ContentOptions::default(),
+            selection: SelectionOptions::default(),
+            dedupe_composite: false,
+            concurrency: 1,
+            verify: false,
+            passthrough_platforms: Vec::new(),
+            strategy_overrides: Vec::new(),
+            copy_mode: CopyMode::Copy,
+            backup_dir: None,
+            new_dir_policy: NewDirPolicy::Create,
+        }
+    }
+}
+
+/// How resource content is rewritten for its target
+#[derive(Debug, Clone)]
+pub struct ContentOptions {
+    /// Merge platform-specific frontmatter into universal resources (`--no-frontmatter-merge`)
+    pub merge_frontmatter: bool,
+    /// Prepend provenance comments to installed files (`--stamp`)
+    pub stamp: bool,
+    /// Convert Gemini commands to TOML; markdown is kept when off (`--no-universal-gemini-toml`)
+    pub gemini_toml: bool,
+    /// Values for `{{var}}` placeholders in markdown bodies (`--template`/`--var`)
+    pub template_vars: Option<TemplateVars>,
+    /// Output extensions overridden per platform and resource type (`--platform-output-ext`)
+    pub output_ext_overrides: Vec<OutputExtOverride>,
+}
+
+impl Default for ContentOptions {
+    fn default() -> Self {
+        Self {
+            merge_frontmatter: true,
+            stamp: false,
+            gemini_toml: true,
+            template_vars: None,
+            output_ext_overrides: Vec::new(),
+        }
+    }
+}
+
+/// Which resources and targets are written
+#[derive(Debug, Clone)]
+pub struct SelectionOptions {
+    /// Skip executable and script files in skill directories (`--ignore-scripts`)
+    pub ignore_scripts: bool,
+    /// Install only JSON settings and MCP resources (`--settings-only`)
+    pub settings_only: bool,
+    /// Write only targets that do not exist yet (`--copy-only-new`)
+    pub copy_only_new: bool,
+    /// Skip dir bundle resources not modified after this time (`--since-file`)
+    pub since: Option<std::time::SystemTime>,
+    /// Frontmatter key listing the only platforms a resource is installed for
+    /// (`--platform-filter-frontmatter-key`)
+    pub platform_filter_key: String,
+}
+
+impl Default for SelectionOptions {
+    fn default() -> Self {
+        Self {
+            ignore_scripts: false,
+            settings_only: false,
+            copy_only_new: false,
+            since: None,
+            platform_filter_key: super::DEFAULT_PLATFORM_FILTER_KEY.to_string(),
+        }
+    }
+}
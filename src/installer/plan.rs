@@ -0,0 +1,170 @@
+//! Install plan for `install --dry-run --json`
+//!
+//! The installer records every file it would write instead of writing it.
+//! Each operation is classified against the workspace as it is now, and
+//! against targets planned earlier in the same run, so a second bundle
+//! writing the same file shows up as an overwrite or merge.
+
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+
+use crate::platform::MergeStrategy;
+
+/// What writing a planned target does to the workspace
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PlannedAction {
+    /// Target does not exist yet
+    New,
+    /// Target exists and is replaced
+    Overwrite,
+    /// Target exists and is merged into
+    Merge,
+}
+
+/// One file the install would write
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PlannedOperation {
+    /// Name of the bundle the file comes from
+    pub bundle: String,
+    /// Path of the resource within the bundle
+    pub source: String,
+    /// Platform id the file is written for
+    pub platform: String,
+    /// Workspace-relative target path
+    pub target: String,
+    /// Merge strategy applied to the target
+    pub merge: MergeStrategy,
+    /// Whether the target is created, overwritten or merged into
+    pub action: PlannedAction,
+}
+
+/// Install plan printed by `install --dry-run --json`
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct InstallPlan {
+    /// Planned writes in install order
+    pub operations: Vec<PlannedOperation>,
+}
+
+/// A file the installer would write, passed to [`PlanCollector::record`]
+pub struct PlannedWrite<'a> {
+    /// Name of the bundle the file comes from
+    pub bundle: &'a str,
+    /// Path of the resource within the bundle
+    pub source: &'a Path,
+    /// Platform id the file is written for
+    pub platform: &'a str,
+    /// Absolute target path
+    pub target: &'a Path,
+    /// Merge strategy applied to the target
+    pub merge: MergeStrategy,
+}
+
+/// A bundle file that no platform installs (`install --report-unmatched`)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnmatchedFile {
+    pub bundle: String,
+    /// Path of the file within the bundle
+    pub path: String,
+}
+
+impl std::fmt::Display for UnmatchedFile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.bundle, self.path)
+    }
+}
+
+/// Collects planned operations in install order
+pub struct PlanCollector<'a> {
+    workspace_root: &'a Path,
+    planned_targets: HashSet<PathBuf>,
+    plan: InstallPlan,
+}
+
+impl<'a> PlanCollector<'a> {
+    pub fn new(workspace_root: &'a Path) -> Self {
+        Self {
+            workspace_root,
+            planned_targets: HashSet::new(),
+            plan: InstallPlan::default(),
+        }
+    }
+
+    /// Record that `write.source` of `write.bundle` would be written to `write.target`
+    pub fn record(&mut self, write: &PlannedWrite<'_>) {
+        let target = write.target;
+        let exists = !self.planned_targets.insert(target.to_path_buf()) || target.exists();
+        let action = match (exists, write.merge) {
+            (false, _) => PlannedAction::New,
+            (true, MergeStrategy::Replace) => PlannedAction::Overwrite,
+            (true, _) => PlannedAction::Merge,
+        };
+        let relative = target.strip_prefix(self.workspace_root).unwrap_or(target);
+        self.plan.operations.push(PlannedOperation {
+            bundle: write.bundle.to_string(),
+            source: write.source.to_string_lossy().replace('\\', "/"),
+            platform: write.platform.to_string(),
+            target: relative.to_string_lossy().replace('\\', "/"),
+            merge: write.merge,
+            action,
+        });
+    }
+
+    pub fn finish(self) -> InstallPlan {
+        self.plan
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plan_classifies_new_overwrite_and_merge() {
+        let temp = tempfile::TempDir::new_in(crate::temp::temp_dir_base())
+            .expect("Failed to create temp dir");
+        let root = temp.path();
+        std::fs::create_dir_all(root.join(".claude")).expect("Failed to create platform dir");
+        std::fs::write(root.join(".claude/settings.json"), "{}").expect("Failed to write");
+
+        let mut collector = PlanCollector::new(root);
+        let command = root.join(".claude/commands/review.md");
+        collector.record(&PlannedWrite {
+            bundle: "a",
+            source: Path::new("commands/review.md"),
+            platform: "claude",
+            target: &command,
+            merge: MergeStrategy::Replace,
+        });
+        collector.record(&PlannedWrite {
+            bundle: "b",
+            source: Path::new("commands/review.md"),
+            platform: "claude",
+            target: &command,
+            merge: MergeStrategy::Replace,
+        });
+        collector.record(&PlannedWrite {
+            bundle: "b",
+            source: Path::new("settings.json"),
+            platform: "claude",
+            target: &root.join(".claude/settings.json"),
+            merge: MergeStrategy::Deep,
+        });
+
+        let plan = collector.finish();
+        let actions: Vec<PlannedAction> = plan.operations.iter().map(|o| o.action).collect();
+        assert_eq!(
+            actions,
+            [
+                PlannedAction::New,
+                PlannedAction::Overwrite,
+                PlannedAction::Merge
+            ]
+        );
+        assert_eq!(plan.operations[0].target, ".claude/commands/review.md");
+        assert!(!command.exists());
+    }
+}
@@ -0,0 +1,196 @@
+//! Provenance sidecars in platform directories (`--record-provenance`)
+//!
+//! Each platform directory gets a `.augent-provenance.json` mapping the files
+//! installed into it to the bundle, source file and commit they came from, so
+//! the mapping travels with the directory when it is copied or shared without
+//! the `.augent` workspace. Paths are relative to the platform directory;
+//! files written outside it (such as a root-level MCP config) are left out.
+//!
+//! Entries from earlier installs are kept as long as their file still exists,
+//! so installing one bundle does not drop the provenance of the others.
+
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::{InstalledFile, ResolvedBundle};
+use crate::error::{AugentError, Result};
+use crate::platform::Platform;
+
+use super::manifest;
+
+/// Sidecar file name inside each platform directory
+pub const SIDECAR_FILE: &str = ".augent-provenance.json";
+
+/// Where one installed file came from
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProvenanceEntry {
+    /// Name of the bundle the file came from
+    pub bundle: String,
+    /// Path of the resource within the bundle
+    pub source: String,
+    /// Commit the bundle was installed from (absent for dir bundles)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sha: Option<String>,
+}
+
+/// Sidecar content: entries keyed by path relative to the platform directory
+pub type Sidecar = BTreeMap<String, ProvenanceEntry>;
+
+/// Write or update the sidecar of every platform that files were installed for
+pub fn write_sidecars(
+    installed_files: &HashMap<String, InstalledFile>,
+    platforms: &[Platform],
+    workspace_root: &Path,
+    bundles: &[ResolvedBundle],
+    verify: bool,
+) -> Result<()> {
+    let shas: HashMap<&str, &str> = bundles
+        .iter()
+        .filter_map(|b| Some((b.name.as_str(), b.resolved_sha.as_deref()?)))
+        .collect();
+    let manifest = manifest::build(installed_files, platforms, workspace_root);
+
+    for platform in platforms {
+        let platform_dir = workspace_root.join(&platform.directory);
+        let prefix = format!("{}/", platform.directory.trim_end_matches('/'));
+        let installed: Vec<(String, ProvenanceEntry)> = manifest
+            .get(&platform.id)
+            .into_iter()
+            .flatten()
+            .filter_map(|entry| {
+                let path = entry.path.strip_prefix(&prefix)?;
+                Some((
+                    path.to_string(),
+                    ProvenanceEntry {
+                        bundle: entry.bundle.clone(),
+                        source: entry.source.clone(),
+                        sha: shas.get(entry.bundle.as_str()).map(|s| (*s).to_string()),
+                    },
+                ))
+            })
+            .collect();
+        if installed.is_empty() {
+            continue;
+        }
+
+        let sidecar_path = platform_dir.join(SIDECAR_FILE);
+        let mut sidecar = read_sidecar(&sidecar_path);
+        sidecar.retain(|path, _| platform_dir.join(path).is_file());
+        sidecar.extend(installed);
+        write_sidecar(&sidecar_path, &sidecar, verify)?;
+    }
+    Ok(())
+}
+
+/// Entries of an existing sidecar; a missing or unreadable one starts empty
+pub fn read_sidecar(path: &Path) -> Sidecar {
+    std::fs::read_to_string(path)
+        .ok()
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
+fn write_sidecar(path: &Path, sidecar: &Sidecar, verify: bool) -> Result<()> {
+    let write_failed = |reason: String| AugentError::FileWriteFailed {
+        path: path.display().to_string(),
+        reason,
+    };
+    let json = serde_json::to_string_pretty(sidecar).map_err(|e| write_failed(e.to_string()))?;
+    super::file_ops::write_file(path, json + "\n", verify)
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::*;
+    use crate::installer::Installer;
+
+    fn bundle(name: &str, source_path: &Path, sha: Option<&str>) -> ResolvedBundle {
+        ResolvedBundle {
+            name: name.to_string(),
+            dependency: None,
+            source_path: source_path.to_path_buf(),
+            resolved_sha: sha.map(str::to_string),
+            resolved_ref: None,
+            git_source: None,
+            config: None,
+            platform_sources: HashMap::new(),
+            artifact_source: None,
+        }
+    }
+
+    fn write_file(root: &Path, relative: &str) {
+        let path = root.join(relative);
+        std::fs::create_dir_all(path.parent().expect("Path has no parent"))
+            .expect("Failed to create directory");
+        std::fs::write(path, "Content.\n").expect("Failed to write file");
+    }
+
+    fn install_with_sidecars(
+        workspace_root: &Path,
+        platforms: &[Platform],
+        bundles: &[ResolvedBundle],
+    ) {
+        let mut installer = Installer::new_with_dry_run(workspace_root, platforms.to_vec(), false);
+        installer
+            .install_bundles(bundles)
+            .expect("Failed to install bundles");
+        write_sidecars(
+            installer.installed_files(),
+            platforms,
+            workspace_root,
+            bundles,
+            false,
+        )
+        .expect("Failed to write sidecars");
+    }
+
+    #[test]
+    fn test_write_sidecars_records_bundle_and_sha_per_file() {
+        let temp = tempfile::TempDir::new_in(crate::temp::temp_dir_base())
+            .expect("Failed to create temp dir");
+        let workspace_root = temp.path().join("workspace");
+        let remote = temp.path().join("remote");
+        let local = temp.path().join("local");
+        write_file(&remote, "commands/review.md");
+        write_file(&local, "rules/lint.md");
+        let platforms: Vec<Platform> = crate::platform::default_platforms()
+            .into_iter()
+            .filter(|p| p.id == "claude")
+            .collect();
+        let sha = "0123456789abcdef0123456789abcdef01234567";
+        let bundles = [
+            bundle("@author/remote", &remote, Some(sha)),
+            bundle("local", &local, None),
+        ];
+
+        install_with_sidecars(&workspace_root, &platforms, &bundles);
+
+        let sidecar = read_sidecar(&workspace_root.join(".claude").join(SIDECAR_FILE));
+        assert_eq!(
+            sidecar.get("commands/review.md"),
+            Some(&ProvenanceEntry {
+                bundle: "@author/remote".to_string(),
+                source: "commands/review.md".to_string(),
+                sha: Some(sha.to_string()),
+            })
+        );
+        assert_eq!(
+            sidecar.get("rules/lint.md"),
+            Some(&ProvenanceEntry {
+                bundle: "local".to_string(),
+                source: "rules/lint.md".to_string(),
+                sha: None,
+            })
+        );
+        assert_eq!(sidecar.len(), 2);
+
+        // A later install of one bundle keeps the other bundle's entries
+        install_with_sidecars(&workspace_root, &platforms, &bundles[1..]);
+        let sidecar = read_sidecar(&workspace_root.join(".claude").join(SIDECAR_FILE));
+        assert_eq!(sidecar.len(), 2);
+        assert_eq!(sidecar["commands/review.md"].sha.as_deref(), Some(sha));
+    }
+}
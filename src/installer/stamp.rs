@@ -0,0 +1,169 @@
+//! Provenance stamps for generated files
+//!
+//! With `--stamp`, each installed file gets a comment naming the bundle,
+//! its source URL and resolved SHA, e.g.:
+//!
+//! ```text
+//! <!-- Generated by augent from @owner/repo@abc1234 (https://github.com/owner/repo.git) -->
+//! ```
+//!
+//! The comment syntax follows the file type. Markdown stamps go after any
+//! frontmatter and script stamps after any shebang, so both keep working. Formats without comments (JSON)
+//! and unknown file types are left untouched.
+
+use std::path::Path;
+
+use crate::domain::ResolvedBundle;
+use crate::error::Result;
+
+/// Prefix identifying an augent provenance stamp
+const STAMP_PREFIX: &str = "Generated by augent from";
+
+/// Length of the abbreviated SHA shown in stamps
+const SHORT_SHA_LEN: usize = 7;
+
+/// Provenance text for files installed from `bundle`
+pub fn provenance(bundle: &ResolvedBundle) -> String {
+    let mut text = format!("{STAMP_PREFIX} {}", bundle.name);
+    if let Some(sha) = &bundle.resolved_sha {
+        text.push('@');
+        text.push_str(&sha[..sha.len().min(SHORT_SHA_LEN)]);
+    }
+    if let Some(git_source) = &bundle.git_source {
+        text = format!("{text} ({})", git_source.url);
+    }
+    text
+}
+
+/// Prepend the provenance stamp to an installed file, if its format allows comments
+pub fn stamp_file(target: &Path, provenance: &str, verify: bool) -> Result<()> {
+    if !target.is_file() {
+        return Ok(());
+    }
+    let Ok(content) = std::fs::read_to_string(target) else {
+        // Binary files are never stamped
+        return Ok(());
+    };
+    let extension = target
+        .extension()
+        .and_then(|e| e.to_str())
+        .unwrap_or_default();
+    let Some(stamped) = stamp_content(&content, extension, provenance) else {
+        return Ok(());
+    };
+    super::file_ops::write_file(target, stamped, verify)
+}
+
+/// Insert a provenance comment into `content`, or `None` when the format is unsupported
+fn stamp_content(content: &str, extension: &str, provenance: &str) -> Option<String> {
+    if content.contains(STAMP_PREFIX) {
+        return None;
+    }
+    match extension {
+        "md" | "mdc" => Some(stamp_markdown(content, provenance)),
+        "toml" | "yaml" | "yml" => Some(format!("# {provenance}\n{content}")),
+        "sh" => Some(stamp_script(content, provenance)),
+        "jsonc" => Some(format!("// {provenance}\n{content}")),
+        _ => None,
+    }
+}
+
+/// Place the stamp after a leading shebang, which must stay on the first line
+fn stamp_script(content: &str, provenance: &str) -> String {
+    let comment = format!("# {provenance}\n");
+    if !content.starts_with("#!") {
+        return format!("{comment}{content}");
+    }
+    match content.split_once('\n') {
+        Some((shebang, rest)) => format!("{shebang}\n{comment}{rest}"),
+        None => format!("{content}\n{comment}"),
+    }
+}
+
+/// Place the stamp after YAML frontmatter (which must stay on the first line)
+fn stamp_markdown(content: &str, provenance: &str) -> String {
+    let comment = format!("<!-- {provenance} -->\n");
+    let Some(rest) = content.strip_prefix("---\n") else {
+        return format!("{comment}{content}");
+    };
+    match rest.find("\n---\n") {
+        Some(end) => {
+            let split = "---\n".len() + end + "\n---\n".len();
+            format!("{}{comment}{}", &content[..split], &content[split..])
+        }
+        None => format!("{comment}{content}"),
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::*;
+    use crate::source::GitSource;
+
+    fn git_bundle() -> ResolvedBundle {
+        ResolvedBundle {
+            name: "@owner/repo".to_string(),
+            dependency: None,
+            source_path: std::path::PathBuf::from("/cache/repo"),
+            resolved_sha: Some("abc1234def5678".to_string()),
+            resolved_ref: Some("main".to_string()),
+            git_source: Some(GitSource::new("https://github.com/owner/repo.git")),
+            config: None,
+            platform_sources: std::collections::HashMap::new(),
+            artifact_source: None,
+        }
+    }
+
+    #[test]
+    fn test_provenance_includes_name_sha_and_url() {
+        assert_eq!(
+            provenance(&git_bundle()),
+            "Generated by augent from @owner/repo@abc1234 (https://github.com/owner/repo.git)"
+        );
+    }
+
+    #[test]
+    fn test_stamp_markdown_after_frontmatter() {
+        let stamped = stamp_content("---\ndescription: x\n---\n\nBody\n", "mdc", "STAMP")
+            .expect("Markdown should be stamped");
+        assert_eq!(
+            stamped,
+            "---\ndescription: x\n---\n<!-- STAMP -->\n\nBody\n"
+        );
+    }
+
+    #[test]
+    fn test_stamp_script_after_shebang() {
+        let stamped = stamp_content("#!/bin/sh\necho hi\n", "sh", "STAMP")
+            .expect("Scripts should be stamped");
+        assert_eq!(stamped, "#!/bin/sh\n# STAMP\necho hi\n");
+
+        let plain = stamp_content("echo hi\n", "sh", "STAMP").expect("Scripts should be stamped");
+        assert_eq!(plain, "# STAMP\necho hi\n");
+    }
+
+    #[test]
+    fn test_stamp_skips_json_and_existing_stamps() {
+        assert!(stamp_content("{}", "json", "STAMP").is_none());
+        let once = stamp_content("# Title\n", "md", &provenance(&git_bundle()))
+            .expect("Markdown should be stamped");
+        assert!(stamp_content(&once, "md", &provenance(&git_bundle())).is_none());
+    }
+
+    #[test]
+    fn test_stamp_file_writes_source_info() {
+        let temp = tempfile::TempDir::new_in(crate::temp::temp_dir_base())
+            .expect("Failed to create temp dir");
+        let target = temp.path().join("rule.md");
+        std::fs::write(&target, "# Rule\n").expect("Failed to write file");
+
+        stamp_file(&target, &provenance(&git_bundle()), false).expect("Failed to stamp file");
+
+        let content = std::fs::read_to_string(&target).expect("Failed to read file");
+        assert!(content.starts_with(
+            "<!-- Generated by augent from @owner/repo@abc1234 (https://github.com/owner/repo.git) -->\n"
+        ));
+        assert!(content.ends_with("# Rule\n"));
+    }
+}
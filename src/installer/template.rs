@@ -0,0 +1,172 @@
+//! Install-time templating of markdown resource bodies
+//!
+//! With `--template` (or any `--var key=value`), `{{name}}` placeholders in
+//! markdown resources are replaced with workspace variables before the file
+//! is emitted. `workspace_name` and `repo_url` are provided automatically;
+//! `--var` values take precedence.
+//!
+//! Placeholders inside fenced code blocks are never substituted, so examples
+//! survive intact. Undefined variables are left as-is and reported.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use crate::error::{AugentError, Result};
+
+/// Variables available to templates, by name
+pub type TemplateVars = BTreeMap<String, String>;
+
+const OPEN: &str = "{{";
+const CLOSE: &str = "}}";
+
+/// Parse a `key=value` pair from `--var`
+pub fn parse_var(s: &str) -> std::result::Result<(String, String), String> {
+    let (key, value) = s
+        .split_once('=')
+        .ok_or_else(|| format!("expected key=value, got '{s}'"))?;
+    let key = key.trim();
+    if !is_variable_name(key) {
+        return Err(format!("invalid variable name '{key}'"));
+    }
+    Ok((key.to_string(), value.to_string()))
+}
+
+/// Automatic variables for a workspace, overridden by `user_vars`
+pub fn workspace_variables(workspace_root: &Path, user_vars: &[(String, String)]) -> TemplateVars {
+    let mut vars = TemplateVars::new();
+    vars.insert(
+        "workspace_name".to_string(),
+        crate::workspace::initialization::infer_workspace_name(workspace_root),
+    );
+    if let Some(url) = origin_url(workspace_root) {
+        vars.insert("repo_url".to_string(), url);
+    }
+    vars.extend(user_vars.iter().cloned());
+    vars
+}
+
+fn origin_url(workspace_root: &Path) -> Option<String> {
+    let repo = git2::Repository::open(workspace_root).ok()?;
+    let remote = repo.find_remote("origin").ok()?;
+    remote.url().map(str::to_string)
+}
+
+/// Whether templating applies to `source` (markdown resources only)
+pub fn is_templatable(source: &Path) -> bool {
+    source
+        .extension()
+        .is_some_and(|ext| ext == "md" || ext == "mdc")
+}
+
+/// Substitute placeholders outside code fences
+///
+/// Returns the rendered content and the names of undefined variables.
+pub fn render(content: &str, vars: &TemplateVars) -> (String, Vec<String>) {
+    let mut rendered = String::with_capacity(content.len());
+    let mut undefined = Vec::new();
+    let mut in_fence = false;
+
+    for line in content.split_inclusive('\n') {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_fence = !in_fence;
+            rendered.push_str(line);
+        } else if in_fence {
+            rendered.push_str(line);
+        } else {
+            rendered.push_str(&render_line(line, vars, &mut undefined));
+        }
+    }
+    (rendered, undefined)
+}
+
+fn render_line(line: &str, vars: &TemplateVars, undefined: &mut Vec<String>) -> String {
+    let mut out = String::with_capacity(line.len());
+    let mut rest = line;
+    while let Some(start) = rest.find(OPEN) {
+        let after_open = &rest[start + OPEN.len()..];
+        let Some(end) = after_open.find(CLOSE) else {
+            break;
+        };
+        let name = after_open[..end].trim();
+        out.push_str(&rest[..start]);
+        let value = vars.get(name).filter(|_| is_variable_name(name));
+        if let Some(value) = value {
+            out.push_str(value);
+        } else {
+            note_undefined(name, undefined);
+            out.push_str(&rest[start..start + OPEN.len() + end + CLOSE.len()]);
+        }
+        rest = &after_open[end + CLOSE.len()..];
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Remember an undefined variable name once, ignoring non-variable text
+fn note_undefined(name: &str, undefined: &mut Vec<String>) {
+    if is_variable_name(name) && !undefined.iter().any(|n| n == name) {
+        undefined.push(name.to_string());
+    }
+}
+
+fn is_variable_name(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-' || c == '.')
+}
+
+/// Write rendered content to a staging file so converters that read their
+/// source path see the templated content
+///
+/// The file keeps the source's name; it lives as long as the returned guard.
+pub fn stage_rendered(source: &Path, content: &str) -> Result<(tempfile::TempDir, PathBuf)> {
+    let staging = crate::temp::temp_dir()?;
+    let path = staging.path().join(source.file_name().unwrap_or_default());
+    std::fs::write(&path, content).map_err(|e| AugentError::FileWriteFailed {
+        path: path.display().to_string(),
+        reason: e.to_string(),
+    })?;
+    Ok((staging, path))
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::*;
+
+    fn vars() -> TemplateVars {
+        let mut vars = TemplateVars::new();
+        vars.insert("workspace_name".to_string(), "my-project".to_string());
+        vars
+    }
+
+    #[test]
+    fn test_render_replaces_variables_outside_code_fences() {
+        let content = "Project: {{workspace_name}} / {{ workspace_name }}\n\n```\necho {{x}} {{workspace_name}}\n```\n";
+        let (rendered, undefined) = render(content, &vars());
+        assert_eq!(
+            rendered,
+            "Project: my-project / my-project\n\n```\necho {{x}} {{workspace_name}}\n```\n"
+        );
+        assert!(undefined.is_empty());
+    }
+
+    #[test]
+    fn test_render_leaves_undefined_variables() {
+        let (rendered, undefined) = render("Owner: {{owner}}, {{owner}}\n", &vars());
+        assert_eq!(rendered, "Owner: {{owner}}, {{owner}}\n");
+        assert_eq!(undefined, vec!["owner".to_string()]);
+    }
+
+    #[test]
+    fn test_parse_var() {
+        assert_eq!(
+            parse_var("team=platform=core").expect("Should parse"),
+            ("team".to_string(), "platform=core".to_string())
+        );
+        assert!(parse_var("novalue").is_err());
+        assert!(parse_var("bad name=x").is_err());
+    }
+}
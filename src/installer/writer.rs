@@ -6,7 +6,7 @@
 
 use std::path::Path;
 
-use crate::error::{AugentError, Result};
+use crate::error::Result;
 use serde_yaml::Value as YamlValue;
 
 use super::file_ops;
@@ -16,6 +16,7 @@ pub fn write_merged_frontmatter_markdown(
     merged: &YamlValue,
     body: &str,
     target: &Path,
+    verify: bool,
 ) -> Result<()> {
     let yaml = crate::universal::serialize_to_yaml(merged);
     let yaml = yaml.trim_end();
@@ -25,9 +26,5 @@ pub fn write_merged_frontmatter_markdown(
         format!("---\n{yaml}\n---\n\n{body}")
     };
     file_ops::ensure_parent_dir(target)?;
-    std::fs::write(target, out).map_err(|e| AugentError::FileWriteFailed {
-        path: target.display().to_string(),
-        reason: e.to_string(),
-    })?;
-    Ok(())
+    file_ops::write_file(target, out, verify)
 }
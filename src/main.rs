@@ -53,19 +53,71 @@ fn check_git_repository(workspace_path: Option<PathBuf>) -> Result<()> {
     Ok(())
 }
 
+/// Check every requested workspace (or the current directory) is in a git repository
+fn check_git_repositories(workspaces: &[PathBuf]) -> Result<()> {
+    if workspaces.is_empty() {
+        return check_git_repository(None);
+    }
+    workspaces
+        .iter()
+        .try_for_each(|workspace| check_git_repository(Some(workspace.clone())))
+}
+
+/// The workspace for commands that operate on a single workspace
+fn single_workspace(workspaces: Vec<PathBuf>) -> Result<Option<PathBuf>> {
+    if workspaces.len() > 1 {
+        return Err(AugentError::ConfigInvalid {
+            message: "--workspace can only be given more than once for install".to_string(),
+        });
+    }
+    Ok(workspaces.into_iter().next())
+}
+
 fn needs_git_repo(command: &Commands) -> bool {
     matches!(
         command,
-        Commands::Install(_) | Commands::Uninstall(_) | Commands::List(_) | Commands::Show(_)
+        Commands::Install(_)
+            | Commands::Uninstall(_)
+            | Commands::List(_)
+            | Commands::Outdated(_)
+            | Commands::Status(_)
+            | Commands::Show(_)
+            | Commands::Diff(_)
+            | Commands::Clean(_)
+            | Commands::Doctor(_)
+            | Commands::Freeze(_)
     )
 }
 
-fn execute_command(workspace: Option<PathBuf>, command: Commands) -> Result<()> {
+fn execute_command(
+    workspaces: Vec<PathBuf>,
+    profile: Option<&str>,
+    command: Commands,
+) -> Result<()> {
     match command {
-        Commands::Install(args) => commands::install::run(workspace, args),
-        Commands::Uninstall(args) => commands::uninstall::run(workspace, args),
-        Commands::List(args) => commands::list::run(workspace, &args),
-        Commands::Show(args) => commands::show::run(workspace, args),
+        Commands::Install(args) => commands::install::run(&workspaces, profile, *args),
+        Commands::Uninstall(args) => {
+            commands::uninstall::run(single_workspace(workspaces)?, profile, args)
+        }
+        Commands::List(args) => commands::list::run(single_workspace(workspaces)?, profile, &args),
+        Commands::Outdated(args) => {
+            commands::outdated::run(single_workspace(workspaces)?, profile, &args)
+        }
+        Commands::Status(args) => {
+            commands::status::run(single_workspace(workspaces)?, profile, &args)
+        }
+        Commands::Show(args) => commands::show::run(single_workspace(workspaces)?, profile, args),
+        Commands::Diff(args) => commands::diff::run(single_workspace(workspaces)?, &args),
+        Commands::Clean(args) => {
+            commands::clean::run(single_workspace(workspaces)?, profile, &args)
+        }
+        Commands::Doctor(args) => {
+            commands::doctor::run(single_workspace(workspaces)?, profile, &args)
+        }
+        Commands::Freeze(args) => {
+            commands::freeze::run(single_workspace(workspaces)?, profile, &args)
+        }
+        Commands::Platforms(args) => commands::platforms::run(single_workspace(workspaces)?, &args),
         Commands::Cache(args) => commands::clean_cache::run(args),
         Commands::Version => {
             commands::version::run();
@@ -81,20 +133,30 @@ fn execute_command(workspace: Option<PathBuf>, command: Commands) -> Result<()>
 fn main() {
     let cli = Cli::parse();
 
+    // Remove temp dirs left behind by interrupted runs
+    temp::cleanup_orphans();
+
     // Check git repository for commands that require it
     // Cache, version, and completions commands can be run outside a git repository
     if needs_git_repo(&cli.command) {
-        if let Err(e) = check_git_repository(cli.workspace.clone()) {
-            eprintln!("Error: {e}");
-            std::process::exit(1);
+        if let Err(e) = check_git_repositories(&cli.workspace) {
+            eprintln!("Error: {}", e.display_with_hint());
+            std::process::exit(e.exit_code());
+        }
+    }
+
+    if let Some(profile) = &cli.profile {
+        if let Err(e) = workspace::config::validate_profile(profile) {
+            eprintln!("Error: {}", e.display_with_hint());
+            std::process::exit(e.exit_code());
         }
     }
 
-    let result = execute_command(cli.workspace, cli.command);
+    let result = execute_command(cli.workspace, cli.profile.as_deref(), cli.command);
 
     if let Err(e) = result {
-        eprintln!("Error: {e}");
-        std::process::exit(1);
+        eprintln!("Error: {}", e.display_with_hint());
+        std::process::exit(e.exit_code());
     }
 }
 
@@ -0,0 +1,207 @@
+//! Diff operation (`augent diff`)
+//!
+//! Shows what installing bundles would change without touching the
+//! workspace. The planned targets that already exist are copied into a
+//! scratch directory and the bundles are installed there by the regular
+//! installer, so frontmatter transformations, format conversions and merges
+//! into existing files are exactly what `install` would produce. Each target
+//! is then compared with the file in the workspace.
+
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::Path;
+
+use similar::TextDiff;
+
+use crate::domain::ResolvedBundle;
+use crate::error::{AugentError, Result};
+use crate::installer::Installer;
+use crate::platform::Platform;
+
+/// How installing would change a target
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiffStatus {
+    /// Target does not exist yet
+    Added,
+    /// Target exists with different content
+    Modified,
+    /// Target exists with the same content
+    Unchanged,
+}
+
+/// Change to one target file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileDiff {
+    /// Workspace-relative target path
+    pub target: String,
+    pub status: DiffStatus,
+    /// Unified diff from the current to the installed content (empty when unchanged)
+    pub diff: String,
+}
+
+/// Compare every file installing `bundles` for `platforms` would write with the workspace
+pub fn diff_bundles(
+    workspace_root: &Path,
+    platforms: &[Platform],
+    bundles: &[ResolvedBundle],
+) -> Result<Vec<FileDiff>> {
+    let targets: BTreeSet<String> =
+        Installer::new_with_dry_run(workspace_root, platforms.to_vec(), true)
+            .plan(bundles)
+            .operations
+            .into_iter()
+            .map(|op| op.target)
+            .collect();
+
+    let scratch = crate::temp::temp_dir().map_err(|e| AugentError::IoError {
+        message: format!("Failed to create temp directory: {e}"),
+        source: Some(Box::new(e)),
+    })?;
+    for target in &targets {
+        let current = workspace_root.join(target);
+        if current.is_file() {
+            copy_target(&current, &scratch.path().join(target))?;
+        }
+    }
+    Installer::new_with_dry_run(scratch.path(), platforms.to_vec(), false)
+        .install_bundles(bundles)?;
+
+    targets
+        .into_iter()
+        .map(|target| {
+            let current = workspace_root.join(&target);
+            let installed = scratch.path().join(&target);
+            diff_target(target, &current, &installed)
+        })
+        .collect()
+}
+
+fn diff_target(target: String, current: &Path, installed: &Path) -> Result<FileDiff> {
+    let new = read(installed)?;
+    let (status, old) = if current.is_file() {
+        let old = read(current)?;
+        let status = if old == new {
+            DiffStatus::Unchanged
+        } else {
+            DiffStatus::Modified
+        };
+        (status, old)
+    } else {
+        (DiffStatus::Added, Vec::new())
+    };
+    if status == DiffStatus::Unchanged {
+        return Ok(FileDiff {
+            target,
+            status,
+            diff: String::new(),
+        });
+    }
+
+    let diff = match (std::str::from_utf8(&old), std::str::from_utf8(&new)) {
+        (Ok(old), Ok(new)) => {
+            let old_header = if status == DiffStatus::Added {
+                "/dev/null".to_string()
+            } else {
+                format!("a/{target}")
+            };
+            TextDiff::from_lines(old, new)
+                .unified_diff()
+                .header(&old_header, &format!("b/{target}"))
+                .to_string()
+        }
+        _ => format!("Binary file {target} differs\n"),
+    };
+    Ok(FileDiff {
+        target,
+        status,
+        diff,
+    })
+}
+
+fn copy_target(from: &Path, to: &Path) -> Result<()> {
+    crate::installer::file_ops::ensure_parent_dir(to)?;
+    fs::copy(from, to).map_err(|e| AugentError::FileWriteFailed {
+        path: to.display().to_string(),
+        reason: e.to_string(),
+    })?;
+    Ok(())
+}
+
+fn read(path: &Path) -> Result<Vec<u8>> {
+    fs::read(path).map_err(|e| AugentError::FileReadFailed {
+        path: path.display().to_string(),
+        reason: e.to_string(),
+    })
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use std::path::PathBuf;
+
+    fn dir_bundle(name: &str, source_path: PathBuf) -> ResolvedBundle {
+        ResolvedBundle {
+            name: name.to_string(),
+            dependency: None,
+            source_path,
+            resolved_sha: None,
+            resolved_ref: None,
+            git_source: None,
+            config: None,
+            platform_sources: HashMap::new(),
+            artifact_source: None,
+        }
+    }
+
+    fn write_command(bundle_dir: &Path, name: &str, content: &str) {
+        let commands = bundle_dir.join("commands");
+        fs::create_dir_all(&commands).expect("Failed to create commands dir");
+        fs::write(commands.join(name), content).expect("Failed to write command");
+    }
+
+    #[test]
+    fn test_diff_bundles_reports_changed_and_unchanged_files() {
+        let temp = tempfile::TempDir::new_in(crate::temp::temp_dir_base())
+            .expect("Failed to create temp dir");
+        let workspace_root = temp.path().join("workspace");
+        let bundle_dir = temp.path().join("bundle");
+        write_command(&bundle_dir, "review.md", "Review the diff.\n");
+        write_command(&bundle_dir, "test.md", "Run the tests.\n");
+        let platforms: Vec<Platform> = crate::platform::default_platforms()
+            .into_iter()
+            .filter(|p| p.id == "claude")
+            .collect();
+        let bundles = [dir_bundle("tools", bundle_dir.clone())];
+
+        let diffs = diff_bundles(&workspace_root, &platforms, &bundles).expect("Failed to diff");
+        assert!(diffs.iter().all(|d| d.status == DiffStatus::Added));
+        assert!(diffs[0].diff.contains("+Review the diff."));
+        assert!(!workspace_root.join(".claude").exists());
+
+        Installer::new_with_dry_run(&workspace_root, platforms.clone(), false)
+            .install_bundles(&bundles)
+            .expect("Failed to install");
+        write_command(&bundle_dir, "review.md", "Review the whole diff.\n");
+
+        let diffs = diff_bundles(&workspace_root, &platforms, &bundles).expect("Failed to diff");
+        let statuses: Vec<(&str, DiffStatus)> = diffs
+            .iter()
+            .map(|d| (d.target.as_str(), d.status))
+            .collect();
+        assert_eq!(
+            statuses,
+            vec![
+                (".claude/commands/review.md", DiffStatus::Modified),
+                (".claude/commands/test.md", DiffStatus::Unchanged),
+            ]
+        );
+        assert!(diffs[0].diff.contains("-Review the diff."));
+        assert!(diffs[0].diff.contains("+Review the whole diff."));
+        assert!(diffs[1].diff.is_empty());
+        let installed = fs::read_to_string(workspace_root.join(".claude/commands/review.md"))
+            .expect("Failed to read installed command");
+        assert!(!installed.contains("whole"));
+    }
+}
@@ -0,0 +1,168 @@
+//! Freeze operation (`augent freeze`)
+//!
+//! Snapshots the workspace's locked bundles into one self-contained dir
+//! bundle: the canonical source of every locked bundle (its directory, or
+//! the cached checkout at the locked SHA) is copied to
+//! `<out-dir>/bundles/<name>/`, and a generated `augent.yaml` lists them as
+//! local dependencies in lockfile order. Installing the frozen bundle needs
+//! no network and reproduces the same files.
+//!
+//! Only discovered resources are copied. Bundles' own `augent.yaml` files are
+//! left out, since the lockfile already lists transitive dependencies and
+//! re-resolving them would need git.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::config::{BundleConfig, BundleDependency, LockedBundle, LockedSource};
+use crate::error::{AugentError, Result};
+use crate::installer::discovery;
+use crate::source::GitSource;
+use crate::workspace::Workspace;
+
+/// Directory inside the frozen bundle holding the copied bundles
+const FROZEN_BUNDLES_DIR: &str = "bundles";
+
+/// A locked bundle copied into the frozen bundle
+#[derive(Debug, Clone)]
+pub struct FrozenBundle {
+    /// Locked bundle name
+    pub name: String,
+    /// Where its source was copied from
+    pub from: PathBuf,
+}
+
+/// Copy every locked bundle of `workspace` into `out_dir`
+///
+/// `out_dir` must be inside the workspace, since local dependencies are
+/// resolved relative to the workspace root. A previous freeze in `out_dir`
+/// is replaced; any other non-empty directory is refused. Locked bundles
+/// installed from inside `out_dir` (an earlier freeze) are not copied again.
+pub fn freeze(workspace: &Workspace, out_dir: &Path) -> Result<Vec<FrozenBundle>> {
+    let root = std::fs::canonicalize(&workspace.root).map_or_else(
+        |_| workspace.root.clone(),
+        |p| dunce::simplified(&p).to_path_buf(),
+    );
+    let relative_out = out_dir
+        .strip_prefix(&root)
+        .map_err(|_| AugentError::ConfigInvalid {
+            message: format!(
+                "Freeze output '{}' must be inside the workspace '{}'",
+                out_dir.display(),
+                workspace.root.display()
+            ),
+        })?
+        .to_path_buf();
+    let sources = workspace
+        .lockfile
+        .bundles
+        .iter()
+        .map(|bundle| source_dir(&workspace.root, bundle).map(|from| (bundle, from)))
+        .filter(|source| !matches!(source, Ok((_, from)) if from.starts_with(out_dir)))
+        .collect::<Result<Vec<_>>>()?;
+    prepare_out_dir(out_dir)?;
+
+    let mut config = BundleConfig {
+        description: Some("Frozen snapshot of locked bundles (generated by augent freeze)".into()),
+        ..BundleConfig::default()
+    };
+    let mut frozen = Vec::new();
+    for (bundle, from) in sources {
+        let dir_name = crate::cache::bundle_name_to_cache_key(&bundle.name);
+        let target = out_dir.join(FROZEN_BUNDLES_DIR).join(&dir_name);
+        copy_resources(&from, &target)?;
+
+        let dep_path = relative_out.join(FROZEN_BUNDLES_DIR).join(&dir_name);
+        config.bundles.push(BundleDependency::local(
+            bundle.name.clone(),
+            format!("./{}", dep_path.to_string_lossy().replace('\\', "/")),
+        ));
+        frozen.push(FrozenBundle {
+            name: bundle.name.clone(),
+            from,
+        });
+    }
+
+    let name = out_dir.file_name().map_or_else(
+        || "frozen".to_string(),
+        |n| n.to_string_lossy().into_owned(),
+    );
+    let config_path = out_dir.join("augent.yaml");
+    fs::write(&config_path, config.to_yaml(&name)?).map_err(|e| freeze_error(&config_path, &e))?;
+    Ok(frozen)
+}
+
+/// Directory holding a locked bundle's canonical source files
+fn source_dir(workspace_root: &Path, bundle: &LockedBundle) -> Result<PathBuf> {
+    let dir = match &bundle.source {
+        LockedSource::Dir { path, .. } => Some(workspace_root.join(path)),
+        LockedSource::Git { url, path, sha, .. } => {
+            let source = GitSource {
+                url: url.clone(),
+                path: path.clone(),
+                git_ref: None,
+                resolved_sha: Some(sha.clone()),
+            };
+            crate::cache::lookup::get_cached(&source)?.map(|(content_path, _, _)| content_path)
+        }
+        LockedSource::Archive { url, path, .. } => Some(
+            crate::cache::archive::cached_archive_path(url, path.as_deref()),
+        ),
+        LockedSource::Oci {
+            reference,
+            digest,
+            path,
+            ..
+        } => Some(crate::cache::oci::cached_oci_path(
+            reference,
+            digest,
+            path.as_deref(),
+        )),
+    };
+    dir.filter(|d| d.is_dir())
+        .ok_or_else(|| AugentError::CacheOperationFailed {
+            message: format!(
+                "Source of '{}' is not available; run 'augent install' first",
+                bundle.name
+            ),
+        })
+}
+
+/// Copy the installable resources of the bundle at `from` to `target`
+fn copy_resources(from: &Path, target: &Path) -> Result<()> {
+    fs::create_dir_all(target).map_err(|e| freeze_error(target, &e))?;
+    for resource in discovery::discover_resources(from) {
+        let relative = resource
+            .bundle_path
+            .strip_prefix(from)
+            .unwrap_or(&resource.bundle_path);
+        let dest = target.join(relative);
+        crate::installer::file_ops::ensure_parent_dir(&dest)?;
+        fs::copy(&resource.absolute_path, &dest).map_err(|e| freeze_error(&dest, &e))?;
+    }
+    Ok(())
+}
+
+/// Create `out_dir`, clearing it when it holds a previous freeze
+fn prepare_out_dir(out_dir: &Path) -> Result<()> {
+    let has_entries = fs::read_dir(out_dir).is_ok_and(|mut entries| entries.next().is_some());
+    if has_entries {
+        if !out_dir.join("augent.yaml").is_file() {
+            return Err(AugentError::ConfigInvalid {
+                message: format!(
+                    "Freeze output '{}' is not empty and is not a previous freeze",
+                    out_dir.display()
+                ),
+            });
+        }
+        fs::remove_dir_all(out_dir).map_err(|e| freeze_error(out_dir, &e))?;
+    }
+    fs::create_dir_all(out_dir).map_err(|e| freeze_error(out_dir, &e))
+}
+
+fn freeze_error(path: &Path, e: &std::io::Error) -> AugentError {
+    AugentError::FileWriteFailed {
+        path: path.display().to_string(),
+        reason: e.to_string(),
+    }
+}
@@ -6,6 +6,7 @@ use crate::common::path_normalizer::{PathNormalizer, paths_match};
 use crate::config::{
     BundleDependency, LockedBundle, LockedSource, WorkspaceBundle, utils::BundleContainer,
 };
+use crate::domain::ArtifactSource;
 use crate::error::Result;
 use crate::workspace::Workspace;
 
@@ -64,8 +65,8 @@ impl<'a> ConfigUpdater<'a> {
         if bundle.name == workspace_name {
             return;
         }
-        let is_git_bundle = bundle.git_source.is_some();
-        if !is_git_bundle && !update_augent_yaml {
+        let is_remote_bundle = bundle.git_source.is_some() || bundle.artifact_source.is_some();
+        if !is_remote_bundle && !update_augent_yaml {
             return;
         }
 
@@ -85,6 +86,8 @@ impl<'a> ConfigUpdater<'a> {
             let mut dep = BundleDependency::git(&bundle.name, &git_source.url, ref_for_yaml);
             dep.path.clone_from(&git_source.path);
             dep
+        } else if let Some(artifact) = &bundle.artifact_source {
+            artifact_dependency(&bundle.name, artifact)
         } else {
             let bundle_path = &bundle.source_path;
             let dir_name = self.get_dir_bundle_name(bundle_path, &bundle.name);
@@ -235,7 +238,8 @@ impl<'a> ConfigUpdater<'a> {
     }
 
     fn try_get_bundle_ref_to_backfill(&self, dep: &BundleDependency) -> Option<(String, String)> {
-        if dep.git.is_none() || dep.git_ref.is_some() {
+        // A version constraint picks its tag on every install, so it never gets a ref
+        if dep.git.is_none() || dep.git_ref.is_some() || dep.version.is_some() {
             return None;
         }
 
@@ -285,3 +289,13 @@ fn extract_bundle_name_from_path(bundle_path: &std::path::Path, default_name: &s
         .unwrap_or(default_name)
         .to_string()
 }
+
+/// augent.yaml entry for a bundle extracted from a downloaded artifact
+fn artifact_dependency(name: &str, artifact: &ArtifactSource) -> BundleDependency {
+    match artifact {
+        ArtifactSource::Archive { url, path } => BundleDependency::archive(name, url, path.clone()),
+        ArtifactSource::Oci {
+            reference, path, ..
+        } => BundleDependency::oci(name, format!("oci://{reference}"), path.clone()),
+    }
+}
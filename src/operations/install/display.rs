@@ -3,11 +3,15 @@
 
 use crate::cli::InstallArgs;
 use crate::domain::ResolvedBundle;
+use crate::error::{AugentError, Result};
 use crate::platform::Platform;
+use crate::ui::ProgressMode;
 
 /// Print platform installation information
 pub fn print_platform_info(args: &InstallArgs, platforms: &[Platform]) {
-    if args.dry_run {
+    if args.lock_only {
+        println!("Updating lockfile only, no platform files will be written");
+    } else if args.dry_run {
         println!(
             "[DRY RUN] Would install for {} platform(s): {}",
             platforms.len(),
@@ -32,16 +36,19 @@ pub fn print_platform_info(args: &InstallArgs, platforms: &[Platform]) {
 
 /// Print installation summary
 pub fn print_install_summary(
+    args: &InstallArgs,
     resolved_bundles: &[ResolvedBundle],
+    platforms: &[Platform],
     installed_files_map: &std::collections::HashMap<String, crate::domain::InstalledFile>,
-    dry_run: bool,
 ) {
     let total_files: usize = installed_files_map
         .values()
         .map(|f| f.target_paths.len())
         .sum();
 
-    if dry_run {
+    if args.lock_only {
+        println!("Locked {} bundle(s)", resolved_bundles.len());
+    } else if args.dry_run {
         println!(
             "[DRY RUN] Would install {} bundle(s), {} file(s)",
             resolved_bundles.len(),
@@ -55,12 +62,53 @@ pub fn print_install_summary(
         );
     }
 
+    if ProgressMode::from_flags(args.summary_only) == ProgressMode::SummaryOnly {
+        print_platform_counts(platforms, installed_files_map);
+        return;
+    }
+
     for bundle in resolved_bundles {
         println!("  - {}", bundle.name);
         print_bundle_files(&bundle.name, installed_files_map);
     }
 }
 
+/// Write the per-platform manifest requested with `--platform-summary-json`
+pub fn write_platform_summary(
+    path: &std::path::Path,
+    platforms: &[Platform],
+    workspace_root: &std::path::Path,
+    installed_files_map: &std::collections::HashMap<String, crate::domain::InstalledFile>,
+) -> Result<()> {
+    let manifest =
+        crate::installer::manifest::build(installed_files_map, platforms, workspace_root);
+    let write_failed = |reason: String| AugentError::FileWriteFailed {
+        path: path.display().to_string(),
+        reason,
+    };
+    let json = serde_json::to_string_pretty(&manifest).map_err(|e| write_failed(e.to_string()))?;
+    std::fs::write(path, json + "\n").map_err(|e| write_failed(e.to_string()))
+}
+
+/// Print the number of installed files per platform
+fn print_platform_counts(
+    platforms: &[Platform],
+    installed_files_map: &std::collections::HashMap<String, crate::domain::InstalledFile>,
+) {
+    for platform in platforms {
+        let count = installed_files_map
+            .values()
+            .flat_map(|f| f.target_paths.iter())
+            .filter(|target| {
+                std::path::Path::new(target)
+                    .components()
+                    .any(|c| c.as_os_str() == platform.directory.as_str())
+            })
+            .count();
+        println!("  {}: {count} file(s)", platform.id);
+    }
+}
+
 fn print_bundle_files(
     bundle_name: &str,
     installed_files_map: &std::collections::HashMap<String, crate::domain::InstalledFile>,
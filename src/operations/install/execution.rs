@@ -5,7 +5,7 @@ use crate::cli::InstallArgs;
 use crate::config::WorkspaceBundle;
 use crate::domain::ResolvedBundle;
 use crate::error::Result;
-use crate::installer::Installer;
+use crate::installer::{ContentOptions, Installer, InstallerOptions, SelectionOptions};
 use crate::platform::Platform;
 use crate::transaction::Transaction;
 use crate::ui::ProgressReporter;
@@ -33,13 +33,65 @@ impl<'a> ExecutionOrchestrator<'a> {
     fn create_installer<'b>(
         workspace_root: &'b std::path::Path,
         platforms: &[Platform],
-        dry_run: bool,
+        args: &InstallArgs,
         progress: Option<&'b mut crate::ui::InteractiveProgressReporter>,
     ) -> crate::installer::Installer<'b> {
-        if let Some(p) = progress {
-            Installer::new_with_progress(workspace_root, platforms.to_vec(), dry_run, Some(p))
+        let installer = if let Some(p) = progress {
+            Installer::new_with_progress(workspace_root, platforms.to_vec(), args.dry_run, Some(p))
         } else {
-            Installer::new_with_dry_run(workspace_root, platforms.to_vec(), dry_run)
+            Installer::new_with_dry_run(workspace_root, platforms.to_vec(), args.dry_run)
+        };
+        installer.with_options(Self::installer_options(args, workspace_root))
+    }
+
+    /// Installer options selected by the install arguments
+    fn installer_options(args: &InstallArgs, workspace_root: &std::path::Path) -> InstallerOptions {
+        InstallerOptions {
+            content: Self::content_options(args, workspace_root),
+            selection: Self::selection_options(args),
+            dedupe_composite: args.dedupe_composite,
+            concurrency: crate::common::concurrency::resolve_concurrency(args.concurrency),
+            verify: args.verify,
+            passthrough_platforms: args.platform_passthrough.clone(),
+            strategy_overrides: args.strategy_override.clone(),
+            copy_mode: if args.link {
+                crate::installer::file_ops::CopyMode::Symlink
+            } else {
+                crate::installer::file_ops::CopyMode::Copy
+            },
+            backup_dir: args.backup.clone(),
+            new_dir_policy: if args.no_new_dirs {
+                crate::installer::file_ops::NewDirPolicy::Deny
+            } else if args.confirm_new_dirs {
+                crate::installer::file_ops::NewDirPolicy::Confirm
+            } else {
+                crate::installer::file_ops::NewDirPolicy::Create
+            },
+        }
+    }
+
+    fn content_options(args: &InstallArgs, workspace_root: &std::path::Path) -> ContentOptions {
+        let template_vars = (args.template || !args.vars.is_empty())
+            .then(|| crate::installer::template::workspace_variables(workspace_root, &args.vars));
+        ContentOptions {
+            merge_frontmatter: !args.no_frontmatter_merge,
+            stamp: args.stamp,
+            gemini_toml: !args.no_universal_gemini_toml,
+            template_vars,
+            output_ext_overrides: args.platform_output_ext.clone(),
+        }
+    }
+
+    fn selection_options(args: &InstallArgs) -> SelectionOptions {
+        SelectionOptions {
+            ignore_scripts: args.ignore_scripts,
+            settings_only: args.settings_only,
+            copy_only_new: args.copy_only_new,
+            since: args
+                .since_file
+                .as_deref()
+                .and_then(crate::installer::file_ops::marker_mtime),
+            platform_filter_key: args.platform_filter_frontmatter_key.clone(),
         }
     }
 
@@ -57,40 +109,169 @@ impl<'a> ExecutionOrchestrator<'a> {
 
     pub fn install_bundles_with_progress(
         &self,
-        _installer: &crate::installer::Installer<'_>,
         args: &InstallArgs,
         resolved_bundles: &[ResolvedBundle],
         platforms: &[Platform],
+        journal: std::sync::Arc<crate::transaction::journal::FileJournal>,
     ) -> Result<(
         Vec<WorkspaceBundle>,
         std::collections::HashMap<String, crate::domain::InstalledFile>,
     )> {
         let workspace_root = self.workspace.root.clone();
+        let (unchanged, to_install) =
+            self.partition_unchanged(args, resolved_bundles, platforms)?;
+        for bundle in &unchanged {
+            println!("{}: already installed (unchanged)", bundle.name);
+        }
 
-        let mut progress: Option<crate::ui::InteractiveProgressReporter> =
-            if !args.dry_run && !resolved_bundles.is_empty() {
-                Some(crate::ui::InteractiveProgressReporter::new(
-                    resolved_bundles.len() as u64,
-                ))
-            } else {
-                None
-            };
+        let mut progress: Option<crate::ui::InteractiveProgressReporter> = if !args.dry_run
+            && !to_install.is_empty()
+        {
+            crate::ui::ProgressMode::from_flags(args.summary_only).reporter(to_install.len() as u64)
+        } else {
+            None
+        };
 
-        let (workspace_bundles_result, installed_files_map) = {
+        let (workspace_bundles_result, installed_files_map, conflicts) = {
             let mut installer =
-                Self::create_installer(&workspace_root, platforms, args.dry_run, progress.as_mut());
-            let result = installer.install_bundles(resolved_bundles);
+                Self::create_installer(&workspace_root, platforms, args, progress.as_mut())
+                    .with_journal(journal);
+            let result = Self::check_strict(args, &installer, &to_install)
+                .and_then(|()| Self::print_plan(args, &installer, &to_install))
+                .and_then(|()| installer.install_bundles(&to_install))
+                .inspect(|_| Self::print_unmatched(args, &installer, &to_install));
             let installed_files = installer.installed_files().clone();
-            (result, installed_files)
+            (result, installed_files, installer.conflicts().to_vec())
         };
 
         Self::handle_progress_result(&mut progress, &workspace_bundles_result);
+        for conflict in &conflicts {
+            eprintln!("Warning: {conflict}");
+        }
+        let mut workspace_bundles = workspace_bundles_result?;
+        workspace_bundles.extend(unchanged.iter().map(|bundle| {
+            super::unchanged::existing_workspace_bundle(self.workspace, &bundle.name)
+        }));
 
-        Ok((workspace_bundles_result?, installed_files_map))
+        if let Some(marker) = args.since_file.as_deref().filter(|_| !args.dry_run) {
+            crate::installer::file_ops::touch_marker(marker)?;
+        }
+
+        // --strict implies --validate-toml
+        if args.validate_toml || args.strict {
+            Self::validate_toml_outputs(&installed_files_map)?;
+        }
+
+        Ok((workspace_bundles, installed_files_map))
+    }
+
+    /// List bundle files that no platform installs (`--report-unmatched`)
+    fn print_unmatched(args: &InstallArgs, installer: &Installer<'_>, bundles: &[ResolvedBundle]) {
+        if !args.report_unmatched {
+            return;
+        }
+        let unmatched = installer.unmatched_files(bundles);
+        if unmatched.is_empty() {
+            println!("No unmatched bundle files.");
+            return;
+        }
+        println!(
+            "Bundle files not installed for any platform ({}):",
+            unmatched.len()
+        );
+        for file in &unmatched {
+            println!("  {file}");
+        }
+    }
+
+    /// Print the dry-run install plan as JSON (`--json`)
+    fn print_plan(
+        args: &InstallArgs,
+        installer: &Installer<'_>,
+        bundles: &[ResolvedBundle],
+    ) -> Result<()> {
+        if !args.json {
+            return Ok(());
+        }
+        let json = serde_json::to_string_pretty(&installer.plan(bundles)).map_err(|e| {
+            crate::error::AugentError::IoError {
+                message: format!("Failed to serialize install plan: {e}"),
+                source: None,
+            }
+        })?;
+        println!("{json}");
+        Ok(())
+    }
+
+    /// Fail when bundles would replace each other's files (`--strict`)
+    ///
+    /// Checks the plan so a conflict leaves no files written.
+    fn check_strict(
+        args: &InstallArgs,
+        installer: &Installer<'_>,
+        bundles: &[ResolvedBundle],
+    ) -> Result<()> {
+        if !args.strict {
+            return Ok(());
+        }
+        let conflicts = installer.planned_conflicts(bundles);
+        if conflicts.is_empty() {
+            return Ok(());
+        }
+        let lines: Vec<String> = conflicts
+            .iter()
+            .map(|c| {
+                format!(
+                    "  {}: {} and {}",
+                    c.target.display(),
+                    c.first_bundle,
+                    c.second_bundle
+                )
+            })
+            .collect();
+        Err(crate::error::AugentError::BundleValidationFailed {
+            message: format!(
+                "{} target(s) installed by more than one bundle (--strict):\n{}",
+                conflicts.len(),
+                lines.join("\n")
+            ),
+        })
+    }
+
+    /// Split bundles into those already installed at their locked SHA and those to install
+    fn partition_unchanged(
+        &self,
+        args: &InstallArgs,
+        resolved_bundles: &[ResolvedBundle],
+        platforms: &[Platform],
+    ) -> Result<(Vec<ResolvedBundle>, Vec<ResolvedBundle>)> {
+        let options = Self::installer_options(args, &self.workspace.root);
+        if args.dry_run || !super::unchanged::renders_as_plain_install(&options) {
+            return Ok((Vec::new(), resolved_bundles.to_vec()));
+        }
+        let cache_dir = crate::cache::bundles_cache_dir()?;
+        let modified =
+            crate::workspace::modified::detect_modified_files(self.workspace, &cache_dir);
+        Ok(resolved_bundles.iter().cloned().partition(|bundle| {
+            super::unchanged::is_unchanged(self.workspace, bundle, platforms, &modified)
+        }))
+    }
+
+    /// Parse every generated TOML file to catch escaping bugs in converters
+    fn validate_toml_outputs(
+        installed_files_map: &std::collections::HashMap<String, crate::domain::InstalledFile>,
+    ) -> Result<()> {
+        installed_files_map
+            .values()
+            .flat_map(|installed| &installed.target_paths)
+            .try_for_each(|target| {
+                crate::installer::formats::gemini::validate_generated_toml(std::path::Path::new(
+                    target,
+                ))
+            })
     }
 
     pub fn track_installed_files_in_transaction(
-        _installer: &crate::installer::Installer<'_>,
         workspace_root: &std::path::Path,
         installed_files_map: &std::collections::HashMap<String, crate::domain::InstalledFile>,
         transaction: &mut Transaction,
@@ -128,17 +309,117 @@ impl<'a> ExecutionOrchestrator<'a> {
 
         if !ctx.args.dry_run {
             self.workspace.save()?;
-            *self.workspace = Workspace::open(ctx.workspace_root)?;
+            let profile = self.workspace.profile.take();
+            *self.workspace = Workspace::open_with_profile(ctx.workspace_root, profile.as_deref())?;
         }
         Ok(())
     }
 
+    /// Platforms already present in the workspace plus any requested with `--platforms-create`
     pub fn get_or_select_platforms(
-        _args: &InstallArgs,
+        args: &InstallArgs,
         workspace_root: &std::path::Path,
         _force_interactive: bool,
     ) -> Result<Vec<Platform>> {
-        let platforms = crate::platform::detection::detect_platforms(workspace_root)?;
+        let mut platforms = crate::platform::detection::detect_platforms(workspace_root)?;
+        let requested = crate::platform::detection::get_platforms(
+            &args.platforms_create,
+            Some(workspace_root),
+        )?;
+        let missing: Vec<Platform> = requested
+            .into_iter()
+            .filter(|platform| !platforms.iter().any(|p| p.id == platform.id))
+            .collect();
+        platforms.extend(missing);
+
+        // `--to` narrows the present platforms down to the listed ones
+        if !args.platforms.is_empty() {
+            let only =
+                crate::platform::detection::get_platforms(&args.platforms, Some(workspace_root))?;
+            platforms.retain(|p| only.iter().any(|o| o.id == p.id));
+        }
         Ok(platforms)
     }
+
+    /// Create directories for platforms requested with `--platforms-create`
+    pub fn create_requested_platform_dirs(
+        args: &InstallArgs,
+        workspace_root: &std::path::Path,
+        platforms: &[Platform],
+    ) -> Result<()> {
+        if args.dry_run {
+            return Ok(());
+        }
+        platforms
+            .iter()
+            .filter(|p| {
+                args.platforms_create
+                    .iter()
+                    .any(|name| *name == p.id || p.aliases.contains(name))
+            })
+            .map(|platform| workspace_root.join(&platform.directory))
+            .try_for_each(|dir| create_platform_dir(&dir))
+    }
+
+    /// Refuse platform directories that resolve outside the workspace
+    ///
+    /// A platform directory, or any of its ancestors below the workspace
+    /// root, may be a symlink; writes through it would escape the repository.
+    /// Missing directories are checked through their nearest existing ancestor.
+    pub fn check_platform_dirs_within_workspace(
+        workspace_root: &std::path::Path,
+        platforms: &[Platform],
+    ) -> Result<()> {
+        let Ok(root) = std::fs::canonicalize(workspace_root) else {
+            return Ok(());
+        };
+        platforms
+            .iter()
+            .map(|platform| workspace_root.join(&platform.directory))
+            .try_for_each(|dir| check_dir_within(&root, &dir))
+    }
+
+    /// Warn when the platform directories hold uncommitted changes install may overwrite
+    pub fn warn_about_uncommitted_changes(
+        args: &InstallArgs,
+        workspace_root: &std::path::Path,
+        platforms: &[Platform],
+    ) {
+        if args.allow_dirty || args.dry_run {
+            return;
+        }
+        let dirs: Vec<std::path::PathBuf> = platforms
+            .iter()
+            .map(|p| std::path::PathBuf::from(&p.directory))
+            .collect();
+        if let Some(warning) =
+            crate::workspace::git::uncommitted_changes_warning(workspace_root, &dirs)
+        {
+            eprintln!("Warning: {warning}");
+        }
+    }
+}
+
+/// Refuse `dir` when its nearest existing ancestor resolves outside `root`
+fn check_dir_within(root: &std::path::Path, dir: &std::path::Path) -> Result<()> {
+    let Some(resolved) = dir
+        .ancestors()
+        .find_map(|ancestor| std::fs::canonicalize(ancestor).ok())
+    else {
+        return Ok(());
+    };
+    if resolved.starts_with(root) {
+        return Ok(());
+    }
+    Err(crate::error::AugentError::UnsafeTargetPath {
+        path: dir.display().to_string(),
+        resolved: dunce::simplified(&resolved).display().to_string(),
+    })
+}
+
+fn create_platform_dir(dir: &std::path::Path) -> Result<()> {
+    std::fs::create_dir_all(dir).map_err(|e| crate::error::AugentError::FileWriteFailed {
+        path: dir.display().to_string(),
+        reason: e.to_string(),
+    })
 }
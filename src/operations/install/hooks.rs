@@ -0,0 +1,222 @@
+//! Workspace install hooks (`--allow-hooks`)
+//!
+//! With `--allow-hooks`, an executable `.augent/hooks/pre-install` runs after
+//! bundles are resolved and platforms selected but before anything is
+//! written, and `.augent/hooks/post-install` runs after the workspace files
+//! are saved. Missing hooks are skipped, and no hook runs on `--dry-run`.
+//!
+//! Hooks run in the workspace root with the install context in environment
+//! variables. Their output is captured and echoed to stderr so `--json` output
+//! stays clean. A hook that exits non-zero, or runs longer than
+//! `AUGENT_HOOK_TIMEOUT` seconds (default 300), fails the install; a failing
+//! pre-install hook therefore aborts it before any file is touched.
+
+use std::io::Read;
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
+
+use crate::domain::ResolvedBundle;
+use crate::error::{AugentError, Result};
+use crate::platform::Platform;
+
+/// Directory of hook scripts inside the workspace's `.augent` directory
+pub const HOOKS_DIR: &str = "hooks";
+
+/// Environment variable overriding the hook timeout in seconds
+pub const HOOK_TIMEOUT_ENV: &str = "AUGENT_HOOK_TIMEOUT";
+
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(300);
+const POLL_INTERVAL: Duration = Duration::from_millis(20);
+
+/// Point in the install at which a hook runs
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Hook {
+    PreInstall,
+    PostInstall,
+}
+
+impl Hook {
+    /// Script file name under `.augent/hooks/`
+    pub fn file_name(self) -> &'static str {
+        match self {
+            Self::PreInstall => "pre-install",
+            Self::PostInstall => "post-install",
+        }
+    }
+}
+
+/// Install state passed to hooks
+pub struct HookContext<'a> {
+    pub workspace_root: &'a Path,
+    pub bundles: &'a [ResolvedBundle],
+    pub platforms: &'a [Platform],
+}
+
+/// Run `hook` for this install when hooks are allowed and it exists
+///
+/// The script receives `AUGENT_HOOK` (hook name), `AUGENT_WORKSPACE`
+/// (workspace root), `AUGENT_BUNDLES` (comma-separated bundle names in
+/// install order) and `AUGENT_PLATFORMS` (comma-separated platform ids).
+pub fn run(hook: Hook, ctx: &HookContext<'_>, allow_hooks: bool, dry_run: bool) -> Result<()> {
+    if !allow_hooks || dry_run {
+        return Ok(());
+    }
+    let workspace_root = ctx.workspace_root;
+    let script = workspace_root
+        .join(crate::workspace::WORKSPACE_DIR)
+        .join(HOOKS_DIR)
+        .join(hook.file_name());
+    if !script.is_file() {
+        return Ok(());
+    }
+
+    let bundle_names: Vec<&str> = ctx.bundles.iter().map(|b| b.name.as_str()).collect();
+    let platform_ids: Vec<&str> = ctx.platforms.iter().map(|p| p.id.as_str()).collect();
+    let mut command = Command::new(&script);
+    command
+        .current_dir(workspace_root)
+        .env("AUGENT_HOOK", hook.file_name())
+        .env("AUGENT_WORKSPACE", workspace_root)
+        .env("AUGENT_BUNDLES", bundle_names.join(","))
+        .env("AUGENT_PLATFORMS", platform_ids.join(","));
+    run_with_timeout(command, hook, hook_timeout())
+}
+
+fn hook_timeout() -> Duration {
+    std::env::var(HOOK_TIMEOUT_ENV)
+        .ok()
+        .and_then(|value| value.trim().parse().ok())
+        .map_or(DEFAULT_TIMEOUT, Duration::from_secs)
+}
+
+/// Spawn `command`, echo its captured output and fail on non-zero exit or timeout
+fn run_with_timeout(mut command: Command, hook: Hook, timeout: Duration) -> Result<()> {
+    let failed = |reason: String| AugentError::HookFailed {
+        hook: hook.file_name().to_string(),
+        reason,
+    };
+    let mut child = command
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| failed(e.to_string()))?;
+
+    // Drain both pipes while waiting so a chatty hook cannot block on a full pipe
+    let stdout = child.stdout.take().map(drain);
+    let stderr = child.stderr.take().map(drain);
+    let deadline = Instant::now() + timeout;
+    let status = loop {
+        if let Some(status) = child.try_wait().map_err(|e| failed(e.to_string()))? {
+            break Some(status);
+        }
+        if Instant::now() >= deadline {
+            let _ = child.kill();
+            let _ = child.wait();
+            break None;
+        }
+        std::thread::sleep(POLL_INTERVAL);
+    };
+    // After a timeout, processes the hook started may still hold the pipes
+    // open, so its output is abandoned rather than waited for
+    let Some(status) = status else {
+        return Err(failed(format!("timed out after {timeout:?}")));
+    };
+    let stdout = stdout.and_then(|h| h.join().ok()).unwrap_or_default();
+    let stderr = stderr.and_then(|h| h.join().ok()).unwrap_or_default();
+    eprint!("{stdout}{stderr}");
+
+    if status.success() {
+        Ok(())
+    } else {
+        Err(failed(format!("exited with {status}")))
+    }
+}
+
+fn drain(mut pipe: impl Read + Send + 'static) -> std::thread::JoinHandle<String> {
+    std::thread::spawn(move || {
+        let mut output = Vec::new();
+        let _ = pipe.read_to_end(&mut output);
+        String::from_utf8_lossy(&output).into_owned()
+    })
+}
+
+#[cfg(all(test, unix))]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::*;
+    use std::os::unix::fs::PermissionsExt;
+
+    fn write_hook(root: &Path, hook: Hook, body: &str) {
+        let dir = root.join(crate::workspace::WORKSPACE_DIR).join(HOOKS_DIR);
+        std::fs::create_dir_all(&dir).expect("Failed to create hooks dir");
+        let path = dir.join(hook.file_name());
+        std::fs::write(&path, format!("#!/bin/sh\n{body}\n")).expect("Failed to write hook");
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755))
+            .expect("Failed to make hook executable");
+    }
+
+    #[test]
+    fn test_hook_runs_only_with_allow_hooks() {
+        let temp = tempfile::TempDir::new_in(crate::temp::temp_dir_base())
+            .expect("Failed to create temp dir");
+        let root = temp.path();
+        write_hook(
+            root,
+            Hook::PostInstall,
+            "echo \"$AUGENT_HOOK $AUGENT_PLATFORMS\" > marker",
+        );
+        let platforms: Vec<Platform> = crate::platform::default_platforms()
+            .into_iter()
+            .filter(|p| p.id == "claude")
+            .collect();
+
+        let ctx = HookContext {
+            workspace_root: root,
+            bundles: &[],
+            platforms: &platforms,
+        };
+
+        run(Hook::PostInstall, &ctx, false, false).expect("Hook should be skipped");
+        assert!(!root.join("marker").exists());
+        run(Hook::PostInstall, &ctx, true, true).expect("Hook should be skipped");
+        assert!(!root.join("marker").exists());
+
+        run(Hook::PostInstall, &ctx, true, false).expect("Hook should succeed");
+        let marker = std::fs::read_to_string(root.join("marker")).expect("Hook wrote no marker");
+        assert_eq!(marker.trim(), "post-install claude");
+    }
+
+    #[test]
+    fn test_failing_pre_install_hook_errors() {
+        let temp = tempfile::TempDir::new_in(crate::temp::temp_dir_base())
+            .expect("Failed to create temp dir");
+        write_hook(temp.path(), Hook::PreInstall, "echo invalid >&2\nexit 3");
+
+        let ctx = HookContext {
+            workspace_root: temp.path(),
+            bundles: &[],
+            platforms: &[],
+        };
+
+        let result = run(Hook::PreInstall, &ctx, true, false);
+        assert!(matches!(
+            result,
+            Err(AugentError::HookFailed { ref hook, .. }) if hook == "pre-install"
+        ));
+    }
+
+    #[test]
+    fn test_hook_timeout_kills_script() {
+        let command = {
+            let mut command = Command::new("sh");
+            command.args(["-c", "exec sleep 5"]);
+            command
+        };
+        let started = Instant::now();
+        let result = run_with_timeout(command, Hook::PreInstall, Duration::from_millis(100));
+        assert!(matches!(result, Err(AugentError::HookFailed { .. })));
+        assert!(started.elapsed() < Duration::from_secs(5));
+    }
+}
@@ -1,8 +1,8 @@
 //! Lockfile generation for install operation
 //! Handles creating locked bundles and generating lockfiles
 
-use crate::config::{LockedBundle, LockedSource};
-use crate::domain::ResolvedBundle;
+use crate::config::{LockedBundle, LockedPlatformRef, LockedSource};
+use crate::domain::{ArtifactSource, ResolvedBundle};
 use crate::error::Result;
 use crate::hash;
 use crate::installer::discovery::discover_resources;
@@ -103,6 +103,27 @@ fn extract_metadata(bundle: &ResolvedBundle) -> BundleMetadata {
     }
 }
 
+/// Create locked source for a bundle extracted from a downloaded artifact
+fn create_artifact_locked_source(artifact: &ArtifactSource, bundle_hash: String) -> LockedSource {
+    match artifact {
+        ArtifactSource::Archive { url, path } => LockedSource::Archive {
+            url: url.clone(),
+            path: path.clone(),
+            hash: bundle_hash,
+        },
+        ArtifactSource::Oci {
+            reference,
+            digest,
+            path,
+        } => LockedSource::Oci {
+            reference: reference.clone(),
+            digest: digest.clone(),
+            path: path.clone(),
+            hash: bundle_hash,
+        },
+    }
+}
+
 /// Create a locked bundle from a resolved bundle
 #[allow(dead_code)]
 pub fn create_locked_bundle_from_resolved(
@@ -119,6 +140,8 @@ pub fn create_locked_bundle_from_resolved(
 
     let source = if let Some(ref git_source) = bundle.git_source {
         create_git_locked_source(bundle, git_source, bundle_hash)
+    } else if let Some(artifact) = &bundle.artifact_source {
+        create_artifact_locked_source(artifact, bundle_hash)
     } else {
         let relative_path = calculate_relative_path(&bundle.source_path, workspace_root);
         create_dir_locked_source(relative_path, bundle_hash)
@@ -135,5 +158,16 @@ pub fn create_locked_bundle_from_resolved(
         homepage,
         source,
         files,
+        platform_refs: bundle
+            .platform_sources
+            .iter()
+            .map(|(platform_id, source)| {
+                let locked = LockedPlatformRef {
+                    git_ref: source.git_ref.clone(),
+                    sha: source.sha.clone(),
+                };
+                (platform_id.clone(), locked)
+            })
+            .collect(),
     })
 }
@@ -15,7 +15,10 @@
 //! - **names**: Name fixer that ensures correct bundle naming conventions
 //! - **lockfile**: Lockfile helpers for SHA tracking and hash verification
 //! - **display**: Display utilities for user-facing output
+//! - **preflight**: Offline cache check for `--dry-run --offline`
+//! - **unchanged**: Per-bundle skip when the locked SHA is already installed
 //! - **context**: Shared context consolidating coordinator instances and common state
+//! - **hooks**: Workspace `pre-install`/`post-install` scripts run with `--allow-hooks`
 //!
 //! ## Installation Workflow
 //!
@@ -99,7 +102,8 @@
 //!
 //! All file modifications are performed within a transaction:
 //!
-//! - Files are tracked before modification
+//! - Files are tracked before modification; every target is snapshotted
+//!   into the transaction's file journal before the installer writes it
 //! - On error, all changes are rolled back
 //! - Only committed changes persist
 //! - Modified files are preserved across installations
@@ -115,7 +119,7 @@
 //! let args = InstallArgs::parse();
 //!
 //! // Open workspace
-//! let mut workspace = Workspace::init_or_open(&std::path::Path::new("."))?;
+//! let mut workspace = Workspace::init_or_open(&std::path::Path::new("."), None)?;
 //!
 //! // Create install operation
 //! let options = InstallOptions::from(&args);
@@ -129,10 +133,13 @@ pub mod config;
 pub mod context;
 pub mod display;
 pub mod execution;
+pub mod hooks;
 pub mod lockfile;
 pub mod names;
 pub mod orchestrator;
+pub mod preflight;
 pub mod resolution;
+pub mod unchanged;
 pub mod workspace;
 
 pub use orchestrator::{InstallOperation, InstallOptions};
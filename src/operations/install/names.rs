@@ -94,6 +94,8 @@ impl<'a> NameFixer<'a> {
                 resolved_ref: None,
                 git_source: None,
                 config: None,
+                platform_sources: std::collections::HashMap::new(),
+                artifact_source: None,
             };
             resolved_bundles.push(workspace_bundle);
         }
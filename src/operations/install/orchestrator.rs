@@ -20,6 +20,16 @@ impl From<&InstallArgs> for InstallOptions {
     }
 }
 
+impl From<&InstallArgs> for crate::cache::FetchOptions {
+    fn from(args: &InstallArgs) -> Self {
+        Self {
+            prefer_cache: args.prefer_cache,
+            offline: args.offline,
+            full_clone: args.full_clone,
+        }
+    }
+}
+
 /// Main orchestrator for install operation
 pub struct InstallOperation<'a> {
     workspace: &'a mut Workspace,
@@ -72,15 +82,17 @@ impl<'a> InstallOperation<'a> {
                 && !source.starts_with('/')
                 && !source.starts_with("http")
                 && !source.starts_with("git@")
-                && !source.starts_with("github:"))
+                && !source.starts_with("github:")
+                && !source.starts_with("gist:"))
     }
 
     /// Get names of already installed bundles for menu display
     pub fn get_installed_bundle_names_for_menu(
         workspace_root: &std::path::Path,
+        profile: Option<&str>,
         discovered: &[DiscoveredBundle],
     ) -> Vec<String> {
-        let Ok(workspace) = Workspace::open(workspace_root) else {
+        let Ok(workspace) = Workspace::open_with_profile(workspace_root, profile) else {
             return vec![];
         };
 
@@ -148,14 +160,44 @@ impl<'a> InstallOperation<'a> {
     fn select_and_validate_platforms(&mut self, args: &InstallArgs) -> Result<Vec<Platform>> {
         use super::execution::ExecutionOrchestrator;
 
+        // Lock-only installs never write platform files
+        if args.lock_only {
+            return Ok(Vec::new());
+        }
+
         let workspace_root = self.workspace.root.clone();
-        let platforms =
+        crate::platform::output_ext::validate_overrides(
+            &args.platform_output_ext,
+            &workspace_root,
+        )?;
+        let order =
+            crate::platform::detection::get_platforms(&args.platform_order, Some(&workspace_root))?;
+        let mut selected =
             ExecutionOrchestrator::get_or_select_platforms(args, &workspace_root, false)?;
+        // Passthrough platforms are installed even when not detected
+        let passthrough: Vec<_> = crate::platform::detection::get_platforms(
+            &args.platform_passthrough,
+            Some(&workspace_root),
+        )?
+        .into_iter()
+        .filter(|platform| !selected.iter().any(|p| p.id == platform.id))
+        .collect();
+        selected.extend(passthrough);
+        let platforms = crate::platform::detection::apply_platform_order(selected, &order);
 
         if platforms.is_empty() {
             return Err(AugentError::NoPlatformsDetected);
         }
 
+        // Checked before --platforms-create, which would create the directories outside
+        if !args.allow_external_targets {
+            ExecutionOrchestrator::check_platform_dirs_within_workspace(
+                &workspace_root,
+                &platforms,
+            )?;
+        }
+        ExecutionOrchestrator::create_requested_platform_dirs(args, &workspace_root, &platforms)?;
+        ExecutionOrchestrator::warn_about_uncommitted_changes(args, &workspace_root, &platforms);
         Ok(platforms)
     }
 
@@ -174,23 +216,18 @@ impl<'a> InstallOperation<'a> {
         let workspace_root = self.workspace.root.clone();
         let mut exec_orchestrator = ExecutionOrchestrator::new(self.workspace);
 
-        let installer = crate::installer::Installer::new_with_dry_run(
-            &workspace_root,
-            platforms.to_vec(),
-            args.dry_run,
-        );
-
-        let bundle_result = exec_orchestrator.install_bundles_with_progress(
-            &installer,
-            args,
-            resolved_bundles,
-            platforms,
-        )?;
-        let workspace_bundles = bundle_result.0.clone();
-        let installed_files_map = bundle_result.1;
+        let (workspace_bundles, installed_files_map) = if args.lock_only {
+            (Vec::new(), std::collections::HashMap::new())
+        } else {
+            exec_orchestrator.install_bundles_with_progress(
+                args,
+                resolved_bundles,
+                platforms,
+                transaction.file_journal(),
+            )?
+        };
 
         ExecutionOrchestrator::track_installed_files_in_transaction(
-            &installer,
             &workspace_root,
             &installed_files_map,
             transaction,
@@ -248,6 +285,37 @@ impl<'a> InstallOperation<'a> {
             .clone())
     }
 
+    /// Print the install summary and write the requested summary and provenance files
+    fn report_installed(
+        args: &InstallArgs,
+        ctx: &super::hooks::HookContext<'_>,
+        installed_files_map: &std::collections::HashMap<String, crate::domain::InstalledFile>,
+    ) -> Result<()> {
+        use super::display;
+
+        if !args.json {
+            display::print_install_summary(args, ctx.bundles, ctx.platforms, installed_files_map);
+        }
+        if let Some(path) = &args.platform_summary_json {
+            display::write_platform_summary(
+                path,
+                ctx.platforms,
+                ctx.workspace_root,
+                installed_files_map,
+            )?;
+        }
+        if args.record_provenance && !args.dry_run {
+            crate::installer::provenance::write_sidecars(
+                installed_files_map,
+                ctx.platforms,
+                ctx.workspace_root,
+                ctx.bundles,
+                args.verify,
+            )?;
+        }
+        Ok(())
+    }
+
     /// Execute the install operation
     pub fn execute(
         &mut self,
@@ -263,11 +331,27 @@ impl<'a> InstallOperation<'a> {
         let resolved_bundles = self.prepare_bundles_with_workspace(resolved_bundles, args)?;
 
         let platforms = self.select_and_validate_platforms(args)?;
-        if platforms.is_empty() {
+        if platforms.is_empty() && !args.lock_only {
             return Err(AugentError::NoPlatformsDetected);
         }
 
-        display::print_platform_info(args, &platforms);
+        // --json keeps stdout to the plan document
+        if !args.json {
+            display::print_platform_info(args, &platforms);
+        }
+
+        let workspace_root = self.workspace.root.clone();
+        let hook_ctx = super::hooks::HookContext {
+            workspace_root: &workspace_root,
+            bundles: &resolved_bundles,
+            platforms: &platforms,
+        };
+        super::hooks::run(
+            super::hooks::Hook::PreInstall,
+            &hook_ctx,
+            args.allow_hooks,
+            args.dry_run,
+        )?;
 
         let (_workspace_bundles, installed_files_map) = self.install_bundles_and_update_configs(
             args,
@@ -276,7 +360,13 @@ impl<'a> InstallOperation<'a> {
             transaction,
         )?;
 
-        display::print_install_summary(&resolved_bundles, &installed_files_map, args.dry_run);
+        Self::report_installed(args, &hook_ctx, &installed_files_map)?;
+        super::hooks::run(
+            super::hooks::Hook::PostInstall,
+            &hook_ctx,
+            args.allow_hooks,
+            args.dry_run,
+        )?;
 
         Ok(())
     }
@@ -0,0 +1,69 @@
+//! Offline cache pre-flight for `install --dry-run --offline`
+//!
+//! Checks, without fetching, whether every git bundle in the lockfile is
+//! already cached at its locked SHA. Bundles that are not would need a
+//! network fetch, which an air-gapped install cannot do.
+
+use crate::config::{LockedSource, Lockfile};
+use crate::error::Result;
+use crate::source::GitSource;
+
+/// Cache availability of one locked git bundle
+#[derive(Debug, Clone)]
+pub struct CacheStatus {
+    pub name: String,
+    pub url: String,
+    pub sha: String,
+    pub cached: bool,
+}
+
+/// Look up every locked git bundle in the cache
+pub fn check_cache(lockfile: &Lockfile) -> Result<Vec<CacheStatus>> {
+    let mut statuses = Vec::new();
+    for bundle in &lockfile.bundles {
+        let LockedSource::Git {
+            url,
+            path,
+            git_ref,
+            sha,
+            ..
+        } = &bundle.source
+        else {
+            continue;
+        };
+        let source = GitSource {
+            url: url.clone(),
+            path: path.clone(),
+            git_ref: git_ref.clone(),
+            resolved_sha: Some(sha.clone()),
+        };
+        statuses.push(CacheStatus {
+            name: bundle.name.clone(),
+            url: url.clone(),
+            sha: sha.clone(),
+            cached: crate::cache::lookup::get_cached(&source)?.is_some(),
+        });
+    }
+    Ok(statuses)
+}
+
+/// Print the pre-flight report; returns whether every bundle is cached
+pub fn print_report(statuses: &[CacheStatus]) -> bool {
+    println!("[DRY RUN] Offline cache check:");
+    if statuses.is_empty() {
+        println!("  No git bundles in lockfile");
+    }
+    for status in statuses {
+        let state = if status.cached {
+            "cached"
+        } else {
+            "requires network"
+        };
+        println!("  {state}: {} ({}@{})", status.name, status.url, status.sha);
+    }
+    let missing = statuses.iter().filter(|s| !s.cached).count();
+    if missing > 0 {
+        println!("[DRY RUN] {missing} bundle(s) would require a network fetch");
+    }
+    missing == 0
+}
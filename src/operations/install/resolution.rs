@@ -36,56 +36,106 @@ impl<'a> InstallResolver<'a> {
         url
     }
 
+    /// Source string for a git dependency: its URL with the ref as fragment
+    ///
+    /// A `version` constraint is resolved to the highest matching tag first;
+    /// `locked` is the dependency's tag in the lockfile.
+    pub(crate) fn git_dep_source(
+        git_url: &str,
+        dep: &crate::config::BundleDependency,
+        locked: Option<&str>,
+        options: crate::cache::FetchOptions,
+    ) -> Result<String> {
+        let git_ref = match &dep.version {
+            Some(version) => Some(crate::resolver::version::resolve_version_tag(
+                git_url, version, locked, options,
+            )?),
+            None => dep.git_ref.clone(),
+        };
+        Ok(git_ref.map_or_else(
+            || git_url.to_string(),
+            |git_ref| format!("{git_url}#{git_ref}"),
+        ))
+    }
+
     /// Collect all bundles from workspace bundle configuration
+    ///
+    /// The git bundles are fetched with up to `jobs` threads first.
     fn collect_workspace_bundles(
         &self,
         bundle_resolver: &mut Resolver,
+        jobs: usize,
     ) -> Result<Vec<ResolvedBundle>> {
+        let deps = &self.workspace.bundle_config.bundles;
+        let options = bundle_resolver.fetch_options();
+        let locked_tags = self.locked_tags();
+        let git_sources = deps
+            .iter()
+            .map(|dep| match (&dep.git, &dep.path) {
+                (Some(git_url), None) => {
+                    let locked = locked_tags.get(&dep.name).map(String::as_str);
+                    Self::git_dep_source(git_url, dep, locked, options).map(Some)
+                }
+                _ => Ok(None),
+            })
+            .collect::<Result<Vec<Option<String>>>>()?;
+        let prefetched: Vec<String> = git_sources.iter().flatten().cloned().collect();
+        bundle_resolver.prefetch(&prefetched, jobs);
+
         let mut all_bundles = Vec::new();
-        for dep in &self.workspace.bundle_config.bundles {
-            Self::resolve_single_dep(dep, bundle_resolver, &mut all_bundles)?;
+        for (dep, git_source) in deps.iter().zip(&git_sources) {
+            Self::resolve_workspace_dep(
+                dep,
+                git_source.as_deref(),
+                bundle_resolver,
+                &mut all_bundles,
+            )?;
         }
         Ok(all_bundles)
     }
 
-    fn resolve_single_dep(
+    /// Resolve a workspace dependency from its git source, archive URL or OCI
+    /// reference, or from its path when it has none of them
+    fn resolve_workspace_dep(
         dep: &crate::config::BundleDependency,
+        git_source: Option<&str>,
         bundle_resolver: &mut Resolver,
         all_bundles: &mut Vec<ResolvedBundle>,
     ) -> Result<()> {
-        let has_git_url = dep.git.is_some();
-        let has_path = dep.path.is_some();
-
-        let has_valid_combination = (has_git_url && !has_path) || (!has_git_url && has_path);
-        if !has_valid_combination {
-            return Ok(());
+        match (git_source, Self::artifact_dep_source(dep)) {
+            (Some(source), _) => Self::resolve_git_dep(dep, source, bundle_resolver, all_bundles),
+            (None, Some(source)) => {
+                let bundles = bundle_resolver.resolve(&source, false)?;
+                all_bundles.extend(bundles);
+                Ok(())
+            }
+            (None, None) if dep.git.is_none() && dep.path.is_some() => {
+                Self::resolve_path_dep(dep, bundle_resolver, all_bundles)
+            }
+            (None, None) => Ok(()),
         }
+    }
 
-        if has_git_url {
-            Self::resolve_git_dep(dep, bundle_resolver, all_bundles)?;
-        } else {
-            Self::resolve_path_dep(dep, bundle_resolver, all_bundles)?;
-        }
-        Ok(())
+    /// Source of an archive or OCI dependency, with its path as the fragment
+    fn artifact_dep_source(dep: &crate::config::BundleDependency) -> Option<String> {
+        let source = match (&dep.archive, &dep.oci) {
+            (Some(url), _) => format!("tarball:{url}"),
+            (None, Some(reference)) => format!("oci://{}", reference.trim_start_matches("oci://")),
+            (None, None) => return None,
+        };
+        Some(match &dep.path {
+            Some(path) => format!("{source}#{path}"),
+            None => source,
+        })
     }
 
     fn resolve_git_dep(
         dep: &crate::config::BundleDependency,
+        source: &str,
         bundle_resolver: &mut Resolver,
         all_bundles: &mut Vec<ResolvedBundle>,
     ) -> Result<()> {
-        let git_url =
-            dep.git
-                .as_ref()
-                .ok_or_else(|| crate::error::AugentError::ConfigParseFailed {
-                    path: "workspace config".to_string(),
-                    reason: "git dependency missing git URL".to_string(),
-                })?;
-        let source = dep
-            .git_ref
-            .as_ref()
-            .map_or_else(|| git_url.clone(), |git_ref| format!("{git_url}#{git_ref}"));
-        let bundles = bundle_resolver.resolve(&source, false)?;
+        let bundles = bundle_resolver.resolve_with_platform_refs(source, &dep.platform_refs)?;
         all_bundles.extend(bundles);
         Ok(())
     }
@@ -121,11 +171,18 @@ impl<'a> InstallResolver<'a> {
         }
     }
 
-    /// Resolve multiple bundles with git sources
+    /// Resolve multiple bundles with git sources, fetching them with up to `jobs` threads first
     fn resolve_git_bundles(
         selected_bundles: &[crate::domain::DiscoveredBundle],
         bundle_resolver: &mut Resolver,
+        jobs: usize,
     ) -> Result<Vec<ResolvedBundle>> {
+        let git_sources: Vec<String> = selected_bundles
+            .iter()
+            .filter_map(|b| b.git_source.as_ref().map(Self::build_git_source_url))
+            .collect();
+        bundle_resolver.prefetch(&git_sources, jobs);
+
         let mut all_bundles = Vec::new();
         for discovered in selected_bundles {
             Self::resolve_bundle_with_git_or_local(discovered, bundle_resolver, &mut all_bundles)?;
@@ -191,32 +248,89 @@ impl<'a> InstallResolver<'a> {
         args: &crate::cli::InstallArgs,
         selected_bundles: &[crate::domain::DiscoveredBundle],
     ) -> Result<Vec<ResolvedBundle>> {
-        let mut bundle_resolver = Resolver::new(&self.workspace.root);
-        let pb = Self::create_progress_bar(args.dry_run);
+        let mut bundle_resolver = Resolver::new(&self.workspace.root)
+            .with_fetch_options(args.into())
+            .with_locked_platform_refs(self.locked_platform_refs(args.frozen))
+            .with_locked_archives(self.locked_archives())
+            .with_locked_tags(self.locked_tags())
+            .with_allow_version_conflicts(args.allow_version_conflicts)
+            .with_overrides(self.workspace.bundle_config.overrides.clone());
+        let pb = Self::create_progress_bar(args.dry_run || args.summary_only);
+        let jobs = crate::common::concurrency::resolve_concurrency(args.concurrency);
 
         let resolved_bundles = match selected_bundles.len() {
             0 => match args.source.as_ref() {
                 Some(source) => bundle_resolver.resolve(source, false),
-                None => return self.collect_workspace_bundles(&mut bundle_resolver),
+                None => self.collect_workspace_bundles(&mut bundle_resolver, jobs),
             },
             1 => Self::resolve_single_bundle(&selected_bundles[0], &mut bundle_resolver),
-            _ => Self::resolve_multiple_bundles(selected_bundles, &mut bundle_resolver),
+            _ => Self::resolve_multiple_bundles(selected_bundles, &mut bundle_resolver, jobs),
         }?;
 
         if let Some(pb) = pb {
             pb.finish_and_clear();
         }
+        for conflict in bundle_resolver.conflicts() {
+            let sha = &conflict.first_sha;
+            eprintln!("Warning: {conflict}; using {}", sha.get(..7).unwrap_or(sha));
+        }
 
         Ok(resolved_bundles)
     }
 
+    /// Per-platform commits from the lockfile, pinned only for `--frozen`
+    fn locked_platform_refs(&self, frozen: bool) -> crate::resolver::git::LockedPlatformRefs {
+        if !frozen {
+            return crate::resolver::git::LockedPlatformRefs::new();
+        }
+        self.workspace
+            .lockfile
+            .bundles
+            .iter()
+            .filter(|bundle| !bundle.platform_refs.is_empty())
+            .map(|bundle| (bundle.name.clone(), bundle.platform_refs.clone()))
+            .collect()
+    }
+
+    /// Content hashes of the archive bundles in the lockfile
+    fn locked_archives(&self) -> crate::cache::archive::LockedArchives {
+        self.workspace
+            .lockfile
+            .bundles
+            .iter()
+            .filter_map(|bundle| match &bundle.source {
+                crate::config::LockedSource::Archive { url, path, hash } => {
+                    Some(((url.clone(), path.clone()), hash.clone()))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Git refs of the bundles in the lockfile
+    fn locked_tags(&self) -> crate::resolver::version::LockedTags {
+        self.workspace
+            .lockfile
+            .bundles
+            .iter()
+            .filter_map(|bundle| match &bundle.source {
+                crate::config::LockedSource::Git {
+                    git_ref: Some(git_ref),
+                    ..
+                } => Some((bundle.name.clone(), git_ref.clone())),
+                _ => None,
+            })
+            .collect()
+    }
+
     fn resolve_multiple_bundles(
         selected_bundles: &[crate::domain::DiscoveredBundle],
         bundle_resolver: &mut Resolver,
+        jobs: usize,
     ) -> Result<Vec<ResolvedBundle>> {
         let has_git_source = selected_bundles.iter().any(|b| b.git_source.is_some());
         if has_git_source {
-            Self::resolve_git_bundles(selected_bundles, bundle_resolver)
+            Self::resolve_git_bundles(selected_bundles, bundle_resolver, jobs)
         } else {
             Self::resolve_local_bundles(selected_bundles, bundle_resolver)
         }
@@ -0,0 +1,85 @@
+//! Per-bundle short-circuit for reinstalling an unchanged git bundle
+//!
+//! Re-running `augent install` for a bundle that is already installed at the
+//! same commit has nothing to write. A bundle is unchanged when its resolved
+//! SHA is the one pinned in the lockfile, every locked file is present in
+//! every target platform directory, and none of its files were modified
+//! locally. Such bundles are reported and skipped instead of reinstalled.
+//!
+//! The lockfile does not record how files were rendered, so the skip only
+//! applies when the install writes files the way a plain install does.
+
+use crate::config::utils::BundleContainer;
+use crate::config::{LockedSource, WorkspaceBundle};
+use crate::domain::ResolvedBundle;
+use crate::installer::InstallerOptions;
+use crate::installer::file_ops::CopyMode;
+use crate::platform::Platform;
+use crate::workspace::Workspace;
+use crate::workspace::modified::ModifiedFile;
+
+/// Whether `bundle` is installed at its resolved SHA with all files in place
+pub fn is_unchanged(
+    workspace: &Workspace,
+    bundle: &ResolvedBundle,
+    platforms: &[Platform],
+    modified: &[ModifiedFile],
+) -> bool {
+    let Some(sha) = bundle.resolved_sha.as_deref() else {
+        return false;
+    };
+    let Some(locked) = workspace.lockfile.find_bundle(&bundle.name) else {
+        return false;
+    };
+    let LockedSource::Git {
+        sha: locked_sha, ..
+    } = &locked.source
+    else {
+        return false;
+    };
+    if locked_sha != sha || locked.files.is_empty() {
+        return false;
+    }
+    if modified.iter().any(|m| m.source_bundle == bundle.name) {
+        return false;
+    }
+
+    platforms.iter().all(|platform| {
+        let platform_dir = workspace.root.join(&platform.directory);
+        locked
+            .files
+            .iter()
+            .all(|file| is_installed(file, &platform_dir, &workspace.root))
+    })
+}
+
+/// Whether `options` write files exactly as a plain install does
+///
+/// Options that change file content or placement (`--stamp`, `--template`,
+/// `--link`, `--platform-output-ext`, ...) must rewrite unchanged bundles.
+pub fn renders_as_plain_install(options: &InstallerOptions) -> bool {
+    let content = &options.content;
+    content.merge_frontmatter
+        && !content.stamp
+        && content.gemini_toml
+        && content.template_vars.is_none()
+        && content.output_ext_overrides.is_empty()
+        && !options.dedupe_composite
+        && options.passthrough_platforms.is_empty()
+        && options.strategy_overrides.is_empty()
+        && options.copy_mode == CopyMode::Copy
+}
+
+fn is_installed(bundle_file: &str, platform_dir: &std::path::Path, root: &std::path::Path) -> bool {
+    crate::workspace::path::find_file_candidates(bundle_file, platform_dir, root)
+        .is_ok_and(|candidates| candidates.iter().any(|c| c.is_file()))
+}
+
+/// Index entry to keep for a skipped bundle
+pub fn existing_workspace_bundle(workspace: &Workspace, name: &str) -> WorkspaceBundle {
+    workspace
+        .config
+        .find_bundle(name)
+        .cloned()
+        .unwrap_or_else(|| WorkspaceBundle::new(name.to_string()))
+}
@@ -0,0 +1,99 @@
+//! Dependency graph output for `list --graph`
+//!
+//! The bundles declared in the workspace's `augent.yaml` are resolved the
+//! same way install resolves them (git sources come from the cache when
+//! already fetched), and the resolver's dependency list is rendered as a
+//! Graphviz digraph: one node per bundle, one edge from each bundle to every
+//! dependency it declares. Nodes and edges are sorted so the output is stable.
+
+use std::collections::{BTreeSet, HashMap};
+use std::fmt::Write as _;
+
+use crate::error::Result;
+use crate::operations::install::resolution::InstallResolver;
+use crate::resolver::Resolver;
+use crate::resolver::graph::build_dependency_list;
+use crate::workspace::Workspace;
+
+/// Resolve the workspace's bundles and render their dependency graph as DOT
+pub fn workspace_dot(workspace: &Workspace) -> Result<String> {
+    let mut sources = Vec::new();
+    for dep in &workspace.bundle_config.bundles {
+        match (&dep.git, &dep.path) {
+            (Some(git_url), None) => sources.push(InstallResolver::git_dep_source(
+                git_url,
+                dep,
+                None,
+                crate::cache::FetchOptions::default(),
+            )?),
+            (None, Some(path)) => sources.push(path.clone()),
+            _ => {}
+        }
+    }
+
+    let bundles: HashMap<_, _> = Resolver::new(&workspace.root)
+        .with_overrides(workspace.bundle_config.overrides.clone())
+        .resolve_multiple(&sources)?
+        .into_iter()
+        .map(|bundle| (bundle.name.clone(), bundle))
+        .collect();
+    Ok(render_dot(&build_dependency_list(&bundles)))
+}
+
+/// Render a dependency list (bundle name to dependency names) as a DOT digraph
+pub fn render_dot(deps: &HashMap<String, Vec<String>>) -> String {
+    let nodes: BTreeSet<&str> = deps
+        .iter()
+        .flat_map(|(name, bundle_deps)| {
+            std::iter::once(name.as_str()).chain(bundle_deps.iter().map(String::as_str))
+        })
+        .collect();
+    let edges: BTreeSet<(&str, &str)> = deps
+        .iter()
+        .flat_map(|(name, bundle_deps)| {
+            bundle_deps
+                .iter()
+                .map(move |dep| (name.as_str(), dep.as_str()))
+        })
+        .collect();
+
+    let mut dot = String::from("digraph bundles {\n    rankdir=LR;\n    node [shape=box];\n");
+    for node in nodes {
+        let _ = writeln!(dot, "    {};", quote(node));
+    }
+    for (from, to) in edges {
+        let _ = writeln!(dot, "    {} -> {};", quote(from), quote(to));
+    }
+    dot.push_str("}\n");
+    dot
+}
+
+/// DOT quoted ID, so names like `@owner/repo` need no further escaping
+fn quote(id: &str) -> String {
+    format!("\"{}\"", id.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_dot_dependency_chain() {
+        let deps = HashMap::from([
+            ("app".to_string(), vec!["@acme/tools".to_string()]),
+            ("@acme/tools".to_string(), vec!["base".to_string()]),
+            ("base".to_string(), vec![]),
+        ]);
+
+        let dot = render_dot(&deps);
+        assert!(dot.starts_with("digraph bundles {\n"));
+        assert!(dot.ends_with("}\n"));
+        for node in ["\"app\";", "\"@acme/tools\";", "\"base\";"] {
+            assert!(dot.contains(node), "missing node {node} in:\n{dot}");
+        }
+        assert!(dot.contains("\"app\" -> \"@acme/tools\";"));
+        assert!(dot.contains("\"@acme/tools\" -> \"base\";"));
+        assert_eq!(dot.matches("->").count(), 2);
+    }
+}
@@ -4,15 +4,28 @@
 //! listing business logic, including bundle information display and
 //! resource grouping.
 
-use crate::cli::ListArgs;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use walkdir::WalkDir;
+
+use crate::cli::{GraphFormat, ListArgs, OutputFormat};
+use crate::config::WorkspaceConfig;
 use crate::config::utils::BundleContainer;
+use crate::error::Result;
 use crate::workspace::Workspace;
 
+mod graph;
+mod output;
+
 /// Configuration options for list
 #[derive(Debug, Clone)]
 pub struct ListOptions {
     pub detailed: bool,
     pub json: bool,
+    pub output_format: Option<OutputFormat>,
+    pub unmanaged: bool,
+    pub graph: Option<GraphFormat>,
 }
 
 impl From<&ListArgs> for ListOptions {
@@ -20,6 +33,9 @@ impl From<&ListArgs> for ListOptions {
         Self {
             detailed: args.detailed,
             json: args.json,
+            output_format: args.output_format,
+            unmanaged: args.unmanaged,
+            graph: args.graph,
         }
     }
 }
@@ -40,8 +56,79 @@ impl<'a> ListOperation<'a> {
     }
 
     /// Execute list operation
-    pub fn execute(&self, options: &ListOptions) {
+    pub fn execute(&self, options: &ListOptions) -> Result<()> {
+        if let Some(GraphFormat::Dot) = options.graph {
+            print!("{}", graph::workspace_dot(self.workspace)?);
+            return Ok(());
+        }
+        if options.unmanaged {
+            return list_unmanaged(self.workspace);
+        }
+        if let Some(format) = options.output_format {
+            print!(
+                "{}",
+                output::render(format, self.workspace, options.detailed)?
+            );
+            return Ok(());
+        }
         list_bundles(self.workspace, options);
+        Ok(())
+    }
+}
+
+/// List files in detected platform directories that augent did not install
+fn list_unmanaged(workspace: &Workspace) -> Result<()> {
+    let unmanaged = find_unmanaged_files(workspace)?;
+
+    if unmanaged.is_empty() {
+        println!("No unmanaged files found.");
+        return Ok(());
+    }
+
+    println!("Unmanaged files ({}):", unmanaged.len());
+    for path in &unmanaged {
+        println!("  {path}");
+    }
+    Ok(())
+}
+
+/// Files under detected platform directories not recorded for any bundle
+///
+/// Tracked locations come from the workspace index, completed by scanning
+/// for the lockfile's bundle files the same way the index is rebuilt.
+/// Returned paths are relative to the workspace root and sorted.
+fn find_unmanaged_files(workspace: &Workspace) -> Result<Vec<String>> {
+    let root = &workspace.root;
+    let rebuilt =
+        crate::workspace::operations::rebuild_workspace_config(root, &workspace.lockfile)?;
+    let mut managed = HashSet::new();
+    collect_managed_paths(&workspace.config, root, &mut managed);
+    collect_managed_paths(&rebuilt, root, &mut managed);
+
+    let mut unmanaged = Vec::new();
+    for platform_dir in crate::workspace::operations::detect_installed_platforms(root)? {
+        let files = WalkDir::new(&platform_dir)
+            .into_iter()
+            .filter_map(std::result::Result::ok)
+            .filter(|entry| entry.file_type().is_file() && !managed.contains(entry.path()));
+        for entry in files {
+            let relative = entry.path().strip_prefix(root).unwrap_or(entry.path());
+            unmanaged.push(relative.to_string_lossy().to_string());
+        }
+    }
+    unmanaged.sort();
+    Ok(unmanaged)
+}
+
+/// Add the absolute paths of all locations recorded in `config`
+fn collect_managed_paths(config: &WorkspaceConfig, root: &Path, managed: &mut HashSet<PathBuf>) {
+    let locations = config
+        .bundles
+        .iter()
+        .flat_map(|bundle| bundle.enabled.values())
+        .flatten();
+    for location in locations {
+        managed.insert(root.join(location));
     }
 }
 
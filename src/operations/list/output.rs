@@ -0,0 +1,179 @@
+//! Alternative output formats for `list --output-format`
+//!
+//! `table` and `plain` are rendered from the lockfile alone; `json` and
+//! `yaml` serialize the same per-bundle objects as `list --json`, but as a
+//! single list so the whole output parses as one document.
+
+use std::fmt::Write;
+
+use crate::cli::OutputFormat;
+use crate::config::utils::BundleContainer;
+use crate::config::{LockedBundle, LockedSource};
+use crate::error::{AugentError, Result};
+use crate::ui::formatter::{DisplayContext, JsonFormatter};
+use crate::workspace::Workspace;
+
+/// Render the workspace's locked bundles in the given format
+pub fn render(format: OutputFormat, workspace: &Workspace, detailed: bool) -> Result<String> {
+    let bundles = &workspace.lockfile.bundles;
+    match format {
+        OutputFormat::Table => Ok(render_table(bundles)),
+        OutputFormat::Plain => Ok(render_plain(bundles)),
+        OutputFormat::Json | OutputFormat::Yaml => {
+            let values: Vec<serde_json::Value> = bundles
+                .iter()
+                .map(|bundle| {
+                    let ctx = DisplayContext {
+                        workspace_root: &workspace.root,
+                        workspace_bundle: workspace.config.find_bundle(&bundle.name),
+                        workspace_config: &workspace.config,
+                        detailed,
+                    };
+                    JsonFormatter::bundle_value(bundle, &ctx)
+                })
+                .collect();
+            render_structured(format, &values)
+        }
+    }
+}
+
+fn render_structured(format: OutputFormat, values: &[serde_json::Value]) -> Result<String> {
+    let rendered = if format == OutputFormat::Yaml {
+        serde_yaml::to_string(values).map_err(|e| e.to_string())
+    } else {
+        serde_json::to_string_pretty(values)
+            .map(|json| json + "\n")
+            .map_err(|e| e.to_string())
+    };
+    rendered.map_err(|reason| AugentError::ConfigInvalid {
+        message: format!("Failed to serialize bundle list: {reason}"),
+    })
+}
+
+/// One-line description of where a bundle comes from
+fn source_summary(source: &LockedSource) -> String {
+    match source {
+        LockedSource::Dir { path, .. } => path.clone(),
+        LockedSource::Git { url, path, sha, .. } => {
+            let short_sha = sha.get(..7).unwrap_or(sha);
+            match path {
+                Some(path) => format!("{url}:{path}@{short_sha}"),
+                None => format!("{url}@{short_sha}"),
+            }
+        }
+        LockedSource::Archive { url, path, .. } => match path {
+            Some(path) => format!("{url}#{path}"),
+            None => url.clone(),
+        },
+        LockedSource::Oci {
+            reference, path, ..
+        } => match path {
+            Some(path) => format!("oci://{reference}#{path}"),
+            None => format!("oci://{reference}"),
+        },
+    }
+}
+
+/// Aligned columns: name, version, file count and source
+fn render_table(bundles: &[LockedBundle]) -> String {
+    let header = ["NAME", "VERSION", "FILES", "SOURCE"].map(str::to_string);
+    let rows: Vec<[String; 4]> = bundles
+        .iter()
+        .map(|bundle| {
+            [
+                bundle.name.clone(),
+                bundle.version.clone().unwrap_or_else(|| "-".to_string()),
+                bundle.files.len().to_string(),
+                source_summary(&bundle.source),
+            ]
+        })
+        .collect();
+
+    let mut widths = header.clone().map(|h| h.len());
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let mut out = String::new();
+    for row in std::iter::once(&header).chain(&rows) {
+        let line: Vec<String> = row
+            .iter()
+            .zip(widths)
+            .map(|(cell, width)| format!("{cell:<width$}"))
+            .collect();
+        out.push_str(line.join("  ").trim_end());
+        out.push('\n');
+    }
+    out
+}
+
+/// One `name<TAB>source` line per bundle
+fn render_plain(bundles: &[LockedBundle]) -> String {
+    bundles.iter().fold(String::new(), |mut out, bundle| {
+        let _ = writeln!(out, "{}\t{}", bundle.name, source_summary(&bundle.source));
+        out
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bundle(name: &str, source: LockedSource, files: usize) -> LockedBundle {
+        LockedBundle {
+            name: name.to_string(),
+            source,
+            description: None,
+            version: None,
+            author: None,
+            license: None,
+            homepage: None,
+            files: (0..files).map(|i| format!("commands/{i}.md")).collect(),
+            platform_refs: std::collections::BTreeMap::new(),
+        }
+    }
+
+    fn bundles() -> Vec<LockedBundle> {
+        vec![
+            bundle(
+                "local",
+                LockedSource::Dir {
+                    path: "bundles/local".to_string(),
+                    hash: "h".to_string(),
+                },
+                2,
+            ),
+            bundle(
+                "@author/repo",
+                LockedSource::Git {
+                    url: "https://github.com/author/repo".to_string(),
+                    path: None,
+                    git_ref: None,
+                    sha: "0123456789abcdef".to_string(),
+                    hash: "h".to_string(),
+                },
+                10,
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_render_table_aligns_columns() {
+        assert_eq!(
+            render_table(&bundles()),
+            "NAME          VERSION  FILES  SOURCE\n\
+             local         -        2      bundles/local\n\
+             @author/repo  -        10     https://github.com/author/repo@0123456\n"
+        );
+    }
+
+    #[test]
+    fn test_render_plain_one_line_per_bundle() {
+        assert_eq!(
+            render_plain(&bundles()),
+            "local\tbundles/local\n@author/repo\thttps://github.com/author/repo@0123456\n"
+        );
+    }
+}
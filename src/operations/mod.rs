@@ -5,6 +5,10 @@
 //! - uninstall: Clean uninstallation (modularized submodules)
 //! - list: List installed bundles (modularized)
 //! - show: Display bundle details (modularized)
+//! - freeze: Snapshot locked bundles into one dir bundle
+//! - diff: Compare the files bundles would install with the workspace
+//! - outdated: Compare locked git bundles with their remote refs
+//! - status: Report installed files modified or deleted since install
 //!
 //! The operations coordinate with:
 //! - Resolver: Dependency resolution (from resolver module)
@@ -14,9 +18,13 @@
 //! - Cache coordination (from cache module)
 //! - UI: Progress reporting (from ui module)
 
+pub mod diff;
+pub mod freeze;
 pub mod install;
 pub mod list;
+pub mod outdated;
 pub mod show;
+pub mod status;
 pub mod uninstall;
 
 // List operation exports (modularized)
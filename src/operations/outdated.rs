@@ -0,0 +1,291 @@
+//! Outdated operation (`augent outdated`)
+//!
+//! Compares the locked SHA of every git bundle with the SHA its ref points to
+//! on the remote. Remotes are only listed (`git ls-remote`); nothing is
+//! cloned and neither the cache nor the workspace is modified. Dir bundles
+//! are skipped, and bundles locked to an exact SHA are reported as pinned.
+
+use serde::Serialize;
+
+use crate::config::{LockedBundle, LockedSource};
+use crate::error::{AugentError, Result};
+
+/// How a locked git bundle compares with its remote ref
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OutdatedStatus {
+    /// The ref still points at the locked SHA
+    Current,
+    /// The ref has moved on the remote
+    Outdated,
+    /// The bundle is locked to an exact SHA rather than a branch or tag
+    Pinned,
+    /// The remote could not be listed
+    Error,
+}
+
+/// A locked git bundle and the latest SHA of its ref
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct OutdatedBundle {
+    pub name: String,
+    pub url: String,
+    #[serde(rename = "ref")]
+    pub git_ref: Option<String>,
+    /// Locked SHA
+    pub current: String,
+    /// SHA the ref points to on the remote
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latest: Option<String>,
+    pub status: OutdatedStatus,
+    /// Why the remote could not be listed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Check every locked git bundle against its remote, using up to `concurrency` threads
+pub fn check_outdated(bundles: &[LockedBundle], concurrency: usize) -> Vec<OutdatedBundle> {
+    let git_bundles: Vec<&LockedBundle> = bundles
+        .iter()
+        .filter(|bundle| matches!(bundle.source, LockedSource::Git { .. }))
+        .collect();
+    crate::common::concurrency::map_ordered(&git_bundles, concurrency, |bundle| {
+        check_bundle(bundle)
+    })
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+fn check_bundle(bundle: &LockedBundle) -> Option<OutdatedBundle> {
+    let LockedSource::Git {
+        url, git_ref, sha, ..
+    } = &bundle.source
+    else {
+        return None;
+    };
+    let mut outdated = OutdatedBundle {
+        name: bundle.name.clone(),
+        url: url.clone(),
+        git_ref: git_ref.clone(),
+        current: sha.clone(),
+        latest: None,
+        status: OutdatedStatus::Pinned,
+        error: None,
+    };
+    if git_ref.as_deref().is_some_and(|r| is_pinned_ref(r, sha)) {
+        return Some(outdated);
+    }
+    match crate::git::remote_sha(url, git_ref.as_deref()) {
+        Ok(latest) => {
+            outdated.status = if latest == *sha {
+                OutdatedStatus::Current
+            } else {
+                OutdatedStatus::Outdated
+            };
+            outdated.latest = Some(latest);
+        }
+        Err(e) => {
+            outdated.status = OutdatedStatus::Error;
+            outdated.error = Some(e.to_string());
+        }
+    }
+    Some(outdated)
+}
+
+/// Whether `git_ref` is (an abbreviation of) the locked SHA itself
+fn is_pinned_ref(git_ref: &str, sha: &str) -> bool {
+    git_ref.len() >= 7 && git_ref.chars().all(|c| c.is_ascii_hexdigit()) && sha.starts_with(git_ref)
+}
+
+/// Aligned columns: name, locked SHA, latest SHA and ref
+pub fn render_table(bundles: &[OutdatedBundle]) -> String {
+    let header = ["NAME", "CURRENT", "LATEST", "REF"].map(str::to_string);
+    let rows: Vec<[String; 4]> = bundles
+        .iter()
+        .map(|bundle| {
+            let latest = match (bundle.status, &bundle.latest) {
+                (OutdatedStatus::Pinned, _) => "pinned".to_string(),
+                (_, Some(latest)) => short_sha(latest).to_string(),
+                (_, None) => "unknown".to_string(),
+            };
+            [
+                bundle.name.clone(),
+                short_sha(&bundle.current).to_string(),
+                latest,
+                bundle.git_ref.clone().unwrap_or_else(|| "HEAD".to_string()),
+            ]
+        })
+        .collect();
+
+    let mut widths = header.clone().map(|h| h.len());
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let mut out = String::new();
+    for row in std::iter::once(&header).chain(&rows) {
+        let line: Vec<String> = row
+            .iter()
+            .zip(widths)
+            .map(|(cell, width)| format!("{cell:<width$}"))
+            .collect();
+        out.push_str(line.join("  ").trim_end());
+        out.push('\n');
+    }
+    out
+}
+
+/// JSON array of the checked bundles
+pub fn render_json(bundles: &[OutdatedBundle]) -> Result<String> {
+    serde_json::to_string_pretty(bundles)
+        .map(|json| json + "\n")
+        .map_err(|e| AugentError::ConfigInvalid {
+            message: format!("Failed to serialize outdated bundles: {e}"),
+        })
+}
+
+fn short_sha(sha: &str) -> &str {
+    sha.get(..7).unwrap_or(sha)
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+
+    fn commit_file(repo: &git2::Repository, content: &str) -> git2::Oid {
+        let workdir = repo.workdir().expect("Repository has no workdir");
+        std::fs::write(workdir.join("README.md"), content).expect("Failed to write file");
+        let mut index = repo.index().expect("Failed to open index");
+        index
+            .add_path(Path::new("README.md"))
+            .expect("Failed to stage file");
+        index.write().expect("Failed to write index");
+        let tree = repo
+            .find_tree(index.write_tree().expect("Failed to write tree"))
+            .expect("Failed to find tree");
+        let signature =
+            git2::Signature::now("Augent", "augent@example.com").expect("Failed to sign");
+        let parent = repo.head().ok().and_then(|h| h.peel_to_commit().ok());
+        let parents: Vec<&git2::Commit> = parent.iter().collect();
+        repo.commit(
+            Some("HEAD"),
+            &signature,
+            &signature,
+            content,
+            &tree,
+            &parents,
+        )
+        .expect("Failed to commit")
+    }
+
+    fn locked(name: &str, source: LockedSource) -> LockedBundle {
+        LockedBundle {
+            name: name.to_string(),
+            description: None,
+            version: None,
+            author: None,
+            license: None,
+            homepage: None,
+            source,
+            files: Vec::new(),
+            platform_refs: std::collections::BTreeMap::new(),
+        }
+    }
+
+    fn git_source(url: &str, git_ref: &str, sha: git2::Oid) -> LockedSource {
+        LockedSource::Git {
+            url: url.to_string(),
+            path: None,
+            git_ref: Some(git_ref.to_string()),
+            sha: sha.to_string(),
+            hash: "blake3:0".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_check_outdated_compares_locked_sha_with_remote_ref() {
+        let temp = tempfile::TempDir::new_in(crate::temp::temp_dir_base())
+            .expect("Failed to create temp directory");
+        let origin = git2::Repository::init(temp.path()).expect("Failed to init repository");
+        let locked_sha = commit_file(&origin, "v1\n");
+        let branch = origin
+            .head()
+            .expect("Repository has no HEAD")
+            .shorthand()
+            .expect("HEAD has no name")
+            .to_string();
+        let url = format!("file://{}", temp.path().display());
+
+        let bundles = [
+            locked("@author/current", git_source(&url, &branch, locked_sha)),
+            locked(
+                "@author/pinned",
+                git_source(&url, &locked_sha.to_string()[..12], locked_sha),
+            ),
+            locked(
+                "local",
+                LockedSource::Dir {
+                    path: "bundles/local".to_string(),
+                    hash: "blake3:0".to_string(),
+                },
+            ),
+        ];
+        let report = check_outdated(&bundles, 2);
+        let statuses: Vec<(&str, OutdatedStatus)> =
+            report.iter().map(|b| (b.name.as_str(), b.status)).collect();
+        assert_eq!(
+            statuses,
+            vec![
+                ("@author/current", OutdatedStatus::Current),
+                ("@author/pinned", OutdatedStatus::Pinned),
+            ]
+        );
+
+        // An extra commit on the branch moves it past the locked SHA
+        let latest_sha = commit_file(&origin, "v2\n");
+        let report = check_outdated(&bundles, 2);
+        assert_eq!(report[0].status, OutdatedStatus::Outdated);
+        assert_eq!(report[0].latest, Some(latest_sha.to_string()));
+        assert_eq!(report[1].status, OutdatedStatus::Pinned);
+        assert_eq!(report[1].latest, None);
+    }
+
+    #[test]
+    fn test_render_outdated_report() {
+        let outdated = OutdatedBundle {
+            name: "@author/current".to_string(),
+            url: "https://github.com/author/current.git".to_string(),
+            git_ref: Some("main".to_string()),
+            current: "1111111aaaa".to_string(),
+            latest: Some("2222222bbbb".to_string()),
+            status: OutdatedStatus::Outdated,
+            error: None,
+        };
+        let pinned = OutdatedBundle {
+            name: "@author/pinned".to_string(),
+            git_ref: Some("1111111aaaa".to_string()),
+            latest: None,
+            status: OutdatedStatus::Pinned,
+            ..outdated.clone()
+        };
+        let report = [outdated, pinned];
+
+        let table = render_table(&report);
+        let lines: Vec<&str> = table.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[1].contains("1111111"));
+        assert!(lines[1].contains("2222222"));
+        assert!(lines[2].contains("pinned"));
+
+        let json: serde_json::Value =
+            serde_json::from_str(&render_json(&report).expect("Failed to render JSON"))
+                .expect("Invalid JSON");
+        assert_eq!(json[0]["status"], "outdated");
+        assert_eq!(json[0]["ref"], "main");
+        assert_eq!(json[1]["status"], "pinned");
+    }
+}
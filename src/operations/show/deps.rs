@@ -0,0 +1,75 @@
+//! Declared dependency listing for `show --deps`
+//!
+//! Resolves a bundle source without installing it and reads the
+//! dependencies its `augent.yaml` declares. Git sources are fetched into the
+//! cache, and the config is read from the cached repository checkout, since
+//! the resources copy does not normally carry it.
+
+use std::path::Path;
+
+use crate::config::BundleDependency;
+use crate::error::Result;
+use crate::source::BundleSource;
+
+/// Dependencies declared by the bundle at `source`
+///
+/// Relative directory sources resolve against `workspace_root`.
+pub fn declared_dependencies(source: &str, workspace_root: &Path) -> Result<Vec<BundleDependency>> {
+    let config_dir = match BundleSource::parse(source)? {
+        BundleSource::Dir { path } => workspace_root.join(path),
+        BundleSource::Git(git) => {
+            let (_, sha, _) = crate::cache::cache_entry::cache_bundle(
+                &git,
+                crate::cache::FetchOptions::default(),
+            )?;
+            let entry = crate::cache::paths::repo_cache_entry_path(&git.url, &sha)?;
+            let repository = crate::cache::paths::entry_repository_path(&entry);
+            git.path
+                .as_deref()
+                .map_or_else(|| repository.clone(), |p| repository.join(p))
+        }
+        BundleSource::Archive { url, path } => {
+            let root =
+                crate::cache::archive::cache_archive(&url, crate::cache::FetchOptions::default())?;
+            path.map_or_else(|| root.clone(), |p| root.join(p))
+        }
+        BundleSource::Oci { reference, path } => {
+            let reference = crate::cache::oci::OciReference::parse(&reference)?;
+            let (root, _) =
+                crate::cache::oci::cache_oci(&reference, crate::cache::FetchOptions::default())?;
+            path.map_or_else(|| root.clone(), |p| root.join(p))
+        }
+    };
+    Ok(crate::resolver::config::load_bundle_config(&config_dir)?
+        .map(|config| config.bundles)
+        .unwrap_or_default())
+}
+
+/// Human-readable source of a dependency: `url[:path][#ref]`, or the local path
+pub fn dependency_source(dep: &BundleDependency) -> String {
+    let Some(url) = &dep.git else {
+        return dep.path.clone().unwrap_or_default();
+    };
+    let mut source = url.clone();
+    if let Some(path) = &dep.path {
+        source = format!("{source}:{path}");
+    }
+    if let Some(git_ref) = &dep.git_ref {
+        source = format!("{source}#{git_ref}");
+    }
+    source
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dependency_source_formats_git_and_local() {
+        let git = BundleDependency::git("tools", "https://github.com/a/b.git", Some("v1".into()));
+        assert_eq!(dependency_source(&git), "https://github.com/a/b.git#v1");
+        let local = BundleDependency::local("shared", "./bundles/shared");
+        assert_eq!(dependency_source(&local), "./bundles/shared");
+    }
+}
@@ -2,13 +2,20 @@
 //!
 //! This module provides functionality to display bundle information.
 
+pub mod deps;
 pub mod selection;
+pub mod size;
+pub mod tree;
 
 use selection::select_bundle_interactively;
 
 use crate::cli::ShowArgs;
+use crate::common::config_utils;
+use crate::config::LockedSource;
 use crate::config::utils::BundleContainer;
 use crate::error::{AugentError, Result};
+use crate::installer::mcp;
+use crate::source::GitSource;
 use crate::ui::formatter::{
     DetailedFormatter, DisplayContext, DisplayFormatter, JsonFormatter, SimpleFormatter,
 };
@@ -33,6 +40,14 @@ impl<'a> ShowOperation<'a> {
 
     /// Execute show operation
     pub fn execute(&self, args: ShowArgs) -> Result<()> {
+        if let Some(platform) = args.platform.as_deref().filter(|_| args.mcp) {
+            return self.show_mcp(platform);
+        }
+
+        if let Some(source) = args.name.as_deref().filter(|_| args.deps) {
+            return self.show_deps(source);
+        }
+
         let bundle_name = if let Some(name) = args.name {
             name
         } else {
@@ -53,6 +68,17 @@ impl<'a> ShowOperation<'a> {
             return Err(AugentError::BundleNotFound { name: bundle_name });
         };
 
+        if args.size {
+            return self.show_size(locked_bundle);
+        }
+
+        if args.tree {
+            let bundle_dir = self.bundle_dir(&locked_bundle.source)?;
+            println!("{}", locked_bundle.name);
+            print!("{}", tree::render_bundle(&bundle_dir));
+            return Ok(());
+        }
+
         let formatter: Box<dyn DisplayFormatter> = if args.json {
             Box::new(JsonFormatter)
         } else if args.detailed {
@@ -73,4 +99,97 @@ impl<'a> ShowOperation<'a> {
 
         Ok(())
     }
+
+    /// Directory holding a locked bundle's resources; git bundles come from the cache
+    fn bundle_dir(&self, source: &LockedSource) -> Result<PathBuf> {
+        let LockedSource::Git {
+            url,
+            path,
+            git_ref,
+            sha,
+            ..
+        } = source
+        else {
+            return Ok(config_utils::get_bundle_path(&self.workspace_root, source));
+        };
+        let git_source = GitSource {
+            url: url.clone(),
+            path: path.clone(),
+            git_ref: git_ref.clone(),
+            resolved_sha: Some(sha.clone()),
+        };
+        let (content_path, _, _) =
+            crate::cache::lookup::get_cached(&git_source)?.ok_or_else(|| {
+                AugentError::CacheOperationFailed {
+                    message: format!("{url}@{sha} is not cached; run 'augent install' first"),
+                }
+            })?;
+        Ok(content_path)
+    }
+
+    /// Print the source and installed footprint of a locked bundle
+    fn show_size(&self, locked_bundle: &crate::config::LockedBundle) -> Result<()> {
+        use crate::cache::stats::{dir_size, format_size_human_readable};
+
+        println!("{}", locked_bundle.name);
+        match self.bundle_dir(&locked_bundle.source) {
+            Ok(dir) => println!(
+                "  Source:    {} ({})",
+                format_size_human_readable(dir_size(&dir)?),
+                dir.display()
+            ),
+            Err(_) => println!("  Source:    not cached"),
+        }
+        let installed = self
+            .workspace
+            .config
+            .find_bundle(&locked_bundle.name)
+            .map(|bundle| size::installed_size(&self.workspace_root, bundle))
+            .unwrap_or_default();
+        println!(
+            "  Installed: {} in {} file(s)",
+            format_size_human_readable(installed.bytes),
+            installed.files
+        );
+        Ok(())
+    }
+
+    /// Print the dependencies declared by the bundle at `source` without installing it
+    fn show_deps(&self, source: &str) -> Result<()> {
+        let dependencies = deps::declared_dependencies(source, &self.workspace_root)?;
+        if dependencies.is_empty() {
+            println!("No dependencies declared");
+        }
+        for dep in &dependencies {
+            println!("{}: {}", dep.name, deps::dependency_source(dep));
+        }
+        Ok(())
+    }
+
+    /// Print the merged MCP config for `platform_id` without writing it
+    fn show_mcp(&self, platform_id: &str) -> Result<()> {
+        let platform =
+            crate::platform::detection::get_platform(platform_id, Some(&self.workspace_root))
+                .ok_or_else(|| AugentError::PlatformNotSupported {
+                    platform: platform_id.to_string(),
+                })?;
+        let Some(target) = mcp::mcp_config_target(&platform, &self.workspace_root) else {
+            return Err(AugentError::PlatformConfigFailed {
+                message: format!("Platform '{}' has no MCP config", platform.id),
+            });
+        };
+
+        let bundle_dirs: Vec<PathBuf> = self
+            .workspace
+            .lockfile
+            .bundles()
+            .iter()
+            .map(|bundle| config_utils::get_bundle_path(&self.workspace_root, &bundle.source))
+            .collect();
+        println!(
+            "{}",
+            mcp::preview_merged_config(&bundle_dirs, &target, mcp::mcp_array_policy(&platform))?
+        );
+        Ok(())
+    }
 }
@@ -0,0 +1,98 @@
+//! Disk footprint for `show --size`
+//!
+//! The source size is the size of the directory the bundle's resources are
+//! read from (the cached resources for git bundles). The installed size sums
+//! the files the workspace index records for the bundle on every platform.
+
+use std::collections::BTreeSet;
+use std::path::Path;
+
+use crate::config::WorkspaceBundle;
+
+/// Installed footprint of a bundle
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct InstalledSize {
+    /// Total size in bytes
+    pub bytes: u64,
+    /// Number of installed files found on disk
+    pub files: usize,
+}
+
+/// Sum the sizes of the installed files recorded for `bundle`
+///
+/// A target written for several source files (e.g. a merged config) is
+/// counted once. Recorded files missing from disk are skipped.
+pub fn installed_size(workspace_root: &Path, bundle: &WorkspaceBundle) -> InstalledSize {
+    let targets: BTreeSet<&String> = bundle.enabled.values().flatten().collect();
+    let mut size = InstalledSize::default();
+    for target in targets {
+        let Ok(metadata) = std::fs::metadata(workspace_root.join(target)) else {
+            continue;
+        };
+        if metadata.is_file() {
+            size.bytes += metadata.len();
+            size.files += 1;
+        }
+    }
+    size
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_installed_size_sums_recorded_files_once() {
+        let temp = tempfile::TempDir::new_in(crate::temp::temp_dir_base())
+            .expect("Failed to create temp directory");
+        let root = temp.path();
+        let files = [
+            (".claude/commands/review.md", "Review the diff.\n"),
+            (".cursor/commands/review.md", "Review the diff.\n"),
+            (".cursor/mcp.json", "{\"mcpServers\": {}}\n"),
+        ];
+        for (path, content) in files {
+            let path = root.join(path);
+            std::fs::create_dir_all(path.parent().expect("path has a parent"))
+                .expect("Failed to create dir");
+            std::fs::write(path, content).expect("Failed to write file");
+        }
+
+        let bundle = WorkspaceBundle {
+            name: "tools".to_string(),
+            enabled: HashMap::from([
+                (
+                    "commands/review.md".to_string(),
+                    vec![
+                        ".claude/commands/review.md".to_string(),
+                        ".cursor/commands/review.md".to_string(),
+                    ],
+                ),
+                (
+                    "mcp_servers/a.json".to_string(),
+                    vec![".cursor/mcp.json".to_string()],
+                ),
+                (
+                    "mcp_servers/b.json".to_string(),
+                    vec![".cursor/mcp.json".to_string()],
+                ),
+                (
+                    "commands/removed.md".to_string(),
+                    vec![".claude/commands/removed.md".to_string()],
+                ),
+            ]),
+            mcp_servers: HashMap::new(),
+        };
+
+        let expected: u64 = files.iter().map(|(_, content)| content.len() as u64).sum();
+        assert_eq!(
+            installed_size(root, &bundle),
+            InstalledSize {
+                bytes: expected,
+                files: 3,
+            }
+        );
+    }
+}
@@ -0,0 +1,107 @@
+//! Resource tree rendering for `show --tree`
+//!
+//! Lays out a bundle's installable resources (after skill filtering) as an
+//! indented directory tree, with a file count on every directory.
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+use crate::installer::discovery;
+
+#[derive(Default)]
+struct Node {
+    dirs: BTreeMap<String, Node>,
+    files: Vec<String>,
+}
+
+impl Node {
+    fn insert(&mut self, path: &Path) {
+        let parts: Vec<String> = path
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy().into_owned())
+            .collect();
+        let Some((file, dirs)) = parts.split_last() else {
+            return;
+        };
+        let mut node = self;
+        for dir in dirs {
+            node = node.dirs.entry(dir.clone()).or_default();
+        }
+        node.files.push(file.clone());
+    }
+
+    fn file_count(&self) -> usize {
+        self.files.len() + self.dirs.values().map(Node::file_count).sum::<usize>()
+    }
+
+    fn render_into(&self, depth: usize, out: &mut String) {
+        let indent = "  ".repeat(depth);
+        for (name, dir) in &self.dirs {
+            let _ = writeln!(
+                out,
+                "{indent}{name}/ ({})",
+                file_count_label(dir.file_count())
+            );
+            dir.render_into(depth + 1, out);
+        }
+        let mut files = self.files.clone();
+        files.sort();
+        for file in files {
+            let _ = writeln!(out, "{indent}{file}");
+        }
+    }
+}
+
+fn file_count_label(count: usize) -> String {
+    if count == 1 {
+        "1 file".to_string()
+    } else {
+        format!("{count} files")
+    }
+}
+
+/// Render bundle-relative resource paths as an indented tree
+pub fn render(paths: &[PathBuf]) -> String {
+    let mut root = Node::default();
+    for path in paths {
+        root.insert(path);
+    }
+    let mut out = String::new();
+    root.render_into(0, &mut out);
+    out
+}
+
+/// Render the resources of the bundle at `bundle_dir`
+pub fn render_bundle(bundle_dir: &Path) -> String {
+    let resources = discovery::filter_skills_resources(discovery::discover_resources(bundle_dir));
+    let paths: Vec<PathBuf> = resources.into_iter().map(|r| r.bundle_path).collect();
+    render(&paths)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_groups_by_directory_with_counts() {
+        let paths: Vec<PathBuf> = [
+            "skills/tools/deploy/SKILL.md",
+            "commands/review.md",
+            "skills/tools/deploy/scripts/run.sh",
+            "AGENTS.md",
+            "commands/fix.md",
+        ]
+        .iter()
+        .map(PathBuf::from)
+        .collect();
+
+        assert_eq!(
+            render(&paths),
+            "commands/ (2 files)\n  fix.md\n  review.md\n\
+             skills/ (2 files)\n  tools/ (2 files)\n    deploy/ (2 files)\n      \
+             scripts/ (1 file)\n        run.sh\n      SKILL.md\n\
+             AGENTS.md\n"
+        );
+    }
+}
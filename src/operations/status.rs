@@ -0,0 +1,194 @@
+//! Status operation (`augent status`)
+//!
+//! Reports how the installed files have drifted from what the bundles
+//! provide: files edited since install (hash mismatch with the bundle
+//! source) and files the index lists but that are gone from disk, grouped by
+//! bundle, together with the platforms installed in the workspace. Nothing
+//! is modified.
+
+use std::collections::BTreeSet;
+use std::path::Path;
+
+use crate::error::Result;
+use crate::workspace::Workspace;
+use crate::workspace::modified::detect_modified_files;
+use crate::workspace::operations::detect_installed_platforms;
+
+/// Drifted files of one bundle
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BundleStatus {
+    pub name: String,
+    /// Workspace-relative paths whose content differs from the bundle
+    pub modified: Vec<String>,
+    /// Workspace-relative paths in the index that do not exist on disk
+    pub missing: Vec<String>,
+}
+
+impl BundleStatus {
+    /// Whether no file of the bundle is modified or missing
+    pub fn is_clean(&self) -> bool {
+        self.modified.is_empty() && self.missing.is_empty()
+    }
+}
+
+/// Drift of the whole workspace
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorkspaceStatus {
+    /// Installed platform directories, relative to the workspace root
+    pub platforms: Vec<String>,
+    /// Every bundle in the index, in index order
+    pub bundles: Vec<BundleStatus>,
+}
+
+impl WorkspaceStatus {
+    /// Whether any installed file is modified or missing
+    pub fn has_drift(&self) -> bool {
+        self.bundles.iter().any(|bundle| !bundle.is_clean())
+    }
+
+    /// Number of modified and missing files across all bundles
+    pub fn drifted_files(&self) -> usize {
+        self.bundles
+            .iter()
+            .map(|bundle| bundle.modified.len() + bundle.missing.len())
+            .sum()
+    }
+}
+
+/// Compare the installed files of every bundle with their sources
+///
+/// `cache_dir` is the bundles cache that git bundle sources are read from.
+pub fn workspace_status(workspace: &Workspace, cache_dir: &Path) -> Result<WorkspaceStatus> {
+    let root = &workspace.root;
+    let platforms = detect_installed_platforms(root)?
+        .iter()
+        .map(|dir| relative(root, dir))
+        .collect();
+
+    let modified_files = detect_modified_files(workspace, cache_dir);
+    let bundles = workspace
+        .config
+        .bundles
+        .iter()
+        .map(|bundle| {
+            let modified: BTreeSet<String> = modified_files
+                .iter()
+                .filter(|file| file.source_bundle == bundle.name)
+                .map(|file| relative(root, &file.installed_path))
+                .collect();
+            let missing: BTreeSet<String> = bundle
+                .enabled
+                .values()
+                .flatten()
+                .filter(|location| !root.join(location).exists())
+                .cloned()
+                .collect();
+            BundleStatus {
+                name: bundle.name.clone(),
+                modified: modified.into_iter().collect(),
+                missing: missing.into_iter().collect(),
+            }
+        })
+        .collect();
+
+    Ok(WorkspaceStatus { platforms, bundles })
+}
+
+fn relative(root: &Path, path: &Path) -> String {
+    path.strip_prefix(root)
+        .unwrap_or(path)
+        .to_string_lossy()
+        .replace('\\', "/")
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::*;
+    use crate::config::{LockedBundle, LockedSource, WorkspaceBundle};
+    use std::fs;
+
+    /// Workspace with a dir bundle whose two commands are installed for Claude
+    fn installed_workspace() -> (tempfile::TempDir, Workspace) {
+        let (temp, mut workspace) = crate::test_fixtures::create_workspace();
+        let mut bundle = WorkspaceBundle::new("local");
+        for name in ["review.md", "test.md"] {
+            let source = format!("commands/{name}");
+            let target = format!(".claude/commands/{name}");
+            let content = format!("# {name}\n");
+            crate::test_fixtures::create_test_files(
+                &temp,
+                &[
+                    (&format!("bundles/local/{source}"), &content),
+                    (&target, &content),
+                ],
+            );
+            bundle.add_file(source, vec![target]);
+        }
+        workspace.config.bundles.push(bundle);
+        workspace.lockfile.bundles.push(LockedBundle {
+            name: "local".to_string(),
+            description: None,
+            version: None,
+            author: None,
+            license: None,
+            homepage: None,
+            source: LockedSource::Dir {
+                path: "bundles/local".to_string(),
+                hash: "blake3:0".to_string(),
+            },
+            files: vec![
+                "commands/review.md".to_string(),
+                "commands/test.md".to_string(),
+            ],
+            platform_refs: std::collections::BTreeMap::new(),
+        });
+        (temp, workspace)
+    }
+
+    #[test]
+    fn test_workspace_status_clean() {
+        let (temp, workspace) = installed_workspace();
+
+        let status = workspace_status(&workspace, temp.path()).expect("Failed to get status");
+        assert!(!status.has_drift());
+        assert_eq!(status.drifted_files(), 0);
+        assert!(status.platforms.contains(&".claude".to_string()));
+        assert_eq!(status.bundles.len(), 1);
+        assert!(status.bundles[0].is_clean());
+    }
+
+    #[test]
+    fn test_workspace_status_modified() {
+        let (temp, workspace) = installed_workspace();
+        fs::write(
+            workspace.root.join(".claude/commands/review.md"),
+            "# review.md\n\nLocal notes.\n",
+        )
+        .expect("Failed to edit file");
+
+        let status = workspace_status(&workspace, temp.path()).expect("Failed to get status");
+        assert!(status.has_drift());
+        assert_eq!(
+            status.bundles[0].modified,
+            vec![".claude/commands/review.md".to_string()]
+        );
+        assert!(status.bundles[0].missing.is_empty());
+    }
+
+    #[test]
+    fn test_workspace_status_missing() {
+        let (temp, workspace) = installed_workspace();
+        fs::remove_file(workspace.root.join(".claude/commands/test.md"))
+            .expect("Failed to remove file");
+
+        let status = workspace_status(&workspace, temp.path()).expect("Failed to get status");
+        assert!(status.has_drift());
+        assert_eq!(status.drifted_files(), 1);
+        assert!(status.bundles[0].modified.is_empty());
+        assert_eq!(
+            status.bundles[0].missing,
+            vec![".claude/commands/test.md".to_string()]
+        );
+    }
+}
@@ -4,7 +4,7 @@
 
 use crate::error::{AugentError, Result};
 use crate::workspace::Workspace;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fs;
 
 #[allow(dead_code)]
@@ -31,6 +31,18 @@ fn get_bundle_config_path(
             Ok(Some(bundle_cache_dir.join("augent.yaml")))
         }
         crate::config::LockedSource::Dir { hash: _, path: _ } => Ok(None),
+        crate::config::LockedSource::Archive { url, path, .. } => Ok(Some(
+            crate::cache::archive::cached_archive_path(url, path.as_deref()).join("augent.yaml"),
+        )),
+        crate::config::LockedSource::Oci {
+            reference,
+            digest,
+            path,
+            ..
+        } => Ok(Some(
+            crate::cache::oci::cached_oci_path(reference, digest, path.as_deref())
+                .join("augent.yaml"),
+        )),
     }
 }
 
@@ -111,3 +123,150 @@ pub fn check_bundle_dependents(
 
     Ok(dependents)
 }
+
+/// Directory holding a locked bundle's `augent.yaml`, if it is available locally
+///
+/// Git bundles are read from their cached repository checkout; nothing is fetched.
+fn locked_config_dir(
+    workspace: &Workspace,
+    locked: &crate::config::LockedBundle,
+) -> Result<std::path::PathBuf> {
+    match &locked.source {
+        crate::config::LockedSource::Dir { path, .. } => Ok(workspace.root.join(path)),
+        crate::config::LockedSource::Git { url, sha, path, .. } => {
+            let entry = crate::cache::paths::repo_cache_entry_path(url, sha)?;
+            let repository = crate::cache::paths::entry_repository_path(&entry);
+            Ok(path
+                .as_deref()
+                .map_or_else(|| repository.clone(), |p| repository.join(p)))
+        }
+        crate::config::LockedSource::Archive { url, path, .. } => Ok(
+            crate::cache::archive::cached_archive_path(url, path.as_deref()),
+        ),
+        crate::config::LockedSource::Oci {
+            reference,
+            digest,
+            path,
+            ..
+        } => Ok(crate::cache::oci::cached_oci_path(
+            reference,
+            digest,
+            path.as_deref(),
+        )),
+    }
+}
+
+/// Dependency graph of the installed bundles, in the resolver's adjacency-list form
+///
+/// Fails when a bundle's directory is not available locally: its dependencies
+/// are unknown, so a dependency it still needs could look orphaned.
+pub fn locked_dependency_graph(workspace: &Workspace) -> Result<HashMap<String, Vec<String>>> {
+    let mut graph = HashMap::new();
+    for locked in &workspace.lockfile.bundles {
+        let config_dir = locked_config_dir(workspace, locked)?;
+        if !config_dir.is_dir() {
+            return Err(AugentError::BundleValidationFailed {
+                message: format!(
+                    "cannot read the dependencies of '{}' from {}; reinstall it before using --cascade",
+                    locked.name,
+                    config_dir.display()
+                ),
+            });
+        }
+        let deps = crate::resolver::config::load_bundle_config(&config_dir)?
+            .map(|config| config.bundles.into_iter().map(|dep| dep.name).collect())
+            .unwrap_or_default();
+        graph.insert(locked.name.clone(), deps);
+    }
+    Ok(graph)
+}
+
+/// Bundles reachable from `roots` in `graph`, including the roots
+fn reachable<'a>(
+    graph: &'a HashMap<String, Vec<String>>,
+    roots: impl IntoIterator<Item = &'a String>,
+) -> HashSet<&'a String> {
+    let mut seen = HashSet::new();
+    let mut stack: Vec<&String> = roots.into_iter().collect();
+    while let Some(name) = stack.pop() {
+        if !seen.insert(name) {
+            continue;
+        }
+        if let Some(deps) = graph.get(name) {
+            stack.extend(deps);
+        }
+    }
+    seen
+}
+
+/// Dependencies left unneeded once `removed` is uninstalled (`--cascade`)
+///
+/// A dependency of a removed bundle is orphaned unless it is declared directly
+/// in the workspace (`direct`) or still reachable from a bundle that stays.
+/// Returned in `installed` order.
+pub fn orphaned_dependencies(
+    graph: &HashMap<String, Vec<String>>,
+    installed: &[String],
+    direct: &[String],
+    removed: &[String],
+) -> Vec<String> {
+    let candidates: HashSet<&String> = reachable(graph, removed)
+        .into_iter()
+        .filter(|name| !removed.contains(name) && !direct.contains(name))
+        .collect();
+    let kept = installed
+        .iter()
+        .filter(|name| !removed.contains(name) && !candidates.contains(name));
+    let needed = reachable(graph, kept);
+    installed
+        .iter()
+        .filter(|name| candidates.contains(name) && !needed.contains(name))
+        .cloned()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(items: &[&str]) -> Vec<String> {
+        items.iter().map(ToString::to_string).collect()
+    }
+
+    fn graph(edges: &[(&str, &[&str])]) -> HashMap<String, Vec<String>> {
+        edges
+            .iter()
+            .map(|(name, deps)| ((*name).to_string(), names(deps)))
+            .collect()
+    }
+
+    #[test]
+    fn test_orphaned_dependencies_keeps_shared_dependency() {
+        let graph = graph(&[("a", &["b"]), ("c", &["b"]), ("b", &[])]);
+        let installed = names(&["b", "a", "c"]);
+        let direct = names(&["a", "c"]);
+
+        assert!(orphaned_dependencies(&graph, &installed, &direct, &names(&["a"])).is_empty());
+    }
+
+    #[test]
+    fn test_orphaned_dependencies_removes_transitive_chain() {
+        let graph = graph(&[("a", &["b"]), ("b", &["d"]), ("d", &[]), ("c", &[])]);
+        let installed = names(&["d", "b", "a", "c"]);
+        let direct = names(&["a", "c"]);
+
+        assert_eq!(
+            orphaned_dependencies(&graph, &installed, &direct, &names(&["a"])),
+            names(&["d", "b"])
+        );
+    }
+
+    #[test]
+    fn test_orphaned_dependencies_keeps_directly_declared_dependency() {
+        let graph = graph(&[("a", &["b"]), ("b", &[])]);
+        let installed = names(&["b", "a"]);
+        let direct = names(&["a", "b"]);
+
+        assert!(orphaned_dependencies(&graph, &installed, &direct, &names(&["a"])).is_empty());
+    }
+}
@@ -38,7 +38,7 @@ impl<'a> UninstallOperation<'a> {
     }
 
     pub fn execute(&mut self, args: UninstallArgs) -> Result<()> {
-        let bundle_names = self.resolve_bundle_names(&args)?;
+        let mut bundle_names = self.resolve_bundle_names(&args)?;
 
         if bundle_names.is_empty() {
             return Err(AugentError::BundleNotFound {
@@ -48,6 +48,19 @@ impl<'a> UninstallOperation<'a> {
 
         self.validate_bundles_installed(&bundle_names)?;
 
+        let orphans = if args.cascade {
+            self.orphaned_dependencies(&bundle_names)?
+        } else {
+            Vec::new()
+        };
+        if !orphans.is_empty() {
+            println!(
+                "Also uninstalling orphaned dependencies: {}",
+                orphans.join(", ")
+            );
+        }
+        bundle_names.extend(orphans);
+
         let confirmed = validate_dependencies_and_confirm(self.workspace, &args, &bundle_names)?;
         if !confirmed {
             return Ok(());
@@ -96,6 +109,31 @@ impl<'a> UninstallOperation<'a> {
         })
     }
 
+    /// Dependencies of `bundle_names` that no remaining bundle needs
+    fn orphaned_dependencies(&self, bundle_names: &[String]) -> Result<Vec<String>> {
+        let graph = dependency::locked_dependency_graph(self.workspace)?;
+        let installed: Vec<String> = self
+            .workspace
+            .lockfile
+            .bundles
+            .iter()
+            .map(|b| b.name.clone())
+            .collect();
+        let direct: Vec<String> = self
+            .workspace
+            .bundle_config
+            .bundles
+            .iter()
+            .map(|dep| dep.name.clone())
+            .collect();
+        Ok(dependency::orphaned_dependencies(
+            &graph,
+            &installed,
+            &direct,
+            bundle_names,
+        ))
+    }
+
     fn validate_bundles_installed(&self, bundle_names: &[String]) -> Result<()> {
         self.check_all_bundles_installed(bundle_names)
     }
@@ -126,7 +164,9 @@ fn validate_dependencies_and_confirm(
     args: &UninstallArgs,
     bundle_names: &[String],
 ) -> Result<bool> {
-    let dependency_map = dependency::build_dependency_map(ws)?;
+    let mut dependency_map = dependency::build_dependency_map(ws)?;
+    // Bundles uninstalled together do not block each other
+    dependency_map.retain(|dependent, _| !bundle_names.contains(dependent));
     for bundle_name in bundle_names {
         dependency::check_bundle_dependents(ws, bundle_name, &dependency_map)?;
     }
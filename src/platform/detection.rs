@@ -11,6 +11,9 @@ use super::{Platform, loader::PlatformLoader};
 /// Returns platforms whose directory exists in the workspace (e.g. `.opencode`, `.cursor`).
 /// Root-level agent files (AGENTS.md, CLAUDE.md, etc.) do not add any platform; only
 /// platform directories are used so install targets only the platforms the user actually has.
+///
+/// A platform found through its `directory_glob` is relocated to the matched directory,
+/// so install writes into the variant directory (e.g. `.claude-work`).
 pub fn detect_platforms(workspace_root: &Path) -> Result<Vec<Platform>> {
     if !workspace_root.exists() {
         return Err(AugentError::WorkspaceNotFound {
@@ -22,13 +25,58 @@ pub fn detect_platforms(workspace_root: &Path) -> Result<Vec<Platform>> {
     let platforms = loader.load()?;
 
     let detected: Vec<Platform> = platforms
+        .clone()
         .into_iter()
-        .filter(|p| workspace_root.join(&p.directory).exists())
+        .filter_map(|mut p| {
+            if workspace_root.join(&p.directory).exists() {
+                return Some(p);
+            }
+            let directory = p.matched_directory(workspace_root, &platforms)?;
+            p.relocate(&directory);
+            Some(p)
+        })
         .collect();
 
     Ok(detected)
 }
 
+/// Whether a known platform is detected in a workspace, and why
+#[derive(Debug, Clone)]
+pub struct PlatformDetection {
+    /// Platform as loaded, including workspace and global overrides
+    pub platform: Platform,
+    /// Defined in a `platforms.jsonc` rather than built in
+    pub custom: bool,
+    /// Platform directory found in the workspace (`directory` or a
+    /// `directory_glob` match), or `None` when the platform is not detected
+    pub found_directory: Option<String>,
+}
+
+/// Every known platform with its detection result, in loaded order
+///
+/// Detection follows [`detect_platforms`], so the platforms with a
+/// `found_directory` are the ones install targets by default.
+pub fn explain_detection(workspace_root: &Path) -> Result<Vec<PlatformDetection>> {
+    let builtin = PlatformLoader::load_builtin_platforms()?;
+    let platforms = PlatformLoader::new(workspace_root).load()?;
+
+    Ok(platforms
+        .iter()
+        .map(|p| {
+            let found_directory = if workspace_root.join(&p.directory).exists() {
+                Some(p.directory.clone())
+            } else {
+                p.matched_directory(workspace_root, &platforms)
+            };
+            PlatformDetection {
+                platform: p.clone(),
+                custom: !builtin.iter().any(|b| b.id == p.id),
+                found_directory,
+            }
+        })
+        .collect())
+}
+
 /// Detect platforms or return an error if none found
 #[allow(dead_code)] // Used by tests
 pub fn detect_platforms_or_error(workspace_root: &Path) -> Result<Vec<Platform>> {
@@ -61,18 +109,12 @@ pub fn get_platform(id: &str, workspace_root: Option<&Path>) -> Option<Platform>
         }
     };
 
-    // First try exact ID match
-    if let Some(platform) = platforms.iter().find(|p| p.id == id) {
-        return Some(platform.clone());
-    }
-
-    // Handle aliases: cursor-ai -> cursor
-    let alias_id = match id {
-        "cursor-ai" => "cursor",
-        _ => return None,
-    };
-
-    platforms.iter().find(|p| p.id == alias_id).cloned()
+    // First try exact ID match, then aliases declared in platform definitions
+    platforms
+        .iter()
+        .find(|p| p.id == id)
+        .or_else(|| platforms.iter().find(|p| p.aliases.iter().any(|a| a == id)))
+        .cloned()
 }
 
 /// Get multiple platforms by ID
@@ -96,6 +138,21 @@ pub fn get_platforms(ids: &[String], workspace_root: Option<&Path>) -> Result<Ve
     Ok(platforms)
 }
 
+/// Reorder platforms so those in `order` come first, in that order
+///
+/// Platforms not listed keep their loaded order after the listed ones.
+pub fn apply_platform_order(platforms: Vec<Platform>, order: &[Platform]) -> Vec<Platform> {
+    let rank = |platform: &Platform| {
+        order
+            .iter()
+            .position(|p| p.id == platform.id)
+            .unwrap_or(order.len())
+    };
+    let mut platforms = platforms;
+    platforms.sort_by_key(rank);
+    platforms
+}
+
 /// Detect platforms, or use specified platforms if provided
 #[allow(dead_code)] // Used by tests
 pub fn resolve_platforms(workspace_root: &Path, specified: &[String]) -> Result<Vec<Platform>> {
@@ -112,6 +169,26 @@ mod tests {
     use super::*;
     use crate::test_fixtures::create_temp_dir;
 
+    #[test]
+    fn test_explain_detection_reports_only_existing_directory() {
+        let temp = create_temp_dir();
+        std::fs::create_dir(temp.path().join(".cursor")).expect("Failed to create .cursor");
+
+        let detections = explain_detection(temp.path()).expect("Failed to explain detection");
+        let cursor = detections
+            .iter()
+            .find(|d| d.platform.id == "cursor")
+            .expect("cursor should be known");
+        assert_eq!(cursor.found_directory.as_deref(), Some(".cursor"));
+        assert!(!cursor.custom);
+        assert!(
+            detections
+                .iter()
+                .filter(|d| d.platform.id != "cursor")
+                .all(|d| d.found_directory.is_none())
+        );
+    }
+
     #[test]
     fn test_detect_platforms_empty() {
         let temp = create_temp_dir();
@@ -179,6 +256,20 @@ mod tests {
         assert_eq!(platforms.len(), 2);
     }
 
+    #[test]
+    fn test_get_platforms_resolves_short_aliases() {
+        let platforms = get_platforms(&["oc".to_string(), "cursor-ai".to_string()], None)
+            .expect("Aliases should resolve");
+        let ids: Vec<&str> = platforms.iter().map(|p| p.id.as_str()).collect();
+        assert_eq!(ids, ["opencode", "cursor"]);
+
+        let result = get_platforms(&["not-an-alias".to_string()], None);
+        assert!(matches!(
+            result,
+            Err(AugentError::PlatformNotSupported { platform }) if platform == "not-an-alias"
+        ));
+    }
+
     #[test]
     fn test_get_platforms_unknown() {
         let result = get_platforms(&["unknown".to_string()], None);
@@ -233,6 +324,25 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_apply_platform_order() {
+        let platforms = get_platforms(
+            &[
+                "claude".to_string(),
+                "cursor".to_string(),
+                "codex".to_string(),
+            ],
+            None,
+        )
+        .expect("Failed to get platforms");
+        let order = get_platforms(&["codex".to_string(), "cursor-ai".to_string()], None)
+            .expect("Failed to get platforms");
+
+        let ordered = apply_platform_order(platforms, &order);
+        let ids: Vec<&str> = ordered.iter().map(|p| p.id.as_str()).collect();
+        assert_eq!(ids, vec!["codex", "cursor", "claude"]);
+    }
+
     #[test]
     fn test_get_platforms_resolves_aliases() {
         let platforms = get_platforms(&["cursor-ai".to_string(), "claude".to_string()], None)
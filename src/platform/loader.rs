@@ -9,6 +9,9 @@ use std::path::PathBuf;
 use super::Platform;
 use crate::error::{AugentError, Result};
 
+/// Prefix of per-platform directory overrides (e.g. `AUGENT_PLATFORM_DIR_CLAUDE`)
+pub const PLATFORM_DIR_ENV_PREFIX: &str = "AUGENT_PLATFORM_DIR_";
+
 /// Platform configuration loader
 pub struct PlatformLoader {
     /// Workspace root directory
@@ -29,6 +32,8 @@ impl PlatformLoader {
     /// 1. Built-in platforms (from platforms.jsonc)
     /// 2. Workspace platforms.jsonc (if exists)
     /// 3. Global platforms.jsonc from ~/.config/augent/platforms.jsonc (if exists)
+    ///
+    /// Directories are then overridden from `AUGENT_PLATFORM_DIR_<ID>` when set.
     pub fn load(&self) -> Result<Vec<Platform>> {
         let mut platforms = Self::load_builtin_platforms()?;
 
@@ -40,6 +45,8 @@ impl PlatformLoader {
             platforms = Self::merge_platforms(platforms, global_platforms);
         }
 
+        apply_directory_overrides(&mut platforms, |name| std::env::var(name).ok());
+
         Ok(platforms)
     }
 
@@ -160,8 +167,7 @@ impl PlatformLoader {
             .map_err(|e| Self::create_parse_error(path, e.to_string()))
     }
 
-    #[cfg(test)]
-    #[allow(dead_code)]
+    /// Strip JSONC comments, leaving plain JSON
     pub(crate) fn strip_jsonc_comments(content: &str) -> String {
         Self::strip_jsonc_comments_impl(content)
     }
@@ -256,6 +262,27 @@ impl PlatformLoader {
 }
 
 /// Parser state for JSONC comment stripping
+/// Environment variable consulted for a platform's directory
+pub fn platform_dir_env_var(platform_id: &str) -> String {
+    format!(
+        "{PLATFORM_DIR_ENV_PREFIX}{}",
+        platform_id.to_ascii_uppercase().replace('-', "_")
+    )
+}
+
+/// Relocate platforms whose directory variable is set to a non-empty value
+fn apply_directory_overrides(platforms: &mut [Platform], lookup: impl Fn(&str) -> Option<String>) {
+    for platform in platforms {
+        let Some(directory) = lookup(&platform_dir_env_var(&platform.id)) else {
+            continue;
+        };
+        let directory = directory.trim().trim_end_matches('/');
+        if !directory.is_empty() {
+            platform.relocate(directory);
+        }
+    }
+}
+
 #[derive(Clone, Copy)]
 enum JsoncParserState {
     Default,
@@ -299,4 +326,35 @@ mod tests {
         assert_eq!(platforms.len(), 1);
         assert_eq!(platforms[0].id, "test");
     }
+
+    #[test]
+    fn test_directory_override_relocates_platform() {
+        let mut platforms = PlatformLoader::load_builtin_platforms()
+            .expect("Failed to load platforms")
+            .into_iter()
+            .filter(|p| p.id == "cursor" || p.id == "claude")
+            .collect::<Vec<_>>();
+
+        apply_directory_overrides(&mut platforms, |name| {
+            (name == "AUGENT_PLATFORM_DIR_CURSOR").then(|| "tools/cursor/".to_string())
+        });
+
+        let cursor = platforms
+            .iter()
+            .find(|p| p.id == "cursor")
+            .expect("cursor platform");
+        assert_eq!(cursor.directory, "tools/cursor");
+        assert!(cursor.detection.iter().any(|d| d == "tools/cursor"));
+        assert!(
+            cursor
+                .transforms
+                .iter()
+                .all(|t| !t.to.starts_with(".cursor/"))
+        );
+        let claude = platforms
+            .iter()
+            .find(|p| p.id == "claude")
+            .expect("claude platform");
+        assert_eq!(claude.directory, ".claude");
+    }
 }
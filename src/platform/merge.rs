@@ -5,7 +5,7 @@
 //!
 //! ## Merge Strategies
 //!
-//! Augent supports four merge strategies, each designed for different file types:
+//! Augent supports five merge strategies, each designed for different file types:
 //!
 //! ### Replace (Default)
 //!
@@ -75,17 +75,46 @@
 //! Content from bundle B
 //! ```
 //!
+//! When installing with `--dedupe-composite`, each bundle's content is kept
+//! in its own section between markers naming the bundle:
+//!
+//! ```text
+//! <!-- augent:begin @author/bundle -->
+//! Content from the bundle
+//! <!-- augent:end @author/bundle -->
+//! ```
+//!
+//! Reinstalling the bundle replaces its section in place, so upgrades do not
+//! leave stale copies behind, and the section can be removed again later.
+//!
 //! Use composite merge when:
 //! - Combining markdown documentation files
 //! - Preserving all content is important
 //! - A clear visual separator is desired
 //!
+//! ### Yaml
+//!
+//! Deep merge for YAML files such as platform settings fragments. Mappings
+//! merge recursively like [Deep](#deep); any other pair of values, including
+//! two sequences or a sequence against a mapping, is replaced by the new value.
+//!
+//! ```yaml
+//! # Existing            # New                 # Result
+//! editor:               editor:               editor:
+//!   tab_size: 2           theme: dark           tab_size: 2
+//! plugins: [a]          plugins: [b]            theme: dark
+//!                                             plugins: [b]
+//! ```
+//!
 //! ## Array Handling
 //!
 //! Both shallow and deep merge strategies handle arrays differently:
 //!
 //! - **Shallow merge**: Arrays are replaced entirely (new array wins)
-//! - **Deep merge**: Arrays are deduplicated and merged (no duplicates)
+//! - **Deep merge**: Arrays follow the rule's [`ArrayMergePolicy`]; by default
+//!   they are deduplicated and merged (no duplicates), with objects that carry
+//!   a `name` field matched by name so a later bundle's entry replaces the
+//!   earlier one
 //!
 //! ```json
 //! // Shallow merge
@@ -99,6 +128,21 @@
 //! Result:    {"items": [1, 2, 3, 4, 5]}  // Deduplicated and merged
 //! ```
 //!
+//! ## Appended Arrays
+//!
+//! A transform rule can list key paths whose arrays are unioned with the
+//! target that is already installed, whatever its merge strategy (`"append":
+//! ["tools"]` on an agents rule). This lets several bundles each contribute
+//! to an agent's `tools`: items already installed come first, followed by the
+//! new items not yet present. JSON and YAML files are merged as documents and
+//! markdown files through their frontmatter; paths are dot-separated
+//! (`permissions.allow`).
+//!
+//! ```yaml
+//! # Installed            # New                 # Result
+//! tools: [Read, Grep]    tools: [Bash]         tools: [Read, Grep, Bash]
+//! ```
+//!
 //! ## Usage Example
 //!
 //! ```rust,no_run
@@ -131,8 +175,11 @@
 //!
 //! Replace and Composite strategies work with any string content.
 
+use std::path::Path;
+
 use serde::{Deserialize, Serialize};
 use serde_json::Value as JsonValue;
+use serde_yaml::Value as YamlValue;
 
 use crate::error::{AugentError, Result};
 
@@ -149,40 +196,90 @@ pub enum MergeStrategy {
     Deep,
     /// Append content with delimiter (for markdown files like AGENTS.md)
     Composite,
+    /// Recursive deep merge for YAML mappings (sequences are replaced)
+    Yaml,
+}
+
+/// How deep merges combine two JSON arrays at the same path
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ArrayMergePolicy {
+    /// New array replaces the existing one
+    Replace,
+    /// New items are appended to the existing ones
+    Concat,
+    /// New items are appended unless already present; objects with a `name`
+    /// field replace the existing object of the same name
+    #[default]
+    ConcatUnique,
+}
+
+impl ArrayMergePolicy {
+    /// Whether this is the default policy (omitted when serializing)
+    #[allow(clippy::trivially_copy_pass_by_ref)] // Signature required by serde
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
 }
 
 impl MergeStrategy {
     /// Merge two strings according to this strategy
-    #[allow(dead_code)] // Used by tests
     pub fn merge_strings(self, existing: &str, new_content: &str) -> Result<String> {
+        self.merge_strings_with_policy(existing, new_content, ArrayMergePolicy::default())
+    }
+
+    /// Merge two strings, combining JSON arrays of deep merges by `arrays`
+    pub fn merge_strings_with_policy(
+        self,
+        existing: &str,
+        new_content: &str,
+        arrays: ArrayMergePolicy,
+    ) -> Result<String> {
         match self {
             MergeStrategy::Replace => Ok(new_content.to_string()),
             MergeStrategy::Composite => Ok(merge_composite(existing, new_content)),
+            MergeStrategy::Yaml => {
+                let existing_yaml = parse_yaml(existing, "merge source")?;
+                let new_yaml = parse_yaml(new_content, "merge target")?;
+                Ok(crate::universal::serialize_to_yaml(&merge_yaml_deep(
+                    existing_yaml,
+                    new_yaml,
+                )))
+            }
             MergeStrategy::Shallow | MergeStrategy::Deep => {
-                // Try to parse as JSON
-                let existing_json: JsonValue =
-                    serde_json::from_str(existing).map_err(|e| AugentError::ConfigParseFailed {
-                        path: "merge source".to_string(),
-                        reason: e.to_string(),
-                    })?;
-                let new_json: JsonValue =
-                    serde_json::from_str(new_content).map_err(|e| create_merge_target_error(&e))?;
-
-                let merged = match self {
-                    MergeStrategy::Shallow => merge_json_shallow(existing_json, new_json),
-                    MergeStrategy::Deep => merge_json_deep(existing_json, new_json),
-                    _ => unreachable!(),
-                };
-
-                serde_json::to_string_pretty(&merged).map_err(|e| AugentError::ConfigParseFailed {
-                    path: "merge result".to_string(),
-                    reason: e.to_string(),
-                })
+                merge_json_strings(self, existing, new_content, arrays)
             }
         }
     }
 }
 
+/// Merge two JSON documents, shallowly or deeply by `strategy`
+fn merge_json_strings(
+    strategy: MergeStrategy,
+    existing: &str,
+    new_content: &str,
+    arrays: ArrayMergePolicy,
+) -> Result<String> {
+    let existing_json: JsonValue =
+        serde_json::from_str(existing).map_err(|e| AugentError::ConfigParseFailed {
+            path: "merge source".to_string(),
+            reason: e.to_string(),
+        })?;
+    let new_json: JsonValue =
+        serde_json::from_str(new_content).map_err(|e| create_merge_target_error(&e))?;
+
+    let merged = if strategy == MergeStrategy::Shallow {
+        merge_json_shallow(existing_json, new_json)
+    } else {
+        deep_merge_with_array_policy(existing_json, new_json, arrays)
+    };
+
+    serde_json::to_string_pretty(&merged).map_err(|e| AugentError::ConfigParseFailed {
+        path: "merge result".to_string(),
+        reason: e.to_string(),
+    })
+}
+
 /// Merge markdown content with composite strategy
 /// Appends new content with a separator
 #[allow(dead_code)] // Used internally by merge_strings which is used by tests
@@ -202,6 +299,52 @@ fn merge_composite(existing: &str, new_content: &str) -> String {
     format!("{existing}\n\n<!-- Augent: Additional content below -->\n\n{new_content}")
 }
 
+/// Insert or replace the composite section owned by `owner`
+///
+/// Content outside the owner's markers is left untouched. A missing section
+/// is appended after a blank line.
+pub fn merge_composite_section(existing: &str, owner: &str, content: &str) -> String {
+    let (begin, end) = section_markers(owner);
+    let section = format!("{begin}\n{}\n{end}", content.trim());
+    if let Some((start, stop)) = find_section(existing, &begin, &end) {
+        return format!("{}{section}{}", &existing[..start], &existing[stop..]);
+    }
+    let existing = existing.trim_end();
+    if existing.is_empty() {
+        format!("{section}\n")
+    } else {
+        format!("{existing}\n\n{section}\n")
+    }
+}
+
+/// Remove the composite section owned by `owner`, or `None` when it has none
+pub fn remove_composite_section(existing: &str, owner: &str) -> Option<String> {
+    let (begin, end) = section_markers(owner);
+    let (start, stop) = find_section(existing, &begin, &end)?;
+    let before = existing[..start].trim_end();
+    let after = existing[stop..].trim_start();
+    Some(match (before.is_empty(), after.is_empty()) {
+        (true, true) => String::new(),
+        (true, false) => after.to_string(),
+        (false, true) => format!("{before}\n"),
+        (false, false) => format!("{before}\n\n{after}"),
+    })
+}
+
+fn section_markers(owner: &str) -> (String, String) {
+    (
+        format!("<!-- augent:begin {owner} -->"),
+        format!("<!-- augent:end {owner} -->"),
+    )
+}
+
+/// Byte range of the section between `begin` and `end`, markers included
+fn find_section(content: &str, begin: &str, end: &str) -> Option<(usize, usize)> {
+    let start = content.find(begin)?;
+    let end_offset = content[start..].find(end)?;
+    Some((start, start + end_offset + end.len()))
+}
+
 /// Shallow merge: only top-level keys from new object override existing
 #[allow(dead_code)] // Used internally by merge_strings which is used by tests
 fn merge_json_shallow(mut existing: JsonValue, new: JsonValue) -> JsonValue {
@@ -213,34 +356,245 @@ fn merge_json_shallow(mut existing: JsonValue, new: JsonValue) -> JsonValue {
     existing
 }
 
-/// Deep merge: recursively merge nested objects
-#[allow(dead_code)] // Used internally by merge_strings which is used by tests
-fn merge_json_deep(existing: JsonValue, new: JsonValue) -> JsonValue {
+/// Deep merge: recursively merge nested objects, combining arrays by `policy`
+pub fn deep_merge_with_array_policy(
+    existing: JsonValue,
+    new: JsonValue,
+    policy: ArrayMergePolicy,
+) -> JsonValue {
     match (existing, new) {
-        (JsonValue::Object(mut existing_map), JsonValue::Object(new_map)) => {
+        (JsonValue::Object(existing_map), JsonValue::Object(new_map)) => {
+            JsonValue::Object(deep_merge_objects(existing_map, new_map, policy))
+        }
+        (JsonValue::Array(existing_arr), JsonValue::Array(new_arr)) => {
+            JsonValue::Array(merge_json_arrays(existing_arr, new_arr, policy))
+        }
+        // For non-objects/arrays, new value wins
+        (_, new) => new,
+    }
+}
+
+fn deep_merge_objects(
+    mut existing: serde_json::Map<String, JsonValue>,
+    new: serde_json::Map<String, JsonValue>,
+    policy: ArrayMergePolicy,
+) -> serde_json::Map<String, JsonValue> {
+    for (key, new_value) in new {
+        let merged_value = match existing.remove(&key) {
+            Some(existing_value) => deep_merge_with_array_policy(existing_value, new_value, policy),
+            None => new_value,
+        };
+        existing.insert(key, merged_value);
+    }
+    existing
+}
+
+fn merge_json_arrays(
+    mut existing: Vec<JsonValue>,
+    new: Vec<JsonValue>,
+    policy: ArrayMergePolicy,
+) -> Vec<JsonValue> {
+    match policy {
+        ArrayMergePolicy::Replace => new,
+        ArrayMergePolicy::Concat => {
+            existing.extend(new);
+            existing
+        }
+        ArrayMergePolicy::ConcatUnique => {
+            for item in new {
+                push_unique_item(&mut existing, item);
+            }
+            existing
+        }
+    }
+}
+
+/// Append `item` unless already present; an object replaces the one of the same `name`
+fn push_unique_item(existing: &mut Vec<JsonValue>, item: JsonValue) {
+    let same_name = item_name(&item).and_then(|name| {
+        existing
+            .iter()
+            .position(|other| item_name(other) == Some(name))
+    });
+    match same_name {
+        Some(pos) => existing[pos] = item,
+        None if !existing.contains(&item) => existing.push(item),
+        None => {}
+    }
+}
+
+/// The `name` field of an object array item, used to match entries
+fn item_name(item: &JsonValue) -> Option<&str> {
+    item.get("name").and_then(JsonValue::as_str)
+}
+
+/// Deep merge the YAML file at `source` into `target`
+///
+/// A missing target is simply created as a copy of `source`.
+pub fn merge_yaml_files(source: &Path, target: &Path, verify: bool) -> Result<()> {
+    let read = |path: &Path| {
+        std::fs::read_to_string(path).map_err(|e| AugentError::FileReadFailed {
+            path: path.display().to_string(),
+            reason: e.to_string(),
+        })
+    };
+    let new_content = read(source)?;
+    let merged = if target.exists() {
+        MergeStrategy::Yaml.merge_strings(&read(target)?, &new_content)?
+    } else {
+        new_content
+    };
+    crate::installer::file_ops::ensure_parent_dir(target)?;
+    crate::installer::file_ops::write_file(target, merged, verify)
+}
+
+/// Deep merge of YAML values: mappings merge recursively, anything else is replaced
+fn merge_yaml_deep(existing: YamlValue, new: YamlValue) -> YamlValue {
+    match (existing, new) {
+        (YamlValue::Mapping(mut existing_map), YamlValue::Mapping(new_map)) => {
             for (key, new_value) in new_map {
                 let merged_value = match existing_map.remove(&key) {
-                    Some(existing_value) => merge_json_deep(existing_value, new_value),
+                    Some(existing_value) => merge_yaml_deep(existing_value, new_value),
                     None => new_value,
                 };
                 existing_map.insert(key, merged_value);
             }
-            JsonValue::Object(existing_map)
-        }
-        (JsonValue::Array(mut existing_arr), JsonValue::Array(new_arr)) => {
-            // For arrays, append new items (avoiding duplicates for primitives)
-            for item in new_arr {
-                let _ = existing_arr
-                    .contains(&item)
-                    .then(|| existing_arr.push(item));
-            }
-            JsonValue::Array(existing_arr)
+            YamlValue::Mapping(existing_map)
         }
-        // For non-objects/arrays, new value wins
+        // An empty document merges as nothing
+        (existing, YamlValue::Null) => existing,
         (_, new) => new,
     }
 }
 
+fn parse_yaml(content: &str, path: &str) -> Result<YamlValue> {
+    serde_yaml::from_str(content).map_err(|e| AugentError::ConfigParseFailed {
+        path: path.to_string(),
+        reason: e.to_string(),
+    })
+}
+
+/// Union the arrays at `keys` in the `existing` target into `new_content`
+///
+/// The format follows the target's extension: `.json` and `.yaml`/`.yml`
+/// are merged as documents, `.md` through its frontmatter. Content in any
+/// other format, or where either side does not parse, is returned unchanged.
+pub fn append_arrays(
+    existing: &str,
+    new_content: &str,
+    target: &Path,
+    keys: &[String],
+) -> Result<String> {
+    let extension = target
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .unwrap_or_default();
+    let merged = match extension {
+        "json" => append_json_arrays(existing, new_content, keys)?,
+        "yaml" | "yml" => append_yaml_arrays(existing, new_content, keys),
+        "md" => append_frontmatter_arrays(existing, new_content, keys),
+        _ => None,
+    };
+    Ok(merged.unwrap_or_else(|| new_content.to_string()))
+}
+
+fn append_json_arrays(
+    existing: &str,
+    new_content: &str,
+    keys: &[String],
+) -> Result<Option<String>> {
+    let (Ok(existing), Ok(mut merged)) = (
+        serde_json::from_str::<JsonValue>(existing),
+        serde_json::from_str::<JsonValue>(new_content),
+    ) else {
+        return Ok(None);
+    };
+    for key in keys {
+        let path: Vec<&str> = key.split('.').collect();
+        let (Some(JsonValue::Array(old)), Some(JsonValue::Array(new))) =
+            (json_at(&existing, &path), json_at_mut(&mut merged, &path))
+        else {
+            continue;
+        };
+        *new = union(old, std::mem::take(new));
+    }
+    serde_json::to_string_pretty(&merged)
+        .map(Some)
+        .map_err(|e| AugentError::ConfigParseFailed {
+            path: "merge result".to_string(),
+            reason: e.to_string(),
+        })
+}
+
+fn append_yaml_arrays(existing: &str, new_content: &str, keys: &[String]) -> Option<String> {
+    let existing = serde_yaml::from_str::<YamlValue>(existing).ok()?;
+    let mut merged = serde_yaml::from_str::<YamlValue>(new_content).ok()?;
+    union_yaml_keys(&existing, &mut merged, keys);
+    Some(crate::universal::serialize_to_yaml(&merged))
+}
+
+fn append_frontmatter_arrays(existing: &str, new_content: &str, keys: &[String]) -> Option<String> {
+    let (existing, _) = crate::universal::parse_frontmatter_and_body(existing)?;
+    let (mut merged, _) = crate::universal::parse_frontmatter_and_body(new_content)?;
+    let rest = after_frontmatter(new_content)?;
+    union_yaml_keys(&existing, &mut merged, keys);
+    let yaml = crate::universal::serialize_to_yaml(&merged);
+    Some(format!("---\n{}\n---\n{rest}", yaml.trim_end()))
+}
+
+/// Everything after the closing `---` line of a markdown file's frontmatter
+fn after_frontmatter(content: &str) -> Option<&str> {
+    let mut offset = 0;
+    for (index, line) in content.split_inclusive('\n').enumerate() {
+        offset += line.len();
+        if index > 0 && line.trim() == "---" {
+            return Some(&content[offset..]);
+        }
+    }
+    None
+}
+
+fn union_yaml_keys(existing: &YamlValue, merged: &mut YamlValue, keys: &[String]) {
+    for key in keys {
+        let path: Vec<&str> = key.split('.').collect();
+        let (Some(YamlValue::Sequence(old)), Some(YamlValue::Sequence(new))) =
+            (yaml_at(existing, &path), yaml_at_mut(merged, &path))
+        else {
+            continue;
+        };
+        *new = union(old, std::mem::take(new));
+    }
+}
+
+/// Items of `existing` followed by the items of `new` not among them
+fn union<T: PartialEq + Clone>(existing: &[T], new: Vec<T>) -> Vec<T> {
+    let mut merged = existing.to_vec();
+    for item in new {
+        if !merged.contains(&item) {
+            merged.push(item);
+        }
+    }
+    merged
+}
+
+fn json_at<'a>(value: &'a JsonValue, path: &[&str]) -> Option<&'a JsonValue> {
+    path.iter().try_fold(value, |value, key| value.get(*key))
+}
+
+fn json_at_mut<'a>(value: &'a mut JsonValue, path: &[&str]) -> Option<&'a mut JsonValue> {
+    path.iter()
+        .try_fold(value, |value, key| value.get_mut(*key))
+}
+
+fn yaml_at<'a>(value: &'a YamlValue, path: &[&str]) -> Option<&'a YamlValue> {
+    path.iter().try_fold(value, |value, key| value.get(*key))
+}
+
+fn yaml_at_mut<'a>(value: &'a mut YamlValue, path: &[&str]) -> Option<&'a mut YamlValue> {
+    path.iter()
+        .try_fold(value, |value, key| value.get_mut(*key))
+}
+
 /// Create a `ConfigParseFailed` error for merge target
 fn create_merge_target_error(error: &serde_json::Error) -> AugentError {
     AugentError::ConfigParseFailed {
@@ -248,3 +602,220 @@ fn create_merge_target_error(error: &serde_json::Error) -> AugentError {
         reason: error.to_string(),
     }
 }
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::*;
+
+    fn merge_yaml(existing: &str, new: &str) -> YamlValue {
+        let merged = MergeStrategy::Yaml
+            .merge_strings(existing, new)
+            .expect("Failed to merge yaml");
+        serde_yaml::from_str(&merged).expect("Merged yaml should parse")
+    }
+
+    const FIRST_MCP: &str = r#"{"servers": [
+        {"name": "filesystem", "command": "fs-old"},
+        {"name": "git", "command": "git-mcp"}
+    ]}"#;
+    const SECOND_MCP: &str = r#"{"servers": [
+        {"name": "filesystem", "command": "fs-new"},
+        {"name": "web", "command": "web-mcp"}
+    ]}"#;
+
+    fn merge_mcp(policy: ArrayMergePolicy) -> Vec<JsonValue> {
+        let merged = MergeStrategy::Deep
+            .merge_strings_with_policy(FIRST_MCP, SECOND_MCP, policy)
+            .expect("Failed to merge mcp configs");
+        let merged: JsonValue = serde_json::from_str(&merged).expect("Merged json should parse");
+        merged["servers"]
+            .as_array()
+            .expect("servers should be an array")
+            .clone()
+    }
+
+    fn commands(servers: &[JsonValue]) -> Vec<&str> {
+        servers
+            .iter()
+            .filter_map(|s| s["command"].as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_deep_merge_concat_unique_dedupes_servers_by_name() {
+        let servers = merge_mcp(ArrayMergePolicy::ConcatUnique);
+        assert_eq!(commands(&servers), ["fs-new", "git-mcp", "web-mcp"]);
+    }
+
+    #[test]
+    fn test_deep_merge_concat_keeps_every_server() {
+        let servers = merge_mcp(ArrayMergePolicy::Concat);
+        assert_eq!(
+            commands(&servers),
+            ["fs-old", "git-mcp", "fs-new", "web-mcp"]
+        );
+    }
+
+    #[test]
+    fn test_deep_merge_replace_keeps_only_new_servers() {
+        let servers = merge_mcp(ArrayMergePolicy::Replace);
+        assert_eq!(commands(&servers), ["fs-new", "web-mcp"]);
+    }
+
+    #[test]
+    fn test_deep_merge_concat_unique_skips_equal_primitives() {
+        let merged = deep_merge_with_array_policy(
+            serde_json::json!({"args": ["-y", "a"]}),
+            serde_json::json!({"args": ["a", "b"]}),
+            ArrayMergePolicy::ConcatUnique,
+        );
+        assert_eq!(merged["args"], serde_json::json!(["-y", "a", "b"]));
+    }
+
+    #[test]
+    fn test_array_policy_deserializes_from_rule() {
+        let rule: crate::platform::TransformRule = serde_json::from_str(
+            r#"{"from": "mcp.jsonc", "to": ".mcp.json", "merge": "deep", "arrays": "concat"}"#,
+        )
+        .expect("Failed to parse rule");
+        assert_eq!(rule.arrays, ArrayMergePolicy::Concat);
+
+        let rule: crate::platform::TransformRule =
+            serde_json::from_str(r#"{"from": "mcp.jsonc", "to": ".mcp.json"}"#)
+                .expect("Failed to parse rule");
+        assert_eq!(rule.arrays, ArrayMergePolicy::ConcatUnique);
+    }
+
+    #[test]
+    fn test_merge_composite_section_replaces_previous_section() {
+        let existing = "# Local notes\n";
+        let once = merge_composite_section(existing, "@test/a", "Old content\n");
+        let other = merge_composite_section(&once, "@test/b", "Other bundle");
+        let upgraded = merge_composite_section(&other, "@test/a", "New content");
+
+        assert!(upgraded.starts_with("# Local notes\n"));
+        assert!(!upgraded.contains("Old content"));
+        assert_eq!(upgraded.matches("New content").count(), 1);
+        assert_eq!(upgraded.matches("<!-- augent:begin @test/a -->").count(), 1);
+        assert!(upgraded.contains("Other bundle"));
+        assert_eq!(
+            merge_composite_section(&upgraded, "@test/a", "New content"),
+            upgraded
+        );
+    }
+
+    #[test]
+    fn test_remove_composite_section_keeps_other_content() {
+        let merged = merge_composite_section("# Local notes", "@test/a", "Bundle content");
+        let removed = remove_composite_section(&merged, "@test/a").unwrap();
+        assert_eq!(removed, "# Local notes\n");
+        assert!(remove_composite_section(&removed, "@test/a").is_none());
+
+        let only_bundle = merge_composite_section("", "@test/a", "Bundle content");
+        assert_eq!(
+            remove_composite_section(&only_bundle, "@test/a").unwrap(),
+            ""
+        );
+    }
+
+    #[test]
+    fn test_merge_yaml_deep_merges_mappings() {
+        let merged = merge_yaml(
+            "editor:\n  tab_size: 2\n  theme: light\nname: base\n",
+            "editor:\n  theme: dark\nextra: true\n",
+        );
+        assert_eq!(merged["editor"]["tab_size"], YamlValue::from(2));
+        assert_eq!(merged["editor"]["theme"], YamlValue::from("dark"));
+        assert_eq!(merged["name"], YamlValue::from("base"));
+        assert_eq!(merged["extra"], YamlValue::from(true));
+    }
+
+    #[test]
+    fn test_merge_yaml_replaces_sequences_and_mismatched_types() {
+        let merged = merge_yaml(
+            "plugins: [a, b]\nhooks: [x]\n",
+            "plugins: [c]\nhooks:\n  pre: y\n",
+        );
+        assert_eq!(
+            merged["plugins"],
+            serde_yaml::from_str::<YamlValue>("[c]").expect("Failed to parse")
+        );
+        assert_eq!(merged["hooks"]["pre"], YamlValue::from("y"));
+    }
+
+    #[test]
+    fn test_merge_yaml_invalid_input() {
+        assert!(
+            MergeStrategy::Yaml
+                .merge_strings("a: [unclosed", "b: 1")
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_merge_yaml_files_copies_missing_target_then_merges() {
+        let temp = tempfile::TempDir::new_in(crate::temp::temp_dir_base())
+            .expect("Failed to create temp dir");
+        let first = temp.path().join("first.yaml");
+        let second = temp.path().join("second.yaml");
+        let target = temp.path().join("out/settings.yaml");
+        std::fs::write(&first, "a: 1\nnested:\n  x: 1\n").expect("Failed to write first");
+        std::fs::write(&second, "nested:\n  y: 2\n").expect("Failed to write second");
+
+        merge_yaml_files(&first, &target, false).expect("Failed to copy into missing target");
+        assert_eq!(
+            std::fs::read_to_string(&target).expect("Failed to read target"),
+            "a: 1\nnested:\n  x: 1\n"
+        );
+
+        merge_yaml_files(&second, &target, false).expect("Failed to merge into target");
+        let merged: YamlValue =
+            serde_yaml::from_str(&std::fs::read_to_string(&target).expect("Failed to read target"))
+                .expect("Merged yaml should parse");
+        assert_eq!(merged["a"], YamlValue::from(1));
+        assert_eq!(merged["nested"]["x"], YamlValue::from(1));
+        assert_eq!(merged["nested"]["y"], YamlValue::from(2));
+    }
+
+    #[test]
+    fn test_append_arrays_unions_json_key_paths_only() {
+        let existing = r#"{"permissions": {"allow": ["Read", "Grep"]}, "tags": ["a"]}"#;
+        let new = r#"{"permissions": {"allow": ["Grep", "Bash"]}, "tags": ["b"]}"#;
+        let merged = append_arrays(
+            existing,
+            new,
+            Path::new("agent.json"),
+            &["permissions.allow".to_string()],
+        )
+        .expect("Failed to append arrays");
+        let merged: JsonValue = serde_json::from_str(&merged).expect("Merged json should parse");
+        assert_eq!(
+            merged["permissions"]["allow"],
+            serde_json::json!(["Read", "Grep", "Bash"])
+        );
+        assert_eq!(merged["tags"], serde_json::json!(["b"]));
+    }
+
+    #[test]
+    fn test_append_arrays_unions_markdown_frontmatter() {
+        let existing = "---\nname: helper\ntools:\n- Read\n---\n\nOld body.\n";
+        let new = "---\nname: helper\ntools:\n- Bash\n---\n\nNew body.\n";
+        let merged = append_arrays(
+            existing,
+            new,
+            Path::new("helper.md"),
+            &["tools".to_string()],
+        )
+        .expect("Failed to append arrays");
+        assert_eq!(
+            merged,
+            "---\nname: helper\ntools:\n- Read\n- Bash\n---\n\nNew body.\n"
+        );
+
+        // Unknown formats are left as written
+        let text = append_arrays("a", "b", Path::new("notes.txt"), &["tools".to_string()])
+            .expect("Failed to append arrays");
+        assert_eq!(text, "b");
+    }
+}
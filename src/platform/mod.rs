@@ -4,16 +4,20 @@
 //! - Platform definitions (Platform, `TransformRule`, `MergeStrategy`)
 //! - Platform detection (via detection module)
 //! - Merge strategies for combining files (via merge module)
+//! - Per-install output extension overrides (via `output_ext` module)
+//! - Per-install merge strategy overrides (via `strategy_override` module)
 
 use std::path::{Path, PathBuf};
 
 use serde::{Deserialize, Serialize};
 
-pub use merge::MergeStrategy;
+pub use merge::{ArrayMergePolicy, MergeStrategy};
 
 pub mod detection;
 pub mod loader;
 pub mod merge;
+pub mod output_ext;
+pub mod strategy_override;
 
 /// A supported AI coding platform
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -24,9 +28,18 @@ pub struct Platform {
     /// Display name for the platform
     pub name: String,
 
+    /// Alternative names accepted wherever a platform id is (e.g. `oc` for `opencode`)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub aliases: Vec<String>,
+
     /// Directory where platform-specific files are stored (e.g., ".claude", ".cursor")
     pub directory: String,
 
+    /// Glob matching variant directories (e.g. `.claude*` for `.claude-work`),
+    /// used when `directory` itself does not exist
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub directory_glob: Option<String>,
+
     /// Detection patterns (directories or files that indicate this platform)
     pub detection: Vec<String>,
 
@@ -45,12 +58,21 @@ impl Platform {
         Self {
             id: id.into(),
             name: name.into(),
+            aliases: Vec::new(),
             directory: directory.into(),
+            directory_glob: None,
             detection: Vec::new(),
             transforms: Vec::new(),
         }
     }
 
+    /// Add an alternative name for the platform
+    #[allow(dead_code)]
+    pub fn with_alias(mut self, alias: impl Into<String>) -> Self {
+        self.aliases.push(alias.into());
+        self
+    }
+
     /// Add a detection pattern
     #[allow(dead_code)]
     pub fn with_detection(mut self, pattern: impl Into<String>) -> Self {
@@ -75,13 +97,134 @@ impl Platform {
         })
     }
 
+    /// Merge strategy of the first transform rule whose `from` matches `bundle_path`
+    ///
+    /// Files no rule matches are replaced.
+    pub fn merge_strategy_for(&self, bundle_path: &Path) -> MergeStrategy {
+        let path = bundle_path.to_string_lossy().replace('\\', "/");
+        self.transforms
+            .iter()
+            .find(|rule| {
+                rule.from == path || crate::workspace::path::matches_glob(&rule.from, &path)
+            })
+            .map_or(MergeStrategy::Replace, |rule| rule.merge)
+    }
+
+    /// Set the glob matching variant platform directories
+    #[allow(dead_code)]
+    pub fn with_directory_glob(mut self, pattern: impl Into<String>) -> Self {
+        self.directory_glob = Some(pattern.into());
+        self
+    }
+
     /// Get the platform directory path
+    ///
+    /// This is `directory`, or the first root-level directory matching
+    /// `directory_glob` when `directory` does not exist.
     #[allow(dead_code)]
     pub fn directory_path(&self, workspace_root: &Path) -> PathBuf {
-        workspace_root.join(&self.directory)
+        let directory = self
+            .matched_directory(workspace_root, &default_platforms())
+            .unwrap_or_else(|| self.directory.clone());
+        workspace_root.join(directory)
+    }
+
+    /// Existing platform directory in the workspace, relative to its root
+    ///
+    /// `directory` wins when present; otherwise root-level directories are
+    /// matched against `directory_glob` in name order. Directories owned by
+    /// another of `known` platforms (`.claude-plugin` for `.claude*`) never match.
+    pub fn matched_directory(&self, workspace_root: &Path, known: &[Platform]) -> Option<String> {
+        if workspace_root.join(&self.directory).is_dir() {
+            return Some(self.directory.clone());
+        }
+        let pattern = self.directory_glob.as_deref()?;
+        let entries = std::fs::read_dir(workspace_root).ok()?;
+        let mut names: Vec<String> = entries
+            .filter_map(std::result::Result::ok)
+            .filter(|e| e.path().is_dir())
+            .filter_map(|e| e.file_name().to_str().map(str::to_string))
+            .filter(|name| crate::workspace::path::matches_glob(pattern, name))
+            .filter(|name| {
+                !known
+                    .iter()
+                    .any(|p| p.id != self.id && p.directory == *name)
+            })
+            .collect();
+        names.sort();
+        names.into_iter().next()
+    }
+
+    /// Workspace-relative install path of a bundle resource, when a transform
+    /// places it somewhere other than the same path under the platform directory
+    ///
+    /// Only transforms that stay inside the platform directory are applied:
+    /// directory renames with an unchanged glob tail (`commands/**/*.md` to
+    /// `.kilocode/workflows/**/*.md`) and single markdown files
+    /// (`AGENTS.md` to `.kiro/steering/AGENTS.md`).
+    pub fn mapped_target(&self, bundle_path: &Path) -> Option<PathBuf> {
+        let path = bundle_path.to_string_lossy().replace('\\', "/");
+        let is_markdown = bundle_path
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("md"));
+        let prefix = format!("{}/", self.directory);
+        self.transforms.iter().find_map(|rule| {
+            (rule.from == path && is_markdown && rule.to.starts_with(&prefix))
+                .then(|| PathBuf::from(&rule.to))
+                .or_else(|| self.mapped_by_directory_rule(rule, &path))
+        })
+    }
+
+    /// Install path of `path` under a directory-renaming transform rule
+    fn mapped_by_directory_rule(&self, rule: &TransformRule, path: &str) -> Option<PathBuf> {
+        let to = rule.to.strip_prefix(&format!("{}/", self.directory))?;
+        let (from_dir, tail) = rule.from.split_once("/**/*")?;
+        let (to_dir, to_tail) = to.split_once("/**/*")?;
+        let is_pattern = |dir: &str| dir.contains(['*', '{']);
+        if tail != to_tail || from_dir == to_dir || is_pattern(from_dir) || is_pattern(to_dir) {
+            return None;
+        }
+        let rest = path.strip_prefix(from_dir)?.strip_prefix('/')?;
+        rest.ends_with(tail)
+            .then(|| PathBuf::from(&self.directory).join(to_dir).join(rest))
+    }
+
+    /// Appended array key paths of the first transform rule whose `from` matches `bundle_path`
+    pub fn append_keys_for(&self, bundle_path: &Path) -> &[String] {
+        let path = bundle_path.to_string_lossy().replace('\\', "/");
+        self.transforms
+            .iter()
+            .find(|rule| {
+                rule.from == path || crate::workspace::path::matches_glob(&rule.from, &path)
+            })
+            .map_or(&[], |rule| rule.append.as_slice())
+    }
+
+    /// Move the platform to another directory
+    ///
+    /// Detection patterns and transform targets under the old directory are
+    /// rewritten too, so install targets, detection and reverse lookup agree.
+    pub fn relocate(&mut self, directory: &str) {
+        let old = std::mem::replace(&mut self.directory, directory.to_string());
+        for pattern in &mut self.detection {
+            *pattern = rebase_path(pattern, &old, directory);
+        }
+        for rule in &mut self.transforms {
+            rule.to = rebase_path(&rule.to, &old, directory);
+        }
     }
 }
 
+/// Rewrite `path` from under directory `old` to under `new`, leaving other paths as they are
+fn rebase_path(path: &str, old: &str, new: &str) -> String {
+    if path == old {
+        return new.to_string();
+    }
+    path.strip_prefix(old)
+        .and_then(|rest| rest.strip_prefix('/'))
+        .map_or_else(|| path.to_string(), |rest| format!("{new}/{rest}"))
+}
+
 /// A transformation rule for converting resources
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TransformRule {
@@ -95,6 +238,14 @@ pub struct TransformRule {
     #[serde(default)]
     pub merge: MergeStrategy,
 
+    /// How deep merges into this rule's target combine JSON arrays
+    #[serde(default, skip_serializing_if = "ArrayMergePolicy::is_default")]
+    pub arrays: ArrayMergePolicy,
+
+    /// Key paths (e.g. `tools`) whose arrays are unioned with the installed target
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub append: Vec<String>,
+
     /// Optional file extension transformation
     #[serde(skip_serializing_if = "Option::is_none")]
     pub extension: Option<String>,
@@ -108,6 +259,8 @@ impl TransformRule {
             from: from.into(),
             to: to.into(),
             merge: MergeStrategy::Replace,
+            arrays: ArrayMergePolicy::default(),
+            append: Vec::new(),
             extension: None,
         }
     }
@@ -119,6 +272,20 @@ impl TransformRule {
         self
     }
 
+    /// Set how deep merges combine JSON arrays
+    #[allow(dead_code)]
+    pub fn with_arrays(mut self, policy: ArrayMergePolicy) -> Self {
+        self.arrays = policy;
+        self
+    }
+
+    /// Union the arrays at these key paths with the installed target
+    #[allow(dead_code)]
+    pub fn with_append(mut self, keys: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.append = keys.into_iter().map(Into::into).collect();
+        self
+    }
+
     /// Set extension transformation
     #[allow(dead_code)]
     pub fn with_extension(mut self, ext: impl Into<String>) -> Self {
@@ -165,6 +332,51 @@ mod unit_tests {
         assert!(!cursor.is_detected(temp.path()));
     }
 
+    #[test]
+    fn test_directory_glob_matches_variant_directory() {
+        let temp =
+            TempDir::new_in(crate::temp::temp_dir_base()).expect("Failed to create temp directory");
+        std::fs::create_dir(temp.path().join(".claude-work"))
+            .expect("Failed to create .claude-work directory");
+
+        let platforms = super::default_platforms();
+        let claude = platforms
+            .iter()
+            .find(|p| p.id == "claude")
+            .expect("Claude platform should exist");
+
+        assert_eq!(
+            claude.matched_directory(temp.path(), &platforms).as_deref(),
+            Some(".claude-work")
+        );
+        assert_eq!(
+            claude.directory_path(temp.path()),
+            temp.path().join(".claude-work")
+        );
+
+        let detected = crate::workspace::operations::detect_installed_platforms(temp.path())
+            .expect("Detection should succeed");
+        assert_eq!(detected, vec![temp.path().join(".claude-work")]);
+
+        // Another platform's directory is not a variant
+        let plugin_only =
+            TempDir::new_in(crate::temp::temp_dir_base()).expect("Failed to create temp directory");
+        std::fs::create_dir(plugin_only.path().join(".claude-plugin"))
+            .expect("Failed to create .claude-plugin directory");
+        assert!(
+            claude
+                .matched_directory(plugin_only.path(), &platforms)
+                .is_none()
+        );
+
+        std::fs::create_dir(temp.path().join(".claude"))
+            .expect("Failed to create .claude directory");
+        assert_eq!(
+            claude.matched_directory(temp.path(), &platforms).as_deref(),
+            Some(".claude")
+        );
+    }
+
     #[test]
     fn test_transform_rule() {
         let rule = TransformRule::new("commands/**/*.md", ".cursor/rules/**/*.mdc")
@@ -177,6 +389,40 @@ mod unit_tests {
         assert_eq!(rule.extension, Some("mdc".to_string()));
     }
 
+    #[test]
+    fn test_mapped_target_follows_directory_renames() {
+        let platforms = default_platforms();
+        let platform = |id: &str| {
+            platforms
+                .iter()
+                .find(|p| p.id == id)
+                .expect("Platform should exist")
+        };
+
+        let kiro = platform("kiro");
+        assert_eq!(
+            kiro.mapped_target(Path::new("rules/style/lint.md")),
+            Some(PathBuf::from(".kiro/steering/style/lint.md"))
+        );
+        assert_eq!(
+            kiro.mapped_target(Path::new("AGENTS.md")),
+            Some(PathBuf::from(".kiro/steering/AGENTS.md"))
+        );
+        assert_eq!(kiro.mapped_target(Path::new("mcp.jsonc")), None);
+        assert_eq!(
+            platform("kilo").mapped_target(Path::new("commands/fix.md")),
+            Some(PathBuf::from(".kilocode/workflows/fix.md"))
+        );
+        assert_eq!(
+            platform("claude").mapped_target(Path::new("commands/fix.md")),
+            None
+        );
+        assert_eq!(
+            platform("claude").mapped_target(Path::new("skills/deploy/run.sh")),
+            None
+        );
+    }
+
     #[test]
     fn test_default_platforms() {
         let platforms = default_platforms();
@@ -0,0 +1,151 @@
+//! Transient output extension overrides (`--platform-output-ext`)
+//!
+//! An override such as `cursor:rules=txt` changes the extension of the files
+//! one platform emits for one resource type, for a single install. Platform
+//! definitions are not modified.
+
+use std::path::{Path, PathBuf};
+
+use crate::error::{AugentError, Result};
+
+use super::Platform;
+
+/// Output extension override for one platform and resource type
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutputExtOverride {
+    pub platform: String,
+    pub resource_type: String,
+    pub extension: String,
+}
+
+impl OutputExtOverride {
+    /// Parse `platform:resource_type=extension`
+    pub fn parse(s: &str) -> std::result::Result<Self, String> {
+        let invalid = || format!("expected platform:resource_type=extension, got '{s}'");
+        let (target, extension) = s.split_once('=').ok_or_else(invalid)?;
+        let (platform, resource_type) = target.split_once(':').ok_or_else(invalid)?;
+        let extension = extension.trim().trim_start_matches('.');
+        if platform.is_empty() || resource_type.is_empty() || extension.is_empty() {
+            return Err(invalid());
+        }
+        Ok(Self {
+            platform: platform.trim().to_string(),
+            resource_type: resource_type.trim().to_string(),
+            extension: extension.to_string(),
+        })
+    }
+
+    /// Check the platform exists and has a transform rule for the resource type
+    pub fn validate(&self, platforms: &[Platform]) -> Result<()> {
+        let platform = platforms
+            .iter()
+            .find(|p| p.id == self.platform)
+            .ok_or_else(|| AugentError::PlatformNotSupported {
+                platform: self.platform.clone(),
+            })?;
+
+        let prefix = format!("{}/", self.resource_type);
+        if platform
+            .transforms
+            .iter()
+            .any(|rule| rule.from.starts_with(&prefix))
+        {
+            return Ok(());
+        }
+        Err(AugentError::PlatformConfigFailed {
+            message: format!(
+                "Platform '{}' has no transform for resource type '{}'",
+                self.platform, self.resource_type
+            ),
+        })
+    }
+
+    fn matches(&self, platform: &Platform, resource_type: &str) -> bool {
+        self.platform == platform.id && self.resource_type == resource_type
+    }
+}
+
+/// Validate overrides against all known platforms in the workspace
+pub fn validate_overrides(overrides: &[OutputExtOverride], workspace_root: &Path) -> Result<()> {
+    if overrides.is_empty() {
+        return Ok(());
+    }
+    let platforms = super::loader::PlatformLoader::new(workspace_root).load()?;
+    overrides
+        .iter()
+        .try_for_each(|output_ext| output_ext.validate(&platforms))
+}
+
+/// Apply the matching override, if any, to a target path
+pub fn apply_overrides(
+    overrides: &[OutputExtOverride],
+    platform: &Platform,
+    resource_type: &str,
+    target: PathBuf,
+) -> PathBuf {
+    match overrides
+        .iter()
+        .find(|o| o.matches(platform, resource_type))
+    {
+        Some(output_ext) => target.with_extension(&output_ext.extension),
+        None => target,
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::*;
+
+    fn cursor() -> Platform {
+        super::super::default_platforms()
+            .into_iter()
+            .find(|p| p.id == "cursor")
+            .expect("Cursor platform should exist")
+    }
+
+    #[test]
+    fn test_parse_output_ext_override() {
+        let output_ext = OutputExtOverride::parse("cursor:rules=.txt").expect("Should parse");
+        assert_eq!(output_ext.platform, "cursor");
+        assert_eq!(output_ext.resource_type, "rules");
+        assert_eq!(output_ext.extension, "txt");
+        assert!(OutputExtOverride::parse("cursor=txt").is_err());
+        assert!(OutputExtOverride::parse("cursor:rules").is_err());
+    }
+
+    #[test]
+    fn test_validate_output_ext_override() {
+        let platforms = vec![cursor()];
+        let valid = OutputExtOverride::parse("cursor:rules=txt").expect("Should parse");
+        assert!(valid.validate(&platforms).is_ok());
+
+        let unknown_platform = OutputExtOverride::parse("nope:rules=txt").expect("Should parse");
+        assert!(unknown_platform.validate(&platforms).is_err());
+
+        let unknown_type = OutputExtOverride::parse("cursor:widgets=txt").expect("Should parse");
+        assert!(unknown_type.validate(&platforms).is_err());
+    }
+
+    #[test]
+    fn test_apply_overrides_only_matching_resource_type() {
+        let overrides = vec![OutputExtOverride::parse("cursor:rules=txt").expect("Should parse")];
+        let platform = cursor();
+
+        let rule = apply_overrides(
+            &overrides,
+            &platform,
+            "rules",
+            PathBuf::from(".cursor/rules/lint.md"),
+        );
+        assert_eq!(rule, PathBuf::from(".cursor/rules/lint.txt"));
+
+        let command = apply_overrides(
+            &overrides,
+            &platform,
+            "commands",
+            PathBuf::from(".cursor/commands/review.md"),
+        );
+        assert_eq!(command, PathBuf::from(".cursor/commands/review.md"));
+    }
+}
@@ -0,0 +1,98 @@
+//! Transient merge strategy overrides (`--strategy-override`)
+//!
+//! An override such as `rules=composite` changes how the files of one
+//! resource type are merged into existing targets, on every platform, for a
+//! single install. Platform definitions are not modified.
+
+use super::MergeStrategy;
+
+/// Resource type of `mcp_servers/` files, which `mcp` is accepted for
+const MCP_SERVERS_TYPE: &str = "mcp_servers";
+
+/// Merge strategy override for one resource type
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StrategyOverride {
+    pub resource_type: String,
+    pub strategy: MergeStrategy,
+}
+
+impl StrategyOverride {
+    /// Parse `resource_type=strategy`, where strategy is a `MergeStrategy` name
+    pub fn parse(s: &str) -> std::result::Result<Self, String> {
+        let (resource_type, name) = s
+            .split_once('=')
+            .ok_or_else(|| format!("expected resource_type=strategy, got '{s}'"))?;
+        let name = name.trim();
+        let resource_type = match resource_type.trim() {
+            "" => return Err(format!("expected resource_type=strategy, got '{s}'")),
+            "mcp" => MCP_SERVERS_TYPE,
+            other => other,
+        };
+        let strategy =
+            serde_json::from_value::<MergeStrategy>(serde_json::Value::String(name.to_string()))
+                .map_err(|_| {
+                    format!(
+                        "unknown merge strategy '{name}' (expected replace, shallow, deep, composite or yaml)"
+                    )
+                })?;
+        let json_config = matches!(
+            strategy,
+            MergeStrategy::Replace | MergeStrategy::Shallow | MergeStrategy::Deep
+        );
+        if resource_type == MCP_SERVERS_TYPE && !json_config {
+            return Err(format!(
+                "MCP servers are merged into a JSON config; use replace, shallow or deep, got '{name}'"
+            ));
+        }
+        Ok(Self {
+            resource_type: resource_type.to_string(),
+            strategy,
+        })
+    }
+}
+
+/// The strategy overriding `resource_type`, if any (the last override wins)
+pub fn strategy_for(overrides: &[StrategyOverride], resource_type: &str) -> Option<MergeStrategy> {
+    overrides
+        .iter()
+        .rev()
+        .find(|o| o.resource_type == resource_type)
+        .map(|o| o.strategy)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_strategy_override() {
+        assert_eq!(
+            StrategyOverride::parse("rules=replace"),
+            Ok(StrategyOverride {
+                resource_type: "rules".to_string(),
+                strategy: MergeStrategy::Replace,
+            })
+        );
+        assert_eq!(
+            StrategyOverride::parse(" mcp = deep ").map(|o| o.resource_type),
+            Ok("mcp_servers".to_string())
+        );
+        assert!(StrategyOverride::parse("rules").is_err());
+        assert!(StrategyOverride::parse("=deep").is_err());
+        assert!(StrategyOverride::parse("rules=merge").is_err());
+        assert!(StrategyOverride::parse("mcp=composite").is_err());
+    }
+
+    #[test]
+    fn test_strategy_for_uses_last_override() {
+        let overrides = [
+            StrategyOverride::parse("rules=deep").unwrap_or_else(|e| panic!("{e}")),
+            StrategyOverride::parse("rules=composite").unwrap_or_else(|e| panic!("{e}")),
+        ];
+        assert_eq!(
+            strategy_for(&overrides, "rules"),
+            Some(MergeStrategy::Composite)
+        );
+        assert_eq!(strategy_for(&overrides, "commands"), None);
+    }
+}
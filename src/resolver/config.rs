@@ -16,7 +16,8 @@ use crate::error::{AugentError, Result};
 ///
 /// # Returns
 ///
-/// `Some(BundleConfig)` if `augent.yaml` exists, `None` otherwise
+/// `Some(BundleConfig)` if `augent.yaml` exists, or if it is absent but a
+/// `package.json` declares `augent.bundles`; `None` otherwise
 ///
 /// # Errors
 ///
@@ -24,7 +25,7 @@ use crate::error::{AugentError, Result};
 pub fn load_bundle_config(path: &Path) -> Result<Option<BundleConfig>> {
     let config_path = path.join("augent.yaml");
     if !config_path.exists() {
-        return Ok(None);
+        return Ok(load_package_json_config(path));
     }
 
     let content =
@@ -37,6 +38,17 @@ pub fn load_bundle_config(path: &Path) -> Result<Option<BundleConfig>> {
     Ok(Some(config))
 }
 
+/// Bundle config built from `package.json` dependencies, if declared
+fn load_package_json_config(path: &Path) -> Option<BundleConfig> {
+    let bundles = crate::config::load_from_package_json(
+        &path.join(crate::config::package_json::PACKAGE_JSON_FILE),
+    )?;
+    Some(BundleConfig {
+        bundles,
+        ..BundleConfig::new()
+    })
+}
+
 /// Load marketplace configuration from repository if it exists
 ///
 /// # Arguments
@@ -99,6 +111,22 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_load_bundle_config_from_package_json() {
+        let temp = TempDir::new().expect("Failed to create temp directory");
+        std::fs::write(
+            temp.path().join("package.json"),
+            r#"{"augent": {"bundles": [{"name": "tools", "path": "./tools"}]}}"#,
+        )
+        .expect("Failed to write package.json");
+
+        let config = load_bundle_config(temp.path())
+            .expect("Config should be Ok")
+            .expect("Config should fall back to package.json");
+        assert_eq!(config.bundles.len(), 1);
+        assert_eq!(config.bundles[0].name, "tools");
+    }
+
     #[test]
     fn test_load_marketplace_config_missing() {
         let temp = TempDir::new().expect("Failed to create temp directory");
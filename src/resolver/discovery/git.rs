@@ -9,7 +9,6 @@ use crate::cache;
 use crate::config::MarketplaceConfig;
 use crate::domain::DiscoveredBundle;
 use crate::error::Result;
-use crate::git;
 use crate::resolver::discovery::helpers;
 use crate::source::GitSource;
 
@@ -129,6 +128,7 @@ pub fn process_git_bundle(bundle: &mut DiscoveredBundle, ctx: &GitBundleContext<
 ///
 /// # Arguments
 /// * `source` - Git source to check
+/// * `options` - How refs are resolved against the cache
 ///
 /// # Returns
 /// * `(Option<Vec<DiscoveredBundle>>, String)` - Bundle list and SHA
@@ -136,12 +136,14 @@ pub fn process_git_bundle(bundle: &mut DiscoveredBundle, ctx: &GitBundleContext<
 ///   - If no cache: `(None, String::new())`
 pub fn try_get_cached_bundles(
     source: &GitSource,
+    options: cache::FetchOptions,
 ) -> Result<(Option<Vec<DiscoveredBundle>>, String)> {
     if source.resolved_sha.is_some() {
         return Ok((None, String::new()));
     }
 
-    let Ok(sha) = git::ls_remote(&source.url, source.git_ref.as_deref()) else {
+    let Ok(sha) = cache::lookup::resolve_sha(&source.url, source.git_ref.as_deref(), options)
+    else {
         return Ok((None, String::new()));
     };
 
@@ -153,7 +155,11 @@ pub fn try_get_cached_bundles(
         return Ok((None, sha));
     }
 
+    // Only marketplace repositories list their bundles from the cache
     let bundles = helpers::load_cached_bundles_from_marketplace(source, &sha)?;
+    if bundles.is_empty() {
+        return Ok((None, sha));
+    }
     Ok((Some(bundles), sha))
 }
 
@@ -24,12 +24,27 @@ pub use local::discover_local_bundles;
 /// Discover bundles in a source directory
 ///
 /// Returns discovered bundles sorted alphabetically by name.
-pub fn discover_bundles(source: &str, workspace_root: &Path) -> Result<Vec<DiscoveredBundle>> {
-    let bundle_source = crate::source::BundleSource::parse(source)?;
+pub fn discover_bundles(
+    source: &str,
+    workspace_root: &Path,
+    options: cache_api::FetchOptions,
+) -> Result<Vec<DiscoveredBundle>> {
+    let bundle_source = crate::source::BundleSource::parse_with(source, options)?;
 
     let mut discovered = match bundle_source {
         crate::source::BundleSource::Dir { path } => discover_local_bundles(&path, workspace_root)?,
-        crate::source::BundleSource::Git(git_source) => discover_git_bundles(&git_source)?,
+        crate::source::BundleSource::Git(git_source) => discover_git_bundles(&git_source, options)?,
+        crate::source::BundleSource::Archive { url, path } => {
+            let root = crate::cache::archive::cache_archive(&url, options)?;
+            let dir = path.map_or_else(|| root.clone(), |p| root.join(p));
+            discover_local_bundles(&dir, &root)?
+        }
+        crate::source::BundleSource::Oci { reference, path } => {
+            let reference = crate::cache::oci::OciReference::parse(&reference)?;
+            let (root, _) = crate::cache::oci::cache_oci(&reference, options)?;
+            let dir = path.map_or_else(|| root.clone(), |p| root.join(p));
+            discover_local_bundles(&dir, &root)?
+        }
     };
 
     discovered.sort_by(|a, b| a.name.cmp(&b.name));
@@ -38,14 +53,20 @@ pub fn discover_bundles(source: &str, workspace_root: &Path) -> Result<Vec<Disco
 }
 
 /// Discover bundles in a cached git repository
-fn discover_git_bundles(source: &GitSource) -> Result<Vec<DiscoveredBundle>> {
-    let (cached_bundles, _sha) = git::try_get_cached_bundles(source)?;
+fn discover_git_bundles(
+    source: &GitSource,
+    options: cache_api::FetchOptions,
+) -> Result<Vec<DiscoveredBundle>> {
+    let concrete_source = crate::resolver::git::resolve_tag_glob(source, options)?;
+    let source = concrete_source.as_ref().unwrap_or(source);
+
+    let (cached_bundles, _sha) = git::try_get_cached_bundles(source, options)?;
 
     if let Some(bundles) = cached_bundles {
         return Ok(bundles);
     }
 
-    let (temp_dir, sha, resolved_ref) = cache_api::clone_and_checkout(source)?;
+    let (temp_dir, sha, resolved_ref) = cache_api::clone_and_checkout(source, options)?;
     let repo_path = temp_dir.path();
     let content_path = cache_api::content_path_in_repo(repo_path, source);
 
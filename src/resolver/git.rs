@@ -4,14 +4,24 @@
 //! - Git repository bundle resolution
 //! - Bundle name derivation from git URLs
 //! - SHA and resolved ref handling
+//! - `glob:` refs, resolved to the newest matching remote tag
+//! - A per-run source cache so identical sources are fetched once
+//! - Parallel prefetching of independent top-level sources
+//! - Detection of a bundle required at two different commits
+
+use std::collections::{BTreeMap, HashMap};
 
 use crate::cache;
 use crate::common::string_utils;
-use crate::config::BundleDependency;
-use crate::domain::ResolvedBundle;
+use crate::config::{BundleDependency, LockedPlatformRef};
+use crate::domain::{PlatformSource, ResolvedBundle};
 use crate::error::{AugentError, Result};
+use crate::git::refs::TAG_GLOB_PREFIX;
 use crate::source::GitSource;
 
+/// Dependent reported for bundles required directly by the workspace
+pub const WORKSPACE_DEPENDENT: &str = "workspace";
+
 fn create_bundle_not_found_error(git_source: &GitSource) -> AugentError {
     let ref_suffix = git_source
         .git_ref
@@ -44,44 +54,244 @@ fn create_resolved_bundle(info: BundleBuildInfo, git_source: &GitSource) -> Reso
         resolved_ref: info.resolved_ref,
         git_source: Some(git_source.clone()),
         config: None,
+        platform_sources: HashMap::new(),
+        artifact_source: None,
     }
 }
 
-/// Resolve a git bundle from a `GitSource`
+/// Per-platform ref overrides locked for each bundle (bundle name -> platform id -> ref)
+pub type LockedPlatformRefs = HashMap<String, BTreeMap<String, LockedPlatformRef>>;
+
+/// Cache the bundle at each per-platform ref override
 ///
-/// # Arguments
+/// A ref pinned in `locked` is fetched at its locked commit instead of being
+/// resolved again. Returns a map of platform id to the cached source.
+pub fn resolve_platform_sources(
+    git_source: &GitSource,
+    options: cache::FetchOptions,
+    platform_refs: &BTreeMap<String, String>,
+    locked: Option<&BTreeMap<String, LockedPlatformRef>>,
+) -> Result<HashMap<String, PlatformSource>> {
+    platform_refs
+        .iter()
+        .map(|(platform_id, git_ref)| {
+            let locked_sha = locked
+                .and_then(|locked| locked.get(platform_id))
+                .filter(|locked| locked.git_ref == *git_ref)
+                .map(|locked| locked.sha.clone());
+            let platform_source = GitSource {
+                git_ref: Some(locked_sha.clone().unwrap_or_else(|| git_ref.clone())),
+                resolved_sha: locked_sha,
+                ..git_source.clone()
+            };
+            let (path, sha, _resolved_ref) = cache::cache_bundle(&platform_source, options)?;
+            let source = PlatformSource {
+                git_ref: git_ref.clone(),
+                sha,
+                path,
+            };
+            Ok((platform_id.clone(), source))
+        })
+        .collect()
+}
+
+/// A git source after fetching: the concrete source (glob refs replaced)
+/// and its cached content
+#[derive(Clone)]
+struct FetchedSource {
+    source: GitSource,
+    content_path: std::path::PathBuf,
+    sha: String,
+    resolved_ref: Option<String>,
+}
+
+type SourceKey = (String, Option<String>, Option<String>, Option<String>);
+
+/// Per-run cache of fetched git sources, keyed by url, ref, path and SHA
+///
+/// Overlapping dependency graphs often reference the same source before its
+/// bundle name is known; this keeps each one to a single ls-remote/clone.
+#[derive(Default)]
+pub struct SourceCache {
+    entries: std::collections::HashMap<SourceKey, FetchedSource>,
+}
+
+impl SourceCache {
+    fn fetch(
+        &mut self,
+        git_source: &GitSource,
+        options: cache::FetchOptions,
+    ) -> Result<FetchedSource> {
+        self.fetch_with(git_source, |source| fetch_source(source, options))
+    }
+
+    fn fetch_with(
+        &mut self,
+        git_source: &GitSource,
+        fetch: impl FnOnce(&GitSource) -> Result<FetchedSource>,
+    ) -> Result<FetchedSource> {
+        let key = source_key(git_source);
+        if let Some(fetched) = self.entries.get(&key) {
+            return Ok(fetched.clone());
+        }
+        let fetched = fetch(git_source)?;
+        self.entries.insert(key, fetched.clone());
+        Ok(fetched)
+    }
+}
+
+fn source_key(git_source: &GitSource) -> SourceKey {
+    (
+        git_source.url.clone(),
+        git_source.git_ref.clone(),
+        git_source.path.clone(),
+        git_source.resolved_sha.clone(),
+    )
+}
+
+/// Resolve a glob ref and clone (or look up) the source in the bundle cache
+fn fetch_source(git_source: &GitSource, options: cache::FetchOptions) -> Result<FetchedSource> {
+    let concrete_source = resolve_tag_glob(git_source, options)?;
+    let source = concrete_source.unwrap_or_else(|| git_source.clone());
+    let (content_path, sha, resolved_ref) = cache::cache_bundle(&source, options)?;
+    Ok(FetchedSource {
+        source,
+        content_path,
+        sha,
+        resolved_ref,
+    })
+}
+
+/// Fetch `git_sources` into `sources` using up to `jobs` threads
+///
+/// Only the ls-remote and clone work runs in parallel; concurrent writes of
+/// the same cache entry are serialized by its entry lock, so resolution
+/// afterwards simply finds every source fetched. Failures are not recorded:
+/// resolving the source again reports the error at its place in the
+/// resolution order.
+pub fn prefetch(
+    sources: &mut SourceCache,
+    git_sources: &[GitSource],
+    options: cache::FetchOptions,
+    jobs: usize,
+) {
+    let pending: Vec<&GitSource> = git_sources
+        .iter()
+        .filter(|source| !sources.entries.contains_key(&source_key(source)))
+        .collect();
+    let fetched = crate::common::concurrency::map_ordered(&pending, jobs, |source| {
+        fetch_source(source, options).ok()
+    });
+    for (source, fetched) in pending.into_iter().zip(fetched) {
+        if let Some(fetched) = fetched {
+            sources.entries.insert(source_key(source), fetched);
+        }
+    }
+}
+
+/// Context for git bundle resolution
+pub struct ResolveGitContext<'a> {
+    /// Git repository source specification
+    pub git_source: &'a GitSource,
+    /// Optional dependency info
+    pub dependency: Option<&'a BundleDependency>,
+    /// Resolution stack for cycle detection
+    pub resolution_stack: &'a [String],
+    /// Already resolved bundles
+    pub resolved: &'a std::collections::HashMap<String, ResolvedBundle>,
+    /// Sources already fetched in this run
+    pub sources: &'a mut SourceCache,
+    /// How sources missing from `sources` are fetched
+    pub fetch_options: cache::FetchOptions,
+    /// Bundle that first required each resolved bundle
+    pub dependents: &'a std::collections::HashMap<String, String>,
+    /// Collector for bundles required again at a different commit
+    pub conflicts: &'a mut Vec<ShaConflict>,
+}
+
+/// A bundle already resolved at one commit and required again at another
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShaConflict {
+    /// Name of the bundle required twice
+    pub name: String,
+    /// Bundle (or `workspace`) whose requirement was resolved first
+    pub first_dependent: String,
+    /// Commit the first requirement resolved to
+    pub first_sha: String,
+    /// Bundle (or `workspace`) whose requirement conflicts with the first
+    pub second_dependent: String,
+    /// Commit the second requirement resolved to
+    pub second_sha: String,
+}
+
+impl std::fmt::Display for ShaConflict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let short = |sha: &str| sha.get(..7).unwrap_or(sha).to_string();
+        write!(
+            f,
+            "{} requires {} at {}, {} requires it at {}",
+            self.first_dependent,
+            self.name,
+            short(&self.first_sha),
+            self.second_dependent,
+            short(&self.second_sha)
+        )
+    }
+}
+
+/// The bundle resolved earlier as `name`, if any
 ///
-/// * `git_source` - Git repository source specification
-/// * `dependency` - Optional dependency information
-/// * `skip_deps` - Whether to skip dependency resolution
-/// * `resolution_stack` - Current resolution stack for cycle detection
-/// * `resolved` - Map of already resolved bundles
+/// The first resolution wins; when it is at a different commit the conflict
+/// is collected and the caller decides whether it fails resolution.
+fn already_resolved(
+    ctx: &mut ResolveGitContext<'_>,
+    name: &str,
+    sha: &str,
+) -> Option<ResolvedBundle> {
+    let resolved_bundle = ctx.resolved.get(name)?;
+    let first_sha = resolved_bundle.resolved_sha.as_ref()?;
+    if first_sha != sha {
+        let dependent = |bundle: Option<&String>| {
+            bundle
+                .cloned()
+                .unwrap_or_else(|| WORKSPACE_DEPENDENT.to_string())
+        };
+        ctx.conflicts.push(ShaConflict {
+            name: name.to_string(),
+            first_dependent: dependent(ctx.dependents.get(name)),
+            first_sha: first_sha.clone(),
+            second_dependent: dependent(ctx.resolution_stack.last()),
+            second_sha: sha.to_string(),
+        });
+    }
+    Some(resolved_bundle.clone())
+}
+
+/// Resolve a git bundle from a `GitSource`
 ///
 /// # Errors
 ///
 /// Returns error if git operation fails, bundle not found, validation fails,
 /// or circular dependency detected.
-pub fn resolve_git(
-    git_source: &GitSource,
-    dependency: Option<&BundleDependency>,
-    _skip_deps: bool,
-    resolution_stack: &[String],
-    resolved: &std::collections::HashMap<String, ResolvedBundle>,
-) -> Result<ResolvedBundle> {
-    let (content_path, sha, resolved_ref) = cache::cache_bundle(git_source)?;
+pub fn resolve_git(ctx: &mut ResolveGitContext<'_>) -> Result<ResolvedBundle> {
+    let FetchedSource {
+        source: git_source,
+        content_path,
+        sha,
+        resolved_ref,
+    } = ctx.sources.fetch(ctx.git_source, ctx.fetch_options)?;
+    let dependency = ctx.dependency;
 
     if !content_path.is_dir() {
-        return Err(create_bundle_not_found_error(git_source));
+        return Err(create_bundle_not_found_error(&git_source));
     }
 
-    let name = determine_bundle_name(git_source, dependency, None);
+    let name = determine_bundle_name(&git_source, dependency, None);
 
-    crate::resolver::validation::check_cycle(&name, resolution_stack)?;
+    crate::resolver::validation::check_cycle(&name, ctx.resolution_stack)?;
 
-    if let Some(resolved_bundle) = resolved.get(&name) {
-        if resolved_bundle.resolved_sha.as_ref() == Some(&sha) {
-            return Ok(resolved_bundle.clone());
-        }
+    if let Some(resolved_bundle) = already_resolved(ctx, &name, &sha) {
+        return Ok(resolved_bundle);
     }
 
     let build_info = BundleBuildInfo {
@@ -92,7 +302,85 @@ pub fn resolve_git(
         dependency: dependency.cloned(),
     };
 
-    Ok(create_resolved_bundle(build_info, git_source))
+    Ok(create_resolved_bundle(build_info, &git_source))
+}
+
+/// Replace a `glob:` ref with the newest remote tag matching it
+///
+/// Returns `None` when the ref is not a glob.
+pub(crate) fn resolve_tag_glob(
+    git_source: &GitSource,
+    options: cache::FetchOptions,
+) -> Result<Option<GitSource>> {
+    let Some(pattern) = git_source
+        .git_ref
+        .as_deref()
+        .and_then(|r| r.strip_prefix(TAG_GLOB_PREFIX))
+    else {
+        return Ok(None);
+    };
+
+    if options.offline {
+        return Err(cache::lookup::offline_error(
+            &git_source.url,
+            git_source.git_ref.as_deref(),
+        ));
+    }
+    let tags = crate::git::ls_remote_tags(&git_source.url)?;
+    let tag =
+        latest_matching_tag(&tags, pattern).ok_or_else(|| AugentError::GitRefResolveFailed {
+            git_ref: format!("{TAG_GLOB_PREFIX}{pattern}"),
+            reason: format!("no tag in {} matches '{pattern}'", git_source.url),
+        })?;
+
+    Ok(Some(GitSource {
+        git_ref: Some(tag),
+        resolved_sha: None,
+        ..git_source.clone()
+    }))
+}
+
+/// Newest tag matching `pattern`, comparing digit runs numerically
+/// so `release-10` sorts after `release-9`
+fn latest_matching_tag(tags: &[String], pattern: &str) -> Option<String> {
+    tags.iter()
+        .filter(|tag| crate::workspace::path::matches_glob(pattern, tag))
+        .max_by(|a, b| natural_cmp(a, b))
+        .cloned()
+}
+
+fn natural_cmp(a: &str, b: &str) -> std::cmp::Ordering {
+    let (mut a, mut b) = (a, b);
+    loop {
+        let (Some(ca), Some(cb)) = (a.chars().next(), b.chars().next()) else {
+            return a.len().cmp(&b.len());
+        };
+        let ordering = if ca.is_ascii_digit() && cb.is_ascii_digit() {
+            let (num_a, rest_a) = split_digits(a);
+            let (num_b, rest_b) = split_digits(b);
+            a = rest_a;
+            b = rest_b;
+            compare_digit_runs(num_a, num_b)
+        } else {
+            a = &a[ca.len_utf8()..];
+            b = &b[cb.len_utf8()..];
+            ca.cmp(&cb)
+        };
+        if ordering.is_ne() {
+            return ordering;
+        }
+    }
+}
+
+fn split_digits(s: &str) -> (&str, &str) {
+    let end = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+    s.split_at(end)
+}
+
+fn compare_digit_runs(a: &str, b: &str) -> std::cmp::Ordering {
+    let a = a.trim_start_matches('0');
+    let b = b.trim_start_matches('0');
+    a.len().cmp(&b.len()).then_with(|| a.cmp(b))
 }
 
 /// Determine bundle name from git source
@@ -147,6 +435,61 @@ mod tests {
         assert_eq!(name, "@owner/repo");
     }
 
+    #[test]
+    fn test_latest_matching_tag_picks_newest_release() {
+        let tags: Vec<String> = ["release-2", "release-10", "release-9", "v99", "release-1.5"]
+            .iter()
+            .map(|t| (*t).to_string())
+            .collect();
+        assert_eq!(
+            latest_matching_tag(&tags, "release-*"),
+            Some("release-10".to_string())
+        );
+        assert_eq!(latest_matching_tag(&tags, "nightly-*"), None);
+    }
+
+    fn fetched(source: &GitSource, sha: &str) -> FetchedSource {
+        FetchedSource {
+            source: source.clone(),
+            content_path: std::path::PathBuf::from("/cache/shared"),
+            sha: sha.repeat(40),
+            resolved_ref: source.git_ref.clone(),
+        }
+    }
+
+    #[test]
+    fn test_source_cache_fetches_identical_source_once() {
+        let source = GitSource {
+            url: "https://github.com/owner/shared.git".to_string(),
+            path: None,
+            git_ref: Some("v1".to_string()),
+            resolved_sha: None,
+        };
+        let mut cache = SourceCache::default();
+        let mut fetch_count = 0;
+        let mut fetch = |s: &GitSource| {
+            fetch_count += 1;
+            Ok(fetched(s, "a"))
+        };
+        for _ in 0..2 {
+            let result = cache
+                .fetch_with(&source, &mut fetch)
+                .expect("Fetch should succeed");
+            assert_eq!(result.sha, "a".repeat(40));
+        }
+
+        let other_ref = GitSource {
+            git_ref: Some("v2".to_string()),
+            ..source
+        };
+        let result = cache
+            .fetch_with(&other_ref, &mut fetch)
+            .expect("Fetch should succeed");
+        assert_eq!(result.sha, "a".repeat(40));
+        assert_eq!(result.resolved_ref.as_deref(), Some("v2"));
+        assert_eq!(fetch_count, 2);
+    }
+
     #[test]
     fn test_derive_base_name_without_git() {
         let name = string_utils::parse_git_url_to_repo_base("https://github.com/owner/repo");
@@ -127,8 +127,12 @@ mod tests {
             .map(|dep| BundleDependency {
                 name: dep.to_string(),
                 git: None,
+                archive: None,
+                oci: None,
                 path: None,
                 git_ref: None,
+                version: None,
+                platform_refs: std::collections::BTreeMap::new(),
             })
             .collect();
 
@@ -146,7 +150,10 @@ mod tests {
                 license: None,
                 homepage: None,
                 bundles,
+                overrides: std::collections::BTreeMap::new(),
             }),
+            platform_sources: std::collections::HashMap::new(),
+            artifact_source: None,
         }
     }
 
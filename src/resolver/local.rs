@@ -118,12 +118,17 @@ pub struct ResolveLocalContext<'a> {
 pub fn resolve_local(ctx: &ResolveLocalContext) -> Result<ResolvedBundle> {
     let full_path = resolve_full_path(ctx.path, ctx.workspace_root)?;
 
-    crate::resolver::validation::validate_local_bundle_path(
-        &full_path,
-        ctx.path,
-        ctx.dependency.is_some(),
-        ctx.workspace_root,
-    )?;
+    // Extracted archives and OCI artifacts live in the cache rather than the workspace
+    if !crate::cache::archive::is_extracted_archive(&full_path)
+        && !crate::cache::oci::is_extracted_artifact(&full_path)
+    {
+        crate::resolver::validation::validate_local_bundle_path(
+            &full_path,
+            ctx.path,
+            ctx.dependency.is_some(),
+            ctx.workspace_root,
+        )?;
+    }
 
     if !full_path.is_dir() {
         return Err(AugentError::BundleNotFound {
@@ -147,6 +152,8 @@ pub fn resolve_local(ctx: &ResolveLocalContext) -> Result<ResolvedBundle> {
         resolved_ref: None,
         git_source: None,
         config,
+        platform_sources: std::collections::HashMap::new(),
+        artifact_source: None,
     };
 
     Ok(resolved)
@@ -18,6 +18,7 @@
 //! - **validation**: Cycle detection and path validation
 //! - **config**: Bundle and marketplace config loading
 //! - **topology**: Topological sorting and dependency graph building
+//! - **version**: Semver constraints resolved against git tags
 //!
 //! # Usage
 //!
@@ -47,6 +48,7 @@ pub mod sort;
 pub mod synthetic;
 pub mod topology;
 pub mod validation;
+pub mod version;
 
 // Re-export submodules
 pub use operation::ResolveOperation;
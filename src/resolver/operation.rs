@@ -6,8 +6,9 @@
 use std::path::{Path, PathBuf};
 
 use crate::config::BundleDependency;
-use crate::domain::{DiscoveredBundle, ResolvedBundle};
+use crate::domain::{ArtifactSource, DiscoveredBundle, ResolvedBundle};
 use crate::error::{AugentError, Result};
+use crate::resolver::git::{LockedPlatformRefs, ShaConflict, WORKSPACE_DEPENDENT};
 use crate::source::{BundleSource, GitSource};
 
 /// High-level resolve operation that orchestrates resolution
@@ -17,6 +18,16 @@ pub struct ResolveOperation {
     resolution_order: Vec<String>,
     resolution_stack: Vec<String>,
     current_context: PathBuf,
+    platform_refs: std::collections::BTreeMap<String, String>,
+    locked_platform_refs: LockedPlatformRefs,
+    locked_archives: crate::cache::archive::LockedArchives,
+    locked_tags: crate::resolver::version::LockedTags,
+    sources: crate::resolver::git::SourceCache,
+    fetch_options: crate::cache::FetchOptions,
+    dependents: std::collections::HashMap<String, String>,
+    conflicts: Vec<ShaConflict>,
+    allow_version_conflicts: bool,
+    overrides: std::collections::BTreeMap<String, BundleDependency>,
 }
 
 impl ResolveOperation {
@@ -28,14 +39,84 @@ impl ResolveOperation {
             resolution_order: Vec::new(),
             resolution_stack: Vec::new(),
             current_context: workspace_root_path,
+            platform_refs: std::collections::BTreeMap::new(),
+            locked_platform_refs: LockedPlatformRefs::new(),
+            locked_archives: crate::cache::archive::LockedArchives::new(),
+            locked_tags: crate::resolver::version::LockedTags::new(),
+            sources: crate::resolver::git::SourceCache::default(),
+            fetch_options: crate::cache::FetchOptions::default(),
+            dependents: std::collections::HashMap::new(),
+            conflicts: Vec::new(),
+            allow_version_conflicts: false,
+            overrides: std::collections::BTreeMap::new(),
         }
     }
 
+    /// How git sources not yet in this run's source cache are fetched
+    #[must_use]
+    pub fn with_fetch_options(mut self, options: crate::cache::FetchOptions) -> Self {
+        self.fetch_options = options;
+        self
+    }
+
+    /// How git sources not yet in this run's source cache are fetched
+    pub fn fetch_options(&self) -> crate::cache::FetchOptions {
+        self.fetch_options
+    }
+
+    /// Fetch per-platform ref overrides at the commits in the lockfile
+    /// instead of resolving the refs again
+    #[must_use]
+    pub fn with_locked_platform_refs(mut self, locked: LockedPlatformRefs) -> Self {
+        self.locked_platform_refs = locked;
+        self
+    }
+
+    /// Reuse extracted archives whose content matches the lockfile instead
+    /// of downloading them again
+    #[must_use]
+    pub fn with_locked_archives(mut self, locked: crate::cache::archive::LockedArchives) -> Self {
+        self.locked_archives = locked;
+        self
+    }
+
+    /// Tags in the lockfile that `version` constraints may reuse when the
+    /// remote is not listed (`--offline`, `--prefer-cache`)
+    #[must_use]
+    pub fn with_locked_tags(mut self, locked: crate::resolver::version::LockedTags) -> Self {
+        self.locked_tags = locked;
+        self
+    }
+
+    /// Force the source of transitive dependencies by name (the workspace's `overrides:`)
+    #[must_use]
+    pub fn with_overrides(
+        mut self,
+        overrides: std::collections::BTreeMap<String, BundleDependency>,
+    ) -> Self {
+        self.overrides = overrides;
+        self
+    }
+
+    /// Keep the first resolution of a bundle required at different commits
+    /// instead of failing; the conflicts are still collected
+    #[must_use]
+    pub fn with_allow_version_conflicts(mut self, allow: bool) -> Self {
+        self.allow_version_conflicts = allow;
+        self
+    }
+
+    /// Bundles required at different commits during resolution so far
+    pub fn conflicts(&self) -> &[ShaConflict] {
+        &self.conflicts
+    }
+
     pub fn resolve(&mut self, source: &str, skip_deps: bool) -> Result<Vec<ResolvedBundle>> {
         self.resolution_order.clear();
 
-        let bundle_source = BundleSource::parse(source)?;
+        let bundle_source = BundleSource::parse_with(source, self.fetch_options)?;
         let bundle = self.resolve_source(&bundle_source, None, skip_deps)?;
+        self.check_conflicts()?;
 
         if skip_deps {
             Ok(vec![bundle])
@@ -44,20 +125,65 @@ impl ResolveOperation {
         }
     }
 
+    /// Resolve a top-level source whose bundle installs some platforms from
+    /// other refs (platform id -> ref)
+    pub fn resolve_with_platform_refs(
+        &mut self,
+        source: &str,
+        platform_refs: &std::collections::BTreeMap<String, String>,
+    ) -> Result<Vec<ResolvedBundle>> {
+        self.platform_refs.clone_from(platform_refs);
+        let resolved = self.resolve(source, false);
+        self.platform_refs.clear();
+        resolved
+    }
+
     pub fn resolve_multiple(&mut self, sources: &[String]) -> Result<Vec<ResolvedBundle>> {
         self.resolution_order.clear();
         self.resolved.clear();
+        self.dependents.clear();
+        self.conflicts.clear();
 
         for source in sources {
-            let bundle_source = BundleSource::parse(source)?;
+            let bundle_source = BundleSource::parse_with(source, self.fetch_options)?;
             let _bundle = self.resolve_source(&bundle_source, None, false)?;
         }
+        self.check_conflicts()?;
 
         self.topological_sort()
     }
 
+    /// Fetch the git sources among `sources` into the cache in parallel
+    ///
+    /// Sources that do not parse or are not git sources are skipped; they are
+    /// reported when resolved. Dependency resolution and the topological sort
+    /// that follow stay sequential, so the resolved order is unaffected.
+    pub fn prefetch(&mut self, sources: &[String], jobs: usize) {
+        let git_sources: Vec<GitSource> = sources
+            .iter()
+            .filter_map(
+                |source| match BundleSource::parse_with(source, self.fetch_options) {
+                    Ok(BundleSource::Git(git_source)) => Some(git_source),
+                    _ => None,
+                },
+            )
+            .collect();
+        if jobs > 1 && git_sources.len() > 1 {
+            crate::resolver::git::prefetch(
+                &mut self.sources,
+                &git_sources,
+                self.fetch_options,
+                jobs,
+            );
+        }
+    }
+
     pub fn discover_bundles(&mut self, source: &str) -> Result<Vec<DiscoveredBundle>> {
-        crate::resolver::discovery::discover_bundles(source, &self.workspace_root)
+        crate::resolver::discovery::discover_bundles(
+            source,
+            &self.workspace_root,
+            self.fetch_options,
+        )
     }
 
     pub fn resolve_source(
@@ -67,37 +193,125 @@ impl ResolveOperation {
         skip_deps: bool,
     ) -> Result<ResolvedBundle> {
         match source {
-            BundleSource::Dir { path } => {
-                let ctx = crate::resolver::local::ResolveLocalContext {
-                    path,
-                    workspace_root: &self.workspace_root,
-                    dependency,
-                    resolution_stack: &self.resolution_stack,
-                    skip_deps,
-                    resolved: &self.resolved,
-                };
-                let resolved = crate::resolver::local::resolve_local(&ctx)?;
-
-                self.track_resolution(&resolved, dependency.is_none());
-                Ok(resolved)
-            }
+            BundleSource::Dir { path } => self.resolve_dir(path, dependency, skip_deps, None),
             BundleSource::Git(git_source) => {
-                let resolved = crate::resolver::git::resolve_git(
-                    git_source,
-                    dependency,
-                    skip_deps,
-                    &self.resolution_stack,
-                    &self.resolved,
+                let mut resolved = crate::resolver::git::resolve_git(
+                    &mut crate::resolver::git::ResolveGitContext {
+                        git_source,
+                        dependency,
+                        resolution_stack: &self.resolution_stack,
+                        resolved: &self.resolved,
+                        sources: &mut self.sources,
+                        fetch_options: self.fetch_options,
+                        dependents: &self.dependents,
+                        conflicts: &mut self.conflicts,
+                    },
                 )?;
+                self.attach_platform_sources(&mut resolved, git_source, dependency)?;
 
                 self.track_resolution(&resolved, dependency.is_none());
                 Ok(resolved)
             }
+            BundleSource::Archive { url, path } => {
+                let root = self.archive_root(url, path.as_deref())?;
+                let dir = path.as_ref().map_or_else(|| root.clone(), |p| root.join(p));
+                let artifact = ArtifactSource::Archive {
+                    url: url.clone(),
+                    path: path.clone(),
+                };
+                self.resolve_dir(&dir, dependency, skip_deps, Some(artifact))
+            }
+            BundleSource::Oci { reference, path } => {
+                let parsed = crate::cache::oci::OciReference::parse(reference)?;
+                let (root, digest) = crate::cache::oci::cache_oci(&parsed, self.fetch_options)?;
+                let dir = path.as_ref().map_or_else(|| root.clone(), |p| root.join(p));
+                let artifact = ArtifactSource::Oci {
+                    reference: reference.clone(),
+                    digest,
+                    path: path.clone(),
+                };
+                self.resolve_dir(&dir, dependency, skip_deps, Some(artifact))
+            }
+        }
+    }
+
+    /// Extracted root of an archive: the extraction matching the lockfile
+    /// when there is one, else a fresh download
+    fn archive_root(&self, url: &str, path: Option<&str>) -> Result<PathBuf> {
+        let locked_root = self
+            .locked_archives
+            .get(&(url.to_string(), path.map(str::to_string)))
+            .and_then(|hash| crate::cache::archive::locked_archive_root(url, path, hash));
+        match locked_root {
+            Some(root) => Ok(root),
+            None => crate::cache::archive::cache_archive(url, self.fetch_options),
+        }
+    }
+
+    /// Resolve a directory bundle, local or extracted into the cache
+    fn resolve_dir(
+        &mut self,
+        dir: &Path,
+        dependency: Option<&BundleDependency>,
+        skip_deps: bool,
+        artifact_source: Option<ArtifactSource>,
+    ) -> Result<ResolvedBundle> {
+        let ctx = crate::resolver::local::ResolveLocalContext {
+            path: dir,
+            workspace_root: &self.workspace_root,
+            dependency,
+            resolution_stack: &self.resolution_stack,
+            skip_deps,
+            resolved: &self.resolved,
+        };
+        let mut resolved = crate::resolver::local::resolve_local(&ctx)?;
+        resolved.artifact_source = artifact_source;
+
+        self.track_resolution(&resolved, dependency.is_none());
+        Ok(resolved)
+    }
+
+    /// Fetch the content of a git bundle's per-platform ref overrides
+    fn attach_platform_sources(
+        &self,
+        resolved: &mut ResolvedBundle,
+        git_source: &GitSource,
+        dependency: Option<&BundleDependency>,
+    ) -> Result<()> {
+        if !resolved.platform_sources.is_empty() {
+            return Ok(());
+        }
+        let platform_refs = dependency.map_or(&self.platform_refs, |dep| &dep.platform_refs);
+        resolved.platform_sources = crate::resolver::git::resolve_platform_sources(
+            git_source,
+            self.fetch_options,
+            platform_refs,
+            self.locked_platform_refs.get(&resolved.name),
+        )?;
+        Ok(())
+    }
+
+    /// Fail on the first collected conflict unless conflicts are allowed
+    fn check_conflicts(&self) -> Result<()> {
+        match self.conflicts.first() {
+            Some(conflict) if !self.allow_version_conflicts => {
+                Err(AugentError::DependencyVersionConflict {
+                    name: conflict.name.clone(),
+                    details: conflict.to_string(),
+                })
+            }
+            _ => Ok(()),
         }
     }
 
     fn track_resolution(&mut self, bundle: &ResolvedBundle, is_top_level: bool) {
         let name = bundle.name.clone();
+        let dependent = self
+            .resolution_stack
+            .last()
+            .cloned()
+            .unwrap_or_else(|| WORKSPACE_DEPENDENT.to_string());
+        self.dependents.entry(name.clone()).or_insert(dependent);
 
         self.resolution_stack.push(name.clone());
 
@@ -134,14 +348,34 @@ impl ResolveOperation {
         dep: &BundleDependency,
         context_path: &Path,
     ) -> Result<ResolvedBundle> {
+        // An override replaces the declared source; its paths are relative
+        // to the workspace, where it is declared.
+        let (dep, context_path) = match self.overrides.get(&dep.name) {
+            Some(overridden) => (overridden.clone(), self.workspace_root.clone()),
+            None => (dep.clone(), context_path.to_path_buf()),
+        };
+
         let source = if let Some(ref git_url) = dep.git {
             let git_source = GitSource {
                 url: git_url.clone(),
                 path: dep.path.clone(),
-                git_ref: dep.git_ref.clone(),
+                git_ref: match &dep.version {
+                    Some(version) => Some(crate::resolver::version::resolve_version_tag(
+                        git_url,
+                        version,
+                        self.locked_tags.get(&dep.name).map(String::as_str),
+                        self.fetch_options,
+                    )?),
+                    None => dep.git_ref.clone(),
+                },
                 resolved_sha: None,
             };
             BundleSource::Git(git_source)
+        } else if let Some(ref url) = dep.archive {
+            BundleSource::Archive {
+                url: url.clone(),
+                path: dep.path.clone(),
+            }
         } else if let Some(ref path_val) = dep.path {
             BundleSource::Dir {
                 path: PathBuf::from(path_val),
@@ -149,16 +383,16 @@ impl ResolveOperation {
         } else {
             return Err(AugentError::BundleValidationFailed {
                 message: format!(
-                    "Dependency '{}' has neither 'git' nor 'path' specified",
+                    "Dependency '{}' has neither 'git', 'archive' nor 'path' specified",
                     dep.name
                 ),
             });
         };
 
         let previous_context = self.current_context.clone();
-        self.current_context = context_path.to_path_buf();
+        self.current_context = context_path;
 
-        let result = self.resolve_source(&source, Some(dep), false);
+        let result = self.resolve_source(&source, Some(&dep), false);
 
         self.current_context = previous_context;
         result
@@ -200,4 +434,255 @@ mod tests {
         assert_eq!(bundles.len(), 1);
         assert_eq!(bundles[0].name, "my-bundle");
     }
+
+    /// Repository with one commit adding a command; returns its URL with the branch as ref
+    fn git_bundle_repo(dir: &Path) -> (String, String) {
+        let repo = git2::Repository::init(dir).expect("Failed to init repository");
+        std::fs::create_dir_all(dir.join("commands")).expect("Failed to create commands dir");
+        std::fs::write(dir.join("commands/review.md"), "Review.\n")
+            .expect("Failed to write command");
+        let mut index = repo.index().expect("Failed to open index");
+        index
+            .add_path(Path::new("commands/review.md"))
+            .expect("Failed to stage file");
+        index.write().expect("Failed to write index");
+        let tree = repo
+            .find_tree(index.write_tree().expect("Failed to write tree"))
+            .expect("Failed to find tree");
+        let signature =
+            git2::Signature::now("Augent", "augent@example.com").expect("Failed to sign");
+        let sha = repo
+            .commit(Some("HEAD"), &signature, &signature, "init", &tree, &[])
+            .expect("Failed to commit");
+        let branch = repo
+            .head()
+            .expect("Repository has no HEAD")
+            .shorthand()
+            .expect("HEAD has no name")
+            .to_string();
+        (
+            format!("file://{}#{branch}", dir.display()),
+            sha.to_string(),
+        )
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_prefetch_keeps_sequential_resolution_order() {
+        let temp = tempfile::TempDir::new_in(crate::temp::temp_dir_base())
+            .expect("Failed to create temp directory");
+        let original = std::env::var("AUGENT_CACHE_DIR").ok();
+        // SAFETY: std::env::set_var is safe in test context.
+        unsafe {
+            std::env::set_var("AUGENT_CACHE_DIR", temp.path().join("cache"));
+        }
+        crate::cache::index::invalidate_index_cache();
+
+        let (sources, shas): (Vec<String>, Vec<String>) = ["charlie", "alpha", "bravo"]
+            .iter()
+            .map(|name| git_bundle_repo(&temp.path().join(name)))
+            .unzip();
+
+        let mut prefetching = ResolveOperation::new(temp.path());
+        prefetching.prefetch(&sources, 3);
+        // Read before resolving again, which would re-add a lost entry
+        let indexed: Vec<(String, String)> = crate::cache::index::read_index()
+            .expect("Failed to read cache index")
+            .into_iter()
+            .map(|entry| (entry.url, entry.sha))
+            .collect();
+        let parallel = prefetching
+            .resolve_multiple(&sources)
+            .expect("Resolution after prefetch should succeed");
+        let sequential = ResolveOperation::new(temp.path())
+            .resolve_multiple(&sources)
+            .expect("Sequential resolution should succeed");
+
+        unsafe {
+            match original {
+                Some(o) => std::env::set_var("AUGENT_CACHE_DIR", o),
+                None => std::env::remove_var("AUGENT_CACHE_DIR"),
+            }
+        }
+        crate::cache::index::invalidate_index_cache();
+
+        let names = |bundles: &[ResolvedBundle]| -> Vec<String> {
+            bundles.iter().map(|b| b.name.clone()).collect()
+        };
+        assert_eq!(parallel.len(), 3);
+        assert_eq!(names(&parallel), names(&sequential));
+        let resolved_shas: Vec<String> = parallel
+            .iter()
+            .map(|b| b.resolved_sha.clone().expect("Git bundle has a SHA"))
+            .collect();
+        assert_eq!(resolved_shas, shas);
+        assert!(
+            parallel
+                .iter()
+                .all(|b| b.source_path.join("commands/review.md").is_file())
+        );
+        for (source, sha) in sources.iter().zip(shas) {
+            let url = source.split('#').next().unwrap_or(source).to_string();
+            assert!(indexed.contains(&(url, sha)), "{source} missing from index");
+        }
+    }
+
+    /// Repository with a command changed between tags `v1` and `v2`; returns its URL and both SHAs
+    fn tagged_repo(dir: &Path) -> (String, String, String) {
+        let repo = git2::Repository::init(dir).expect("Failed to init repository");
+        let signature =
+            git2::Signature::now("Augent", "augent@example.com").expect("Failed to sign");
+        std::fs::create_dir_all(dir.join("commands")).expect("Failed to create commands dir");
+        let mut shas = Vec::new();
+        for tag in ["v1", "v2"] {
+            std::fs::write(dir.join("commands/review.md"), format!("Review {tag}.\n"))
+                .expect("Failed to write command");
+            let mut index = repo.index().expect("Failed to open index");
+            index
+                .add_path(Path::new("commands/review.md"))
+                .expect("Failed to stage file");
+            index.write().expect("Failed to write index");
+            let tree = repo
+                .find_tree(index.write_tree().expect("Failed to write tree"))
+                .expect("Failed to find tree");
+            let parent = repo.head().ok().and_then(|head| head.peel_to_commit().ok());
+            let parents: Vec<&git2::Commit> = parent.iter().collect();
+            let sha = repo
+                .commit(Some("HEAD"), &signature, &signature, tag, &tree, &parents)
+                .expect("Failed to commit");
+            let commit = repo.find_object(sha, None).expect("Failed to find commit");
+            repo.tag_lightweight(tag, &commit, false)
+                .expect("Failed to tag commit");
+            shas.push(sha.to_string());
+        }
+        (
+            format!("file://{}", dir.display()),
+            shas[0].clone(),
+            shas[1].clone(),
+        )
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_resolve_diamond_with_conflicting_refs() {
+        let temp = tempfile::TempDir::new_in(crate::temp::temp_dir_base())
+            .expect("Failed to create temp directory");
+        let original = std::env::var("AUGENT_CACHE_DIR").ok();
+        // SAFETY: std::env::set_var is safe in test context.
+        unsafe {
+            std::env::set_var("AUGENT_CACHE_DIR", temp.path().join("cache"));
+        }
+        crate::cache::index::invalidate_index_cache();
+
+        // a and b both depend on shared, at tags pointing to different commits
+        let (url, sha_v1, sha_v2) = tagged_repo(&temp.path().join("shared"));
+        for (name, tag) in [("a", "v1"), ("b", "v2")] {
+            let dir = temp.path().join(name);
+            std::fs::create_dir_all(&dir).expect("Failed to create bundle directory");
+            std::fs::write(
+                dir.join("augent.yaml"),
+                format!(
+                    "name: {name}\nbundles:\n  - name: shared\n    git: {url}\n    ref: {tag}\n"
+                ),
+            )
+            .expect("Failed to write augent.yaml");
+        }
+        let sources = ["./a".to_string(), "./b".to_string()];
+
+        let strict = ResolveOperation::new(temp.path()).resolve_multiple(&sources);
+        let mut lenient = ResolveOperation::new(temp.path()).with_allow_version_conflicts(true);
+        let allowed = lenient.resolve_multiple(&sources);
+
+        unsafe {
+            match original {
+                Some(o) => std::env::set_var("AUGENT_CACHE_DIR", o),
+                None => std::env::remove_var("AUGENT_CACHE_DIR"),
+            }
+        }
+        crate::cache::index::invalidate_index_cache();
+
+        match strict {
+            Err(AugentError::DependencyVersionConflict { name, details }) => {
+                assert_eq!(name, "shared");
+                assert!(details.contains(&sha_v1[..7]), "{details}");
+                assert!(details.contains(&sha_v2[..7]), "{details}");
+            }
+            other => panic!("Expected a version conflict, got {other:?}"),
+        }
+
+        let bundles = allowed.expect("Resolution with allowed conflicts should succeed");
+        let shared = bundles
+            .iter()
+            .find(|b| b.name == "shared")
+            .expect("shared is resolved");
+        assert_eq!(shared.resolved_sha.as_deref(), Some(sha_v1.as_str()));
+        assert_eq!(
+            lenient.conflicts(),
+            [ShaConflict {
+                name: "shared".to_string(),
+                first_dependent: "a".to_string(),
+                first_sha: sha_v1,
+                second_dependent: "b".to_string(),
+                second_sha: sha_v2,
+            }]
+        );
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_override_redirects_transitive_dependency() {
+        let temp = tempfile::TempDir::new_in(crate::temp::temp_dir_base())
+            .expect("Failed to create temp directory");
+        let original = std::env::var("AUGENT_CACHE_DIR").ok();
+        // SAFETY: std::env::set_var is safe in test context.
+        unsafe {
+            std::env::set_var("AUGENT_CACHE_DIR", temp.path().join("cache"));
+        }
+        crate::cache::index::invalidate_index_cache();
+
+        // app declares shared at v1; the workspace overrides it to v2
+        let (url, _, sha_v2) = tagged_repo(&temp.path().join("shared"));
+        let app = temp.path().join("app");
+        std::fs::create_dir_all(&app).expect("Failed to create bundle directory");
+        std::fs::write(
+            app.join("augent.yaml"),
+            format!("name: app\nbundles:\n  - name: shared\n    git: {url}\n    ref: v1\n"),
+        )
+        .expect("Failed to write augent.yaml");
+        let workspace_config = crate::config::BundleConfig::from_yaml(&format!(
+            "name: workspace\nbundles: []\noverrides:\n  shared:\n    git: {url}\n    ref: v2\n"
+        ))
+        .expect("Failed to parse workspace config");
+
+        let resolved = ResolveOperation::new(temp.path())
+            .with_overrides(workspace_config.overrides)
+            .resolve_multiple(&["./app".to_string()]);
+        let locked = resolved.as_ref().ok().and_then(|bundles| {
+            let shared = bundles.iter().find(|b| b.name == "shared")?;
+            crate::operations::install::lockfile::create_locked_bundle_from_resolved(
+                shared,
+                Some(temp.path()),
+            )
+            .ok()
+        });
+
+        unsafe {
+            match original {
+                Some(o) => std::env::set_var("AUGENT_CACHE_DIR", o),
+                None => std::env::remove_var("AUGENT_CACHE_DIR"),
+            }
+        }
+        crate::cache::index::invalidate_index_cache();
+
+        let bundles = resolved.expect("Resolution should succeed");
+        let names: Vec<&str> = bundles.iter().map(|b| b.name.as_str()).collect();
+        assert_eq!(names, ["shared", "app"]);
+        match locked.expect("shared is locked").source {
+            crate::config::LockedSource::Git { git_ref, sha, .. } => {
+                assert_eq!(git_ref.as_deref(), Some("v2"));
+                assert_eq!(sha, sha_v2);
+            }
+            other => panic!("Expected a git source, got {other:?}"),
+        }
+    }
 }
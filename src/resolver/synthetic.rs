@@ -163,6 +163,7 @@ fn generate_synthetic_config(
         license: None,
         homepage: None,
         bundles: vec![],
+        overrides: std::collections::BTreeMap::new(),
     };
 
     let yaml_content = config
@@ -0,0 +1,130 @@
+//! Semver constraints on git tags
+//!
+//! A dependency's `version` in augent.yaml (e.g. `^1.2`, `~1.2.3`, `=2.0.0`)
+//! is resolved to the highest remote tag that satisfies it. Tags parse as
+//! versions with or without a leading `v`; other tags are ignored. The tag
+//! found is used as the dependency's ref, so the lockfile records the
+//! concrete tag and SHA like for any other ref.
+//!
+//! With `--offline` or `--prefer-cache` the remote is not listed; the tag is
+//! picked among the refs already cached for the repository and the tag in
+//! the lockfile, as long as one of them still satisfies the constraint.
+
+use std::collections::HashMap;
+
+use semver::{Version, VersionReq};
+
+use crate::cache;
+use crate::error::{AugentError, Result};
+
+/// Tag of each git bundle in the lockfile, by bundle name
+pub type LockedTags = HashMap<String, String>;
+
+/// Highest tag satisfying `req`, keeping the tag's original spelling
+pub fn best_matching_tag(tags: &[String], req: &VersionReq) -> Option<String> {
+    tags.iter()
+        .filter_map(|tag| Some((parse_tag(tag)?, tag)))
+        .filter(|(version, _)| req.matches(version))
+        .max_by(|(a, _), (b, _)| a.cmp(b))
+        .map(|(_, tag)| tag.clone())
+}
+
+/// Resolve `constraint` to the highest matching tag of the repository at `url`
+///
+/// `locked` is the dependency's tag in the lockfile, reused offline (and
+/// with `prefer_cache`) when it still satisfies the constraint.
+pub fn resolve_version_tag(
+    url: &str,
+    constraint: &str,
+    locked: Option<&str>,
+    options: cache::FetchOptions,
+) -> Result<String> {
+    let req = VersionReq::parse(constraint).map_err(|e| AugentError::GitRefResolveFailed {
+        git_ref: constraint.to_string(),
+        reason: format!("invalid version constraint: {e}"),
+    })?;
+    if options.offline || options.prefer_cache {
+        let mut known = cache::lookup::cached_refs(url);
+        known.extend(locked.map(ToString::to_string));
+        if let Some(tag) = best_matching_tag(&known, &req) {
+            return Ok(tag);
+        }
+        if options.offline {
+            return Err(cache::lookup::offline_error(url, Some(constraint)));
+        }
+    }
+    let tags = crate::git::ls_remote_tags(url)?;
+    best_matching_tag(&tags, &req).ok_or_else(|| AugentError::GitRefResolveFailed {
+        git_ref: constraint.to_string(),
+        reason: format!("no tag in {url} satisfies '{constraint}'"),
+    })
+}
+
+fn parse_tag(tag: &str) -> Option<Version> {
+    Version::parse(tag.strip_prefix('v').unwrap_or(tag)).ok()
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::*;
+
+    fn tags() -> Vec<String> {
+        [
+            "v1.1.0",
+            "v1.2.0",
+            "v1.2.5",
+            "v1.3.0",
+            "2.0.0",
+            "v2.1.0-beta.1",
+            "latest",
+            "release-3",
+        ]
+        .iter()
+        .map(ToString::to_string)
+        .collect()
+    }
+
+    fn best(constraint: &str) -> Option<String> {
+        let req = VersionReq::parse(constraint).expect("Failed to parse constraint");
+        best_matching_tag(&tags(), &req)
+    }
+
+    #[test]
+    fn test_caret_constraint() {
+        assert_eq!(best("^1.2").as_deref(), Some("v1.3.0"));
+        assert_eq!(best("^2").as_deref(), Some("2.0.0"));
+    }
+
+    #[test]
+    fn test_tilde_constraint() {
+        assert_eq!(best("~1.2").as_deref(), Some("v1.2.5"));
+        assert_eq!(best("~1.1.0").as_deref(), Some("v1.1.0"));
+    }
+
+    #[test]
+    fn test_exact_constraint() {
+        assert_eq!(best("=1.2.0").as_deref(), Some("v1.2.0"));
+        assert_eq!(best("=1.4.0"), None);
+    }
+
+    #[test]
+    fn test_non_semver_tags_ignored() {
+        assert_eq!(best(">=3").as_deref(), None);
+        assert_eq!(best("*").as_deref(), Some("2.0.0"));
+    }
+
+    #[test]
+    fn test_offline_reuses_locked_tag_that_satisfies_constraint() {
+        let offline = cache::FetchOptions {
+            offline: true,
+            ..Default::default()
+        };
+        let url = "https://invalid.example/uncached.git";
+        let tag = resolve_version_tag(url, "^1.2", Some("v1.3.0"), offline)
+            .expect("locked tag satisfies the constraint");
+        assert_eq!(tag, "v1.3.0");
+        assert!(resolve_version_tag(url, "^1.2", Some("v2.0.0"), offline).is_err());
+        assert!(resolve_version_tag(url, "^1.2", None, offline).is_err());
+    }
+}
@@ -6,6 +6,8 @@ use serde::{Deserialize, Serialize};
 use std::path::{Path, PathBuf};
 
 use super::git_source::GitSource;
+use crate::cache::archive::ArchiveFormat;
+use crate::cache::oci::OciReference;
 use crate::error::Result;
 
 /// File URL parser - handles file:// URLs with fragments
@@ -34,6 +36,58 @@ impl FileUrlParser {
     }
 }
 
+/// Archive URL parser - handles `tarball:` sources and `.tar.gz`/`.zip` URLs
+struct ArchiveUrlParser;
+
+impl ArchiveUrlParser {
+    /// Prefix marking a source as an archive whatever its URL looks like
+    const PREFIX: &'static str = "tarball:";
+
+    /// URL after the prefix, or `input` itself when it is an archive URL
+    fn archive_url(input: &str) -> Option<&str> {
+        if let Some(url) = input.strip_prefix(Self::PREFIX) {
+            return Some(url);
+        }
+        let is_url = ["https://", "http://", "file://"]
+            .iter()
+            .any(|scheme| input.starts_with(scheme));
+        (is_url && ArchiveFormat::from_url(input).is_some()).then_some(input)
+    }
+
+    fn try_parse(input: &str) -> Option<BundleSource> {
+        let url = Self::archive_url(input)?;
+        // The fragment selects a subdirectory of the archive
+        let (url, path) = match url.split_once('#') {
+            Some((url, path)) => (url, Some(path.trim_matches('/').to_string())),
+            None => (url, None),
+        };
+        Some(BundleSource::Archive {
+            url: url.to_string(),
+            path: path.filter(|p| !p.is_empty()),
+        })
+    }
+}
+
+/// OCI reference parser - handles `oci://registry/repository[:tag][@digest]`
+struct OciUrlParser;
+
+impl OciUrlParser {
+    fn try_parse(input: &str) -> Option<Result<BundleSource>> {
+        let reference = input.strip_prefix("oci://")?;
+        // The fragment selects a subdirectory of the artifact
+        let (reference, path) = match reference.split_once('#') {
+            Some((reference, path)) => (reference, Some(path.trim_matches('/').to_string())),
+            None => (reference, None),
+        };
+        Some(
+            OciReference::parse(reference).map(|reference| BundleSource::Oci {
+                reference: reference.to_string(),
+                path: path.filter(|p| !p.is_empty()),
+            }),
+        )
+    }
+}
+
 /// Local path parser - handles relative and absolute paths
 struct LocalPathParser;
 
@@ -119,6 +173,20 @@ pub enum BundleSource {
     },
     /// Git repository source
     Git(GitSource),
+    /// Downloaded `.tar.gz` or `.zip` archive, installed like a directory
+    Archive {
+        /// Archive URL (`https://`, `http://` or `file://`)
+        url: String,
+        /// Subdirectory of the extracted archive holding the bundle
+        path: Option<String>,
+    },
+    /// OCI artifact pulled from a registry, installed like a directory
+    Oci {
+        /// Reference without the scheme (`registry/repository[:tag][@digest]`)
+        reference: String,
+        /// Subdirectory of the extracted artifact holding the bundle
+        path: Option<String>,
+    },
 }
 
 impl BundleSource {
@@ -133,8 +201,16 @@ impl BundleSource {
     /// - `user/repo` - GitHub repository (short form)
     /// - `https://github.com/user/repo.git` - Git HTTPS URL
     /// - `https://github.com/user/repo/tree/ref/path` - GitHub web UI URL
+    /// - `https://github.com/user/repo/blob/ref/path/file.md` - GitHub web UI file URL
     /// - `git@github.com:user/repo.git` - Git SSH URL
+    /// - `gitlab:group/subgroup/repo` - GitLab repository (subgroups allowed)
+    /// - `bitbucket:user/repo` - Bitbucket repository
+    /// - `gist:<id>` or `https://gist.github.com/<user>/<id>` - GitHub Gist
     /// - `file://` URLs with fragments (`#ref` or `#subdir`) are treated as git sources
+    /// - `https://.../bundle.tar.gz`, `.tgz` or `.zip` URLs, or any URL after a
+    ///   `tarball:` prefix - Archive, with `#subdir` for a path inside it
+    /// - `oci://registry/repository:tag` or `oci://registry/repository@sha256:...` -
+    ///   OCI artifact, with `#subdir` for a path inside it
     /// - Any of the above with `#subdir` for path
     /// - Any of the above with `#ref` for git ref
     ///
@@ -160,6 +236,11 @@ impl BundleSource {
     /// assert_eq!(source.display_url(), "https://github.com/author/repo.git:plugins/bundle");
     /// ```
     pub fn parse(input: &str) -> Result<Self> {
+        Self::parse_with(input, crate::cache::FetchOptions::default())
+    }
+
+    /// Parse a bundle source, resolving git shorthands as `options` allow
+    pub fn parse_with(input: &str, options: crate::cache::FetchOptions) -> Result<Self> {
         let input = input.trim();
 
         if input.is_empty() {
@@ -169,6 +250,14 @@ impl BundleSource {
             });
         }
 
+        if let Some(source) = OciUrlParser::try_parse(input) {
+            return source;
+        }
+
+        if let Some(source) = ArchiveUrlParser::try_parse(input) {
+            return Ok(source);
+        }
+
         if let Some(source) = FileUrlParser::try_parse(input) {
             return Ok(source);
         }
@@ -177,7 +266,7 @@ impl BundleSource {
             return Ok(source);
         }
 
-        let git_source = GitSource::parse(input)?;
+        let git_source = GitSource::parse_with(input, options)?;
         Ok(BundleSource::Git(git_source))
     }
 }
@@ -246,4 +335,224 @@ mod tests {
         "file:///path/to/bundle",
         BundleSource::Dir { .. }
     );
+    test_parse_ok!(
+        test_parse_tar_gz_url,
+        "https://example.com/releases/tools-1.0.tar.gz",
+        BundleSource::Archive { path: None, .. }
+    );
+    test_parse_ok!(
+        test_parse_zip_file_url,
+        "file:///tmp/tools.zip",
+        BundleSource::Archive { path: None, .. }
+    );
+
+    test_parse_err!(test_parse_oci_without_repository, "oci://ghcr.io");
+
+    #[test]
+    fn test_parse_oci_reference() {
+        let source = BundleSource::parse("oci://localhost:5000/acme/tools:1.0#bundles/review")
+            .expect("Failed to parse");
+        assert_eq!(
+            source,
+            BundleSource::Oci {
+                reference: "localhost:5000/acme/tools:1.0".to_string(),
+                path: Some("bundles/review".to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_tarball_prefix_with_subpath() {
+        let source =
+            BundleSource::parse("tarball:https://example.com/download?id=7#plugins/tools/")
+                .expect("Failed to parse");
+        assert_eq!(
+            source,
+            BundleSource::Archive {
+                url: "https://example.com/download?id=7".to_string(),
+                path: Some("plugins/tools".to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_gist_prefix() {
+        let source = BundleSource::parse("gist:aa5a315d61ae9438b18d").expect("Failed to parse");
+        let BundleSource::Git(git) = source else {
+            panic!("Expected git source");
+        };
+        assert_eq!(git.url, "https://gist.github.com/aa5a315d61ae9438b18d.git");
+        assert_eq!(git.path, None);
+        assert_eq!(git.git_ref, None);
+    }
+
+    #[test]
+    fn test_parse_gist_web_url() {
+        let source = BundleSource::parse("https://gist.github.com/octocat/aa5a315d61ae9438b18d")
+            .expect("Failed to parse");
+        let BundleSource::Git(git) = source else {
+            panic!("Expected git source");
+        };
+        assert_eq!(git.url, "https://gist.github.com/aa5a315d61ae9438b18d.git");
+        assert_eq!(git.path, None);
+    }
+
+    #[test]
+    fn test_parse_gist_with_ref() {
+        let source =
+            BundleSource::parse("gist:aa5a315d61ae9438b18d#abc123").expect("Failed to parse");
+        let BundleSource::Git(git) = source else {
+            panic!("Expected git source");
+        };
+        assert_eq!(git.url, "https://gist.github.com/aa5a315d61ae9438b18d.git");
+        assert_eq!(git.git_ref, Some("abc123".to_string()));
+    }
+
+    test_parse_err!(test_parse_gist_invalid_id, "gist:not/a-valid-id");
+
+    #[test]
+    fn test_parse_gitlab_short() {
+        let source = BundleSource::parse("gitlab:group/repo").expect("Failed to parse");
+        let BundleSource::Git(git) = source else {
+            panic!("Expected git source");
+        };
+        assert_eq!(git.url, "https://gitlab.com/group/repo.git");
+        assert_eq!(git.path, None);
+        assert_eq!(git.git_ref, None);
+    }
+
+    #[test]
+    fn test_parse_gitlab_nested_subgroups() {
+        let source = BundleSource::parse("gitlab:group/sub/team/repo").expect("Failed to parse");
+        let BundleSource::Git(git) = source else {
+            panic!("Expected git source");
+        };
+        assert_eq!(git.url, "https://gitlab.com/group/sub/team/repo.git");
+    }
+
+    #[test]
+    fn test_parse_gitlab_with_ref_and_path() {
+        let source =
+            BundleSource::parse("gitlab:group/sub/repo#v1:plugins/x").expect("Failed to parse");
+        let BundleSource::Git(git) = source else {
+            panic!("Expected git source");
+        };
+        assert_eq!(git.url, "https://gitlab.com/group/sub/repo.git");
+        assert_eq!(git.git_ref, Some("v1".to_string()));
+        assert_eq!(git.path, Some("plugins/x".to_string()));
+    }
+
+    #[test]
+    fn test_parse_gitlab_with_path() {
+        let source =
+            BundleSource::parse("gitlab:group/sub/repo:plugins/x").expect("Failed to parse");
+        let BundleSource::Git(git) = source else {
+            panic!("Expected git source");
+        };
+        assert_eq!(git.url, "https://gitlab.com/group/sub/repo.git");
+        assert_eq!(git.git_ref, None);
+        assert_eq!(git.path, Some("plugins/x".to_string()));
+    }
+
+    test_parse_err!(test_parse_gitlab_missing_repo, "gitlab:group");
+    test_parse_err!(test_parse_gitlab_empty_segment, "gitlab:group//repo");
+
+    #[test]
+    fn test_parse_bitbucket_short() {
+        let source = BundleSource::parse("bitbucket:user/repo@main").expect("Failed to parse");
+        let BundleSource::Git(git) = source else {
+            panic!("Expected git source");
+        };
+        assert_eq!(git.url, "https://bitbucket.org/user/repo.git");
+        assert_eq!(git.git_ref, Some("main".to_string()));
+    }
+
+    #[test]
+    fn test_parse_bitbucket_with_ref_and_path() {
+        let source =
+            BundleSource::parse("bitbucket:user/repo#v2:plugins/x").expect("Failed to parse");
+        let BundleSource::Git(git) = source else {
+            panic!("Expected git source");
+        };
+        assert_eq!(git.url, "https://bitbucket.org/user/repo.git");
+        assert_eq!(git.git_ref, Some("v2".to_string()));
+        assert_eq!(git.path, Some("plugins/x".to_string()));
+    }
+
+    test_parse_err!(test_parse_bitbucket_nested, "bitbucket:team/sub/repo");
+
+    #[test]
+    fn test_parse_glob_ref_keeps_prefix() {
+        let source = BundleSource::parse("github:owner/repo#glob:release-*:plugins/tools")
+            .expect("Failed to parse");
+        let BundleSource::Git(git) = source else {
+            panic!("Expected git source");
+        };
+        assert_eq!(git.git_ref, Some("glob:release-*".to_string()));
+        assert_eq!(git.path, Some("plugins/tools".to_string()));
+    }
+
+    #[test]
+    fn test_parse_github_blob_url_at_repo_root() {
+        let source =
+            BundleSource::parse("https://github.com/owner/repo/blob/main/commands/deploy.md")
+                .expect("Failed to parse");
+        let BundleSource::Git(git) = source else {
+            panic!("Expected git source");
+        };
+        assert_eq!(git.url, "https://github.com/owner/repo.git");
+        assert_eq!(git.git_ref, Some("main".to_string()));
+        assert_eq!(git.path, None);
+    }
+
+    #[test]
+    fn test_parse_github_blob_url_in_bundle_dir() {
+        let source = BundleSource::parse(
+            "https://github.com/owner/repo/blob/v1.0/plugins/review/commands/deploy.md",
+        )
+        .expect("Failed to parse");
+        let BundleSource::Git(git) = source else {
+            panic!("Expected git source");
+        };
+        assert_eq!(git.url, "https://github.com/owner/repo.git");
+        assert_eq!(git.git_ref, Some("v1.0".to_string()));
+        assert_eq!(git.path, Some("plugins/review".to_string()));
+    }
+
+    #[test]
+    fn test_parse_github_blob_url_uses_parent_dir() {
+        let source =
+            BundleSource::parse("https://github.com/owner/repo/blob/main/bundles/x/AGENTS.md")
+                .expect("Failed to parse");
+        let BundleSource::Git(git) = source else {
+            panic!("Expected git source");
+        };
+        assert_eq!(git.path, Some("bundles/x".to_string()));
+    }
+
+    #[test]
+    fn test_parse_github_blob_url_strips_query_string() {
+        let source = BundleSource::parse(
+            "https://github.com/owner/repo/blob/main/plugins/review/commands/deploy.md?raw=true",
+        )
+        .expect("Failed to parse");
+        let BundleSource::Git(git) = source else {
+            panic!("Expected git source");
+        };
+        assert_eq!(git.git_ref, Some("main".to_string()));
+        assert_eq!(git.path, Some("plugins/review".to_string()));
+    }
+
+    #[test]
+    fn test_parse_github_blob_url_strips_line_anchor() {
+        let source =
+            BundleSource::parse("https://github.com/owner/repo/blob/main/commands/deploy.md#L10")
+                .expect("Failed to parse");
+        let BundleSource::Git(git) = source else {
+            panic!("Expected git source");
+        };
+        assert_eq!(git.url, "https://github.com/owner/repo.git");
+        assert_eq!(git.git_ref, Some("main".to_string()));
+        assert_eq!(git.path, None);
+    }
 }
@@ -2,6 +2,7 @@
 //!
 //! This module provides `GitSource` struct and URL parsing logic for Git repositories.
 
+use crate::cache::FetchOptions;
 use crate::error::{AugentError, Result};
 use crate::git::url_parser;
 
@@ -52,6 +53,11 @@ impl GitSource {
 
     /// Parse a git source from a string
     pub fn parse(input: &str) -> Result<Self> {
+        Self::parse_with(input, FetchOptions::default())
+    }
+
+    /// Parse a git source, consulting the registry and probing hosts as `options` allow
+    pub fn parse_with(input: &str, options: FetchOptions) -> Result<Self> {
         let input = input.trim();
 
         // Check for GitHub web UI URL format: https://github.com/{owner}/{repo}/tree/{ref}/{path}
@@ -66,11 +72,15 @@ impl GitSource {
 
         let (main_part, ref_part) = url_parser::parse_fragment(input);
 
+        if let Some(source) = Self::parse_from_registry(main_part, ref_part, options)? {
+            return Ok(source);
+        }
+
         let (path_val, git_ref, url_part_for_parsing) =
-            Self::parse_path_without_fragment(main_part, ref_part);
+            Self::parse_path_without_fragment(main_part, ref_part, options);
 
         // Parse URL/shorthand
-        let url = Self::parse_url(url_part_for_parsing)?;
+        let url = Self::parse_url(url_part_for_parsing, options)?;
 
         Ok(Self {
             url,
@@ -80,13 +90,41 @@ impl GitSource {
         })
     }
 
+    /// Look up a bare bundle name in the configured registry
+    ///
+    /// Consulted before GitHub shorthand so registry names take precedence.
+    /// An explicit `#ref` in the input overrides the registry's ref. The
+    /// registry is not consulted offline.
+    fn parse_from_registry(
+        name: &str,
+        ref_part: Option<&str>,
+        options: FetchOptions,
+    ) -> Result<Option<Self>> {
+        if options.offline || (!name.starts_with('@') && !Self::is_github_shorthand(name)) {
+            return Ok(None);
+        }
+        let Some(registry_url) = super::registry::configured_registry_url() else {
+            return Ok(None);
+        };
+        let Some(mut source) = super::registry::resolve_from_registry(name, &registry_url)? else {
+            return Ok(None);
+        };
+        if let Some(git_ref) = ref_part {
+            source.git_ref = Some(git_ref.to_string());
+        }
+        Ok(Some(source))
+    }
+
     /// Parse path separator handling when main part has no fragment
     /// Returns (`optional_path`, `optional_ref`, `url_part_for_parsing`)
     fn parse_path_without_fragment<'a>(
         main_part: &'a str,
         ref_part: Option<&'a str>,
+        options: FetchOptions,
     ) -> (Option<String>, Option<String>, &'a str) {
-        url_parser::parse_path_without_fragment(main_part, ref_part, Self::parse_url)
+        url_parser::parse_path_without_fragment(main_part, ref_part, |url| {
+            Self::parse_url(url, options)
+        })
     }
 
     /// Check if string looks like a GitHub user/repo shorthand
@@ -95,25 +133,64 @@ impl GitSource {
     }
 
     /// Parse URL portion (without fragment)
-    fn parse_url(input: &str) -> Result<String> {
+    fn parse_url(input: &str, options: FetchOptions) -> Result<String> {
         // Try github: prefix
         if let Some(rest) = input.strip_prefix("github:") {
             return Ok(format!("https://github.com/{rest}.git"));
         }
 
+        // Try gitlab:group/subgroup/repo (any nesting depth)
+        if let Some(rest) = input.strip_prefix(url_parser::GITLAB_PREFIX) {
+            return Self::parse_gitlab_url(input, rest);
+        }
+
+        // Try bitbucket:user/repo
+        if let Some(rest) = input.strip_prefix(url_parser::BITBUCKET_PREFIX) {
+            return Self::parse_bitbucket_url(input, rest);
+        }
+
+        // Try gist:<id> and gist.github.com URLs (gist repo root is the bundle)
+        if input.starts_with("gist:") || input.starts_with("https://gist.github.com/") {
+            return Self::parse_gist_url(input);
+        }
+
         // Try @user/repo prefix (handle @ separately)
         match input.strip_prefix('@') {
             Some(rest) if Self::is_github_shorthand(rest) => {
-                Ok(format!("https://github.com/{rest}.git"))
+                Ok(super::hosts::shorthand_url(rest, options))
             }
-            _ => Self::parse_url_from_input(input),
+            _ => Self::parse_url_from_input(input, options),
         }
     }
 
-    fn parse_url_from_input(input: &str) -> Result<String> {
+    /// Parse `gitlab:group/repo` (`rest` is the part after the prefix)
+    fn parse_gitlab_url(input: &str, rest: &str) -> Result<String> {
+        url_parser::parse_gitlab_shorthand(rest).ok_or_else(|| AugentError::SourceParseFailed {
+            input: input.to_string(),
+            reason: "Expected gitlab:group/repo or gitlab:group/subgroup/repo".to_string(),
+        })
+    }
+
+    /// Parse `bitbucket:user/repo` (`rest` is the part after the prefix)
+    fn parse_bitbucket_url(input: &str, rest: &str) -> Result<String> {
+        url_parser::parse_bitbucket_shorthand(rest).ok_or_else(|| AugentError::SourceParseFailed {
+            input: input.to_string(),
+            reason: "Expected bitbucket:user/repo".to_string(),
+        })
+    }
+
+    /// Parse `gist:<id>` or a gist.github.com URL into the gist's clone URL
+    fn parse_gist_url(input: &str) -> Result<String> {
+        url_parser::parse_gist_url(input).ok_or_else(|| AugentError::SourceParseFailed {
+            input: input.to_string(),
+            reason: "Invalid gist id".to_string(),
+        })
+    }
+
+    fn parse_url_from_input(input: &str, options: FetchOptions) -> Result<String> {
         // user/repo shorthand
         if Self::is_github_shorthand(input) {
-            return Ok(format!("https://github.com/{input}.git"));
+            return Ok(super::hosts::shorthand_url(input, options));
         }
 
         // Full URL formats
@@ -0,0 +1,147 @@
+//! Git host fallback for bare `owner/repo` shorthands
+//!
+//! By default `@owner/repo` and `owner/repo` resolve to GitHub. Setting
+//! `AUGENT_GIT_HOSTS` to a comma-separated list of host base URLs changes
+//! that:
+//!
+//! ```text
+//! AUGENT_GIT_HOSTS=https://github.example.com,https://github.com
+//! ```
+//!
+//! With one host, shorthands resolve to it directly. With several, each host
+//! is probed in order with `git ls-remote` and the first that has the
+//! repository is used, so list internal hosts first to prefer internal forks.
+//! Explicit prefixes such as `github:` are never affected.
+
+/// Environment variable holding the ordered list of git hosts
+pub const GIT_HOSTS_ENV: &str = "AUGENT_GIT_HOSTS";
+
+/// Host used when `AUGENT_GIT_HOSTS` is not set
+const DEFAULT_HOST: &str = "https://github.com";
+
+/// Hosts configured for this process, in order
+pub fn configured_hosts() -> Vec<String> {
+    std::env::var(GIT_HOSTS_ENV)
+        .map(|value| parse_hosts(&value))
+        .unwrap_or_default()
+}
+
+/// Hosts listed in an `AUGENT_GIT_HOSTS` value, or the default host when it lists none
+pub fn hosts_or_default(value: Option<&str>) -> Vec<String> {
+    let hosts = value.map(parse_hosts).unwrap_or_default();
+    if hosts.is_empty() {
+        vec![DEFAULT_HOST.to_string()]
+    } else {
+        hosts
+    }
+}
+
+fn parse_hosts(value: &str) -> Vec<String> {
+    value
+        .split(',')
+        .map(|host| host.trim().trim_end_matches('/'))
+        .filter(|host| !host.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Repository URL for an `owner/repo` shorthand on the configured hosts
+///
+/// In offline mode hosts are not probed; the first host cached for the
+/// repository is used instead.
+pub fn shorthand_url(owner_repo: &str, options: crate::cache::FetchOptions) -> String {
+    if options.offline {
+        return resolve_shorthand(owner_repo, &configured_hosts(), |url| {
+            !crate::cache::index::index_lookup_url(url).is_empty()
+        });
+    }
+    resolve_shorthand(owner_repo, &configured_hosts(), |url| {
+        crate::git::ls_remote(url, None).is_ok()
+    })
+}
+
+/// Pick the first host in `hosts` where `exists` finds the repository
+///
+/// Hosts are only probed when more than one is configured. When none of them
+/// has the repository, the first host is used so the error names it.
+fn resolve_shorthand(owner_repo: &str, hosts: &[String], exists: impl Fn(&str) -> bool) -> String {
+    let url_on = |host: &str| format!("{host}/{owner_repo}.git");
+    match hosts {
+        [] => url_on(DEFAULT_HOST),
+        [host] => url_on(host),
+        [first, ..] => hosts
+            .iter()
+            .map(|host| url_on(host))
+            .find(|url| exists(url))
+            .unwrap_or_else(|| url_on(first)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    #[test]
+    fn test_parse_hosts_trims_entries() {
+        assert_eq!(
+            parse_hosts(" https://ghe.example.com/ ,,https://github.com"),
+            vec!["https://ghe.example.com", "https://github.com"]
+        );
+    }
+
+    #[test]
+    fn test_resolve_shorthand_defaults_to_github() {
+        let probed = Cell::new(false);
+        let url = resolve_shorthand("owner/repo", &[], |_| {
+            probed.set(true);
+            false
+        });
+        assert_eq!(url, "https://github.com/owner/repo.git");
+        assert!(!probed.get());
+    }
+
+    #[test]
+    fn test_resolve_shorthand_single_host_is_not_probed() {
+        let probed = Cell::new(false);
+        let hosts = vec!["https://ghe.example.com".to_string()];
+        let url = resolve_shorthand("owner/repo", &hosts, |_| {
+            probed.set(true);
+            false
+        });
+        assert_eq!(url, "https://ghe.example.com/owner/repo.git");
+        assert!(!probed.get());
+    }
+
+    #[test]
+    fn test_resolve_shorthand_falls_back_to_second_host() {
+        let hosts = vec![
+            "https://ghe.example.com".to_string(),
+            "https://github.com".to_string(),
+        ];
+        let url = resolve_shorthand("owner/repo", &hosts, |url| {
+            url == "https://github.com/owner/repo.git"
+        });
+        assert_eq!(url, "https://github.com/owner/repo.git");
+    }
+
+    #[test]
+    fn test_resolve_shorthand_prefers_first_resolving_host() {
+        let hosts = vec![
+            "https://ghe.example.com".to_string(),
+            "https://github.com".to_string(),
+        ];
+        let url = resolve_shorthand("owner/repo", &hosts, |_| true);
+        assert_eq!(url, "https://ghe.example.com/owner/repo.git");
+    }
+
+    #[test]
+    fn test_resolve_shorthand_unresolved_uses_first_host() {
+        let hosts = vec![
+            "https://ghe.example.com".to_string(),
+            "https://github.com".to_string(),
+        ];
+        let url = resolve_shorthand("owner/repo", &hosts, |_| false);
+        assert_eq!(url, "https://ghe.example.com/owner/repo.git");
+    }
+}
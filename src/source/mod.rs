@@ -4,20 +4,29 @@
 //! - Local directory paths: `./bundles/my-bundle`, `../shared-bundle`
 //! - Git repositories: `https://github.com/user/repo.git`, `git@github.com:user/repo.git`
 //! - GitHub short-form: `github:author/repo`, `author/repo`
-//! - GitHub web UI URLs: `https://github.com/user/repo/tree/ref/path`
+//! - GitLab/Bitbucket short-form: `gitlab:group/subgroup/repo`, `bitbucket:user/repo`
+//! - GitHub web UI URLs: `https://github.com/user/repo/tree/ref/path` (or `/blob/` file links)
+//! - Archives: `https://example.com/bundle.tar.gz`, `.zip`, or `tarball:<url>` (with `#path`)
+//! - OCI artifacts: `oci://ghcr.io/owner/name:tag` or `oci://...@sha256:<digest>` (with `#path`)
 //! - With ref: `github:user/repo#v1.0.0` or `github:user/repo@v1.0.0`
 //! - With path: `github:user/repo:plugins/bundle-name`
 //! - With ref and path: `github:user/repo:plugins/bundle-name#main`
+//! - Registry names: `@owner/name` looked up in `AUGENT_REGISTRY_URL` when set
+//! - Bare shorthands resolve against the hosts in `AUGENT_GIT_HOSTS` when set
 //!
 //! ## Module Organization
 //!
 //! - `bundle_source.rs`: `BundleSource` enum and parsing
 //! - `git_source.rs`: `GitSource` struct and URL parsing
 //! - `bundle.rs`: Fully resolved bundle model with validation
+//! - `registry.rs`: Bundle name lookup in a JSON registry index
+//! - `hosts.rs`: Host fallback for bare `owner/repo` shorthands
 
 pub mod bundle;
 pub mod bundle_source;
 pub mod git_source;
+pub mod hosts;
+pub mod registry;
 
 pub use bundle_source::BundleSource;
 pub use git_source::GitSource;
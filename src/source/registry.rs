@@ -0,0 +1,220 @@
+//! Bundle registry lookup
+//!
+//! A registry is a JSON index mapping bundle names to git sources:
+//!
+//! ```json
+//! {
+//!   "bundles": {
+//!     "@acme/review-tools": {
+//!       "git": "https://github.com/acme/review-tools.git",
+//!       "ref": "v1.2.0",
+//!       "path": "bundles/review"
+//!     }
+//!   }
+//! }
+//! ```
+//!
+//! The registry URL is read from `AUGENT_REGISTRY_URL` and may be an
+//! `http(s)://` URL, a `file://` URL or a local path. Each index is fetched
+//! once per run.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::Duration;
+
+use serde::Deserialize;
+
+use super::git_source::GitSource;
+use crate::error::{AugentError, Result};
+
+/// Environment variable holding the registry index URL
+pub const REGISTRY_URL_ENV: &str = "AUGENT_REGISTRY_URL";
+
+/// How long to wait for a remote registry index
+const FETCH_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Parsed indexes by registry URL
+static INDEXES: OnceLock<Mutex<HashMap<String, Arc<RegistryIndex>>>> = OnceLock::new();
+
+#[derive(Debug, Deserialize)]
+struct RegistryIndex {
+    #[serde(default)]
+    bundles: HashMap<String, RegistryEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RegistryEntry {
+    git: String,
+    #[serde(rename = "ref")]
+    git_ref: Option<String>,
+    path: Option<String>,
+}
+
+/// Registry URL configured for this process, if any
+pub fn configured_registry_url() -> Option<String> {
+    std::env::var(REGISTRY_URL_ENV)
+        .ok()
+        .filter(|url| !url.trim().is_empty())
+}
+
+/// Look up `name` in the registry index at `registry_url`
+///
+/// Returns `None` when the index has no entry for `name`, so callers can fall
+/// back to other source formats. An index that cannot be fetched or parsed is
+/// an error rather than a fallback, which could install a different repository.
+pub fn resolve_from_registry(name: &str, registry_url: &str) -> Result<Option<GitSource>> {
+    let index = load_index(registry_url)?;
+    Ok(index.bundles.get(name).map(|entry| GitSource {
+        url: entry.git.clone(),
+        path: entry.path.clone(),
+        git_ref: entry.git_ref.clone(),
+        resolved_sha: None,
+    }))
+}
+
+/// Parsed index at `registry_url`, fetched on first use
+fn load_index(registry_url: &str) -> Result<Arc<RegistryIndex>> {
+    let indexes = INDEXES.get_or_init(|| Mutex::new(HashMap::new()));
+    let cached = indexes
+        .lock()
+        .ok()
+        .and_then(|indexes| indexes.get(registry_url).cloned());
+    if let Some(index) = cached {
+        return Ok(index);
+    }
+
+    let failed = |reason: String| AugentError::RegistryFailed {
+        url: registry_url.to_string(),
+        reason,
+    };
+    let content = fetch_index(registry_url).map_err(failed)?;
+    let index: Arc<RegistryIndex> =
+        Arc::new(serde_json::from_str(&content).map_err(|e| failed(e.to_string()))?);
+    if let Ok(mut indexes) = indexes.lock() {
+        indexes.insert(registry_url.to_string(), Arc::clone(&index));
+    }
+    Ok(index)
+}
+
+fn fetch_index(registry_url: &str) -> std::result::Result<String, String> {
+    if registry_url.starts_with("http://") || registry_url.starts_with("https://") {
+        let agent = ureq::AgentBuilder::new().timeout(FETCH_TIMEOUT).build();
+        return agent
+            .get(registry_url)
+            .call()
+            .map_err(|e| e.to_string())?
+            .into_string()
+            .map_err(|e| e.to_string());
+    }
+    let path = registry_url.strip_prefix("file://").unwrap_or(registry_url);
+    std::fs::read_to_string(path).map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::*;
+
+    fn write_registry(dir: &std::path::Path) -> std::path::PathBuf {
+        let path = dir.join("registry.json");
+        std::fs::write(
+            &path,
+            r#"{
+  "bundles": {
+    "@acme/review-tools": {
+      "git": "https://git.example.com/acme/review-tools.git",
+      "ref": "v1.2.0",
+      "path": "bundles/review"
+    }
+  }
+}"#,
+        )
+        .expect("Failed to write registry");
+        path
+    }
+
+    #[test]
+    fn test_resolve_from_registry_returns_git_source() {
+        let temp = tempfile::TempDir::new_in(crate::temp::temp_dir_base())
+            .expect("Failed to create temp dir");
+        let registry = write_registry(temp.path());
+        let url = format!("file://{}", registry.display());
+
+        let source = resolve_from_registry("@acme/review-tools", &url)
+            .expect("Registry should load")
+            .expect("Bundle should resolve");
+        assert_eq!(source.url, "https://git.example.com/acme/review-tools.git");
+        assert_eq!(source.git_ref.as_deref(), Some("v1.2.0"));
+        assert_eq!(source.path.as_deref(), Some("bundles/review"));
+    }
+
+    #[test]
+    fn test_resolve_from_registry_unknown_name() {
+        let temp = tempfile::TempDir::new_in(crate::temp::temp_dir_base())
+            .expect("Failed to create temp dir");
+        let registry = write_registry(temp.path());
+
+        let source = resolve_from_registry("@acme/other", &registry.to_string_lossy())
+            .expect("Registry should load");
+        assert!(source.is_none());
+    }
+
+    #[test]
+    fn test_unreadable_registry_is_an_error() {
+        let temp = tempfile::TempDir::new_in(crate::temp::temp_dir_base())
+            .expect("Failed to create temp dir");
+        let invalid = temp.path().join("invalid.json");
+        std::fs::write(&invalid, "not json").expect("Failed to write registry");
+
+        for url in [
+            "/nonexistent/registry.json".to_string(),
+            invalid.to_string_lossy().to_string(),
+        ] {
+            let result = resolve_from_registry("@acme/review-tools", &url);
+            assert!(matches!(result, Err(AugentError::RegistryFailed { .. })));
+        }
+    }
+
+    #[test]
+    fn test_registry_index_is_read_once() {
+        let temp = tempfile::TempDir::new_in(crate::temp::temp_dir_base())
+            .expect("Failed to create temp dir");
+        let registry = write_registry(temp.path());
+        let url = registry.to_string_lossy().to_string();
+
+        resolve_from_registry("@acme/review-tools", &url).expect("Registry should load");
+        std::fs::remove_file(&registry).expect("Failed to remove registry");
+
+        let source =
+            resolve_from_registry("@acme/review-tools", &url).expect("Cached registry should load");
+        assert!(source.is_some());
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_registry_is_skipped_offline() {
+        let original = std::env::var(REGISTRY_URL_ENV).ok();
+        // SAFETY: std::env::set_var is safe in test context.
+        unsafe {
+            std::env::set_var(REGISTRY_URL_ENV, "/nonexistent/registry.json");
+        }
+
+        let offline = crate::cache::FetchOptions {
+            offline: true,
+            ..crate::cache::FetchOptions::default()
+        };
+        let source = GitSource::parse_with("@acme/review-tools", offline);
+        let online = GitSource::parse("@acme/review-tools");
+
+        // SAFETY: std::env::set_var is safe in test context.
+        unsafe {
+            match original {
+                Some(value) => std::env::set_var(REGISTRY_URL_ENV, value),
+                None => std::env::remove_var(REGISTRY_URL_ENV),
+            }
+        }
+        let source = source.expect("Offline parse should not read the registry");
+        assert!(source.url.ends_with("/acme/review-tools.git"));
+        assert!(matches!(online, Err(AugentError::RegistryFailed { .. })));
+    }
+}
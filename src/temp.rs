@@ -1,8 +1,19 @@
 //! Safe temporary directory base so temp dirs are never created under the current working
 //! directory (e.g. when TMPDIR=tmp or TMPDIR=./tmp).
+//!
+//! Augent's own temp dirs are named with [`TEMP_PREFIX`], so dirs orphaned by a
+//! killed process can be told apart from other programs' and swept at startup.
 
 use std::env;
-use std::path::PathBuf;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+/// Name prefix of temp dirs created by augent
+pub const TEMP_PREFIX: &str = "augent-";
+
+/// Age after which an augent temp dir is considered orphaned
+const ORPHAN_AGE: Duration = Duration::from_secs(24 * 60 * 60);
 
 /// Returns a directory path suitable for creating temporary directories.
 /// Never returns a relative path, so temp dirs are never created under the current working
@@ -25,3 +36,79 @@ pub fn temp_dir_base() -> PathBuf {
         }
     }
 }
+
+/// Create an augent temp dir (named with [`TEMP_PREFIX`]) under [`temp_dir_base`]
+pub fn temp_dir() -> std::io::Result<tempfile::TempDir> {
+    tempfile::Builder::new()
+        .prefix(TEMP_PREFIX)
+        .tempdir_in(temp_dir_base())
+}
+
+/// Remove augent temp dirs left behind by interrupted runs
+///
+/// Dirs younger than a day may belong to a running augent and are kept.
+/// Failures are ignored; the sweep is best effort.
+pub fn cleanup_orphans() {
+    let _ = remove_orphans_in(&temp_dir_base(), ORPHAN_AGE);
+}
+
+/// Remove dirs in `base` named with [`TEMP_PREFIX`] and not modified within `max_age`
+fn remove_orphans_in(base: &Path, max_age: Duration) -> std::io::Result<usize> {
+    let Some(cutoff) = SystemTime::now().checked_sub(max_age) else {
+        return Ok(0);
+    };
+    let mut removed = 0;
+    for entry in fs::read_dir(base)?.filter_map(std::result::Result::ok) {
+        if !entry.file_name().to_string_lossy().starts_with(TEMP_PREFIX) {
+            continue;
+        }
+        let Ok(metadata) = fs::symlink_metadata(entry.path()) else {
+            continue;
+        };
+        let stale = metadata.modified().is_ok_and(|modified| modified < cutoff);
+        if metadata.is_dir() && stale && fs::remove_dir_all(entry.path()).is_ok() {
+            removed += 1;
+        }
+    }
+    Ok(removed)
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_temp_dir_is_prefixed() {
+        let dir = temp_dir().expect("Failed to create temp directory");
+        let name = dir.path().file_name().expect("temp dir has a name");
+        assert!(name.to_string_lossy().starts_with(TEMP_PREFIX));
+        assert!(dir.path().starts_with(temp_dir_base()));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_remove_orphans_in_removes_only_stale_prefixed_dirs() {
+        let base =
+            tempfile::TempDir::new_in(temp_dir_base()).expect("Failed to create temp directory");
+        let stale = base.path().join(format!("{TEMP_PREFIX}stale"));
+        let fresh = base.path().join(format!("{TEMP_PREFIX}fresh"));
+        let foreign = base.path().join("other-app-stale");
+        for dir in [&stale, &fresh, &foreign] {
+            fs::create_dir_all(dir.join("repository")).expect("Failed to create dir");
+        }
+        let old = SystemTime::now() - 2 * ORPHAN_AGE;
+        for dir in [&stale, &foreign] {
+            fs::File::open(dir)
+                .and_then(|f| f.set_modified(old))
+                .expect("Failed to set mtime");
+        }
+
+        let removed = remove_orphans_in(base.path(), ORPHAN_AGE).expect("Failed to clean up");
+
+        assert_eq!(removed, 1);
+        assert!(!stale.exists());
+        assert!(fresh.exists());
+        assert!(foreign.exists());
+    }
+}
@@ -115,7 +115,7 @@ pub fn create_workspace() -> (TempDir, crate::workspace::Workspace) {
 pub fn create_workspace_open() -> (TempDir, crate::workspace::Workspace) {
     let (temp, path) = create_git_repo();
     let workspace =
-        crate::workspace::Workspace::init_or_open(&path).expect("Failed to open workspace");
+        crate::workspace::Workspace::init_or_open(&path, None).expect("Failed to open workspace");
     (temp, workspace)
 }
 
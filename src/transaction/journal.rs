@@ -0,0 +1,126 @@
+//! Journal of workspace files overwritten during an install
+//!
+//! Every target is snapshotted the first time it is written, before the
+//! write happens. Rolling back writes the snapshots back and removes
+//! targets that did not exist before, so a failed install leaves the
+//! workspace as it found it.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use crate::error::{AugentError, Result};
+
+/// Prior contents of written files, keyed by target path
+///
+/// `None` marks a target that did not exist before it was written.
+#[derive(Debug, Default)]
+pub struct FileJournal {
+    entries: Mutex<HashMap<PathBuf, Option<Vec<u8>>>>,
+}
+
+impl FileJournal {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Snapshot `target` into the journal, then run `write`
+    ///
+    /// Only the first snapshot of a target is kept, so rollback restores the
+    /// contents from before the install rather than from an earlier bundle.
+    pub fn backup_and_write<T>(
+        &self,
+        target: &Path,
+        write: impl FnOnce() -> Result<T>,
+    ) -> Result<T> {
+        self.backup(target)?;
+        write()
+    }
+
+    fn backup(&self, target: &Path) -> Result<()> {
+        let mut entries = self.entries.lock().map_err(|e| AugentError::IoError {
+            message: format!("Failed to acquire file journal lock: {e}"),
+            source: None,
+        })?;
+        if entries.contains_key(target) {
+            return Ok(());
+        }
+        let prior = if target.is_file() {
+            Some(fs::read(target).map_err(|e| AugentError::FileReadFailed {
+                path: target.display().to_string(),
+                reason: e.to_string(),
+            })?)
+        } else {
+            None
+        };
+        entries.insert(target.to_path_buf(), prior);
+        Ok(())
+    }
+
+    /// Restore every journaled target and empty the journal
+    pub fn rollback(&self) {
+        let Ok(mut entries) = self.entries.lock() else {
+            return;
+        };
+        for (path, prior) in entries.drain() {
+            Self::restore(&path, prior.as_deref());
+        }
+    }
+
+    fn restore(path: &Path, prior: Option<&[u8]>) {
+        // Remove first so a symlinked target (`--link`) is not written through
+        let exists = fs::symlink_metadata(path).is_ok_and(|m| !m.is_dir());
+        if exists {
+            let _ = fs::remove_file(path);
+        }
+        let Some(content) = prior else {
+            return;
+        };
+        if let Err(e) = fs::write(path, content) {
+            eprintln!("Warning: Failed to restore {}: {}", path.display(), e);
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::*;
+
+    fn write_through(journal: &FileJournal, path: &Path, content: &str) {
+        journal
+            .backup_and_write(path, || {
+                fs::write(path, content).map_err(|e| AugentError::IoError {
+                    message: e.to_string(),
+                    source: None,
+                })
+            })
+            .expect("Failed to write");
+    }
+
+    #[test]
+    fn test_rollback_restores_overwritten_and_removes_new_files() {
+        let temp = tempfile::TempDir::new_in(crate::temp::temp_dir_base())
+            .expect("Failed to create temp dir");
+        let existing = temp.path().join("existing.md");
+        let created = temp.path().join("created.md");
+        fs::write(&existing, "before").expect("Failed to write");
+
+        let journal = FileJournal::new();
+        for (path, content) in [
+            (&existing, "first"),
+            (&existing, "second"),
+            (&created, "new"),
+        ] {
+            write_through(&journal, path, content);
+        }
+        journal.rollback();
+
+        assert_eq!(
+            fs::read_to_string(&existing).expect("Failed to read"),
+            "before"
+        );
+        assert!(!created.exists());
+    }
+}
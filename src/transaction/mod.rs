@@ -12,6 +12,9 @@
 //! // Perform operations...
 //! `transaction.track_file_created(path)`;
 //!
+//! // Installer writes go through the journal so overwritten files come back:
+//! `installer.with_journal(transaction.file_journal())`;
+//!
 //! // On success:
 //! `transaction.commit()`;
 //!
@@ -19,12 +22,16 @@
 //! // rollback happens automatically
 //! \`\`\`
 
+pub mod journal;
+
 use std::collections::HashSet;
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
 use crate::error::{AugentError, Result};
 use crate::workspace::Workspace;
+use journal::FileJournal;
 
 /// Configuration file backups
 #[derive(Debug, Clone)]
@@ -41,6 +48,9 @@ pub struct Transaction {
     /// Augent directory path
     augent_dir: PathBuf,
 
+    /// Lockfile of the workspace's profile
+    lockfile_name: String,
+
     /// Configuration file backups
     config_backups: Vec<ConfigBackup>,
 
@@ -53,6 +63,9 @@ pub struct Transaction {
     /// Directories created during this transaction
     created_dirs: HashSet<PathBuf>,
 
+    /// Prior contents of files written by the installer
+    file_journal: Arc<FileJournal>,
+
     /// Whether transaction has been committed
     committed: bool,
 
@@ -65,10 +78,12 @@ impl Transaction {
     pub fn new(workspace: &Workspace) -> Self {
         Self {
             augent_dir: workspace.augent_dir.clone(),
+            lockfile_name: crate::workspace::config::lockfile_name(workspace.profile.as_deref()),
             config_backups: Vec::new(),
             created_files: HashSet::new(),
             modified_files: Vec::new(),
             created_dirs: HashSet::new(),
+            file_journal: Arc::new(FileJournal::new()),
             committed: false,
             rollback_enabled: true,
         }
@@ -80,7 +95,7 @@ impl Transaction {
     pub fn backup_configs(&mut self) -> Result<()> {
         let config_files: Vec<_> = [
             self.augent_dir.join("augent.yaml"),
-            self.augent_dir.join("augent.lock"),
+            self.augent_dir.join(&self.lockfile_name),
             self.augent_dir.join("augent.index.yaml"),
         ]
         .into_iter()
@@ -113,6 +128,11 @@ impl Transaction {
         self.created_dirs.insert(path.into());
     }
 
+    /// Journal that snapshots files before the installer writes them
+    pub fn file_journal(&self) -> Arc<FileJournal> {
+        Arc::clone(&self.file_journal)
+    }
+
     /// Commit the transaction (prevent rollback)
     pub fn commit(mut self) {
         self.committed = true;
@@ -125,6 +145,7 @@ impl Transaction {
         }
 
         Self::remove_created_files(&self.created_files);
+        self.file_journal.rollback();
         Self::restore_file_backups(&self.modified_files);
         Self::remove_empty_created_dirs(&self.created_dirs);
         Self::restore_config_backups(&self.config_backups);
@@ -213,6 +213,53 @@ macro_rules! display_opt_field {
     };
 }
 
+/// Author, license and homepage of a bundle
+struct BundleMetadata {
+    author: Option<String>,
+    license: Option<String>,
+    homepage: Option<String>,
+}
+
+/// Bundle metadata from the lockfile, falling back to the bundle's augent.yaml
+///
+/// Older lockfiles and some git bundles do not carry the metadata; in that
+/// case it is read from the bundle directory or the cached repository.
+fn resolve_bundle_metadata(
+    bundle: &crate::config::LockedBundle,
+    workspace_root: &std::path::Path,
+) -> BundleMetadata {
+    let locked = BundleMetadata {
+        author: bundle.author.clone(),
+        license: bundle.license.clone(),
+        homepage: bundle.homepage.clone(),
+    };
+    if locked.author.is_some() || locked.license.is_some() || locked.homepage.is_some() {
+        return locked;
+    }
+    let Ok(config) = config_utils::load_bundle_config(workspace_root, &bundle.source) else {
+        return locked;
+    };
+    BundleMetadata {
+        author: config.author,
+        license: config.license,
+        homepage: config.homepage,
+    }
+}
+
+fn display_bundle_metadata(bundle: &crate::config::LockedBundle, workspace_root: &std::path::Path) {
+    let metadata = resolve_bundle_metadata(bundle, workspace_root);
+    let fields = [
+        ("Author:", metadata.author),
+        ("License:", metadata.license),
+        ("Homepage:", metadata.homepage),
+    ];
+    for (label, value) in fields {
+        if let Some(value) = value {
+            println!("    {} {}", Style::new().bold().apply_to(label), value);
+        }
+    }
+}
+
 fn display_source_common(bundle: &crate::config::LockedBundle, detailed: bool) {
     println!("    {}", Style::new().bold().apply_to("Source:"));
     display_utils::display_source_detailed_with_indent(
@@ -251,9 +298,10 @@ pub trait DisplayFormatter {
 pub struct SimpleFormatter;
 
 impl DisplayFormatter for SimpleFormatter {
-    fn format_bundle(&self, bundle: &crate::config::LockedBundle, _ctx: &DisplayContext) {
+    fn format_bundle(&self, bundle: &crate::config::LockedBundle, ctx: &DisplayContext) {
         self.format_bundle_name(bundle);
         Self::format_metadata_simple(bundle);
+        display_bundle_metadata(bundle, ctx.workspace_root);
         self.format_source(bundle, false);
         display_marketplace_plugin(bundle);
         display_resources_grouped(&bundle.files);
@@ -292,7 +340,8 @@ pub struct DetailedFormatter;
 impl DisplayFormatter for DetailedFormatter {
     fn format_bundle(&self, bundle: &crate::config::LockedBundle, ctx: &DisplayContext) {
         self.format_bundle_name(bundle);
-        self.format_metadata(bundle);
+        SimpleFormatter::format_metadata_simple(bundle);
+        display_bundle_metadata(bundle, ctx.workspace_root);
         self.format_source(bundle, ctx.detailed);
         display_marketplace_plugin(bundle);
         display_resources_grouped(&bundle.files);
@@ -366,6 +415,29 @@ pub struct JsonFormatter;
 
 impl DisplayFormatter for JsonFormatter {
     fn format_bundle(&self, bundle: &crate::config::LockedBundle, ctx: &DisplayContext) {
+        let output = Self::bundle_value(bundle, ctx);
+        match serde_json::to_string_pretty(&output) {
+            Ok(json_str) => println!("{json_str}"),
+            Err(e) => {
+                eprintln!("Warning: Failed to serialize JSON output: {e}");
+                println!("{{}}");
+            }
+        }
+    }
+
+    fn format_bundle_name(&self, _bundle: &crate::config::LockedBundle) {}
+
+    fn format_metadata(&self, _bundle: &crate::config::LockedBundle) {}
+
+    fn format_source(&self, _bundle: &crate::config::LockedBundle, _detailed: bool) {}
+}
+
+impl JsonFormatter {
+    /// Structured representation of a bundle, shared by the JSON and YAML outputs
+    pub fn bundle_value(
+        bundle: &crate::config::LockedBundle,
+        ctx: &DisplayContext,
+    ) -> serde_json::Value {
         let mut output = serde_json::json!({
             "name": bundle.name,
             "source": bundle.source,
@@ -374,13 +446,14 @@ impl DisplayFormatter for JsonFormatter {
         if let Some(ref desc) = bundle.description {
             output["description"] = serde_json::json!(desc);
         }
-        if let Some(ref author) = bundle.author {
+        let metadata = resolve_bundle_metadata(bundle, ctx.workspace_root);
+        if let Some(author) = metadata.author {
             output["author"] = serde_json::json!(author);
         }
-        if let Some(ref license) = bundle.license {
+        if let Some(license) = metadata.license {
             output["license"] = serde_json::json!(license);
         }
-        if let Some(ref homepage) = bundle.homepage {
+        if let Some(homepage) = metadata.homepage {
             output["homepage"] = serde_json::json!(homepage);
         }
         if let Some(ref version) = bundle.version {
@@ -394,24 +467,9 @@ impl DisplayFormatter for JsonFormatter {
         if ctx.detailed {
             Self::add_detailed_info(&mut output, bundle, ctx);
         }
-
-        match serde_json::to_string_pretty(&output) {
-            Ok(json_str) => println!("{json_str}"),
-            Err(e) => {
-                eprintln!("Warning: Failed to serialize JSON output: {e}");
-                println!("{{}}");
-            }
-        }
+        output
     }
 
-    fn format_bundle_name(&self, _bundle: &crate::config::LockedBundle) {}
-
-    fn format_metadata(&self, _bundle: &crate::config::LockedBundle) {}
-
-    fn format_source(&self, _bundle: &crate::config::LockedBundle, _detailed: bool) {}
-}
-
-impl JsonFormatter {
     fn add_detailed_info(
         output: &mut serde_json::Value,
         bundle: &crate::config::LockedBundle,
@@ -544,6 +602,7 @@ mod tests {
             license: None,
             homepage: None,
             files: vec![],
+            platform_refs: std::collections::BTreeMap::new(),
         };
 
         formatter.format_bundle_name(&bundle);
@@ -4,6 +4,7 @@
 //! - Progress reporting for installations and other long-running operations
 //! - Interactive progress bars using indicatif
 //! - Silent progress for dry-run mode
+//! - Summary-only mode (no progress bars or per-file lines)
 //! - Display utilities for bundles and resources
 //!
 //! All progress reporting goes through the `ProgressReporter` trait, allowing
@@ -44,6 +45,35 @@ pub trait ProgressReporter: Send + Sync {
     fn abandon(&mut self);
 }
 
+/// How install progress is reported
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ProgressMode {
+    /// Progress bars and per-bundle file listing (default)
+    #[default]
+    Interactive,
+    /// No progress bars or per-file lines, only the final per-platform tally
+    SummaryOnly,
+}
+
+impl ProgressMode {
+    /// Select the progress mode from command-line flags
+    pub fn from_flags(summary_only: bool) -> Self {
+        if summary_only {
+            Self::SummaryOnly
+        } else {
+            Self::Interactive
+        }
+    }
+
+    /// Create the progress reporter for this mode, if it shows one
+    pub fn reporter(self, total_bundles: u64) -> Option<InteractiveProgressReporter> {
+        match self {
+            Self::Interactive => Some(InteractiveProgressReporter::new(total_bundles)),
+            Self::SummaryOnly => None,
+        }
+    }
+}
+
 /// Interactive progress reporter with visual progress bars
 ///
 /// Uses indicatif `ProgressBar` for visual progress display during installations.
@@ -192,6 +222,13 @@ mod tests {
         reporter.abandon();
     }
 
+    #[test]
+    fn test_progress_mode_summary_only_has_no_reporter() {
+        assert_eq!(ProgressMode::from_flags(true), ProgressMode::SummaryOnly);
+        assert!(ProgressMode::SummaryOnly.reporter(3).is_none());
+        assert!(ProgressMode::from_flags(false).reporter(3).is_some());
+    }
+
     #[test]
     fn test_interactive_progress_reporter_creation() {
         let reporter = InteractiveProgressReporter::new(5);
@@ -120,11 +120,60 @@ pub fn get_str(value: &Value, key: &str) -> Option<String> {
     }
 }
 
+/// Platform ids listed under `key`: a single id, a comma-separated list or a
+/// sequence of ids. Returns `None` when the key is absent.
+pub fn platform_filter(value: &Value, key: &str) -> Option<Vec<String>> {
+    let mapping = value.as_mapping()?;
+    let ids = match mapping.get(Value::String(key.to_string()))? {
+        Value::String(s) => s
+            .split(',')
+            .map(str::trim)
+            .filter(|id| !id.is_empty())
+            .map(str::to_string)
+            .collect(),
+        Value::Sequence(items) => items
+            .iter()
+            .filter_map(Value::as_str)
+            .map(str::to_string)
+            .collect(),
+        _ => Vec::new(),
+    };
+    Some(ids)
+}
+
+/// Remove a top-level key from a frontmatter mapping
+pub fn remove_key(value: &mut Value, key: &str) {
+    if let Some(mapping) = value.as_mapping_mut() {
+        mapping.remove(Value::String(key.to_string()));
+    }
+}
+
 #[cfg(test)]
 #[allow(clippy::expect_used)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_platform_filter_accepts_string_list_and_sequence() {
+        let (fm, _) = parse_frontmatter_and_body(
+            "---\nplatforms: cursor, claude\ntargets:\n  - kiro\n---\nbody",
+        )
+        .expect("Failed to parse frontmatter");
+        assert_eq!(
+            platform_filter(&fm, "platforms"),
+            Some(vec!["cursor".to_string(), "claude".to_string()])
+        );
+        assert_eq!(
+            platform_filter(&fm, "targets"),
+            Some(vec!["kiro".to_string()])
+        );
+        assert_eq!(platform_filter(&fm, "missing"), None);
+
+        let mut fm = fm;
+        remove_key(&mut fm, "platforms");
+        assert_eq!(platform_filter(&fm, "platforms"), None);
+    }
+
     #[test]
     fn test_parse_frontmatter_and_body() {
         let content = "---\ndescription: hello\n---\n\nbody here";
@@ -3,9 +3,12 @@
 //! Parses YAML frontmatter (between `---` delimiters) and supports
 //! platform-specific blocks keyed by Augent platform id. At install time,
 //! common fields are merged with the platform block for the target platform.
+//! A platform filter key (`platforms` by default) restricts a resource to the
+//! listed platforms and is not written to the installed file.
 
 mod frontmatter;
 
 pub use frontmatter::{
-    get_str, merge_frontmatter_for_platform, parse_frontmatter_and_body, serialize_to_yaml,
+    get_str, merge_frontmatter_for_platform, parse_frontmatter_and_body, platform_filter,
+    remove_key, serialize_to_yaml,
 };
@@ -6,7 +6,7 @@ use std::fs;
 use std::path::Path;
 
 use crate::config::{BundleConfig, Lockfile, WorkspaceConfig};
-use crate::error::Result;
+use crate::error::{AugentError, Result};
 
 /// Bundle config filename
 pub const BUNDLE_CONFIG_FILE: &str = "augent.yaml";
@@ -17,6 +17,32 @@ pub const LOCKFILE_NAME: &str = "augent.lock";
 /// Workspace config filename
 pub const WORKSPACE_INDEX_FILE: &str = "augent.index.yaml";
 
+/// Check the name of a lockfile profile (`--profile`)
+///
+/// Profile names may only contain ASCII letters, digits, `-` and `_`.
+pub fn validate_profile(profile: &str) -> Result<()> {
+    let valid = !profile.is_empty()
+        && profile
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    if !valid {
+        return Err(AugentError::ConfigInvalid {
+            message: format!(
+                "Invalid profile name '{profile}': use only letters, digits, '-' and '_'"
+            ),
+        });
+    }
+    Ok(())
+}
+
+/// Lockfile filename for `profile`, `augent.lock` without one
+pub fn lockfile_name(profile: Option<&str>) -> String {
+    match profile {
+        Some(profile) => format!("augent.{profile}.lock"),
+        None => LOCKFILE_NAME.to_string(),
+    }
+}
+
 /// Load bundle configuration from a directory
 ///
 /// Returns an empty config if augent.yaml does not exist, as config file is optional.
@@ -31,8 +57,15 @@ pub fn load_bundle_config(config_dir: &Path) -> Result<BundleConfig> {
 }
 
 /// Load lockfile from a directory
-pub fn load_lockfile(config_dir: &Path) -> Result<Lockfile> {
-    load_config_file(config_dir, LOCKFILE_NAME, Lockfile::default(), |content| {
+///
+/// With a profile, `augent.<profile>.lock` is loaded; until it has been
+/// written, the default `augent.lock` is used as its starting point.
+pub fn load_lockfile(config_dir: &Path, profile: Option<&str>) -> Result<Lockfile> {
+    let mut filename = lockfile_name(profile);
+    if !config_dir.join(&filename).exists() {
+        filename = LOCKFILE_NAME.to_string();
+    }
+    load_config_file(config_dir, &filename, Lockfile::default(), |content| {
         Lockfile::from_json(content)
     })
 }
@@ -58,25 +91,29 @@ where
         return Ok(default);
     }
 
-    let content =
-        fs::read_to_string(&path).map_err(|e| crate::error::AugentError::ConfigReadFailed {
-            path: path.display().to_string(),
-            reason: e.to_string(),
-        })?;
+    let content = fs::read_to_string(&path).map_err(|e| AugentError::ConfigReadFailed {
+        path: path.display().to_string(),
+        reason: e.to_string(),
+    })?;
 
     parser(&content)
 }
 
 /// Save bundle configuration to a directory
+///
+/// Comments in an existing augent.yaml are preserved.
 pub fn save_bundle_config(
     config_dir: &Path,
     config: &BundleConfig,
     workspace_name: &str,
 ) -> Result<()> {
     let path = config_dir.join(BUNDLE_CONFIG_FILE);
-    let content = config.to_yaml(workspace_name)?;
+    let content = match fs::read_to_string(&path) {
+        Ok(existing) => config.to_yaml_preserving_comments(workspace_name, &existing)?,
+        Err(_) => config.to_yaml(workspace_name)?,
+    };
 
-    fs::write(&path, content).map_err(|e| crate::error::AugentError::FileWriteFailed {
+    fs::write(&path, content).map_err(|e| AugentError::FileWriteFailed {
         path: path.display().to_string(),
         reason: e.to_string(),
     })
@@ -86,22 +123,29 @@ pub fn save_bundle_config(
 ///
 /// Uses an atomic write (temp file + rename) so that readers never
 /// observe a partially written `augent.lock`, which is especially
-/// important under concurrent `install`/`list` operations.
-pub fn save_lockfile(config_dir: &Path, lockfile: &Lockfile, workspace_name: &str) -> Result<()> {
-    let path = config_dir.join(LOCKFILE_NAME);
+/// important under concurrent `install`/`list` operations. With a profile,
+/// the profile's lockfile is written instead.
+pub fn save_lockfile(
+    config_dir: &Path,
+    lockfile: &Lockfile,
+    workspace_name: &str,
+    profile: Option<&str>,
+) -> Result<()> {
+    let lockfile_name = lockfile_name(profile);
+    let path = config_dir.join(&lockfile_name);
     let content = lockfile.to_json(workspace_name)?;
 
     // Write to a temporary file in the same directory first, then
     // atomically rename it into place. This avoids readers ever seeing
     // a truncated or half-written lockfile.
-    let tmp_path = config_dir.join(format!("{LOCKFILE_NAME}.tmp"));
+    let tmp_path = config_dir.join(format!("{lockfile_name}.tmp"));
 
-    fs::write(&tmp_path, &content).map_err(|e| crate::error::AugentError::FileWriteFailed {
+    fs::write(&tmp_path, &content).map_err(|e| AugentError::FileWriteFailed {
         path: tmp_path.display().to_string(),
         reason: e.to_string(),
     })?;
 
-    fs::rename(&tmp_path, &path).map_err(|e| crate::error::AugentError::FileWriteFailed {
+    fs::rename(&tmp_path, &path).map_err(|e| AugentError::FileWriteFailed {
         path: path.display().to_string(),
         reason: e.to_string(),
     })
@@ -116,8 +160,43 @@ pub fn save_workspace_config(
     let path = config_dir.join(WORKSPACE_INDEX_FILE);
     let content = config.to_yaml(workspace_name)?;
 
-    fs::write(&path, content).map_err(|e| crate::error::AugentError::FileWriteFailed {
+    fs::write(&path, content).map_err(|e| AugentError::FileWriteFailed {
         path: path.display().to_string(),
         reason: e.to_string(),
     })
 }
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lockfile_name_for_profile() {
+        assert_eq!(lockfile_name(None), "augent.lock");
+        assert_eq!(lockfile_name(Some("ci")), "augent.ci.lock");
+    }
+
+    #[test]
+    fn test_profile_lockfile_falls_back_to_default() {
+        let temp = tempfile::TempDir::new_in(crate::temp::temp_dir_base())
+            .expect("Failed to create temp directory");
+        fs::write(temp.path().join(LOCKFILE_NAME), "{ invalid").expect("Failed to write");
+
+        // Without augent.ci.lock, the default lockfile is read
+        assert!(load_lockfile(temp.path(), Some("ci")).is_err());
+
+        let json = Lockfile::new()
+            .to_json("ws")
+            .expect("Failed to serialize lockfile");
+        fs::write(temp.path().join("augent.ci.lock"), json).expect("Failed to write");
+        assert!(load_lockfile(temp.path(), Some("ci")).is_ok());
+    }
+
+    #[test]
+    fn test_validate_profile_rejects_invalid_names() {
+        assert!(validate_profile("../ci").is_err());
+        assert!(validate_profile("").is_err());
+        assert!(validate_profile("ci_2-dev").is_ok());
+    }
+}
@@ -29,6 +29,8 @@ pub struct SaveContext<'a> {
     pub should_create_augent_yaml: bool,
     /// Optional path to directory where bundle's augent.yaml should be written
     pub bundle_config_dir: Option<&'a Path>,
+    /// Lockfile profile, whose `augent.<profile>.lock` is written instead
+    pub profile: Option<&'a str>,
 }
 
 /// Save all workspace configuration files to the config directory
@@ -55,6 +57,7 @@ pub struct SaveContext<'a> {
 ///     workspace_name: &name,
 ///     should_create_augent_yaml: true,
 ///     bundle_config_dir: None,
+///     profile: None,
 /// };
 ///
 /// save(&ctx)?;
@@ -73,6 +76,7 @@ pub fn save(ctx: &SaveContext<'_>) -> Result<()> {
         workspace_name: ctx.workspace_name,
         should_create_augent_yaml: ctx.should_create_augent_yaml,
         bundle_config_dir: ctx.bundle_config_dir,
+        profile: ctx.profile,
     };
     operations::save_workspace_configs(&save_ctx)
 }
@@ -114,7 +118,11 @@ mod tests {
                 name: "test-bundle".to_string(),
                 path: Some("./test".to_string()),
                 git: None,
+                archive: None,
+                oci: None,
                 git_ref: None,
+                version: None,
+                platform_refs: std::collections::BTreeMap::new(),
             });
         workspace.lockfile.add_bundle(crate::config::LockedBundle {
             name: "test-bundle".to_string(),
@@ -128,6 +136,7 @@ mod tests {
                 hash: "test-hash".to_string(),
             },
             files: vec![],
+            platform_refs: std::collections::BTreeMap::new(),
         });
         workspace
             .config
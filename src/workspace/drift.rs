@@ -0,0 +1,206 @@
+//! Dependency ref drift detection
+//!
+//! A dependency's `ref` in augent.yaml can be edited without reinstalling,
+//! leaving the lockfile pinned to what the old ref resolved to. This module
+//! compares each declared git ref with the lockfile's resolved ref and SHA.
+//!
+//! Local dir bundles drift too: their files can be edited after install. The
+//! content hash recorded for each dir bundle in the lockfile is compared with
+//! the hash of its directory now.
+
+use std::fmt;
+use std::path::Path;
+
+use crate::config::utils::BundleContainer;
+use crate::config::{BundleConfig, BundleDependency, LockedSource, Lockfile};
+use crate::git::refs::TAG_GLOB_PREFIX;
+
+/// A dependency whose declared ref no longer matches the lockfile
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RefDrift {
+    /// Dependency name
+    pub name: String,
+    /// Ref declared in augent.yaml
+    pub declared: String,
+    /// Ref and SHA pinned in the lockfile, or `None` when the dependency is not locked
+    pub locked: Option<(String, String)>,
+}
+
+impl fmt::Display for RefDrift {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.locked {
+            Some((git_ref, sha)) => {
+                let short_sha = sha.get(..7).unwrap_or(sha);
+                write!(
+                    f,
+                    "{}: augent.yaml says {}, lock pins {git_ref} ({short_sha})",
+                    self.name, self.declared
+                )
+            }
+            None => write!(
+                f,
+                "{}: augent.yaml says {}, but it is not in the lockfile",
+                self.name, self.declared
+            ),
+        }
+    }
+}
+
+/// Git dependencies whose declared ref differs from the locked one
+///
+/// Dependencies without a declared ref follow the remote default and are not
+/// checked. A declared SHA matches when the locked SHA starts with it, and a
+/// `glob:` ref when the locked tag matches the pattern.
+pub fn detect_ref_drift(bundle_config: &BundleConfig, lockfile: &Lockfile) -> Vec<RefDrift> {
+    bundle_config
+        .bundles
+        .iter()
+        .filter(|dep| dep.is_git())
+        .filter_map(|dep| drift_for(dep, lockfile))
+        .collect()
+}
+
+fn drift_for(dep: &BundleDependency, lockfile: &Lockfile) -> Option<RefDrift> {
+    let declared = dep.git_ref.as_ref()?;
+    let locked = match lockfile.find_bundle(&dep.name).map(|b| &b.source) {
+        Some(LockedSource::Git { git_ref, sha, .. }) => {
+            let git_ref = git_ref.clone().unwrap_or_else(|| "main".to_string());
+            if ref_matches(declared, &git_ref, sha) {
+                return None;
+            }
+            Some((git_ref, sha.clone()))
+        }
+        _ => None,
+    };
+    Some(RefDrift {
+        name: dep.name.clone(),
+        declared: declared.clone(),
+        locked,
+    })
+}
+
+/// Dir bundles whose directory content no longer matches the locked hash
+///
+/// The workspace root itself (`path: .`) is not checked, since installing
+/// writes into it. Bundles whose directory is missing are not reported.
+pub fn detect_changed_dir_bundles(workspace_root: &Path, lockfile: &Lockfile) -> Vec<String> {
+    lockfile
+        .bundles
+        .iter()
+        .filter_map(|bundle| match &bundle.source {
+            LockedSource::Dir { path, hash } if path != "." => {
+                let current = crate::hash::hash_directory(&workspace_root.join(path)).ok()?;
+                (!crate::hash::verify_hash(hash, &current)).then(|| bundle.name.clone())
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+fn ref_matches(declared: &str, locked_ref: &str, locked_sha: &str) -> bool {
+    if declared == locked_ref {
+        return true;
+    }
+    if let Some(pattern) = declared.strip_prefix(TAG_GLOB_PREFIX) {
+        return crate::workspace::path::matches_glob(pattern, locked_ref);
+    }
+    declared.len() >= 7
+        && declared.chars().all(|c| c.is_ascii_hexdigit())
+        && locked_sha.starts_with(declared)
+}
+
+#[cfg(test)]
+#[allow(clippy::expect_used)]
+mod tests {
+    use super::*;
+    use crate::config::LockedBundle;
+
+    const SHA: &str = "0123456789abcdef0123456789abcdef01234567";
+
+    fn locked(name: &str, git_ref: &str) -> LockedBundle {
+        LockedBundle {
+            name: name.to_string(),
+            source: LockedSource::Git {
+                url: "https://github.com/owner/repo.git".to_string(),
+                path: None,
+                git_ref: Some(git_ref.to_string()),
+                sha: SHA.to_string(),
+                hash: "blake3:abc".to_string(),
+            },
+            description: None,
+            version: None,
+            author: None,
+            license: None,
+            homepage: None,
+            files: vec![],
+            platform_refs: std::collections::BTreeMap::new(),
+        }
+    }
+
+    fn dependency(name: &str, git_ref: &str) -> BundleDependency {
+        BundleDependency::git(
+            name,
+            "https://github.com/owner/repo.git",
+            Some(git_ref.to_string()),
+        )
+    }
+
+    #[test]
+    fn test_detect_changed_dir_bundles() {
+        let temp = tempfile::TempDir::new_in(crate::temp::temp_dir_base())
+            .expect("Failed to create temp dir");
+        let bundle_dir = temp.path().join("bundles/tools");
+        std::fs::create_dir_all(bundle_dir.join("commands")).expect("Failed to create bundle");
+        std::fs::write(bundle_dir.join("commands/review.md"), "# Review\n")
+            .expect("Failed to write command");
+        let hash = crate::hash::hash_directory(&bundle_dir).expect("Failed to hash bundle");
+        let lockfile = Lockfile {
+            bundles: vec![LockedBundle {
+                source: LockedSource::Dir {
+                    path: "bundles/tools".to_string(),
+                    hash,
+                },
+                ..locked("tools", "v1")
+            }],
+        };
+
+        assert!(detect_changed_dir_bundles(temp.path(), &lockfile).is_empty());
+
+        std::fs::write(bundle_dir.join("commands/review.md"), "# Review v2\n")
+            .expect("Failed to modify command");
+        assert_eq!(
+            detect_changed_dir_bundles(temp.path(), &lockfile),
+            vec!["tools".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_detect_ref_drift_reports_changed_ref() {
+        let config = BundleConfig {
+            bundles: vec![
+                dependency("@owner/repo", "v2"),
+                dependency("@owner/tagged", "glob:release-*"),
+                dependency("@owner/pinned", "0123456"),
+                dependency("@owner/missing", "v1"),
+            ],
+            ..BundleConfig::default()
+        };
+        let lockfile = Lockfile {
+            bundles: vec![
+                locked("@owner/repo", "v1"),
+                locked("@owner/tagged", "release-3"),
+                locked("@owner/pinned", "0123456"),
+            ],
+        };
+
+        let drift = detect_ref_drift(&config, &lockfile);
+
+        assert_eq!(drift.len(), 2);
+        assert_eq!(
+            drift[0].to_string(),
+            "@owner/repo: augent.yaml says v2, lock pins v1 (0123456)"
+        );
+        assert_eq!(drift[1].name, "@owner/missing");
+        assert!(drift[1].locked.is_none());
+    }
+}
@@ -1,6 +1,7 @@
 //! Git repository operations for workspace management
 
 use normpath::PathExt;
+use std::fmt::Write as _;
 use std::path::{Path, PathBuf};
 
 use crate::error::{AugentError, Result};
@@ -91,6 +92,65 @@ pub fn verify_git_root(path: &Path) -> Result<()> {
     Ok(())
 }
 
+/// Most files named in the uncommitted changes warning
+const MAX_LISTED_CHANGES: usize = 5;
+
+/// Tracked files under `dirs` (relative to the workspace root) with uncommitted changes
+///
+/// Untracked and ignored files are not reported. Returns nothing when the
+/// workspace is not in a git repository.
+pub fn uncommitted_changes(workspace_root: &Path, dirs: &[PathBuf]) -> Vec<String> {
+    let Ok(repo) = git2::Repository::discover(workspace_root) else {
+        return Vec::new();
+    };
+    let Some(workdir) = repo.workdir().and_then(|p| p.canonicalize().ok()) else {
+        return Vec::new();
+    };
+    let root = workspace_root
+        .canonicalize()
+        .unwrap_or_else(|_| workspace_root.to_path_buf());
+    let Ok(prefix) = root.strip_prefix(&workdir) else {
+        return Vec::new();
+    };
+
+    let mut options = git2::StatusOptions::new();
+    options.include_untracked(false).include_ignored(false);
+    for dir in dirs {
+        options.pathspec(prefix.join(dir).to_string_lossy().replace('\\', "/"));
+    }
+    let Ok(statuses) = repo.statuses(Some(&mut options)) else {
+        return Vec::new();
+    };
+    let mut changed: Vec<String> = statuses
+        .iter()
+        .filter(|entry| entry.status() != git2::Status::CURRENT)
+        .filter_map(|entry| entry.path().map(str::to_string))
+        .collect();
+    changed.sort();
+    changed
+}
+
+/// Warning that install may overwrite uncommitted changes under `dirs`, if there are any
+pub fn uncommitted_changes_warning(workspace_root: &Path, dirs: &[PathBuf]) -> Option<String> {
+    let changed = uncommitted_changes(workspace_root, dirs);
+    if changed.is_empty() {
+        return None;
+    }
+    let mut listed = changed
+        .iter()
+        .take(MAX_LISTED_CHANGES)
+        .cloned()
+        .collect::<Vec<_>>()
+        .join(", ");
+    if changed.len() > MAX_LISTED_CHANGES {
+        let _ = write!(listed, " and {} more", changed.len() - MAX_LISTED_CHANGES);
+    }
+    Some(format!(
+        "uncommitted changes in platform directories may be overwritten by install: {listed} \
+         (commit or stash them, or pass --allow-dirty)"
+    ))
+}
+
 #[cfg(test)]
 #[allow(clippy::expect_used)]
 mod tests {
@@ -112,4 +172,43 @@ mod tests {
         let result = validate_git_repository_root(temp.path());
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_uncommitted_changes_warning_lists_dirty_tracked_platform_files() {
+        let temp =
+            TempDir::new_in(crate::temp::temp_dir_base()).expect("Failed to create temp directory");
+        let repo = git2::Repository::init(temp.path()).expect("Failed to init git repository");
+        let commands = temp.path().join(".claude/commands");
+        std::fs::create_dir_all(&commands).expect("Failed to create dir");
+        std::fs::write(commands.join("review.md"), "Review.\n").expect("Failed to write");
+        std::fs::write(temp.path().join("notes.md"), "Notes.\n").expect("Failed to write");
+
+        let mut index = repo.index().expect("Failed to open index");
+        index
+            .add_all(["*"], git2::IndexAddOption::DEFAULT, None)
+            .expect("Failed to stage files");
+        index.write().expect("Failed to write index");
+        let tree = repo
+            .find_tree(index.write_tree().expect("Failed to write tree"))
+            .expect("Failed to find tree");
+        let signature =
+            git2::Signature::now("Augent", "augent@example.com").expect("Failed to sign");
+        repo.commit(Some("HEAD"), &signature, &signature, "init", &tree, &[])
+            .expect("Failed to commit");
+
+        let dirs = [PathBuf::from(".claude")];
+        assert_eq!(uncommitted_changes_warning(temp.path(), &dirs), None);
+
+        // Changes outside platform directories and untracked files are not reported
+        std::fs::write(temp.path().join("notes.md"), "Edited.\n").expect("Failed to write");
+        std::fs::write(commands.join("new.md"), "New.\n").expect("Failed to write");
+        assert_eq!(uncommitted_changes_warning(temp.path(), &dirs), None);
+
+        std::fs::write(commands.join("review.md"), "Edited.\n").expect("Failed to write");
+        let warning = uncommitted_changes_warning(temp.path(), &dirs).expect("Expected a warning");
+        assert!(warning.contains(".claude/commands/review.md"));
+        assert!(warning.contains("--allow-dirty"));
+        assert!(!warning.contains("notes.md"));
+        assert!(!warning.contains("new.md"));
+    }
 }
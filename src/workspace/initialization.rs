@@ -1,5 +1,6 @@
 //! Workspace initialization utilities
 
+use std::fmt::Write;
 use std::fs;
 use std::path::{Path, PathBuf};
 
@@ -59,9 +60,9 @@ pub fn infer_workspace_name(root: &Path) -> String {
 ///
 /// Creates a new workspace if one doesn't exist,
 /// or opens an existing one if it does.
-pub fn init_or_open(root: &Path) -> Result<InitializedWorkspace> {
+pub fn init_or_open(root: &Path, profile: Option<&str>) -> Result<InitializedWorkspace> {
     if let Some(existing_root) = super::detection::find_from(root) {
-        open(&existing_root)
+        open(&existing_root, profile)
     } else {
         init(root)
     }
@@ -70,8 +71,9 @@ pub fn init_or_open(root: &Path) -> Result<InitializedWorkspace> {
 /// Open an existing workspace at git repository root
 ///
 /// Loads workspace configuration from .augent/ directory.
-/// Configuration files (augent.yaml, augent.lock, augent.index.yaml) are loaded from .augent/.
-pub fn open(root: &Path) -> Result<InitializedWorkspace> {
+/// Configuration files (augent.yaml, augent.lock, augent.index.yaml) are loaded from .augent/,
+/// the lockfile of `profile` when one is given.
+pub fn open(root: &Path, profile: Option<&str>) -> Result<InitializedWorkspace> {
     git::verify_git_root(root)?;
 
     let augent_dir = root.join(WORKSPACE_DIR);
@@ -82,9 +84,13 @@ pub fn open(root: &Path) -> Result<InitializedWorkspace> {
         });
     }
 
+    if let Some(warning) = shadowed_config_warning(root, &augent_dir) {
+        eprintln!("{warning}");
+    }
+
     let config_dir = augent_dir.clone();
-    let bundle_config = super::config::load_bundle_config(&config_dir)?;
-    let lockfile = super::config::load_lockfile(&config_dir)?;
+    let bundle_config = load_workspace_bundle_config(root, &config_dir)?;
+    let lockfile = super::config::load_lockfile(&config_dir, profile)?;
     let workspace_config = super::config::load_workspace_config(&config_dir)?;
 
     let workspace_name = infer_workspace_name(root);
@@ -107,6 +113,54 @@ pub fn open(root: &Path) -> Result<InitializedWorkspace> {
     })
 }
 
+/// Config files that exist both at the repository root and in `.augent/`
+///
+/// The `.augent/` copies are authoritative; the root copies are ignored.
+fn shadowed_config_files(root: &Path, augent_dir: &Path) -> Vec<&'static str> {
+    use super::config::{BUNDLE_CONFIG_FILE, LOCKFILE_NAME, WORKSPACE_INDEX_FILE};
+
+    [BUNDLE_CONFIG_FILE, LOCKFILE_NAME, WORKSPACE_INDEX_FILE]
+        .into_iter()
+        .filter(|name| root.join(name).is_file() && augent_dir.join(name).is_file())
+        .collect()
+}
+
+/// Warning naming root config files shadowed by their `.augent/` counterparts
+fn shadowed_config_warning(root: &Path, augent_dir: &Path) -> Option<String> {
+    let shadowed = shadowed_config_files(root, augent_dir);
+    if shadowed.is_empty() {
+        return None;
+    }
+    let mut warning = format!(
+        "Warning: Config files found in both {} and {}; using {} and ignoring:",
+        root.display(),
+        augent_dir.display(),
+        augent_dir.display()
+    );
+    for name in shadowed {
+        let _ = write!(warning, "\n  {}", root.join(name).display());
+    }
+    warning.push_str("\nRemove the ignored files to avoid confusion.");
+    Some(warning)
+}
+
+/// Load the workspace bundle config, falling back to `package.json` dependencies
+///
+/// The `augent.bundles` array of the repository's `package.json` is only used
+/// when `.augent/augent.yaml` does not exist.
+fn load_workspace_bundle_config(root: &Path, config_dir: &Path) -> Result<BundleConfig> {
+    let mut bundle_config = super::config::load_bundle_config(config_dir)?;
+    if config_dir.join(super::config::BUNDLE_CONFIG_FILE).exists() {
+        return Ok(bundle_config);
+    }
+    if let Some(bundles) = crate::config::load_from_package_json(
+        &root.join(crate::config::package_json::PACKAGE_JSON_FILE),
+    ) {
+        bundle_config.bundles = bundles;
+    }
+    Ok(bundle_config)
+}
+
 #[cfg(test)]
 #[allow(clippy::expect_used)]
 mod tests {
@@ -143,15 +197,54 @@ mod tests {
     fn test_workspace_init_or_open() {
         let (temp, _path) = create_git_repo();
 
-        let workspace1 = init_or_open(temp.path()).expect("Failed to init or open workspace");
+        let workspace1 = init_or_open(temp.path(), None).expect("Failed to init or open workspace");
         let name1 = infer_workspace_name(&workspace1.root);
 
-        let workspace2 = init_or_open(temp.path()).expect("Failed to init or open workspace");
+        let workspace2 = init_or_open(temp.path(), None).expect("Failed to init or open workspace");
         let name2 = infer_workspace_name(&workspace2.root);
 
         assert_eq!(name2, name1);
     }
 
+    #[test]
+    fn test_workspace_open_uses_package_json_bundles() {
+        let (temp, _path) = create_git_repo();
+        init(temp.path()).expect("Failed to init workspace");
+        std::fs::write(
+            temp.path().join("package.json"),
+            r#"{"augent": {"bundles": [{"name": "@acme/review", "git": "https://github.com/acme/review.git"}]}}"#,
+        )
+        .expect("Failed to write package.json");
+
+        let workspace = open(temp.path(), None).expect("Failed to open workspace");
+        assert_eq!(workspace.bundle_config.bundles.len(), 1);
+        assert_eq!(workspace.bundle_config.bundles[0].name, "@acme/review");
+    }
+
+    #[test]
+    fn test_shadowed_config_warning_names_root_files() {
+        let (temp, _path) = create_git_repo();
+        let workspace = init(temp.path()).expect("Failed to init workspace");
+        std::fs::write(temp.path().join(BUNDLE_CONFIG_FILE), "bundles: []\n")
+            .expect("Failed to write root augent.yaml");
+        std::fs::write(temp.path().join(LOCKFILE_NAME), "{}").expect("Failed to write root lock");
+        std::fs::write(
+            workspace.augent_dir.join(BUNDLE_CONFIG_FILE),
+            "bundles: []\n",
+        )
+        .expect("Failed to write .augent/augent.yaml");
+
+        let warning = shadowed_config_warning(temp.path(), &workspace.augent_dir)
+            .expect("Dual-location augent.yaml should warn");
+        assert!(warning.contains(&temp.path().join(BUNDLE_CONFIG_FILE).display().to_string()));
+        // augent.lock only exists at the root, so it is not shadowed
+        assert!(!warning.contains(LOCKFILE_NAME));
+
+        std::fs::remove_file(temp.path().join(BUNDLE_CONFIG_FILE))
+            .expect("Failed to remove root augent.yaml");
+        assert!(shadowed_config_warning(temp.path(), &workspace.augent_dir).is_none());
+    }
+
     #[test]
     fn test_infer_workspace_name() {
         let name = infer_workspace_name(Path::new("/home/user/my-project"));
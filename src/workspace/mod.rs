@@ -25,6 +25,7 @@
 pub mod config;
 pub mod config_operations;
 pub mod detection;
+pub mod drift;
 pub mod git;
 pub mod init;
 pub mod initialization;
@@ -71,6 +72,9 @@ pub struct Workspace {
     /// When set, augent.yaml is written to this directory instead of `workspace.config_dir`
     /// This is used when installing from a subdirectory that is itself a bundle
     pub bundle_config_dir: Option<PathBuf>,
+
+    /// Lockfile profile (`--profile`); its `augent.<profile>.lock` replaces augent.lock
+    pub profile: Option<String>,
 }
 
 impl Workspace {
@@ -84,22 +88,28 @@ impl Workspace {
     }
 
     pub fn open(root: &Path) -> Result<Self> {
-        let initialized = initialization::open(root)?;
-        Ok(Self::from_initialized(initialized))
+        Self::open_with_profile(root, None)
+    }
+
+    /// Open the workspace with the lockfile of `profile` (`--profile`)
+    pub fn open_with_profile(root: &Path, profile: Option<&str>) -> Result<Self> {
+        let initialized = initialization::open(root, profile)?;
+        Ok(Self::from_initialized(initialized, profile))
     }
 
     pub fn init(root: &Path) -> Result<Self> {
         let initialized = initialization::init(root)?;
-        Ok(Self::from_initialized(initialized))
+        Ok(Self::from_initialized(initialized, None))
     }
 
     pub fn get_workspace_name(&self) -> String {
         initialization::infer_workspace_name(&self.root)
     }
 
-    pub fn init_or_open(root: &Path) -> Result<Self> {
-        let initialized = initialization::init_or_open(root)?;
-        Ok(Self::from_initialized(initialized))
+    /// Open or initialize the workspace with the lockfile of `profile` (`--profile`)
+    pub fn init_or_open(root: &Path, profile: Option<&str>) -> Result<Self> {
+        let initialized = initialization::init_or_open(root, profile)?;
+        Ok(Self::from_initialized(initialized, profile))
     }
 
     pub fn get_bundle_source_path(&self) -> PathBuf {
@@ -122,11 +132,12 @@ impl Workspace {
             workspace_name: &self.get_workspace_name(),
             should_create_augent_yaml: self.should_create_augent_yaml,
             bundle_config_dir: self.bundle_config_dir.as_deref(),
+            profile: self.profile.as_deref(),
         };
         config_operations::save(&ctx)
     }
 
-    fn from_initialized(init: initialization::InitializedWorkspace) -> Self {
+    fn from_initialized(init: initialization::InitializedWorkspace, profile: Option<&str>) -> Self {
         Self {
             root: init.root,
             augent_dir: init.augent_dir,
@@ -136,6 +147,7 @@ impl Workspace {
             config: init.workspace_config,
             should_create_augent_yaml: init.should_create_augent_yaml,
             bundle_config_dir: init.bundle_config_dir,
+            profile: profile.map(str::to_string),
         }
     }
 }
@@ -179,13 +191,29 @@ mod tests {
     fn test_workspace_init_or_open() {
         let (_temp, path) = create_git_repo();
 
-        let workspace1 = Workspace::init_or_open(&path).expect("Failed to init or open workspace");
+        let workspace1 =
+            Workspace::init_or_open(&path, None).expect("Failed to init or open workspace");
         let name1 = workspace1.get_workspace_name();
 
-        let workspace2 = Workspace::init_or_open(&path).expect("Failed to init or open workspace");
+        let workspace2 =
+            Workspace::init_or_open(&path, None).expect("Failed to init or open workspace");
         assert_eq!(workspace2.get_workspace_name(), name1);
     }
 
+    #[test]
+    fn test_workspace_saves_the_lockfile_of_its_profile() {
+        let (temp, path) = create_git_repo();
+        Workspace::init(&path).expect("Failed to init workspace");
+
+        let workspace =
+            Workspace::open_with_profile(&path, Some("ci")).expect("Failed to open workspace");
+        workspace.save().expect("Failed to save workspace");
+
+        let augent_dir = temp.path().join(WORKSPACE_DIR);
+        assert!(augent_dir.join("augent.ci.lock").is_file());
+        assert!(!augent_dir.join(LOCKFILE_NAME).exists());
+    }
+
     #[test]
     fn test_workspace_get_bundle_source_path() {
         let (_temp, path) = create_git_repo();
@@ -134,6 +134,19 @@ fn get_original_hash(
             let file_path = resources_path.join(source_path);
             hash::hash_file(&file_path).ok()
         }
+        LockedSource::Archive { url, path, .. } => {
+            let bundle_dir = crate::cache::archive::cached_archive_path(url, path.as_deref());
+            hash::hash_file(&bundle_dir.join(source_path)).ok()
+        }
+        LockedSource::Oci {
+            reference,
+            digest,
+            path,
+            ..
+        } => {
+            let bundle_dir = crate::cache::oci::cached_oci_path(reference, digest, path.as_deref());
+            hash::hash_file(&bundle_dir.join(source_path)).ok()
+        }
     }
 }
 
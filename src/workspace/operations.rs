@@ -16,6 +16,7 @@ pub struct SaveWorkspaceConfigsContext<'a> {
     pub workspace_name: &'a str,
     pub should_create_augent_yaml: bool,
     pub bundle_config_dir: Option<&'a Path>,
+    pub profile: Option<&'a str>,
 }
 
 /// Rebuild workspace configuration by scanning filesystem for installed files
@@ -89,19 +90,17 @@ fn add_if_exists(candidate_path: &Path, root: &Path) -> Option<String> {
 ///
 /// Uses platform definitions from `PlatformLoader` to detect
 /// which platforms are installed, making this truly platform-independent.
-fn detect_installed_platforms(root: &Path) -> Result<Vec<std::path::PathBuf>> {
+pub fn detect_installed_platforms(root: &Path) -> Result<Vec<std::path::PathBuf>> {
     let mut platforms = Vec::new();
 
     // Get all known platforms from platform definitions (including custom platforms.jsonc)
     let loader = crate::platform::loader::PlatformLoader::new(root);
     let known_platforms = loader.load()?;
 
-    // Check each platform's directory for existence
-    for platform in known_platforms {
-        let platform_dir = root.join(&platform.directory);
-        let is_valid_dir = platform_dir.exists() && platform_dir.is_dir();
-        if is_valid_dir {
-            platforms.push(platform_dir);
+    // Check each platform's directory (or a directory matching its glob) for existence
+    for platform in &known_platforms {
+        if let Some(directory) = platform.matched_directory(root, &known_platforms) {
+            platforms.push(root.join(directory));
         }
     }
 
@@ -135,7 +134,12 @@ pub fn save_workspace_configs(ctx: &SaveWorkspaceConfigsContext) -> Result<()> {
     let mut ordered_workspace_config = ctx.workspace_config.clone();
     ordered_workspace_config.reorganize(&ordered_lockfile);
 
-    crate::workspace::config::save_lockfile(ctx.config_dir, &ordered_lockfile, ctx.workspace_name)?;
+    crate::workspace::config::save_lockfile(
+        ctx.config_dir,
+        &ordered_lockfile,
+        ctx.workspace_name,
+        ctx.profile,
+    )?;
 
     if ctx.should_create_augent_yaml {
         let augent_yaml_dir = ctx.bundle_config_dir.unwrap_or(ctx.config_dir);
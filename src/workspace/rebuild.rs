@@ -80,6 +80,7 @@ pub struct RebuildContext<'a> {
 ///     workspace_name: &name,
 ///     should_create_augent_yaml: false,
 ///     bundle_config_dir: None,
+///     profile: None,
 /// };
 ///
 /// rebuild_and_save(&rebuild_ctx, &save_ctx)?;
@@ -100,6 +101,7 @@ pub fn rebuild_and_save(
         workspace_name: save_ctx.workspace_name,
         should_create_augent_yaml: save_ctx.should_create_augent_yaml,
         bundle_config_dir: save_ctx.bundle_config_dir,
+        profile: save_ctx.profile,
     };
 
     crate::workspace::config_operations::save(&updated_save_ctx)
@@ -0,0 +1,164 @@
+//! Tests for cleaning augent-managed platform files
+#![allow(clippy::expect_used)] // Idiomatic for test assertions
+
+mod common;
+
+const INDEX_WITH_TRACKED_FILES: &str = r#"name: "@test/workspace"
+bundles:
+  - name: my-bundle
+    enabled:
+      commands/debug.md:
+        - .claude/commands/debug.md
+        - .cursor/commands/debug.md
+      rules/lint.md:
+        - .cursor/rules/lint.mdc
+"#;
+
+fn setup_workspace() -> common::TestWorkspace {
+    let workspace = common::TestWorkspace::new();
+    workspace.init_from_fixture("empty");
+    workspace.write_file(".augent/augent.index.yaml", INDEX_WITH_TRACKED_FILES);
+    workspace.write_file(".claude/commands/debug.md", "# Debug\n");
+    workspace.write_file(".cursor/commands/debug.md", "# Debug\n");
+    workspace.write_file(".cursor/rules/lint.mdc", "# Lint\n");
+    workspace.write_file(".cursor/rules/my-own-rule.mdc", "# Mine\n");
+    workspace
+}
+
+#[test]
+fn test_clean_platform_removes_tracked_files_only() {
+    let workspace = setup_workspace();
+
+    common::augent_cmd_for_workspace(&workspace.path)
+        .args(["clean", "--platform", "cursor"])
+        .assert()
+        .success();
+
+    assert!(!workspace.file_exists(".cursor/commands/debug.md"));
+    assert!(!workspace.file_exists(".cursor/rules/lint.mdc"));
+    assert!(workspace.file_exists(".cursor/rules/my-own-rule.mdc"));
+    assert!(workspace.file_exists(".claude/commands/debug.md"));
+
+    let index = workspace.read_file(".augent/augent.index.yaml");
+    assert!(!index.contains(".cursor/"));
+    assert!(index.contains(".claude/commands/debug.md"));
+}
+
+#[test]
+fn test_clean_all_platforms() {
+    let workspace = setup_workspace();
+
+    common::augent_cmd_for_workspace(&workspace.path)
+        .args(["clean", "--all-platforms"])
+        .assert()
+        .success();
+
+    assert!(!workspace.file_exists(".claude/commands/debug.md"));
+    assert!(!workspace.file_exists(".cursor/commands/debug.md"));
+    assert!(workspace.file_exists(".cursor/rules/my-own-rule.mdc"));
+}
+
+#[test]
+fn test_clean_dry_run_keeps_files() {
+    let workspace = setup_workspace();
+
+    common::augent_cmd_for_workspace(&workspace.path)
+        .args(["clean", "--platform", "cursor", "--dry-run"])
+        .assert()
+        .success()
+        .stdout(predicates::str::contains("Would remove 2 file(s)."));
+
+    assert!(workspace.file_exists(".cursor/commands/debug.md"));
+}
+
+#[test]
+fn test_clean_reports_removal_failure_and_keeps_entry_indexed() {
+    let workspace = common::TestWorkspace::new();
+    workspace.init_from_fixture("empty");
+    workspace.write_file(
+        ".augent/augent.index.yaml",
+        r#"name: "@test/workspace"
+bundles:
+  - name: my-bundle
+    enabled:
+      commands/debug.md:
+        - .cursor/commands/debug.md
+"#,
+    );
+    // A directory in place of the tracked file cannot be removed with remove_file
+    workspace.write_file(".cursor/commands/debug.md/keep.md", "# Keep\n");
+
+    common::augent_cmd_for_workspace(&workspace.path)
+        .args(["clean", "--platform", "cursor"])
+        .assert()
+        .failure();
+
+    assert!(workspace.file_exists(".cursor/commands/debug.md/keep.md"));
+    let index = workspace.read_file(".augent/augent.index.yaml");
+    assert!(index.contains(".cursor/commands/debug.md"));
+}
+
+#[test]
+fn test_clean_unmerges_mcp_servers_and_keeps_user_servers() {
+    let workspace = common::TestWorkspace::new();
+    workspace.init_from_fixture("empty");
+    workspace.write_file(
+        ".augent/augent.index.yaml",
+        r#"name: "@test/workspace"
+bundles:
+  - name: my-bundle
+    enabled:
+      mcp_servers/github.json:
+        - .cursor/mcp.json
+    mcp_servers:
+      .cursor/mcp.json:
+        - github
+"#,
+    );
+    workspace.write_file(
+        ".cursor/mcp.json",
+        r#"{"mcpServers": {"github": {"command": "gh"}, "mine": {"command": "mine"}}}"#,
+    );
+
+    common::augent_cmd_for_workspace(&workspace.path)
+        .args(["clean", "--platform", "cursor"])
+        .assert()
+        .success();
+
+    let config = workspace.read_file(".cursor/mcp.json");
+    assert!(!config.contains("github"));
+    assert!(config.contains("mine"));
+
+    let index = workspace.read_file(".augent/augent.index.yaml");
+    assert!(!index.contains(".cursor/mcp.json"));
+}
+
+#[test]
+fn test_clean_removes_only_bundle_section_from_composite_file() {
+    let workspace = common::TestWorkspace::new();
+    workspace.init_from_fixture("empty");
+    workspace.write_file(
+        ".augent/augent.index.yaml",
+        r#"name: "@test/workspace"
+bundles:
+  - name: my-bundle
+    enabled:
+      AGENTS.md:
+        - .claude/AGENTS.md
+"#,
+    );
+    workspace.write_file(
+        ".claude/AGENTS.md",
+        "Local notes.\n\n<!-- augent:begin my-bundle -->\nBundle notes.\n<!-- augent:end my-bundle -->\n",
+    );
+
+    common::augent_cmd_for_workspace(&workspace.path)
+        .args(["clean", "--platform", "claude"])
+        .assert()
+        .success();
+
+    let content = workspace.read_file(".claude/AGENTS.md");
+    assert_eq!(content, "Local notes.\n");
+    let index = workspace.read_file(".augent/augent.index.yaml");
+    assert!(!index.contains(".claude/AGENTS.md"));
+}
@@ -0,0 +1,50 @@
+//! Tests for local bundle changes reported by `augent doctor`
+#![allow(clippy::expect_used)] // Idiomatic for test assertions
+
+mod common;
+
+use predicates::prelude::PredicateBooleanExt;
+
+fn install_tools_bundle() -> common::TestWorkspace {
+    let workspace = common::TestWorkspace::new();
+    workspace.init_from_fixture("empty");
+    workspace.create_agent_dir("claude");
+    workspace.create_bundle("tools");
+    workspace.write_file("bundles/tools/commands/review.md", "# Review\n");
+
+    common::augent_cmd_for_workspace(&workspace.path)
+        .args(["install", "./bundles/tools", "-y"])
+        .assert()
+        .success();
+    workspace
+}
+
+#[test]
+fn test_doctor_reports_modified_dir_bundle() {
+    let workspace = install_tools_bundle();
+    workspace.write_file("bundles/tools/commands/review.md", "# Review v2\n");
+
+    common::augent_cmd_for_workspace(&workspace.path)
+        .args(["doctor"])
+        .assert()
+        .success()
+        .stdout(predicates::str::contains(
+            "Local bundles changed since install (1):",
+        ))
+        .stdout(predicates::str::contains("tools"))
+        .stdout(predicates::str::contains(
+            "Run 'augent install' to reinstall them.",
+        ));
+}
+
+#[test]
+fn test_doctor_unmodified_dir_bundle_has_no_problems() {
+    let workspace = install_tools_bundle();
+
+    common::augent_cmd_for_workspace(&workspace.path)
+        .args(["doctor"])
+        .assert()
+        .success()
+        .stdout(predicates::str::contains("No problems found."))
+        .stdout(predicates::str::contains("Local bundles changed").not());
+}
@@ -0,0 +1,63 @@
+//! Tests for dependency ref drift reported by `augent doctor`
+#![allow(clippy::expect_used)] // Idiomatic for test assertions
+
+mod common;
+
+use predicates::prelude::PredicateBooleanExt;
+
+const LOCKFILE: &str = r#"{
+  "name": "@test/workspace",
+  "bundles": [
+    {
+      "name": "@owner/repo",
+      "source": {
+        "type": "git",
+        "url": "https://github.com/owner/repo.git",
+        "ref": "v1",
+        "sha": "0123456789abcdef0123456789abcdef01234567",
+        "hash": "blake3:abc123"
+      },
+      "files": []
+    }
+  ]
+}"#;
+
+fn setup_workspace(declared_ref: &str) -> common::TestWorkspace {
+    let workspace = common::TestWorkspace::new();
+    workspace.init_from_fixture("empty");
+    workspace.write_file(".augent/augent.lock", LOCKFILE);
+    workspace.write_file(
+        ".augent/augent.yaml",
+        &format!(
+            "name: \"@test/workspace\"\nbundles:\n  - name: \"@owner/repo\"\n    \
+             git: https://github.com/owner/repo.git\n    ref: {declared_ref}\n"
+        ),
+    );
+    workspace
+}
+
+#[test]
+fn test_doctor_reports_ref_drift() {
+    let workspace = setup_workspace("v2");
+
+    common::augent_cmd_for_workspace(&workspace.path)
+        .args(["doctor"])
+        .assert()
+        .success()
+        .stdout(predicates::str::contains("Dependency drift (1):"))
+        .stdout(predicates::str::contains(
+            "@owner/repo: augent.yaml says v2, lock pins v1 (0123456)",
+        ));
+}
+
+#[test]
+fn test_doctor_no_drift_when_refs_match() {
+    let workspace = setup_workspace("v1");
+
+    common::augent_cmd_for_workspace(&workspace.path)
+        .args(["doctor"])
+        .assert()
+        .success()
+        .stdout(predicates::str::contains("No problems found."))
+        .stdout(predicates::str::contains("drift").not());
+}
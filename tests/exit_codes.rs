@@ -0,0 +1,31 @@
+//! Tests for exit codes encoding the failure category
+#![allow(clippy::expect_used)] // Idiomatic for test assertions
+
+mod common;
+
+/// `augent::error::exit_code::VALIDATION`
+const VALIDATION_EXIT_CODE: i32 = 4;
+
+#[test]
+fn test_validation_error_exits_with_validation_code() {
+    let workspace = common::TestWorkspace::new();
+    workspace.init_from_fixture("empty");
+    workspace.create_agent_dir("cursor");
+
+    common::augent_cmd_for_workspace(&workspace.path)
+        .args(["--profile", "not a profile!", "list"])
+        .assert()
+        .code(VALIDATION_EXIT_CODE)
+        .stderr(predicates::str::contains("Error:"));
+}
+
+#[test]
+fn test_usage_error_exits_with_clap_code() {
+    let workspace = common::TestWorkspace::new();
+    workspace.init_from_fixture("empty");
+
+    common::augent_cmd_for_workspace(&workspace.path)
+        .args(["install", "--no-such-flag"])
+        .assert()
+        .code(2);
+}
@@ -0,0 +1,81 @@
+//! Tests for `augent freeze`
+#![allow(clippy::expect_used)] // Idiomatic for test assertions
+
+mod common;
+
+use std::path::Path;
+
+fn copy_tree(from: &Path, to: &Path) {
+    std::fs::create_dir_all(to).expect("Failed to create directory");
+    for entry in std::fs::read_dir(from).expect("Failed to read directory") {
+        let entry = entry.expect("Failed to read directory entry");
+        let target = to.join(entry.file_name());
+        if entry.path().is_dir() {
+            copy_tree(&entry.path(), &target);
+        } else {
+            std::fs::copy(entry.path(), &target).expect("Failed to copy file");
+        }
+    }
+}
+
+#[test]
+fn test_freeze_two_bundles_installs_same_result() {
+    let workspace = common::TestWorkspace::new();
+    workspace.init_from_fixture("empty");
+    workspace.create_agent_dir("cursor");
+    workspace.create_bundle("review-tools");
+    workspace.write_file("bundles/review-tools/commands/review.md", "# Review\n");
+    workspace.create_bundle("style-rules");
+    workspace.write_file("bundles/style-rules/rules/style.md", "# Style\n");
+
+    for source in ["./bundles/review-tools", "./bundles/style-rules"] {
+        common::augent_cmd_for_workspace(&workspace.path)
+            .args(["install", source, "--to", "cursor", "-y"])
+            .assert()
+            .success();
+    }
+
+    common::augent_cmd_for_workspace(&workspace.path)
+        .args(["freeze", "vendor/frozen"])
+        .assert()
+        .success();
+
+    let frozen_yaml = workspace.read_file("vendor/frozen/augent.yaml");
+    assert!(frozen_yaml.contains("./vendor/frozen/bundles/review-tools"));
+    assert!(frozen_yaml.contains("./vendor/frozen/bundles/style-rules"));
+
+    // A fresh workspace without the original bundle sources
+    let reproduced = common::TestWorkspace::new();
+    reproduced.init_from_fixture("empty");
+    reproduced.create_agent_dir("cursor");
+    copy_tree(
+        &workspace.path.join("vendor/frozen"),
+        &reproduced.path.join("vendor/frozen"),
+    );
+
+    common::augent_cmd_for_workspace(&reproduced.path)
+        .args(["install", "./vendor/frozen", "--to", "cursor", "-y"])
+        .assert()
+        .success();
+
+    for installed in [".cursor/commands/review.md", ".cursor/rules/style.md"] {
+        assert_eq!(
+            reproduced.read_file(installed),
+            workspace.read_file(installed),
+            "{installed} should match the original install"
+        );
+    }
+}
+
+#[test]
+fn test_freeze_refuses_non_empty_directory() {
+    let workspace = common::TestWorkspace::new();
+    workspace.init_from_fixture("empty");
+    workspace.write_file("notes/todo.md", "keep me\n");
+
+    common::augent_cmd_for_workspace(&workspace.path)
+        .args(["freeze", "notes"])
+        .assert()
+        .failure();
+    assert_eq!(workspace.read_file("notes/todo.md"), "keep me\n");
+}
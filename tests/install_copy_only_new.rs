@@ -0,0 +1,40 @@
+//! Tests for `install --copy-only-new`
+#![allow(clippy::expect_used)] // Idiomatic for test assertions
+
+mod common;
+
+use predicates::prelude::PredicateBooleanExt;
+
+#[test]
+fn test_copy_only_new_leaves_existing_target_untouched() {
+    let workspace = common::TestWorkspace::new();
+    workspace.init_from_fixture("empty");
+    workspace.create_agent_dir("cursor");
+    workspace.write_file(".cursor/commands/review.md", "hand-made review\n");
+
+    workspace.create_bundle("tools");
+    workspace.write_file("bundles/tools/commands/review.md", "# Review\n");
+    workspace.write_file("bundles/tools/commands/fix.md", "# Fix\n");
+
+    common::augent_cmd_for_workspace(&workspace.path)
+        .args([
+            "install",
+            "./bundles/tools",
+            "--to",
+            "cursor",
+            "--copy-only-new",
+            "-y",
+        ])
+        .assert()
+        .success()
+        .stderr(
+            predicates::str::contains("Skipped existing")
+                .and(predicates::str::contains("review.md")),
+        );
+
+    assert_eq!(
+        workspace.read_file(".cursor/commands/review.md"),
+        "hand-made review\n"
+    );
+    assert_eq!(workspace.read_file(".cursor/commands/fix.md"), "# Fix\n");
+}
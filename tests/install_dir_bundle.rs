@@ -162,3 +162,294 @@ fn test_install_existing_dir_bundle_path() {
     // Verify the updated file was installed
     assert!(workspace.path.join(".cursor/commands/updated.md").exists());
 }
+
+#[test]
+fn test_install_lock_only_updates_lockfile_without_files() {
+    let workspace = common::TestWorkspace::new();
+    workspace.init_from_fixture("empty");
+    workspace.create_agent_dir("cursor");
+
+    workspace.create_bundle("locked-bundle");
+    workspace.write_file(
+        "bundles/locked-bundle/commands/locked.md",
+        "# Locked Command\n",
+    );
+
+    common::augent_cmd_for_workspace(&workspace.path)
+        .args(["install", "./bundles/locked-bundle", "--lock-only", "-y"])
+        .assert()
+        .success();
+
+    let lockfile = std::fs::read_to_string(workspace.path.join(".augent/augent.lock"))
+        .expect("Failed to read augent.lock");
+    assert!(lockfile.contains("locked-bundle"));
+
+    assert!(!workspace.path.join(".cursor/commands/locked.md").exists());
+}
+
+#[test]
+fn test_install_summary_only_prints_counts_without_file_lines() {
+    let workspace = common::TestWorkspace::new();
+    workspace.init_from_fixture("empty");
+    workspace.create_agent_dir("cursor");
+
+    workspace.create_bundle("summary-bundle");
+    workspace.write_file(
+        "bundles/summary-bundle/commands/first.md",
+        "# First Command\n",
+    );
+    workspace.write_file(
+        "bundles/summary-bundle/commands/second.md",
+        "# Second Command\n",
+    );
+
+    common::augent_cmd_for_workspace(&workspace.path)
+        .args([
+            "install",
+            "./bundles/summary-bundle",
+            "--to",
+            "cursor",
+            "--summary-only",
+            "-y",
+        ])
+        .assert()
+        .success()
+        .stdout(predicates::str::contains(
+            "Installed 1 bundle(s), 2 file(s)",
+        ))
+        .stdout(predicates::str::contains("cursor: 2 file(s)"))
+        .stdout(predicates::str::contains("  - summary-bundle").not())
+        .stdout(predicates::str::contains("(commands)").not());
+
+    assert!(workspace.path.join(".cursor/commands/first.md").exists());
+}
+
+#[test]
+fn test_install_defaults_to_present_platforms_only() {
+    let workspace = common::TestWorkspace::new();
+    workspace.init_from_fixture("empty");
+    workspace.create_agent_dir("cursor");
+
+    workspace.create_bundle("present-bundle");
+    workspace.write_file("bundles/present-bundle/commands/present.md", "# Present\n");
+
+    common::augent_cmd_for_workspace(&workspace.path)
+        .args(["install", "./bundles/present-bundle", "-y"])
+        .assert()
+        .success();
+
+    assert!(workspace.file_exists(".cursor/commands/present.md"));
+    assert!(!workspace.file_exists(".claude"));
+}
+
+#[test]
+fn test_install_platforms_create_adds_missing_platform() {
+    let workspace = common::TestWorkspace::new();
+    workspace.init_from_fixture("empty");
+    workspace.create_agent_dir("cursor");
+
+    workspace.create_bundle("create-bundle");
+    workspace.write_file("bundles/create-bundle/commands/create.md", "# Create\n");
+
+    common::augent_cmd_for_workspace(&workspace.path)
+        .args([
+            "install",
+            "./bundles/create-bundle",
+            "--platforms-create",
+            "claude",
+            "-y",
+        ])
+        .assert()
+        .success();
+
+    assert!(workspace.file_exists(".cursor/commands/create.md"));
+    assert!(workspace.file_exists(".claude/commands/create.md"));
+}
+
+#[test]
+fn test_install_with_stamp_writes_provenance_comment() {
+    let workspace = common::TestWorkspace::new();
+    workspace.init_from_fixture("empty");
+    workspace.create_agent_dir("cursor");
+
+    workspace.create_bundle("stamped-bundle");
+    workspace.write_file("bundles/stamped-bundle/commands/stamped.md", "# Stamped\n");
+
+    common::augent_cmd_for_workspace(&workspace.path)
+        .args([
+            "install",
+            "./bundles/stamped-bundle",
+            "--to",
+            "cursor",
+            "--stamp",
+            "-y",
+        ])
+        .assert()
+        .success();
+
+    let content = workspace.read_file(".cursor/commands/stamped.md");
+    assert!(content.starts_with("<!-- Generated by augent from stamped-bundle"));
+    assert!(content.contains("# Stamped"));
+}
+
+#[test]
+fn test_install_uses_platform_directory_from_env() {
+    let workspace = common::TestWorkspace::new();
+    workspace.init_from_fixture("empty");
+    // Detection looks for the relocated directory, not .cursor/
+    std::fs::create_dir_all(workspace.path.join("tools/cursor"))
+        .expect("Failed to create relocated platform directory");
+
+    workspace.create_bundle("relocated-bundle");
+    workspace.write_file(
+        "bundles/relocated-bundle/commands/relocated.md",
+        "# Relocated\n",
+    );
+
+    common::augent_cmd_for_workspace(&workspace.path)
+        .env("AUGENT_PLATFORM_DIR_CURSOR", "tools/cursor")
+        .args([
+            "install",
+            "./bundles/relocated-bundle",
+            "--to",
+            "cursor",
+            "-y",
+        ])
+        .assert()
+        .success();
+
+    assert!(workspace.file_exists("tools/cursor/commands/relocated.md"));
+    assert!(!workspace.file_exists(".cursor/commands/relocated.md"));
+}
+
+#[test]
+fn test_install_with_var_renders_template_placeholders() {
+    let workspace = common::TestWorkspace::new();
+    workspace.init_from_fixture("empty");
+    workspace.create_agent_dir("cursor");
+
+    workspace.create_bundle("templated-bundle");
+    workspace.write_file(
+        "bundles/templated-bundle/commands/templated.md",
+        "Team {{team}} owns this.\n\n```\n{{team}}\n```\n",
+    );
+
+    common::augent_cmd_for_workspace(&workspace.path)
+        .args([
+            "install",
+            "./bundles/templated-bundle",
+            "--to",
+            "cursor",
+            "--var",
+            "team=core",
+            "-y",
+        ])
+        .assert()
+        .success();
+
+    let content = workspace.read_file(".cursor/commands/templated.md");
+    assert!(content.contains("Team core owns this."));
+    assert!(content.contains("```\n{{team}}\n```"));
+}
+
+#[test]
+fn test_install_with_ignore_scripts_skips_skill_scripts() {
+    let workspace = common::TestWorkspace::new();
+    workspace.init_from_fixture("empty");
+    workspace.create_agent_dir("claude");
+
+    workspace.create_bundle("scripted-bundle");
+    workspace.write_file(
+        "bundles/scripted-bundle/skills/deploy/SKILL.md",
+        "---\nname: deploy\ndescription: Deploy the app\n---\n\n# Deploy\n",
+    );
+    workspace.write_file(
+        "bundles/scripted-bundle/skills/deploy/run.sh",
+        "#!/bin/sh\necho deploying\n",
+    );
+
+    common::augent_cmd_for_workspace(&workspace.path)
+        .args([
+            "install",
+            "./bundles/scripted-bundle",
+            "--to",
+            "claude",
+            "--ignore-scripts",
+            "-y",
+        ])
+        .assert()
+        .success()
+        .stderr(predicates::str::contains("Skipped script"));
+
+    assert!(workspace.file_exists(".claude/skills/deploy/SKILL.md"));
+    assert!(!workspace.file_exists(".claude/skills/deploy/run.sh"));
+}
+
+#[test]
+fn test_install_settings_only_writes_only_mcp_config() {
+    let workspace = common::TestWorkspace::new();
+    workspace.init_from_fixture("empty");
+    workspace.create_agent_dir("claude");
+
+    workspace.create_bundle("settings-bundle");
+    workspace.write_file("bundles/settings-bundle/commands/review.md", "# Review\n");
+    workspace.write_file(
+        "bundles/settings-bundle/mcp_servers/github.json",
+        r#"{"command": "github-mcp"}"#,
+    );
+
+    common::augent_cmd_for_workspace(&workspace.path)
+        .args([
+            "install",
+            "./bundles/settings-bundle",
+            "--to",
+            "claude",
+            "--settings-only",
+            "-y",
+        ])
+        .assert()
+        .success();
+
+    assert!(workspace.read_file(".mcp.json").contains("github-mcp"));
+    assert!(!workspace.file_exists(".claude/commands/review.md"));
+}
+
+#[test]
+fn test_install_platform_summary_json_lists_files_per_platform() {
+    let workspace = common::TestWorkspace::new();
+    workspace.init_from_fixture("empty");
+    workspace.create_agent_dir("cursor");
+    workspace.create_agent_dir("claude");
+
+    workspace.create_bundle("summary-bundle");
+    workspace.write_file("bundles/summary-bundle/commands/review.md", "# Review\n");
+
+    common::augent_cmd_for_workspace(&workspace.path)
+        .args([
+            "install",
+            "./bundles/summary-bundle",
+            "--to",
+            "cursor",
+            "claude",
+            "--platform-summary-json",
+            "summary.json",
+            "-y",
+        ])
+        .assert()
+        .success();
+
+    let summary: serde_json::Value =
+        serde_json::from_str(&workspace.read_file("summary.json")).expect("Invalid summary JSON");
+    for (platform, path) in [
+        ("cursor", ".cursor/commands/review.md"),
+        ("claude", ".claude/commands/review.md"),
+    ] {
+        let entries = summary[platform]
+            .as_array()
+            .expect("Platform manifest should be an array");
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0]["path"], path);
+        assert_eq!(entries[0]["bundle"], "summary-bundle");
+        assert_eq!(entries[0]["source"], "commands/review.md");
+    }
+}
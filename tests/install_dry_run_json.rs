@@ -0,0 +1,66 @@
+//! Tests for the JSON install plan printed by `augent install --dry-run --json`
+#![allow(clippy::expect_used)] // Idiomatic for test assertions
+
+mod common;
+
+use predicates::prelude::PredicateBooleanExt;
+
+fn setup_workspace() -> common::TestWorkspace {
+    let workspace = common::TestWorkspace::new();
+    workspace.init_from_fixture("empty");
+    workspace.create_agent_dir("claude");
+    workspace.create_bundle("tools");
+    workspace.write_file("bundles/tools/commands/review.md", "# Review\n");
+    workspace.write_file("bundles/tools/commands/debug.md", "# Debug\n");
+    workspace.write_file(".claude/commands/debug.md", "# Local debug\n");
+    workspace
+}
+
+#[test]
+fn test_install_dry_run_json_prints_plan() {
+    let workspace = setup_workspace();
+
+    let output = common::augent_cmd_for_workspace(&workspace.path)
+        .args(["install", "./bundles/tools", "--dry-run", "--json", "-y"])
+        .output()
+        .expect("Failed to run augent");
+    assert!(output.status.success());
+
+    let plan: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("stdout should be a JSON document");
+    let operations = plan["operations"]
+        .as_array()
+        .expect("plan should list operations");
+    let find = |source: &str| {
+        operations
+            .iter()
+            .find(|op| op["source"] == source)
+            .expect("operation should be planned")
+    };
+
+    let review = find("commands/review.md");
+    assert_eq!(review["bundle"], "tools");
+    assert_eq!(review["platform"], "claude");
+    assert_eq!(review["target"], ".claude/commands/review.md");
+    assert_eq!(review["merge"], "replace");
+    assert_eq!(review["action"], "new");
+    assert_eq!(find("commands/debug.md")["action"], "overwrite");
+
+    assert!(!workspace.file_exists(".claude/commands/review.md"));
+    assert_eq!(
+        workspace.read_file(".claude/commands/debug.md"),
+        "# Local debug\n"
+    );
+}
+
+#[test]
+fn test_install_dry_run_without_json_keeps_human_output() {
+    let workspace = setup_workspace();
+
+    common::augent_cmd_for_workspace(&workspace.path)
+        .args(["install", "./bundles/tools", "--dry-run", "-y"])
+        .assert()
+        .success()
+        .stdout(predicates::str::contains("[DRY RUN] Would install"))
+        .stdout(predicates::str::contains("\"operations\"").not());
+}
@@ -0,0 +1,82 @@
+//! Tests for refusing installs through platform directories that escape the workspace
+#![allow(clippy::expect_used)] // Idiomatic for test assertions
+
+mod common;
+
+#[cfg(unix)]
+fn symlink_claude_outside(workspace: &common::TestWorkspace) -> tempfile::TempDir {
+    let outside = tempfile::TempDir::new().expect("Failed to create outside dir");
+    std::os::unix::fs::symlink(outside.path(), workspace.path.join(".claude"))
+        .expect("Failed to create .claude symlink");
+    outside
+}
+
+#[cfg(unix)]
+#[test]
+fn test_install_refuses_symlinked_platform_dir_outside_workspace() {
+    let workspace = common::TestWorkspace::new();
+    workspace.init_from_fixture("empty");
+    let outside = symlink_claude_outside(&workspace);
+
+    workspace.create_bundle("tools");
+    workspace.write_file("bundles/tools/commands/review.md", "# Review\n");
+
+    common::augent_cmd_for_workspace(&workspace.path)
+        .args(["install", "./bundles/tools", "-y"])
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains("outside of the workspace"));
+
+    assert!(!outside.path().join("commands/review.md").exists());
+}
+
+#[cfg(unix)]
+#[test]
+fn test_platforms_create_refuses_symlinked_ancestor_before_creating_dirs() {
+    let workspace = common::TestWorkspace::new();
+    workspace.init_from_fixture("empty");
+    let outside = tempfile::TempDir::new().expect("Failed to create outside dir");
+    std::os::unix::fs::symlink(outside.path(), workspace.path.join("tools"))
+        .expect("Failed to create tools symlink");
+
+    workspace.create_bundle("tools-bundle");
+    workspace.write_file("bundles/tools-bundle/commands/review.md", "# Review\n");
+
+    common::augent_cmd_for_workspace(&workspace.path)
+        .env("AUGENT_PLATFORM_DIR_CURSOR", "tools/cursor")
+        .args([
+            "install",
+            "./bundles/tools-bundle",
+            "--platforms-create",
+            "cursor",
+            "-y",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains("outside of the workspace"));
+
+    assert!(!outside.path().join("cursor").exists());
+}
+
+#[cfg(unix)]
+#[test]
+fn test_install_allow_external_targets_writes_through_symlink() {
+    let workspace = common::TestWorkspace::new();
+    workspace.init_from_fixture("empty");
+    let outside = symlink_claude_outside(&workspace);
+
+    workspace.create_bundle("tools");
+    workspace.write_file("bundles/tools/commands/review.md", "# Review\n");
+
+    common::augent_cmd_for_workspace(&workspace.path)
+        .args([
+            "install",
+            "./bundles/tools",
+            "--allow-external-targets",
+            "-y",
+        ])
+        .assert()
+        .success();
+
+    assert!(outside.path().join("commands/review.md").exists());
+}
@@ -0,0 +1,38 @@
+//! Tests for `augent install --link` symlinking files back to the bundle source
+#![allow(clippy::expect_used)] // Idiomatic for test assertions
+
+mod common;
+
+#[cfg(unix)]
+#[test]
+fn test_install_link_symlinks_unchanged_files() {
+    let workspace = common::TestWorkspace::new();
+    workspace.init_from_fixture("empty");
+    workspace.create_agent_dir("claude");
+    workspace.create_bundle("tools");
+    workspace.write_file("bundles/tools/commands/plain.md", "Review the diff.\n");
+    workspace.write_file(
+        "bundles/tools/commands/review.md",
+        "---\ndescription: Review\n---\n\nReview.\n",
+    );
+
+    common::augent_cmd_for_workspace(&workspace.path)
+        .args(["install", "./bundles/tools", "--link", "-y"])
+        .assert()
+        .success();
+
+    let is_symlink = |path: &str| {
+        std::fs::symlink_metadata(workspace.path.join(path))
+            .expect("Installed file should exist")
+            .file_type()
+            .is_symlink()
+    };
+    assert!(is_symlink(".claude/commands/plain.md"));
+    assert!(!is_symlink(".claude/commands/review.md"));
+
+    workspace.write_file("bundles/tools/commands/plain.md", "Review everything.\n");
+    assert_eq!(
+        workspace.read_file(".claude/commands/plain.md"),
+        "Review everything.\n"
+    );
+}
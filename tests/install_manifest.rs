@@ -0,0 +1,43 @@
+//! Tests for `install --manifest`
+#![allow(clippy::expect_used)] // Idiomatic for test assertions
+
+mod common;
+
+#[test]
+fn test_install_manifest_uses_sources_and_platforms() {
+    let workspace = common::TestWorkspace::new();
+    workspace.init_from_fixture("empty");
+    workspace.create_agent_dir("cursor");
+    workspace.create_agent_dir("claude");
+
+    workspace.create_bundle("review-tools");
+    workspace.write_file("bundles/review-tools/commands/review.md", "# Review\n");
+    workspace.write_file(
+        "augent.toml",
+        "sources = [\"./bundles/review-tools\"]\nplatforms = [\"claude\"]\n",
+    );
+
+    common::augent_cmd_for_workspace(&workspace.path)
+        .args(["install", "--manifest", "augent.toml", "-y"])
+        .assert()
+        .success()
+        .stdout(predicates::str::contains(
+            "Installing ./bundles/review-tools",
+        ));
+
+    assert!(workspace.file_exists(".claude/commands/review.md"));
+    assert!(!workspace.file_exists(".cursor/commands/review.md"));
+}
+
+#[test]
+fn test_install_manifest_rejects_empty_sources() {
+    let workspace = common::TestWorkspace::new();
+    workspace.init_from_fixture("empty");
+    workspace.write_file("augent.json", r#"{"sources": [], "platforms": ["claude"]}"#);
+
+    common::augent_cmd_for_workspace(&workspace.path)
+        .args(["install", "--manifest", "augent.json", "-y"])
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains("manifest lists no sources"));
+}
@@ -0,0 +1,42 @@
+//! Tests for installing `mcp_servers/` files into platform MCP configs
+#![allow(clippy::expect_used)] // Idiomatic for test assertions
+
+mod common;
+
+#[test]
+fn test_install_merges_mcp_servers_into_platform_configs() {
+    let workspace = common::TestWorkspace::new();
+    workspace.init_from_fixture("empty");
+    workspace.create_agent_dir("claude");
+    workspace.create_agent_dir("cursor");
+    workspace.create_bundle("tools");
+    workspace.write_file("bundles/tools/commands/hello.md", "# Hello\n");
+    workspace.write_file(
+        "bundles/tools/mcp_servers/foo.json",
+        r#"{"command": "foo-mcp", "args": ["--stdio"]}"#,
+    );
+
+    common::augent_cmd_for_workspace(&workspace.path)
+        .args([
+            "install",
+            "./bundles/tools",
+            "--to",
+            "claude",
+            "cursor",
+            "-y",
+        ])
+        .assert()
+        .success();
+
+    for config in [".mcp.json", ".cursor/mcp.json"] {
+        let json: serde_json::Value =
+            serde_json::from_str(&workspace.read_file(config)).expect("MCP config is not JSON");
+        assert_eq!(
+            json["mcpServers"]["foo"]["command"], "foo-mcp",
+            "foo server missing from {config}"
+        );
+    }
+    // Server files are merged, not copied into the platform directories
+    assert!(!workspace.file_exists(".claude/mcp_servers/foo.json"));
+    assert!(!workspace.file_exists(".cursor/mcp_servers/foo.json"));
+}
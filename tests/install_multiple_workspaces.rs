@@ -0,0 +1,72 @@
+//! Tests for installing one source into several workspaces
+#![allow(clippy::expect_used)] // Idiomatic for test assertions
+
+mod common;
+
+fn git(repo: &std::path::Path, args: &[&str]) {
+    let status = std::process::Command::new("git")
+        .args(args)
+        .current_dir(repo)
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .status()
+        .expect("Failed to run git");
+    assert!(status.success(), "git {args:?} failed");
+}
+
+#[test]
+fn test_install_git_bundle_into_two_workspaces() {
+    let first = common::TestWorkspace::new();
+    first.init_from_fixture("empty");
+    first.create_agent_dir("cursor");
+    let second = common::TestWorkspace::new();
+    second.init_from_fixture("empty");
+    second.create_agent_dir("cursor");
+
+    let repo = first.create_mock_git_repo("shared-repo");
+    std::fs::create_dir_all(repo.join("commands")).expect("Failed to create commands dir");
+    std::fs::write(repo.join("commands/shared.md"), "# Shared\n").expect("Failed to write command");
+    git(&repo, &["add", "."]);
+    git(&repo, &["commit", "-m", "Add command"]);
+    git(&repo, &["branch", "-M", "main"]);
+    // The ref fragment makes a file:// URL a git source rather than a directory
+    let source = format!("file://{}#main", repo.display());
+
+    // Every clone reads AUGENT_CLONE_DEPTH, so an invalid value warns once per clone
+    let output = common::augent_cmd_for_workspace(&first.path)
+        .env("AUGENT_CLONE_DEPTH", "invalid")
+        .args(["install", &source, "--to", "cursor", "-y"])
+        .arg("--workspace")
+        .arg(&first.path)
+        .arg("--workspace")
+        .arg(&second.path)
+        .assert()
+        .success()
+        .stdout(predicates::str::contains(
+            "Installed into 2 of 2 workspaces",
+        ))
+        .get_output()
+        .clone();
+
+    assert!(first.file_exists(".cursor/commands/shared.md"));
+    assert!(second.file_exists(".cursor/commands/shared.md"));
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert_eq!(
+        stderr
+            .matches("Ignoring invalid AUGENT_CLONE_DEPTH")
+            .count(),
+        1,
+        "source was not fetched exactly once:\n{stderr}"
+    );
+
+    let index = std::fs::read_to_string(
+        common::test_cache_dir_for_workspace(&first.path)
+            .join("bundles")
+            .join(".augent_cache_index.json"),
+    )
+    .expect("Failed to read cache index");
+    let entries: Vec<serde_json::Value> =
+        serde_json::from_str(&index).expect("Cache index is not JSON");
+    assert_eq!(entries.len(), 1, "{index}");
+}
@@ -0,0 +1,77 @@
+//! Tests for the offline cache pre-flight of `install --dry-run --offline`
+#![allow(clippy::expect_used)] // Idiomatic for test assertions
+
+mod common;
+
+fn git(repo: &std::path::Path, args: &[&str]) {
+    let status = std::process::Command::new("git")
+        .args(args)
+        .current_dir(repo)
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .status()
+        .expect("Failed to run git");
+    assert!(status.success(), "git {args:?} failed");
+}
+
+/// Repos get a host directory each, since git bundle names come from the URL path
+fn create_repo_with_command(name: &str) -> (common::TestWorkspace, String) {
+    let host = common::TestWorkspace::new();
+    let repo = host.create_mock_git_repo(name);
+    std::fs::create_dir_all(repo.join("commands")).expect("Failed to create commands dir");
+    std::fs::write(repo.join(format!("commands/{name}.md")), "# Command\n")
+        .expect("Failed to write command");
+    git(&repo, &["add", "."]);
+    git(&repo, &["commit", "-m", "Add command"]);
+    git(&repo, &["branch", "-M", "main"]);
+    // The ref fragment makes a file:// URL a git source rather than a directory
+    let source = format!("file://{}#main", repo.display());
+    (host, source)
+}
+
+#[test]
+fn test_dry_run_offline_flags_uncached_bundles() {
+    let workspace = common::TestWorkspace::new();
+    workspace.init_from_fixture("empty");
+    workspace.create_agent_dir("cursor");
+
+    let mut hosts = Vec::new();
+    for name in ["cached-repo", "evicted-repo"] {
+        let (host, source) = create_repo_with_command(name);
+        hosts.push(host);
+        common::augent_cmd_for_workspace(&workspace.path)
+            .args(["install", &source, "--to", "cursor", "-y"])
+            .assert()
+            .success();
+    }
+
+    let bundles_cache = common::test_cache_dir_for_workspace(&workspace.path).join("bundles");
+    for entry in std::fs::read_dir(&bundles_cache)
+        .expect("Failed to read cache dir")
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_name().to_string_lossy().contains("evicted-repo"))
+    {
+        std::fs::remove_dir_all(entry.path()).expect("Failed to evict cache entry");
+    }
+
+    let output = common::augent_cmd_for_workspace(&workspace.path)
+        .args(["install", "--dry-run", "--offline"])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let stdout = String::from_utf8(output).expect("Output should be UTF-8");
+
+    let line_for = |name: &str| {
+        stdout
+            .lines()
+            .find(|line| line.contains(name))
+            .expect("Report should list every locked bundle")
+            .to_string()
+    };
+    assert!(line_for("cached-repo").contains("cached: "));
+    assert!(!line_for("cached-repo").contains("requires network"));
+    assert!(line_for("evicted-repo").contains("requires network: "));
+    assert!(stdout.contains("1 bundle(s) would require a network fetch"));
+}
@@ -0,0 +1,35 @@
+//! Tests for installing the raw bundle layout with `--platform-passthrough`
+#![allow(clippy::expect_used)] // Idiomatic for test assertions
+
+mod common;
+
+const RULE: &str = "---\ndescription: Lint\nglobs: \"*.rs\"\n---\n\nLint everything.\n";
+
+#[test]
+fn test_platform_passthrough_installs_raw_layout() {
+    let workspace = common::TestWorkspace::new();
+    workspace.init_from_fixture("empty");
+    workspace.create_agent_dir("claude");
+    workspace.create_bundle("tools");
+    workspace.write_file("bundles/tools/commands/review.md", "# Review\n");
+    workspace.write_file("bundles/tools/rules/lint.md", RULE);
+
+    common::augent_cmd_for_workspace(&workspace.path)
+        .args([
+            "install",
+            "./bundles/tools",
+            "--platform-passthrough",
+            "cursor",
+            "-y",
+        ])
+        .assert()
+        .success();
+
+    assert_eq!(workspace.read_file(".cursor/rules/lint.md"), RULE);
+    assert_eq!(
+        workspace.read_file(".cursor/commands/review.md"),
+        "# Review\n"
+    );
+    assert!(!workspace.file_exists(".cursor/rules/lint.mdc"));
+    assert!(workspace.file_exists(".claude/commands/review.md"));
+}
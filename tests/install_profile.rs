@@ -0,0 +1,56 @@
+//! Tests for per-profile lockfiles (`--profile`)
+#![allow(clippy::expect_used)] // Idiomatic for test assertions
+
+mod common;
+
+fn install_under_profile(workspace: &common::TestWorkspace, source: &str, profile: &str) {
+    common::augent_cmd_for_workspace(&workspace.path)
+        .args([
+            "install",
+            source,
+            "--to",
+            "cursor",
+            "--profile",
+            profile,
+            "-y",
+        ])
+        .assert()
+        .success();
+}
+
+#[test]
+fn test_install_under_two_profiles_writes_separate_lockfiles() {
+    let workspace = common::TestWorkspace::new();
+    workspace.init_from_fixture("empty");
+    workspace.create_agent_dir("cursor");
+
+    workspace.create_bundle("ci-tools");
+    workspace.write_file("bundles/ci-tools/commands/ci.md", "# CI\n");
+    workspace.create_bundle("dev-tools");
+    workspace.write_file("bundles/dev-tools/commands/dev.md", "# Dev\n");
+
+    install_under_profile(&workspace, "./bundles/ci-tools", "ci");
+    install_under_profile(&workspace, "./bundles/dev-tools", "dev");
+
+    let ci_lock = workspace.read_file(".augent/augent.ci.lock");
+    let dev_lock = workspace.read_file(".augent/augent.dev.lock");
+    assert!(ci_lock.contains("ci-tools"));
+    assert!(!ci_lock.contains("dev-tools"));
+    assert!(dev_lock.contains("dev-tools"));
+    assert_ne!(ci_lock, dev_lock);
+}
+
+#[test]
+fn test_install_rejects_invalid_profile_name() {
+    let workspace = common::TestWorkspace::new();
+    workspace.init_from_fixture("empty");
+    workspace.create_agent_dir("cursor");
+    workspace.create_bundle("ci-tools");
+    workspace.write_file("bundles/ci-tools/commands/ci.md", "# CI\n");
+
+    common::augent_cmd_for_workspace(&workspace.path)
+        .args(["install", "./bundles/ci-tools", "--profile", "../ci", "-y"])
+        .assert()
+        .failure();
+    assert!(!workspace.file_exists(".cursor/commands/ci.md"));
+}
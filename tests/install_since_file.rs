@@ -0,0 +1,61 @@
+//! Tests for incremental dir bundle reinstalls (`install --since-file`)
+#![allow(clippy::expect_used)] // Idiomatic for test assertions
+
+mod common;
+
+use std::time::{Duration, SystemTime};
+
+fn install_since(workspace: &common::TestWorkspace) {
+    common::augent_cmd_for_workspace(&workspace.path)
+        .args([
+            "install",
+            "./bundles/local",
+            "--to",
+            "cursor",
+            "--since-file",
+            ".augent/since",
+            "-y",
+        ])
+        .assert()
+        .success();
+}
+
+#[test]
+fn test_since_file_reinstalls_only_modified_resources() {
+    let workspace = common::TestWorkspace::new();
+    workspace.init_from_fixture("empty");
+    workspace.create_agent_dir("cursor");
+    workspace.create_bundle("local");
+    workspace.write_file("bundles/local/commands/edited.md", "# Edited\n");
+    workspace.write_file("bundles/local/commands/untouched.md", "# Untouched\n");
+
+    install_since(&workspace);
+    assert!(workspace.file_exists(".augent/since"));
+    assert_eq!(
+        workspace.read_file(".cursor/commands/untouched.md"),
+        "# Untouched\n"
+    );
+
+    // Tamper with both installed copies so a rewrite is visible
+    workspace.write_file(".cursor/commands/edited.md", "stale\n");
+    workspace.write_file(".cursor/commands/untouched.md", "stale\n");
+
+    // Edit one source after the marker was touched
+    workspace.write_file("bundles/local/commands/edited.md", "# Edited again\n");
+    std::fs::File::options()
+        .append(true)
+        .open(workspace.path.join("bundles/local/commands/edited.md"))
+        .and_then(|f| f.set_modified(SystemTime::now() + Duration::from_secs(60)))
+        .expect("Failed to bump source mtime");
+
+    install_since(&workspace);
+
+    assert_eq!(
+        workspace.read_file(".cursor/commands/edited.md"),
+        "# Edited again\n"
+    );
+    assert_eq!(
+        workspace.read_file(".cursor/commands/untouched.md"),
+        "stale\n"
+    );
+}
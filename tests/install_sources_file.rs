@@ -0,0 +1,65 @@
+//! Tests for `install --sources-file`
+#![allow(clippy::expect_used)] // Idiomatic for test assertions
+
+mod common;
+
+fn git(repo: &std::path::Path, args: &[&str]) {
+    let status = std::process::Command::new("git")
+        .args(args)
+        .current_dir(repo)
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .status()
+        .expect("Failed to run git");
+    assert!(status.success(), "git {args:?} failed");
+}
+
+#[test]
+fn test_install_sources_file_installs_each_source_in_order() {
+    let workspace = common::TestWorkspace::new();
+    workspace.init_from_fixture("empty");
+    workspace.create_agent_dir("cursor");
+
+    workspace.create_bundle("local-bundle");
+    workspace.write_file("bundles/local-bundle/commands/local.md", "# Local\n");
+
+    let repo = workspace.create_mock_git_repo("remote-repo");
+    std::fs::create_dir_all(repo.join("commands")).expect("Failed to create commands dir");
+    std::fs::write(repo.join("commands/remote.md"), "# Remote\n").expect("Failed to write command");
+    git(&repo, &["add", "."]);
+    git(&repo, &["commit", "-m", "Add command"]);
+
+    workspace.write_file(
+        "bundles.txt",
+        &format!(
+            "# Team bundles\n./bundles/local-bundle\n\nfile://{}\n",
+            repo.display()
+        ),
+    );
+
+    let output = common::augent_cmd_for_workspace(&workspace.path)
+        .args([
+            "install",
+            "--sources-file",
+            "bundles.txt",
+            "--to",
+            "cursor",
+            "-y",
+        ])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let stdout = String::from_utf8(output).expect("Output should be UTF-8");
+
+    assert!(workspace.file_exists(".cursor/commands/local.md"));
+    assert!(workspace.file_exists(".cursor/commands/remote.md"));
+    let local = stdout
+        .find("Installing ./bundles/local-bundle")
+        .expect("Local source should be installed");
+    let remote = stdout
+        .find("Installing file://")
+        .expect("Git source should be installed");
+    assert!(local < remote);
+}
@@ -0,0 +1,79 @@
+//! Tests for `augent install --strict` failing on same-target conflicts
+#![allow(clippy::expect_used)] // Idiomatic for test assertions
+
+mod common;
+
+fn setup_conflicting_bundles() -> common::TestWorkspace {
+    let workspace = common::TestWorkspace::new();
+    workspace.init_from_fixture("empty");
+    workspace.create_agent_dir("claude");
+    workspace.create_bundle("first");
+    workspace.write_file("bundles/first/commands/debug.md", "# Debug (first)\n");
+    workspace.create_bundle("second");
+    workspace.write_file("bundles/second/commands/debug.md", "# Debug (second)\n");
+    workspace.write_file(
+        ".augent/augent.yaml",
+        "name: \"@test/workspace\"\nbundles:\n  - name: first\n    path: ./bundles/first\n  \
+         - name: second\n    path: ./bundles/second\n",
+    );
+    workspace
+}
+
+#[test]
+fn test_install_strict_fails_on_conflict_without_writing() {
+    let workspace = setup_conflicting_bundles();
+
+    common::augent_cmd_for_workspace(&workspace.path)
+        .args(["install", "--strict", "--all-bundles", "-y"])
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains(".claude/commands/debug.md"))
+        .stderr(predicates::str::contains("first"))
+        .stderr(predicates::str::contains("second"));
+
+    assert!(!workspace.file_exists(".claude/commands/debug.md"));
+}
+
+#[test]
+fn test_install_without_strict_warns_and_keeps_last_bundle() {
+    let workspace = setup_conflicting_bundles();
+
+    common::augent_cmd_for_workspace(&workspace.path)
+        .args(["install", "--all-bundles", "-y"])
+        .assert()
+        .success()
+        .stderr(predicates::str::contains("Warning:"));
+
+    assert!(
+        workspace
+            .read_file(".claude/commands/debug.md")
+            .contains("second")
+    );
+}
+
+#[test]
+fn test_install_strict_validates_generated_toml() {
+    let workspace = common::TestWorkspace::new();
+    workspace.init_from_fixture("empty");
+    workspace.create_agent_dir("gemini");
+    workspace.create_bundle("broken");
+    workspace.write_file("bundles/broken/commands/bad.toml", "description = \"\n");
+
+    common::augent_cmd_for_workspace(&workspace.path)
+        .args(["install", "./bundles/broken", "--to", "gemini", "-y"])
+        .assert()
+        .success();
+
+    common::augent_cmd_for_workspace(&workspace.path)
+        .args([
+            "install",
+            "./bundles/broken",
+            "--to",
+            "gemini",
+            "--strict",
+            "-y",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains("bad.toml"));
+}
@@ -0,0 +1,98 @@
+//! Tests for skipping bundles already installed at their locked SHA
+#![allow(clippy::expect_used)] // Idiomatic for test assertions
+
+mod common;
+
+use std::time::{Duration, SystemTime};
+
+use predicates::prelude::PredicateBooleanExt;
+
+fn git(repo: &std::path::Path, args: &[&str]) {
+    let status = std::process::Command::new("git")
+        .args(args)
+        .current_dir(repo)
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .status()
+        .expect("Failed to run git");
+    assert!(status.success(), "git {args:?} failed");
+}
+
+/// Workspace with a cursor directory and the source of a git bundle with one command
+fn workspace_with_git_source() -> (common::TestWorkspace, String) {
+    let workspace = common::TestWorkspace::new();
+    workspace.init_from_fixture("empty");
+    workspace.create_agent_dir("cursor");
+
+    let repo = workspace.create_mock_git_repo("review-repo");
+    std::fs::create_dir_all(repo.join("commands")).expect("Failed to create commands dir");
+    std::fs::write(repo.join("commands/review.md"), "# Review\n").expect("Failed to write command");
+    git(&repo, &["add", "."]);
+    git(&repo, &["commit", "-m", "Add command"]);
+    git(&repo, &["branch", "-M", "main"]);
+    // The ref fragment makes a file:// URL a git source rather than a directory
+    let source = format!("file://{}#main", repo.display());
+    (workspace, source)
+}
+
+#[test]
+fn test_reinstall_of_unchanged_git_bundle_skips_writes() {
+    let (workspace, source) = workspace_with_git_source();
+
+    common::augent_cmd_for_workspace(&workspace.path)
+        .args(["install", &source, "--to", "cursor", "-y"])
+        .assert()
+        .success()
+        .stdout(predicates::str::contains("already installed (unchanged)").not());
+
+    let installed = workspace.path.join(".cursor/commands/review.md");
+    let past = SystemTime::now() - Duration::from_secs(3600);
+    std::fs::File::options()
+        .append(true)
+        .open(&installed)
+        .and_then(|f| f.set_modified(past))
+        .expect("Failed to backdate installed file");
+
+    common::augent_cmd_for_workspace(&workspace.path)
+        .args(["install", &source, "--to", "cursor", "-y"])
+        .assert()
+        .success()
+        .stdout(predicates::str::contains("already installed (unchanged)"));
+
+    let modified = std::fs::metadata(&installed)
+        .and_then(|m| m.modified())
+        .expect("Failed to read mtime");
+    assert_eq!(modified, past, "unchanged bundle should not be rewritten");
+    assert!(
+        workspace
+            .read_file(".augent/augent.lock")
+            .contains("commands/review.md")
+    );
+}
+
+#[test]
+fn test_reinstall_with_content_option_rewrites_unchanged_bundle() {
+    let (workspace, source) = workspace_with_git_source();
+
+    common::augent_cmd_for_workspace(&workspace.path)
+        .args(["install", &source, "--to", "cursor", "-y"])
+        .assert()
+        .success();
+    assert!(
+        !workspace
+            .read_file(".cursor/commands/review.md")
+            .contains("Generated by augent")
+    );
+
+    common::augent_cmd_for_workspace(&workspace.path)
+        .args(["install", &source, "--to", "cursor", "-y", "--stamp"])
+        .assert()
+        .success()
+        .stdout(predicates::str::contains("already installed (unchanged)").not());
+
+    assert!(
+        workspace
+            .read_file(".cursor/commands/review.md")
+            .contains("Generated by augent")
+    );
+}
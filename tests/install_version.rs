@@ -0,0 +1,108 @@
+//! Tests for git dependencies pinned by a semver `version` constraint
+#![allow(clippy::expect_used)] // Idiomatic for test assertions
+
+mod common;
+
+fn git(repo: &std::path::Path, args: &[&str]) {
+    let status = std::process::Command::new("git")
+        .args(args)
+        .current_dir(repo)
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .status()
+        .expect("Failed to run git");
+    assert!(status.success(), "git {args:?} failed");
+}
+
+/// Workspace with the source of a repository tagged `v1.2.0`, `v1.3.0` and `v2.0.0`
+fn workspace_with_tagged_source() -> (common::TestWorkspace, String) {
+    let workspace = common::TestWorkspace::new();
+    workspace.init_from_fixture("empty");
+    workspace.create_agent_dir("cursor");
+
+    let repo = workspace.create_mock_git_repo("review-repo");
+    std::fs::create_dir_all(repo.join("commands")).expect("Failed to create commands dir");
+    for tag in ["v1.2.0", "v1.3.0", "v2.0.0"] {
+        std::fs::write(repo.join("commands/review.md"), format!("# Review {tag}\n"))
+            .expect("Failed to write command");
+        git(&repo, &["add", "."]);
+        git(&repo, &["commit", "-m", tag]);
+        git(&repo, &["tag", tag]);
+    }
+    (workspace, format!("file://{}", repo.display()))
+}
+
+#[test]
+fn test_version_dependency_is_not_backfilled_with_locked_ref() {
+    let (workspace, url) = workspace_with_tagged_source();
+    common::augent_cmd_for_workspace(&workspace.path)
+        .args(["install", &format!("{url}#v1.3.0"), "--to", "cursor", "-y"])
+        .assert()
+        .success();
+
+    // Switch the pinned dependency over to a constraint its locked tag still satisfies
+    let config = workspace.read_file(".augent/augent.yaml");
+    assert!(config.contains("ref: v1.3.0"), "{config}");
+    workspace.write_file(
+        ".augent/augent.yaml",
+        &config.replace("ref: v1.3.0", "version: ^1.2"),
+    );
+
+    workspace.create_bundle("local");
+    workspace.write_file("bundles/local/commands/local.md", "# Local\n");
+    for _ in 0..2 {
+        common::augent_cmd_for_workspace(&workspace.path)
+            .args(["install", "./bundles/local", "--to", "cursor", "-y"])
+            .assert()
+            .success();
+    }
+
+    let config = workspace.read_file(".augent/augent.yaml");
+    assert!(config.contains("version: ^1.2"), "{config}");
+    assert!(!config.contains("ref:"), "{config}");
+}
+
+#[test]
+fn test_offline_install_picks_cached_tag_for_version_dependency() {
+    let (workspace, url) = workspace_with_tagged_source();
+    workspace.create_bundle("app");
+    workspace.write_file(
+        "bundles/app/augent.yaml",
+        &format!(
+            "name: app\nbundles:\n  - name: review-tools\n    git: {url}\n    version: ^1.2\n"
+        ),
+    );
+    common::augent_cmd_for_workspace(&workspace.path)
+        .args(["install", "./bundles/app", "--to", "cursor", "-y"])
+        .assert()
+        .success();
+    assert!(
+        workspace
+            .read_file(".augent/augent.lock")
+            .contains("v1.3.0")
+    );
+
+    // Without the lockfile, the tag can only come from the cache
+    std::fs::remove_file(workspace.path.join(".augent/augent.lock"))
+        .expect("Failed to remove lockfile");
+    common::augent_cmd_for_workspace(&workspace.path)
+        .args([
+            "install",
+            "./bundles/app",
+            "--to",
+            "cursor",
+            "-y",
+            "--offline",
+        ])
+        .assert()
+        .success();
+    assert!(
+        workspace
+            .read_file(".augent/augent.lock")
+            .contains("v1.3.0")
+    );
+    assert_eq!(
+        workspace.read_file(".cursor/commands/review.md"),
+        "# Review v1.3.0\n"
+    );
+}
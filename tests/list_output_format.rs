@@ -0,0 +1,89 @@
+//! Tests for `list --output-format`
+#![allow(clippy::expect_used)] // Idiomatic for test assertions
+
+mod common;
+
+const LOCKFILE: &str = r#"{
+  "name": "@test/workspace",
+  "bundles": [
+    {
+      "name": "local-bundle",
+      "version": "1.2.0",
+      "source": {
+        "type": "dir",
+        "path": "bundles/local-bundle",
+        "hash": "blake3:abc123"
+      },
+      "files": ["commands/review.md", "rules/style.md"]
+    },
+    {
+      "name": "@author/repo",
+      "source": {
+        "type": "git",
+        "url": "https://github.com/author/repo.git",
+        "ref": "main",
+        "sha": "0123456789abcdef0123456789abcdef01234567",
+        "hash": "blake3:def456"
+      },
+      "files": ["commands/deploy.md"]
+    }
+  ]
+}"#;
+
+fn workspace_with_bundles() -> common::TestWorkspace {
+    let workspace = common::TestWorkspace::new();
+    workspace.init_from_fixture("empty");
+    workspace.write_file(".augent/augent.lock", LOCKFILE);
+    workspace.write_file("bundles/local-bundle/commands/review.md", "# Review\n");
+    workspace
+}
+
+fn list_output(workspace: &common::TestWorkspace, format: &str) -> String {
+    let output = common::augent_cmd_for_workspace(&workspace.path)
+        .args(["list", "--output-format", format])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    String::from_utf8(output).expect("Output should be UTF-8")
+}
+
+fn names(values: &[serde_json::Value]) -> Vec<&str> {
+    values
+        .iter()
+        .map(|v| v["name"].as_str().expect("Bundle should have a name"))
+        .collect()
+}
+
+#[test]
+fn test_list_output_format_json_and_yaml_parse() {
+    let workspace = workspace_with_bundles();
+
+    let json: Vec<serde_json::Value> =
+        serde_json::from_str(&list_output(&workspace, "json")).expect("Output should be JSON");
+    let yaml: Vec<serde_json::Value> =
+        serde_yaml::from_str(&list_output(&workspace, "yaml")).expect("Output should be YAML");
+
+    assert_eq!(names(&json), vec!["local-bundle", "@author/repo"]);
+    assert_eq!(json, yaml);
+    assert_eq!(json[0]["version"], "1.2.0");
+    assert_eq!(json[1]["source"]["type"], "git");
+}
+
+#[test]
+fn test_list_output_format_table_and_plain() {
+    let workspace = workspace_with_bundles();
+
+    assert_eq!(
+        list_output(&workspace, "table"),
+        "NAME          VERSION  FILES  SOURCE\n\
+         local-bundle  1.2.0    2      bundles/local-bundle\n\
+         @author/repo  -        1      https://github.com/author/repo.git@0123456\n"
+    );
+    assert_eq!(
+        list_output(&workspace, "plain"),
+        "local-bundle\tbundles/local-bundle\n\
+         @author/repo\thttps://github.com/author/repo.git@0123456\n"
+    );
+}
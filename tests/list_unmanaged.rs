@@ -0,0 +1,51 @@
+//! Tests for listing platform files not managed by augent
+#![allow(clippy::expect_used)] // Idiomatic for test assertions
+
+mod common;
+
+use predicates::prelude::PredicateBooleanExt;
+
+#[test]
+fn test_list_unmanaged_shows_only_manual_files() {
+    let workspace = common::TestWorkspace::new();
+    workspace.init_from_fixture("empty");
+    workspace.create_agent_dir("cursor");
+
+    workspace.create_bundle("managed-bundle");
+    workspace.write_file("bundles/managed-bundle/commands/managed.md", "# Managed\n");
+
+    common::augent_cmd_for_workspace(&workspace.path)
+        .args([
+            "install",
+            "./bundles/managed-bundle",
+            "--to",
+            "cursor",
+            "-y",
+        ])
+        .assert()
+        .success();
+    assert!(workspace.file_exists(".cursor/commands/managed.md"));
+
+    workspace.write_file(".cursor/commands/manual.md", "# Manual\n");
+
+    common::augent_cmd_for_workspace(&workspace.path)
+        .args(["list", "--unmanaged"])
+        .assert()
+        .success()
+        .stdout(predicates::str::contains("Unmanaged files (1):"))
+        .stdout(predicates::str::contains(".cursor/commands/manual.md"))
+        .stdout(predicates::str::contains(".cursor/commands/managed.md").not());
+}
+
+#[test]
+fn test_list_unmanaged_none() {
+    let workspace = common::TestWorkspace::new();
+    workspace.init_from_fixture("empty");
+    workspace.create_agent_dir("cursor");
+
+    common::augent_cmd_for_workspace(&workspace.path)
+        .args(["list", "--unmanaged"])
+        .assert()
+        .success()
+        .stdout(predicates::str::contains("No unmanaged files found."));
+}
@@ -0,0 +1,46 @@
+//! Tests for `augent platforms --detect`
+#![allow(clippy::expect_used)] // Idiomatic for test assertions
+
+mod common;
+
+use predicates::prelude::PredicateBooleanExt;
+
+#[test]
+fn test_platforms_detect_reports_only_cursor() {
+    let workspace = common::TestWorkspace::new();
+    workspace.init_from_fixture("empty");
+    workspace.create_agent_dir("cursor");
+
+    common::augent_cmd_for_workspace(&workspace.path)
+        .args(["platforms", "--detect"])
+        .assert()
+        .success()
+        .stdout(
+            predicates::str::is_match(r"cursor +Cursor \(\.cursor\): detected, \.cursor exists")
+                .expect("valid regex"),
+        )
+        .stdout(predicates::str::contains(
+            "(.claude): not detected, no .claude directory",
+        ))
+        .stdout(predicates::str::contains("1 platform(s) detected"));
+}
+
+#[test]
+fn test_platforms_lists_custom_platforms_separately() {
+    let workspace = common::TestWorkspace::new();
+    workspace.init_from_fixture("empty");
+    workspace.write_file(
+        "platforms.jsonc",
+        r#"[{"id": "acme", "name": "Acme", "directory": ".acme", "detection": [".acme"], "transforms": []}]"#,
+    );
+
+    common::augent_cmd_for_workspace(&workspace.path)
+        .args(["platforms"])
+        .assert()
+        .success()
+        .stdout(predicates::str::contains(
+            "Custom platforms (platforms.jsonc):",
+        ))
+        .stdout(predicates::str::contains("Acme (.acme)"))
+        .stdout(predicates::str::contains("detected").not());
+}
@@ -0,0 +1,29 @@
+//! Tests for listing a source's declared dependencies with `show --deps`
+#![allow(clippy::expect_used)] // Idiomatic for test assertions
+
+mod common;
+
+use predicates::prelude::PredicateBooleanExt;
+
+#[test]
+fn test_show_deps_lists_declared_dependencies_without_installing() {
+    let workspace = common::TestWorkspace::new();
+    workspace.init_from_fixture("empty");
+    workspace.create_agent_dir("cursor");
+    workspace.create_bundle("tools");
+    workspace.write_file("bundles/tools/commands/review.md", "# Review\n");
+    workspace.write_file(
+        "bundles/tools/augent.yaml",
+        "name: tools\nbundles:\n  - name: shared\n    path: ./bundles/shared\n  - name: \"@acme/lint\"\n    git: https://github.com/acme/lint.git\n    ref: v1.2.0\n",
+    );
+
+    common::augent_cmd_for_workspace(&workspace.path)
+        .args(["show", "./bundles/tools", "--deps"])
+        .assert()
+        .success()
+        .stdout(predicates::str::contains("shared: ./bundles/shared").and(
+            predicates::str::contains("@acme/lint: https://github.com/acme/lint.git#v1.2.0"),
+        ));
+
+    assert!(!workspace.file_exists(".cursor/commands/review.md"));
+}
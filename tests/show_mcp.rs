@@ -0,0 +1,54 @@
+//! Tests for previewing the merged MCP config with `show --mcp`
+#![allow(clippy::expect_used)] // Idiomatic for test assertions
+
+mod common;
+
+const LOCKFILE: &str = r#"{
+  "name": "@test/workspace",
+  "bundles": [
+    {
+      "name": "github-bundle",
+      "source": {
+        "type": "dir",
+        "path": "bundles/github-bundle",
+        "hash": "blake3:abc123"
+      },
+      "files": ["mcp.jsonc"]
+    },
+    {
+      "name": "fs-bundle",
+      "source": {
+        "type": "dir",
+        "path": "bundles/fs-bundle",
+        "hash": "blake3:def456"
+      },
+      "files": ["mcp_servers/filesystem.json"]
+    }
+  ]
+}"#;
+
+#[test]
+fn test_show_mcp_prints_servers_from_all_bundles() {
+    let workspace = common::TestWorkspace::new();
+    workspace.init_from_fixture("empty");
+    workspace.write_file(".augent/augent.lock", LOCKFILE);
+    workspace.write_file(
+        "bundles/github-bundle/mcp.jsonc",
+        "{\n  // GitHub tools\n  \"mcpServers\": {\"github\": {\"command\": \"github-mcp\"}}\n}\n",
+    );
+    workspace.write_file(
+        "bundles/fs-bundle/mcp_servers/filesystem.json",
+        r#"{"command": "fs-mcp"}"#,
+    );
+
+    common::augent_cmd_for_workspace(&workspace.path)
+        .args(["show", "--mcp", "--platform", "claude"])
+        .assert()
+        .success()
+        .stdout(predicates::str::contains("\"github\""))
+        .stdout(predicates::str::contains("github-mcp"))
+        .stdout(predicates::str::contains("\"filesystem\""))
+        .stdout(predicates::str::contains("fs-mcp"));
+
+    assert!(!workspace.file_exists(".mcp.json"));
+}
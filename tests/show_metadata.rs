@@ -0,0 +1,58 @@
+//! Tests for bundle metadata in show and list output
+#![allow(clippy::expect_used)] // Idiomatic for test assertions
+
+mod common;
+
+const LOCKFILE: &str = r#"{
+  "name": "@test/workspace",
+  "bundles": [
+    {
+      "name": "licensed-bundle",
+      "source": {
+        "type": "dir",
+        "path": "bundles/licensed-bundle",
+        "hash": "blake3:abc123"
+      },
+      "files": ["commands/review.md"]
+    }
+  ]
+}"#;
+
+fn setup_workspace() -> common::TestWorkspace {
+    let workspace = common::TestWorkspace::new();
+    workspace.init_from_fixture("empty");
+    workspace.write_file(".augent/augent.lock", LOCKFILE);
+    workspace.write_file(
+        "bundles/licensed-bundle/augent.yaml",
+        "name: licensed-bundle\nauthor: Jane Doe\nlicense: MIT\nhomepage: https://example.com/licensed\n",
+    );
+    workspace.write_file("bundles/licensed-bundle/commands/review.md", "# Review\n");
+    workspace
+}
+
+#[test]
+fn test_show_displays_author_and_license() {
+    let workspace = setup_workspace();
+
+    common::augent_cmd_for_workspace(&workspace.path)
+        .args(["show", "licensed-bundle"])
+        .assert()
+        .success()
+        .stdout(predicates::str::contains("Author:"))
+        .stdout(predicates::str::contains("Jane Doe"))
+        .stdout(predicates::str::contains("License:"))
+        .stdout(predicates::str::contains("MIT"))
+        .stdout(predicates::str::contains("https://example.com/licensed"));
+}
+
+#[test]
+fn test_list_verbose_displays_license() {
+    let workspace = setup_workspace();
+
+    common::augent_cmd_for_workspace(&workspace.path)
+        .args(["list", "--verbose"])
+        .assert()
+        .success()
+        .stdout(predicates::str::contains("Jane Doe"))
+        .stdout(predicates::str::contains("MIT"));
+}
@@ -0,0 +1,52 @@
+//! Tests for rendering a bundle's resource layout with `show --tree`
+#![allow(clippy::expect_used)] // Idiomatic for test assertions
+
+mod common;
+
+const LOCKFILE: &str = r#"{
+  "name": "@test/workspace",
+  "bundles": [
+    {
+      "name": "skills-bundle",
+      "source": {
+        "type": "dir",
+        "path": "bundles/skills-bundle",
+        "hash": "blake3:abc123"
+      },
+      "files": []
+    }
+  ]
+}"#;
+
+#[test]
+fn test_show_tree_lists_leaf_skill_directory() {
+    let workspace = common::TestWorkspace::new();
+    workspace.init_from_fixture("empty");
+    workspace.write_file(".augent/augent.lock", LOCKFILE);
+    workspace.write_file("bundles/skills-bundle/commands/review.md", "# Review\n");
+    workspace.write_file(
+        "bundles/skills-bundle/skills/tools/SKILL.md",
+        "---\nname: tools\ndescription: Parent\n---\n",
+    );
+    workspace.write_file(
+        "bundles/skills-bundle/skills/tools/deploy/SKILL.md",
+        "---\nname: deploy\ndescription: Deploy\n---\n",
+    );
+    workspace.write_file(
+        "bundles/skills-bundle/skills/tools/deploy/scripts/run.sh",
+        "echo deploy\n",
+    );
+
+    common::augent_cmd_for_workspace(&workspace.path)
+        .args(["show", "skills-bundle", "--tree"])
+        .assert()
+        .success()
+        .stdout(predicates::str::contains(
+            "commands/ (1 file)\n  review.md\n",
+        ))
+        .stdout(predicates::str::contains(
+            "    deploy/ (2 files)\n      scripts/ (1 file)\n        run.sh\n      SKILL.md\n",
+        ))
+        // The parent skill is not a leaf, so its SKILL.md is not installed
+        .stdout(predicates::str::contains("  tools/ (2 files)\n"));
+}
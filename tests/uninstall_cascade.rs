@@ -0,0 +1,90 @@
+//! Tests for `augent uninstall --cascade` removing orphaned dependencies
+#![allow(clippy::expect_used)] // Idiomatic for test assertions
+
+mod common;
+
+/// Create bundle `name` depending on the local bundle `b`
+fn create_bundle_depending_on_b(workspace: &common::TestWorkspace, name: &str) {
+    workspace.create_bundle(name);
+    workspace.write_file(&format!("bundles/{name}/commands/{name}.md"), "# Command\n");
+    workspace.write_file(
+        &format!("bundles/{name}/augent.yaml"),
+        &format!("name: {name}\nbundles:\n  - name: b\n    path: ./bundles/b\n"),
+    );
+}
+
+fn setup_workspace(dependents: &[&str]) -> common::TestWorkspace {
+    let workspace = common::TestWorkspace::new();
+    workspace.init_from_fixture("empty");
+    workspace.create_agent_dir("cursor");
+    workspace.create_bundle("b");
+    workspace.write_file("bundles/b/commands/b.md", "# B\n");
+
+    for name in dependents {
+        create_bundle_depending_on_b(&workspace, name);
+        common::augent_cmd_for_workspace(&workspace.path)
+            .args([
+                "install",
+                &format!("./bundles/{name}"),
+                "--to",
+                "cursor",
+                "-y",
+            ])
+            .assert()
+            .success();
+    }
+    assert!(
+        workspace
+            .read_file(".augent/augent.lock")
+            .contains("\"name\": \"b\"")
+    );
+    workspace
+}
+
+#[test]
+fn test_uninstall_cascade_keeps_dependency_still_needed() {
+    let workspace = setup_workspace(&["a", "c"]);
+
+    common::augent_cmd_for_workspace(&workspace.path)
+        .args(["uninstall", "a", "--cascade", "-y"])
+        .assert()
+        .success();
+
+    let lockfile = workspace.read_file(".augent/augent.lock");
+    assert!(!lockfile.contains("\"name\": \"a\""));
+    assert!(lockfile.contains("\"name\": \"b\""));
+    assert!(lockfile.contains("\"name\": \"c\""));
+}
+
+#[test]
+fn test_uninstall_cascade_removes_orphaned_dependency() {
+    let workspace = setup_workspace(&["a"]);
+
+    common::augent_cmd_for_workspace(&workspace.path)
+        .args(["uninstall", "a", "--cascade", "-y"])
+        .assert()
+        .success()
+        .stdout(predicates::str::contains(
+            "Also uninstalling orphaned dependencies: b",
+        ));
+
+    let lockfile = workspace.read_file(".augent/augent.lock");
+    assert!(!lockfile.contains("\"name\": \"a\""));
+    assert!(!lockfile.contains("\"name\": \"b\""));
+}
+
+#[test]
+fn test_uninstall_cascade_fails_when_remaining_bundle_config_missing() {
+    let workspace = setup_workspace(&["a", "c"]);
+    std::fs::remove_dir_all(workspace.path.join("bundles/c")).expect("Failed to remove bundle c");
+
+    common::augent_cmd_for_workspace(&workspace.path)
+        .args(["uninstall", "a", "--cascade", "-y"])
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains("dependencies of 'c'"));
+
+    let lockfile = workspace.read_file(".augent/augent.lock");
+    assert!(lockfile.contains("\"name\": \"a\""));
+    assert!(lockfile.contains("\"name\": \"b\""));
+}